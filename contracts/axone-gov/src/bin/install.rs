@@ -46,6 +46,7 @@ fn install(
             .install_app::<AxoneGovInterface<_>>(
                 &AxoneGovInstantiateMsg {
                     constitution: constitution.clone(),
+                    ..Default::default()
                 },
                 &[],
             )?;
@@ -72,6 +72,7 @@ fn interact(network: ChainInfo, args: Arguments) -> anyhow::Result<()> {
                 &module_execute(AxoneGovExecuteMsg::RecordDecision {
                     case,
                     motivated: Some(motivated),
+                    signature: None,
                 }),
                 &[],
             )?;
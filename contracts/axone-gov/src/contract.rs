@@ -2,7 +2,7 @@ use crate::{
     error::AxoneGovError,
     handlers,
     msg::{AxoneGovExecuteMsg, AxoneGovInstantiateMsg, AxoneGovMigrateMsg, AxoneGovQueryMsg},
-    replies::{self, INSTANTIATE_REPLY_ID},
+    replies::{self, DECISION_HOOK_REPLY_ID, INSTANTIATE_REPLY_ID},
     APP_VERSION, AXONE_GOV_ID, AXONE_GOV_NAME,
 };
 
@@ -36,7 +36,10 @@ const APP: AxoneGov = AxoneGov::new(AXONE_GOV_ID, APP_VERSION, APP_METADATA_URL)
     .with_execute(handlers::execute_handler)
     .with_query(handlers::query_handler)
     .with_migrate(handlers::migrate_handler)
-    .with_replies(&[(INSTANTIATE_REPLY_ID, replies::instantiate_reply)])
+    .with_replies(&[
+        (INSTANTIATE_REPLY_ID, replies::instantiate_reply),
+        (DECISION_HOOK_REPLY_ID, replies::decision_hook_reply),
+    ])
     .with_dependencies(&[]);
 
 // Export handlers
@@ -0,0 +1,95 @@
+use crate::contract::AxoneGovResult;
+use crate::error::AxoneGovError;
+use crate::prolog::ast::Term;
+use crate::prolog::parser::Parser;
+use crate::GOV_VERDICT_GRANT;
+
+/// A capability granted by a `gov:grant(Capability, Holder, Uses)` verdict.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CapabilityGrant {
+    /// The granted capability, as a Prolog term string.
+    pub capability: String,
+    /// The Bech32 address of the holder, as returned by the constitution.
+    pub holder: String,
+    /// The number of times the capability can be consumed.
+    pub uses: u64,
+}
+
+impl CapabilityGrant {
+    /// Interpret a verdict as returned by the logic module.
+    ///
+    /// `Capability` is any ground term, `Holder` an atom holding the Bech32 address of the holder and
+    /// `Uses` a positive integer. Returns `None` if the verdict is not a grant.
+    pub fn from_verdict(verdict: &str) -> AxoneGovResult<Option<Self>> {
+        let Ok(Term::Compound(functor, args)) = Parser::new(verdict).and_then(Parser::parse_root)
+        else {
+            return Ok(None);
+        };
+        if functor != GOV_VERDICT_GRANT || args.len() != 3 {
+            return Ok(None);
+        }
+
+        let invalid = |reason: &str| AxoneGovError::InvalidCapabilityGrant(reason.to_string());
+        if !args[0].is_ground() {
+            return Err(invalid("the capability must be a ground term"));
+        }
+        let Term::Atom(holder) = &args[1] else {
+            return Err(invalid("the holder must be an address atom"));
+        };
+        let uses = args[2]
+            .as_u64()
+            .filter(|uses| *uses > 0)
+            .ok_or_else(|| invalid("the number of uses must be a positive integer"))?;
+
+        Ok(Some(Self {
+            capability: args[0].to_string(),
+            holder: holder.clone(),
+            uses,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interprets_grant_verdicts() {
+        let cases = vec![
+            (
+                "'gov:grant'(read(dataset), alice, 3)",
+                Ok(Some(CapabilityGrant {
+                    capability: "read(dataset)".to_string(),
+                    holder: "alice".to_string(),
+                    uses: 3,
+                })),
+            ),
+            ("'gov:permitted'", Ok(None)),
+            ("'gov:grant'(read, alice)", Ok(None)),
+            ("not a term", Ok(None)),
+            (
+                "'gov:grant'(read(X), alice, 3)",
+                Err("the capability must be a ground term"),
+            ),
+            (
+                "'gov:grant'(read, f(alice), 3)",
+                Err("the holder must be an address atom"),
+            ),
+            (
+                "'gov:grant'(read, alice, 0)",
+                Err("the number of uses must be a positive integer"),
+            ),
+        ];
+
+        for (verdict, expected) in cases {
+            let result = CapabilityGrant::from_verdict(verdict);
+            match expected {
+                Ok(expected) => assert_eq!(result.expect(verdict), expected, "{verdict}"),
+                Err(reason) => {
+                    let err = result.expect_err(verdict);
+                    assert!(err.to_string().contains(reason), "{verdict}: {err}");
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,8 @@
+use std::collections::HashMap;
 use std::fmt::{self};
 
 use crate::contract::AxoneGovResult;
+use crate::domain::schema::{is_implicit_key, CaseSchema};
 use crate::error::AxoneGovError;
 use crate::prolog::ast::Term;
 use crate::prolog::parser::{ParseError, Parser};
@@ -25,6 +27,90 @@ impl Case {
         term.try_into()
     }
 
+    /// The intent of the case, if it holds an `intent` key bound to an atom.
+    pub fn intent(&self) -> Option<&str> {
+        let Term::Dict(_, pairs) = &self.0 else {
+            unreachable!("Case invariant violated: expected Prolog dict");
+        };
+
+        pairs.iter().find_map(|(key, value)| match value {
+            Term::Atom(intent) if key == "intent" => Some(intent.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Returns true if the case holds the given key.
+    pub fn contains_key(&self, key: &str) -> bool {
+        let Term::Dict(_, pairs) = &self.0 else {
+            unreachable!("Case invariant violated: expected Prolog dict");
+        };
+
+        pairs.iter().any(|(k, _)| k == key)
+    }
+
+    /// The first key for which `matches` returns true, if any.
+    pub fn find_key(&self, matches: impl Fn(&str) -> bool) -> Option<&str> {
+        let Term::Dict(_, pairs) = &self.0 else {
+            unreachable!("Case invariant violated: expected Prolog dict");
+        };
+
+        pairs
+            .iter()
+            .map(|(key, _)| key.as_str())
+            .find(|key| matches(key))
+    }
+
+    /// Validate the case against the schema declared for its intent.
+    ///
+    /// Returns an `InvalidCase` error if a required key is missing, a value is not of the declared
+    /// kind, or the case holds a key the schema does not declare. The `intent` key, the keys
+    /// injected by the contract and the keys of the `enrichment` merged into the case are always
+    /// accepted.
+    pub fn validate_schema(&self, schema: &CaseSchema, enrichment: &Case) -> AxoneGovResult<()> {
+        let Term::Dict(_, pairs) = &self.0 else {
+            unreachable!("Case invariant violated: expected Prolog dict");
+        };
+        let intent = &schema.intent;
+
+        for field in &schema.fields {
+            match pairs.iter().find(|(key, _)| *key == field.key) {
+                Some((key, value)) if !field.kind.matches(value) => {
+                    return Err(AxoneGovError::InvalidCase(format!(
+                        "key '{key}' of intent '{intent}' must be of kind {}, got {value}",
+                        field.kind
+                    )))
+                }
+                None if field.required => {
+                    return Err(AxoneGovError::InvalidCase(format!(
+                        "missing required key '{}' for intent '{intent}'",
+                        field.key
+                    )))
+                }
+                _ => {}
+            }
+        }
+
+        match pairs.iter().find(|(key, _)| {
+            !is_implicit_key(key)
+                && !enrichment.contains_key(key)
+                && !schema.fields.iter().any(|field| field.key == *key)
+        }) {
+            Some((key, _)) => Err(AxoneGovError::InvalidCase(format!(
+                "unexpected key '{key}' for intent '{intent}'"
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Remove the keys for which `remove` returns true.
+    pub fn remove_keys(&mut self, remove: impl Fn(&str) -> bool) {
+        let Term::Dict(_, pairs) = &mut self.0 else {
+            unreachable!("Case invariant violated: expected Prolog dict");
+        };
+
+        pairs.retain(|(key, _)| !remove(key));
+    }
+
     /// Merge another case into this one.
     ///
     /// Keys from `other` replace keys in `self` if they collide. The dictionary
@@ -65,6 +151,77 @@ impl TryFrom<Term> for Case {
     }
 }
 
+/// A pattern selecting cases, as a Prolog dictionary which may hold variables.
+///
+/// A case matches the pattern if it holds every key of the pattern with a matching value, whatever
+/// its other keys and its tag. Nested dictionaries match the same way. Variables match any term,
+/// consistently across the pattern, except `_` which is always fresh.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CasePattern(Term);
+
+impl CasePattern {
+    /// Parse a case pattern from a string.
+    pub fn new(input: &str) -> AxoneGovResult<Self> {
+        match parse_term(input)? {
+            term @ Term::Dict(_, _) => Ok(Self(term)),
+            _ => Err(AxoneGovError::InvalidCase(
+                "case pattern must be a Prolog dict".to_string(),
+            )),
+        }
+    }
+
+    /// Returns true if the case matches the pattern.
+    pub fn matches(&self, case: &Case) -> bool {
+        match_term(&self.0, &case.0, &mut HashMap::new())
+    }
+}
+
+fn match_term<'a>(
+    pattern: &'a Term,
+    term: &'a Term,
+    bindings: &mut HashMap<&'a str, &'a Term>,
+) -> bool {
+    match (pattern, term) {
+        (Term::Variable(name), _) if name == "_" => true,
+        (Term::Variable(name), _) => match bindings.get(name.as_str()) {
+            Some(bound) => *bound == term,
+            None => {
+                bindings.insert(name, term);
+                true
+            }
+        },
+        (Term::Dict(_, pattern_pairs), Term::Dict(_, pairs)) => {
+            pattern_pairs.iter().all(|(key, pattern_value)| {
+                pairs
+                    .iter()
+                    .find(|(k, _)| k == key)
+                    .is_some_and(|(_, value)| match_term(pattern_value, value, bindings))
+            })
+        }
+        (Term::Compound(pattern_functor, pattern_args), Term::Compound(functor, args)) => {
+            pattern_functor == functor
+                && pattern_args.len() == args.len()
+                && pattern_args
+                    .iter()
+                    .zip(args)
+                    .all(|(p, t)| match_term(p, t, bindings))
+        }
+        (Term::List(pattern_items, pattern_tail), Term::List(items, tail)) => {
+            pattern_items.len() == items.len()
+                && pattern_items
+                    .iter()
+                    .zip(items)
+                    .all(|(p, t)| match_term(p, t, bindings))
+                && match (pattern_tail, tail) {
+                    (Some(p), Some(t)) => match_term(p, t, bindings),
+                    (None, None) => true,
+                    _ => false,
+                }
+        }
+        _ => pattern == term,
+    }
+}
+
 fn validate_case(term: &Term) -> AxoneGovResult<()> {
     match term {
         Term::Dict(_, _) => {
@@ -89,3 +246,95 @@ fn parse_term(input: &str) -> AxoneGovResult<Term> {
 fn parse_error(err: ParseError) -> AxoneGovError {
     AxoneGovError::InvalidCase(format!("syntax error at offset {}: {}", err.at, err.msg))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::schema::{FieldSchema, ValueKind};
+
+    #[test]
+    fn matches_case_patterns() {
+        let case = Case::new(
+            "case{intent: transfer, amount: 10, to: bob, 'gov:signer': signer{address: alice, nonce: 1}}",
+        )
+        .expect("valid case");
+
+        let cases = vec![
+            ("ctx{}", true),
+            ("ctx{intent: transfer}", true),
+            ("ctx{intent: withdraw}", false),
+            ("ctx{intent: transfer, amount: A}", true),
+            ("ctx{amount: X, to: X}", false),
+            ("ctx{amount: _, to: _}", true),
+            ("ctx{'gov:signer': signer{address: alice}}", true),
+            ("ctx{'gov:signer': signer{address: bob}}", false),
+            ("ctx{memo: _}", false),
+        ];
+
+        for (pattern, expected) in cases {
+            assert_eq!(
+                CasePattern::new(pattern)
+                    .expect("valid pattern")
+                    .matches(&case),
+                expected,
+                "unexpected match of {pattern}"
+            );
+        }
+
+        CasePattern::new("transfer").expect_err("not a dict");
+    }
+
+    #[test]
+    fn validates_case_against_schema() {
+        let schema = CaseSchema {
+            intent: "transfer".to_string(),
+            fields: vec![
+                FieldSchema {
+                    key: "amount".to_string(),
+                    kind: ValueKind::Integer,
+                    required: true,
+                },
+                FieldSchema {
+                    key: "memo".to_string(),
+                    kind: ValueKind::Atom,
+                    required: false,
+                },
+            ],
+        };
+
+        let cases = vec![
+            ("ctx{intent: transfer, amount: 10}", None),
+            (
+                "ctx{intent: transfer, amount: 10, memo: rent, 'gov:module': m}",
+                None,
+            ),
+            ("ctx{intent: transfer, amount: 10, roles: [admin]}", None),
+            (
+                "ctx{intent: transfer}",
+                Some("missing required key 'amount' for intent 'transfer'"),
+            ),
+            (
+                "ctx{intent: transfer, amount: ten}",
+                Some("key 'amount' of intent 'transfer' must be of kind integer, got ten"),
+            ),
+            (
+                "ctx{intent: transfer, amount: 10, amout: 10}",
+                Some("unexpected key 'amout' for intent 'transfer'"),
+            ),
+        ];
+
+        let enrichment = Case::new("ctx{roles: [admin]}").expect("valid enrichment");
+        for (input, expected) in cases {
+            let result = Case::new(input)
+                .expect("valid case")
+                .validate_schema(&schema, &enrichment);
+            assert_eq!(
+                result,
+                expected.map_or(Ok(()), |msg| Err(AxoneGovError::InvalidCase(
+                    msg.to_string()
+                ))),
+                "unexpected validation of {input}"
+            );
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use crate::contract::AxoneGovResult;
+use crate::domain::schema::is_implicit_key;
+use crate::error::AxoneGovError;
+use crate::prolog::ast::Term;
+use crate::prolog::term as t;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Api;
+use std::collections::BTreeSet;
+
+/// The maximum number of cases recorded by a single `RecordDecisions` message.
+pub const MAX_BATCH_SIZE: u32 = 50;
+/// The maximum number of enrichment providers.
+pub const MAX_ENRICHMENT_PROVIDERS: usize = 5;
+
+/// A contract providing facts to enrich the cases recorded by `RecordDecision` and
+/// `RecordDecisions`.
+///
+/// The provider is queried with an `EnrichmentQueryMsg`, and the Prolog term it returns is merged
+/// into the recorded cases under `key`.
+#[cw_serde]
+pub struct EnrichmentProvider {
+    /// The case key the provided term is set to.
+    pub key: String,
+    /// The Bech32 address of the provider contract.
+    pub contract: String,
+}
+
+/// The configuration of the contract, besides its constitution.
+#[cw_serde]
+pub struct Config {
+    /// The maximum number of cases recorded by a single `RecordDecisions` message, up to 50.
+    pub max_batch_size: u32,
+    /// Optional Bech32 address receiving the decision fees, instead of the hosting account.
+    pub fee_recipient: Option<String>,
+    /// The contracts enriching the recorded cases, at most 5.
+    pub enrichment_providers: Vec<EnrichmentProvider>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            max_batch_size: MAX_BATCH_SIZE,
+            fee_recipient: None,
+            enrichment_providers: Vec::new(),
+        }
+    }
+}
+
+impl Config {
+    /// Ensure the configuration is well-formed, with valid addresses.
+    pub fn validate(&self, api: &dyn Api) -> AxoneGovResult<()> {
+        let invalid = |reason: String| AxoneGovError::InvalidConfig(reason);
+
+        if self.max_batch_size == 0 || self.max_batch_size > MAX_BATCH_SIZE {
+            return Err(invalid(format!(
+                "max_batch_size must be between 1 and {MAX_BATCH_SIZE}, got {}",
+                self.max_batch_size
+            )));
+        }
+        if let Some(recipient) = &self.fee_recipient {
+            api.addr_validate(recipient)
+                .map_err(|err| invalid(format!("invalid fee_recipient: {err}")))?;
+        }
+        if self.enrichment_providers.len() > MAX_ENRICHMENT_PROVIDERS {
+            return Err(invalid(format!(
+                "at most {MAX_ENRICHMENT_PROVIDERS} enrichment providers are allowed"
+            )));
+        }
+
+        let mut keys = BTreeSet::new();
+        for provider in &self.enrichment_providers {
+            if provider.key.is_empty() || is_implicit_key(&provider.key) {
+                return Err(invalid(format!(
+                    "enrichment key '{}' is reserved",
+                    provider.key
+                )));
+            }
+            if !keys.insert(provider.key.as_str()) {
+                return Err(invalid(format!(
+                    "enrichment key '{}' is provided more than once",
+                    provider.key
+                )));
+            }
+            api.addr_validate(&provider.contract).map_err(|err| {
+                invalid(format!(
+                    "invalid enrichment provider for '{}': {err}",
+                    provider.key
+                ))
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Whether a case key is set by one of the configured enrichment providers.
+    pub fn is_enrichment_key(&self, key: &str) -> bool {
+        self.enrichment_providers
+            .iter()
+            .any(|provider| provider.key == key)
+    }
+
+    /// The term describing the configuration to the constitution.
+    pub fn term(&self) -> Term {
+        let mut pairs = vec![t::kv("max_batch_size", self.max_batch_size.into())];
+        if let Some(recipient) = &self.fee_recipient {
+            pairs.push(t::kv("fee_recipient", t::atom(recipient)));
+        }
+        pairs.push(t::kv(
+            "enrichment_providers",
+            t::list(
+                self.enrichment_providers
+                    .iter()
+                    .map(|provider| {
+                        t::dict(
+                            "provider",
+                            vec![
+                                t::kv("key", t::atom(&provider.key)),
+                                t::kv("contract", t::atom(&provider.contract)),
+                            ],
+                        )
+                    })
+                    .collect(),
+            ),
+        ));
+
+        t::dict("config", pairs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::MockApi;
+
+    #[test]
+    fn validates_config() {
+        let api = MockApi::default();
+        let provider = |key: &str| EnrichmentProvider {
+            key: key.to_string(),
+            contract: api.addr_make("provider").to_string(),
+        };
+        let cases = vec![
+            (Config::default(), None),
+            (
+                Config {
+                    max_batch_size: 10,
+                    fee_recipient: Some(api.addr_make("treasury").to_string()),
+                    enrichment_providers: vec![provider("roles"), provider("kyc")],
+                },
+                None,
+            ),
+            (
+                Config {
+                    max_batch_size: 0,
+                    ..Config::default()
+                },
+                Some("max_batch_size must be between 1 and 50, got 0"),
+            ),
+            (
+                Config {
+                    max_batch_size: 51,
+                    ..Config::default()
+                },
+                Some("max_batch_size must be between 1 and 50, got 51"),
+            ),
+            (
+                Config {
+                    fee_recipient: Some("not-an-address".to_string()),
+                    ..Config::default()
+                },
+                Some("invalid fee_recipient"),
+            ),
+            (
+                Config {
+                    enrichment_providers: vec![provider("gov:roles")],
+                    ..Config::default()
+                },
+                Some("enrichment key 'gov:roles' is reserved"),
+            ),
+            (
+                Config {
+                    enrichment_providers: vec![provider("intent")],
+                    ..Config::default()
+                },
+                Some("enrichment key 'intent' is reserved"),
+            ),
+            (
+                Config {
+                    enrichment_providers: vec![provider("roles"), provider("roles")],
+                    ..Config::default()
+                },
+                Some("enrichment key 'roles' is provided more than once"),
+            ),
+            (
+                Config {
+                    enrichment_providers: (0..6).map(|i| provider(&format!("k{i}"))).collect(),
+                    ..Config::default()
+                },
+                Some("at most 5 enrichment providers"),
+            ),
+        ];
+
+        for (config, expected) in cases {
+            let result = config.validate(&api);
+            match expected {
+                None => result.unwrap_or_else(|err| panic!("{config:?}: {err}")),
+                Some(reason) => {
+                    let err = result.expect_err(reason);
+                    assert!(err.to_string().contains(reason), "{config:?}: {err}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn describes_config() {
+        let config = Config {
+            max_batch_size: 10,
+            fee_recipient: Some("treasury".to_string()),
+            enrichment_providers: vec![EnrichmentProvider {
+                key: "roles".to_string(),
+                contract: "provider".to_string(),
+            }],
+        };
+        assert_eq!(
+            config.term().to_string(),
+            "config{max_batch_size: 10, fee_recipient: treasury, enrichment_providers: [provider{key: roles, contract: provider}]}"
+        );
+        assert_eq!(
+            Config::default().term().to_string(),
+            "config{max_batch_size: 50, enrichment_providers: []}"
+        );
+    }
+}
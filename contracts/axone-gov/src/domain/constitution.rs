@@ -1,47 +1,115 @@
 use crate::contract::AxoneGovResult;
+use crate::domain::library::{compose_program, extract_library_directives, Library, LibraryPin};
+use crate::domain::limits::{parse_decision_limits, DecisionLimits};
+use crate::domain::schema::{
+    collected_declarations, ensure_schemas_declared, parse_case_schemas, parse_intent_declarations,
+    CaseSchema, IntentDeclaration,
+};
+use crate::domain::Case;
 use crate::error::AxoneGovError;
 use crate::gateway::logic::{query_service_ask, AxoneLogicQuery, QueryServiceAskRequest};
-use crate::queries::validation::build_required_predicates_query;
+use crate::queries::validation::{
+    build_constitution_validation_query, CASE_SCHEMAS_VARIABLE, DECISION_LIMITS_VARIABLE,
+    INTENTS_VARIABLE,
+};
 use crate::state::StateAccess;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{to_hex, Binary, Checksum, QuerierWrapper};
 use getset::{CopyGetters, Getters};
 
 const REQUIRED_PREDICATES: [&str; 2] = ["decide/2", "decide/3"];
+const INTENT_KEY: &str = "intent";
 
 /// A governance constitution as a Prolog program.
 ///
 /// Represents the governance rules that define how decisions are made
 /// within the axone-gov contract.
+///
+/// A constitution may import library modules through `:- use_library(Name).` directives. Libraries
+/// are resolved and pinned by hash when the constitution is created, and the program evaluated by the
+/// logic module is the composition of the pinned libraries followed by the constitution itself.
+///
+/// The `intent/2` and `case_schema/2` declarations of the constitution are kept along with it, so that
+/// cases can be validated before being decided, as are the `decision_limit/1` declarations bounding
+/// the recorded decisions.
 #[derive(Clone, Debug, Getters, PartialEq)]
 pub struct Constitution {
     #[getset(get = "pub")]
     bytes: Binary,
+    #[getset(get = "pub")]
+    libraries: Vec<Library>,
+    #[getset(get = "pub")]
+    intents: Vec<IntentDeclaration>,
+    #[getset(get = "pub")]
+    schemas: Vec<CaseSchema>,
+    #[getset(get = "pub")]
+    limits: DecisionLimits,
+    program: String,
 }
 
 impl Constitution {
-    /// Reconstruct a Constitution from bytes previously stored in contract state.
-    pub(crate) fn from_state(bytes: Binary, _access: &StateAccess) -> Self {
-        Self { bytes }
+    /// Reconstruct a Constitution from bytes, libraries, intents, case schemas and decision limits
+    /// previously stored in contract state.
+    pub(crate) fn from_state(
+        bytes: Binary,
+        libraries: Vec<Library>,
+        intents: Vec<IntentDeclaration>,
+        schemas: Vec<CaseSchema>,
+        limits: DecisionLimits,
+        _access: &StateAccess,
+    ) -> AxoneGovResult<Self> {
+        let source = std::str::from_utf8(bytes.as_slice())
+            .map_err(|err| AxoneGovError::ConstitutionUtf8(err.to_string()))?;
+        let (_, body) = extract_library_directives(source)?;
+        let program = compose_program(&libraries, &body);
+
+        Ok(Self {
+            bytes,
+            libraries,
+            intents,
+            schemas,
+            limits,
+            program,
+        })
     }
 
     /// Create a new Constitution from a Prolog program.
     ///
+    /// The `resolve_library` function resolves a library directive (name and optional pinned hash)
+    /// to the stored library module.
+    ///
     /// Returns an error if:
     /// - The bytes are not valid UTF-8
+    /// - A library directive is malformed or references an unknown library
     /// - The Prolog program is syntactically invalid
     /// - The required predicates are missing
     /// - The Prolog engine encounters an error during validation
-    pub fn try_new(
+    pub fn try_new<F>(
         bytes: Binary,
+        resolve_library: F,
         querier: &QuerierWrapper<'_, AxoneLogicQuery>,
-    ) -> AxoneGovResult<Self> {
+    ) -> AxoneGovResult<Self>
+    where
+        F: Fn(&str, Option<&[u8; 32]>) -> AxoneGovResult<Library>,
+    {
         let source = std::str::from_utf8(bytes.as_slice())
-            .map(ToString::to_string)
             .map_err(|err| AxoneGovError::ConstitutionUtf8(err.to_string()))?;
 
-        let query = build_required_predicates_query(&REQUIRED_PREDICATES);
-        let request = QueryServiceAskRequest::one(source, query);
+        let (directives, body) = extract_library_directives(source)?;
+        let mut libraries: Vec<Library> = Vec::with_capacity(directives.len());
+        for directive in directives {
+            if libraries.iter().any(|lib| *lib.name() == directive.name) {
+                return Err(AxoneGovError::ConstitutionInvalid(format!(
+                    "library '{}' is used more than once",
+                    directive.name
+                )));
+            }
+            libraries.push(resolve_library(&directive.name, directive.hash.as_ref())?);
+        }
+        let program = compose_program(&libraries, &body);
+
+        let query = build_constitution_validation_query(&REQUIRED_PREDICATES);
+        let request = QueryServiceAskRequest::one(program.as_str(), query);
         let response = query_service_ask(querier, request)
             .map_err(|err| AxoneGovError::PrologEngineError(err.to_string()))?;
 
@@ -67,7 +135,83 @@ impl Constitution {
             )));
         }
 
-        Ok(Self { bytes })
+        let substitutions = &answer.results[0].substitutions;
+        let intents =
+            parse_intent_declarations(&collected_declarations(substitutions, INTENTS_VARIABLE)?)?;
+        let schemas = parse_case_schemas(&collected_declarations(
+            substitutions,
+            CASE_SCHEMAS_VARIABLE,
+        )?)?;
+        ensure_schemas_declared(&intents, &schemas)?;
+        let limits = parse_decision_limits(&collected_declarations(
+            substitutions,
+            DECISION_LIMITS_VARIABLE,
+        )?)?;
+
+        Ok(Self {
+            bytes,
+            libraries,
+            intents,
+            schemas,
+            limits,
+            program,
+        })
+    }
+
+    /// The case schema declared for `intent`, if any.
+    pub fn case_schema(&self, intent: &str) -> Option<&CaseSchema> {
+        self.schemas.iter().find(|schema| schema.intent == intent)
+    }
+
+    /// Validate a caller-provided case, once merged with the contract `enrichment`, against the
+    /// intents and case schemas of the constitution.
+    ///
+    /// When the constitution declares the intents it handles, the case must hold one of them as an
+    /// atom under its `intent` key. The case is then validated against the schema declared for its
+    /// intent, if any, the keys of the enrichment being accepted.
+    pub fn validate_case(&self, case: &Case, enrichment: &Case) -> AxoneGovResult<()> {
+        if !self.intents.is_empty() {
+            let intent = match case.intent() {
+                Some(intent) => intent,
+                None if case.contains_key(INTENT_KEY) => {
+                    return Err(AxoneGovError::InvalidCase(
+                        "the case intent must be an atom".to_string(),
+                    ))
+                }
+                None => {
+                    return Err(AxoneGovError::InvalidCase(
+                        "missing intent, the constitution only decides declared intents"
+                            .to_string(),
+                    ))
+                }
+            };
+            if !self
+                .intents
+                .iter()
+                .any(|declared| declared.intent == intent)
+            {
+                return Err(AxoneGovError::InvalidCase(format!(
+                    "intent '{intent}' is not declared by the constitution"
+                )));
+            }
+        }
+
+        self.validate_schema(case, enrichment)
+    }
+
+    /// Validate the case of a governance act against the schema declared for its intent, if any.
+    ///
+    /// The intent of a governance act is set by the contract, which is why it does not need to be
+    /// declared by the constitution.
+    pub fn validate_governance_case(&self, case: &Case) -> AxoneGovResult<()> {
+        self.validate_schema(case, &Case::default())
+    }
+
+    fn validate_schema(&self, case: &Case, enrichment: &Case) -> AxoneGovResult<()> {
+        match case.intent().and_then(|intent| self.case_schema(intent)) {
+            Some(schema) => case.validate_schema(schema, enrichment),
+            None => Ok(()),
+        }
     }
 
     /// Get the constitution as a UTF-8 string.
@@ -75,6 +219,18 @@ impl Constitution {
         self.as_ref()
     }
 
+    /// Get the effective program evaluated by the logic module.
+    ///
+    /// This is the constitution source itself when it uses no library.
+    pub fn program(&self) -> &str {
+        &self.program
+    }
+
+    /// The library references pinned by this constitution, in order of use.
+    pub fn library_pins(&self) -> Vec<LibraryPin> {
+        self.libraries.iter().map(Library::pin).collect()
+    }
+
     /// Compute the SHA256 hash of the constitution bytes.
     pub fn hash(&self) -> [u8; 32] {
         *Checksum::generate(self.bytes.as_slice()).as_ref()
@@ -107,13 +263,17 @@ pub struct ConstitutionStatus {
     constitution_revision: u64,
     #[getset(get = "pub")]
     constitution_hash: [u8; 32],
+    #[serde(default)]
+    #[getset(get = "pub")]
+    libraries: Vec<LibraryPin>,
 }
 
 impl ConstitutionStatus {
-    pub fn new(constitution_revision: u64, constitution_hash: [u8; 32]) -> Self {
+    pub fn new(constitution_revision: u64, constitution: &Constitution) -> Self {
         Self {
             constitution_revision,
-            constitution_hash,
+            constitution_hash: constitution.hash(),
+            libraries: constitution.library_pins(),
         }
     }
 
@@ -1,4 +1,6 @@
 use crate::domain::constitution::ConstitutionStatus;
+use crate::domain::effective::EffectivePeriod;
+use crate::domain::library::LibraryPin;
 use cosmwasm_std::Addr;
 use getset::{CopyGetters, Getters};
 
@@ -9,6 +11,8 @@ pub struct Decision {
     #[getset(get_copy = "pub")]
     constitution_hash: [u8; 32],
     #[getset(get = "pub")]
+    libraries: Vec<LibraryPin>,
+    #[getset(get = "pub")]
     case: String,
     #[getset(get = "pub")]
     verdict: String,
@@ -20,6 +24,10 @@ pub struct Decision {
     height: u64,
     #[getset(get_copy = "pub")]
     time_seconds: u64,
+    #[getset(get_copy = "pub")]
+    appeal_of: Option<u64>,
+    #[getset(get_copy = "pub")]
+    effective_period: Option<EffectivePeriod>,
 }
 
 impl Decision {
@@ -36,12 +44,27 @@ impl Decision {
         Self {
             constitution_revision: constitution_status.constitution_revision(),
             constitution_hash: *constitution_status.constitution_hash(),
+            libraries: constitution_status.libraries().clone(),
             case,
             verdict,
             motivation,
             author,
             height,
             time_seconds,
+            appeal_of: None,
+            effective_period: None,
         }
     }
+
+    /// Set the period during which the decision is in force, if restricted by the constitution.
+    pub fn effective_during(mut self, period: Option<EffectivePeriod>) -> Self {
+        self.effective_period = period;
+        self
+    }
+
+    /// Mark the decision as an appeal of the decision `decision_id`.
+    pub fn appealing(mut self, decision_id: u64) -> Self {
+        self.appeal_of = Some(decision_id);
+        self
+    }
 }
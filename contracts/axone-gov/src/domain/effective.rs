@@ -0,0 +1,192 @@
+use crate::contract::AxoneGovResult;
+use crate::error::AxoneGovError;
+use crate::prolog::ast::Term;
+use crate::prolog::parser::Parser;
+use crate::GOV_VERDICT_EFFECTIVE;
+use cosmwasm_schema::cw_serde;
+
+/// The period during which a decision is in force, in block time seconds.
+#[cw_serde]
+#[derive(Copy)]
+pub struct EffectivePeriod {
+    /// The time from which the decision is in force (inclusive).
+    pub from_seconds: u64,
+    /// The time from which the decision is no longer in force (exclusive), if any.
+    pub until_seconds: Option<u64>,
+}
+
+impl EffectivePeriod {
+    /// Interpret the period of a `gov:effective(Verdict, Period)` verdict decided at `decided_at`.
+    ///
+    /// `Period` is one of `until(Until)`, `for(Seconds)`, `from(From)` or `period(From, Until)`, with
+    /// times in seconds since epoch. The period starts at `decided_at` unless stated otherwise.
+    fn try_from_term(term: &Term, decided_at: u64) -> AxoneGovResult<Self> {
+        let invalid = || {
+            AxoneGovError::InvalidEffectivePeriod(format!(
+                "expected until(Until), for(Seconds), from(From) or period(From, Until) with non-negative integers, got {term}"
+            ))
+        };
+        let Term::Compound(functor, args) = term else {
+            return Err(invalid());
+        };
+        let times = args
+            .iter()
+            .map(Term::as_u64)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(invalid)?;
+
+        let period = match (functor.as_str(), times.as_slice()) {
+            ("until", [until]) => Self {
+                from_seconds: decided_at,
+                until_seconds: Some(*until),
+            },
+            ("for", [seconds]) => Self {
+                from_seconds: decided_at,
+                until_seconds: Some(decided_at.checked_add(*seconds).ok_or_else(invalid)?),
+            },
+            ("from", [from]) => Self {
+                from_seconds: *from,
+                until_seconds: None,
+            },
+            ("period", [from, until]) => Self {
+                from_seconds: *from,
+                until_seconds: Some(*until),
+            },
+            _ => return Err(invalid()),
+        };
+
+        if let Some(until) = period.until_seconds {
+            if until <= period.from_seconds {
+                return Err(AxoneGovError::InvalidEffectivePeriod(format!(
+                    "the period ends ({until}) before it starts ({})",
+                    period.from_seconds
+                )));
+            }
+        }
+
+        Ok(period)
+    }
+
+    /// Whether the period covers the time `at`.
+    pub fn covers(&self, at: u64) -> bool {
+        self.from_seconds <= at && self.until_seconds.is_none_or(|until| at < until)
+    }
+}
+
+/// Split a verdict returned by the logic module into the recorded verdict and its effective period.
+///
+/// A `gov:effective(Verdict, Period)` verdict is recorded as `Verdict` in force during `Period`; any
+/// other verdict is recorded as is, in force from `decided_at` on.
+pub fn split_effective_verdict(
+    verdict: String,
+    decided_at: u64,
+) -> AxoneGovResult<(String, Option<EffectivePeriod>)> {
+    match Parser::new(&verdict).and_then(Parser::parse_root) {
+        Ok(Term::Compound(functor, args))
+            if functor == GOV_VERDICT_EFFECTIVE && args.len() == 2 =>
+        {
+            let period = EffectivePeriod::try_from_term(&args[1], decided_at)?;
+            Ok((args[0].to_string(), Some(period)))
+        }
+        _ => Ok((verdict, None)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DECIDED_AT: u64 = 1_000;
+
+    #[test]
+    fn splits_effective_verdicts() {
+        let cases = vec![
+            ("allowed", Ok(("allowed", None))),
+            (
+                "'gov:effective'('gov:permitted', until(2000))",
+                Ok((
+                    "'gov:permitted'",
+                    Some(EffectivePeriod {
+                        from_seconds: DECIDED_AT,
+                        until_seconds: Some(2_000),
+                    }),
+                )),
+            ),
+            (
+                "'gov:effective'(grant(read), for(30))",
+                Ok((
+                    "grant(read)",
+                    Some(EffectivePeriod {
+                        from_seconds: DECIDED_AT,
+                        until_seconds: Some(1_030),
+                    }),
+                )),
+            ),
+            (
+                "'gov:effective'(allowed, from(5000))",
+                Ok((
+                    "allowed",
+                    Some(EffectivePeriod {
+                        from_seconds: 5_000,
+                        until_seconds: None,
+                    }),
+                )),
+            ),
+            (
+                "'gov:effective'(allowed, period(10, 20))",
+                Ok((
+                    "allowed",
+                    Some(EffectivePeriod {
+                        from_seconds: 10,
+                        until_seconds: Some(20),
+                    }),
+                )),
+            ),
+            (
+                "'gov:effective'(allowed, until(500))",
+                Err("the period ends (500) before it starts (1000)"),
+            ),
+            (
+                "'gov:effective'(allowed, forever)",
+                Err("expected until(Until)"),
+            ),
+            (
+                "'gov:effective'(allowed, for(-1))",
+                Err("expected until(Until)"),
+            ),
+        ];
+
+        for (verdict, expected) in cases {
+            let result = split_effective_verdict(verdict.to_string(), DECIDED_AT);
+            match expected {
+                Ok((expected_verdict, expected_period)) => assert_eq!(
+                    result.expect(verdict),
+                    (expected_verdict.to_string(), expected_period),
+                    "unexpected split of {verdict}"
+                ),
+                Err(reason) => {
+                    let err = result.expect_err(verdict);
+                    assert!(err.to_string().contains(reason), "{verdict}: {err}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn covers_times_within_period() {
+        let period = EffectivePeriod {
+            from_seconds: 10,
+            until_seconds: Some(20),
+        };
+        assert!(!period.covers(9));
+        assert!(period.covers(10));
+        assert!(period.covers(19));
+        assert!(!period.covers(20));
+
+        let open = EffectivePeriod {
+            from_seconds: 10,
+            until_seconds: None,
+        };
+        assert!(open.covers(u64::MAX));
+    }
+}
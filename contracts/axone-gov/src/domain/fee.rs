@@ -0,0 +1,115 @@
+use crate::contract::AxoneGovResult;
+use crate::error::AxoneGovError;
+use crate::prolog::ast::Term;
+use cosmwasm_std::{Coin, Coins, StdError, Uint128};
+
+const COIN_FUNCTOR: &str = "coin";
+
+/// Parse the fee returned by `decision_fee/2`: a list of `coin(Amount, Denom)` terms, with
+/// `Amount` a non-negative integer and `Denom` an atom.
+pub(crate) fn parse_decision_fee(term: &Term) -> AxoneGovResult<Coins> {
+    let invalid = || {
+        AxoneGovError::InvalidDecisionFee(format!(
+            "expected a list of coin(Amount, Denom) terms, got {term}"
+        ))
+    };
+    let Term::List(items, None) = term else {
+        return Err(invalid());
+    };
+
+    let mut fee = Coins::default();
+    for item in items {
+        let (amount, denom) = match item {
+            Term::Compound(functor, args) if functor == COIN_FUNCTOR && args.len() == 2 => {
+                match (&args[0], &args[1]) {
+                    (Term::Integer(amount), Term::Atom(denom)) => (amount, denom),
+                    _ => return Err(invalid()),
+                }
+            }
+            _ => return Err(invalid()),
+        };
+        let amount = amount.to_string().parse::<u128>().map_err(|_| invalid())?;
+        fee.add(Coin::new(Uint128::new(amount), denom))?;
+    }
+
+    Ok(fee)
+}
+
+/// Compute the funds to refund once the required fee is collected from the funds sent along with a
+/// message.
+pub fn fee_refund(required: &Coins, funds: &[Coin]) -> AxoneGovResult<Vec<Coin>> {
+    let insufficient = || AxoneGovError::InsufficientFee {
+        required: required.to_string(),
+        paid: Coins::try_from(funds.to_vec())
+            .map(|paid| paid.to_string())
+            .unwrap_or_default(),
+    };
+
+    let mut remaining =
+        Coins::try_from(funds.to_vec()).map_err(|err| StdError::generic_err(err.to_string()))?;
+    for coin in required.iter() {
+        remaining.sub(coin.clone()).map_err(|_| insufficient())?;
+    }
+
+    Ok(remaining.into_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prolog::parser::Parser;
+    use cosmwasm_std::coin;
+
+    #[test]
+    fn parses_decision_fees() {
+        let cases = vec![
+            ("[]", Ok(Vec::<Coin>::new())),
+            ("[coin(100, uaxone)]", Ok(vec![coin(100, "uaxone")])),
+            (
+                "[coin(100, uaxone), coin(5, uatom), coin(20, uaxone)]",
+                Ok(vec![coin(5, "uatom"), coin(120, "uaxone")]),
+            ),
+            ("coin(100, uaxone)", Err("expected a list")),
+            ("[coin(-1, uaxone)]", Err("expected a list")),
+            ("[coin(uaxone, 100)]", Err("expected a list")),
+        ];
+
+        for (fee, expected) in cases {
+            let term = Parser::new(fee)
+                .and_then(Parser::parse_root)
+                .expect("valid term");
+            let result = parse_decision_fee(&term);
+            match expected {
+                Ok(expected) => assert_eq!(result.expect(fee).into_vec(), expected, "{fee}"),
+                Err(reason) => {
+                    let err = result.expect_err(fee);
+                    assert!(err.to_string().contains(reason), "{fee}: {err}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn refunds_excess_funds() {
+        let required = Coins::try_from(vec![coin(100, "uaxone")]).expect("valid coins");
+
+        assert_eq!(
+            fee_refund(&required, &[coin(150, "uaxone"), coin(3, "uatom")]).expect("enough"),
+            vec![coin(3, "uatom"), coin(50, "uaxone")]
+        );
+        assert!(fee_refund(&required, &[coin(100, "uaxone")])
+            .expect("enough")
+            .is_empty());
+        assert_eq!(
+            fee_refund(&Coins::default(), &[coin(7, "uaxone")]).expect("no fee"),
+            vec![coin(7, "uaxone")]
+        );
+        assert_eq!(
+            fee_refund(&required, &[coin(99, "uaxone")]).expect_err("insufficient"),
+            AxoneGovError::InsufficientFee {
+                required: "100uaxone".to_string(),
+                paid: "99uaxone".to_string(),
+            }
+        );
+    }
+}
@@ -0,0 +1,339 @@
+use crate::contract::AxoneGovResult;
+use crate::error::AxoneGovError;
+use crate::gateway::logic::{query_service_ask, AxoneLogicQuery, QueryServiceAskRequest};
+use crate::prolog::ast::Term;
+use crate::prolog::parser::Parser;
+use crate::state::StateAccess;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{from_hex, to_hex, Binary, Checksum, QuerierWrapper};
+use getset::Getters;
+
+const USE_LIBRARY_DIRECTIVE: &str = "use_library";
+
+/// A named Prolog library module shared by constitutions.
+///
+/// Libraries hold reusable rule sets (voting helpers, role checks, credential checks, ...) that
+/// constitutions import through the `:- use_library(Name).` directive instead of copying them.
+#[derive(Clone, Debug, Getters, PartialEq)]
+pub struct Library {
+    #[getset(get = "pub")]
+    name: String,
+    #[getset(get = "pub")]
+    bytes: Binary,
+}
+
+impl Library {
+    /// Reconstruct a Library from bytes previously stored in contract state.
+    pub(crate) fn from_state(name: String, bytes: Binary, _access: &StateAccess) -> Self {
+        Self { name, bytes }
+    }
+
+    /// Create a new Library from a name and a Prolog program.
+    ///
+    /// Returns an error if:
+    /// - The name is not a simple lowercase atom (`[a-z][a-z0-9_]*`)
+    /// - The bytes are not valid UTF-8
+    /// - The program itself uses other libraries
+    /// - The Prolog engine cannot load the program
+    pub fn try_new(
+        name: String,
+        bytes: Binary,
+        querier: &QuerierWrapper<'_, AxoneLogicQuery>,
+    ) -> AxoneGovResult<Self> {
+        validate_library_name(&name)?;
+
+        let source = std::str::from_utf8(bytes.as_slice())
+            .map_err(|err| AxoneGovError::LibraryInvalid(format!("not valid UTF-8: {err}")))?;
+
+        let (directives, _) = extract_library_directives(source)?;
+        if !directives.is_empty() {
+            return Err(AxoneGovError::LibraryInvalid(
+                "a library cannot use other libraries".to_string(),
+            ));
+        }
+
+        let request = QueryServiceAskRequest::one(source, "true.");
+        let response = query_service_ask(querier, request)
+            .map_err(|err| AxoneGovError::PrologEngineError(err.to_string()))?;
+        let answer = response
+            .answer
+            .as_ref()
+            .ok_or(AxoneGovError::PrologEngineNoAnswer)?;
+
+        if let Some(error) = answer
+            .results
+            .iter()
+            .find_map(|result| result.error.as_deref())
+        {
+            return Err(AxoneGovError::LibraryInvalid(format!(
+                "program cannot be loaded: {error}"
+            )));
+        }
+
+        Ok(Self { name, bytes })
+    }
+
+    /// Get the library as a UTF-8 string.
+    pub fn source(&self) -> &str {
+        #[allow(clippy::expect_used)] // Library bytes are guaranteed to be valid UTF-8
+        std::str::from_utf8(self.bytes.as_slice()).expect("Library bytes are valid UTF-8")
+    }
+
+    /// Compute the SHA256 hash of the library bytes.
+    pub fn hash(&self) -> [u8; 32] {
+        *Checksum::generate(self.bytes.as_slice()).as_ref()
+    }
+
+    /// The reference pinning this exact library content.
+    pub fn pin(&self) -> LibraryPin {
+        LibraryPin::new(self.name.clone(), self.hash())
+    }
+}
+
+/// A reference to a library module, pinned by the hash of its content.
+#[cw_serde]
+#[derive(Getters)]
+pub struct LibraryPin {
+    #[getset(get = "pub")]
+    name: String,
+    #[getset(get = "pub")]
+    hash: [u8; 32],
+}
+
+impl LibraryPin {
+    pub fn new(name: String, hash: [u8; 32]) -> Self {
+        Self { name, hash }
+    }
+
+    pub fn hash_hex(&self) -> String {
+        to_hex(self.hash)
+    }
+}
+
+/// A `:- use_library(Name).` or `:- use_library(Name, Hash).` directive found in a program.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct LibraryDirective {
+    pub(crate) name: String,
+    pub(crate) hash: Option<[u8; 32]>,
+}
+
+/// Extract the library directives of a program.
+///
+/// Directives must stand alone on their line. Returns the directives in order of appearance along
+/// with the program where each directive line has been blanked out.
+pub(crate) fn extract_library_directives(
+    source: &str,
+) -> AxoneGovResult<(Vec<LibraryDirective>, String)> {
+    let mut directives = Vec::new();
+    let mut lines = Vec::new();
+
+    for line in source.split('\n') {
+        match parse_library_directive(line)? {
+            Some(directive) => {
+                directives.push(directive);
+                lines.push("");
+            }
+            None => lines.push(line),
+        }
+    }
+
+    Ok((directives, lines.join("\n")))
+}
+
+/// Compose the effective program evaluated by the logic module.
+///
+/// Library sources come first, in the order they are used, followed by the program itself.
+pub(crate) fn compose_program(libraries: &[Library], program: &str) -> String {
+    libraries
+        .iter()
+        .map(|library| format!("{}\n", library.source()))
+        .chain(std::iter::once(program.to_string()))
+        .collect()
+}
+
+fn parse_library_directive(line: &str) -> AxoneGovResult<Option<LibraryDirective>> {
+    let Some(body) = line.trim().strip_prefix(":-") else {
+        return Ok(None);
+    };
+    let body = body.trim_start();
+    if !body.starts_with(USE_LIBRARY_DIRECTIVE) {
+        return Ok(None);
+    }
+
+    let invalid = |reason: &str| {
+        AxoneGovError::ConstitutionInvalid(format!(
+            "invalid library directive `{}`: {reason}",
+            line.trim()
+        ))
+    };
+
+    let term = body
+        .strip_suffix('.')
+        .ok_or_else(|| invalid("a directive must end with '.' on the same line"))
+        .and_then(|term| {
+            Parser::new(term)
+                .and_then(Parser::parse_root)
+                .map_err(|err| invalid(&err.msg))
+        })?;
+
+    match term {
+        Term::Compound(functor, args) if functor == USE_LIBRARY_DIRECTIVE => {
+            match args.as_slice() {
+                [Term::Atom(name)] => Ok(Some(LibraryDirective {
+                    name: name.clone(),
+                    hash: None,
+                })),
+                [Term::Atom(name), Term::Atom(hash)] => {
+                    let hash = from_hex(hash)
+                        .ok()
+                        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+                        .ok_or_else(|| invalid("the hash must be a hex encoded sha256"))?;
+                    Ok(Some(LibraryDirective {
+                        name: name.clone(),
+                        hash: Some(hash),
+                    }))
+                }
+                _ => Err(invalid(
+                    "expected use_library(Name) or use_library(Name, Hash)",
+                )),
+            }
+        }
+        // Not a library directive, e.g. `:- use_library_foo.`
+        _ => Ok(None),
+    }
+}
+
+fn validate_library_name(name: &str) -> AxoneGovResult<()> {
+    let mut chars = name.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+
+    if !valid {
+        return Err(AxoneGovError::LibraryInvalid(format!(
+            "invalid library name '{name}': expected [a-z][a-z0-9_]*"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_library_directives() {
+        let hash = [7u8; 32];
+        let pinned = format!(":- use_library(voting, '{}').", to_hex(hash));
+        let cases = vec![
+            ("decide(_, 'gov:permitted').", vec![], "no directive"),
+            (
+                ":- use_library(voting).\ndecide(_, 'gov:permitted').",
+                vec![LibraryDirective {
+                    name: "voting".to_string(),
+                    hash: None,
+                }],
+                "single directive",
+            ),
+            (
+                "  :-   use_library(roles) .  \n:- use_library(voting).\n",
+                vec![
+                    LibraryDirective {
+                        name: "roles".to_string(),
+                        hash: None,
+                    },
+                    LibraryDirective {
+                        name: "voting".to_string(),
+                        hash: None,
+                    },
+                ],
+                "directives with extra spaces",
+            ),
+            (
+                pinned.as_str(),
+                vec![LibraryDirective {
+                    name: "voting".to_string(),
+                    hash: Some(hash),
+                }],
+                "directive pinned by hash",
+            ),
+            (
+                ":- dynamic(foo/1).\nfoo(1).",
+                vec![],
+                "other directives are ignored",
+            ),
+        ];
+
+        for (source, expected, description) in cases {
+            let (directives, _) = extract_library_directives(source)
+                .unwrap_or_else(|err| panic!("unexpected error for {description}: {err}"));
+            assert_eq!(directives, expected, "Failed for case: {description}");
+        }
+    }
+
+    #[test]
+    fn blanks_directive_lines() {
+        let (_, program) =
+            extract_library_directives(":- use_library(voting).\ndecide(_, 'gov:permitted').")
+                .expect("valid directives");
+
+        assert_eq!(program, "\ndecide(_, 'gov:permitted').");
+    }
+
+    #[test]
+    fn rejects_malformed_directives() {
+        let cases = vec![
+            (":- use_library(voting)", "must end with '.'"),
+            (":- use_library('Voting', foo).", "hex encoded sha256"),
+            (":- use_library(voting, 'abcd').", "hex encoded sha256"),
+            (":- use_library(X).", "expected use_library(Name)"),
+            (":- use_library(a, b, c).", "expected use_library(Name)"),
+            (":- use_library(voting(.", "invalid library directive"),
+        ];
+
+        for (source, expected) in cases {
+            let err = extract_library_directives(source).expect_err("expected error");
+            assert!(
+                err.to_string().contains(expected),
+                "unexpected error for {source}: {err}"
+            );
+        }
+    }
+
+    #[test]
+    fn composes_program() {
+        let libraries = vec![
+            Library {
+                name: "a".to_string(),
+                bytes: Binary::from(b"a(1).".to_vec()),
+            },
+            Library {
+                name: "b".to_string(),
+                bytes: Binary::from(b"b(2).".to_vec()),
+            },
+        ];
+
+        assert_eq!(compose_program(&[], "main."), "main.");
+        assert_eq!(compose_program(&libraries, "main."), "a(1).\nb(2).\nmain.");
+    }
+
+    #[test]
+    fn validates_library_names() {
+        let cases = vec![
+            ("voting", true),
+            ("role_checks2", true),
+            ("", false),
+            ("Voting", false),
+            ("2fa", false),
+            ("credential-checks", false),
+            ("gov:voting", false),
+        ];
+
+        for (name, valid) in cases {
+            assert_eq!(
+                validate_library_name(name).is_ok(),
+                valid,
+                "unexpected validation result for '{name}'"
+            );
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use crate::contract::AxoneGovResult;
+use crate::error::AxoneGovError;
+use crate::prolog::ast::Term;
+use cosmwasm_schema::cw_serde;
+
+const MAX_CASE_BYTES_FUNCTOR: &str = "max_case_bytes";
+const MAX_MOTIVATION_BYTES_FUNCTOR: &str = "max_motivation_bytes";
+const RATE_FUNCTOR: &str = "rate";
+
+/// The default maximum size of a recorded case, in bytes.
+const DEFAULT_MAX_CASE_BYTES: u64 = 16_384;
+/// The default maximum size of a recorded motivation, in bytes.
+const DEFAULT_MAX_MOTIVATION_BYTES: u64 = 16_384;
+
+/// The maximum number of decisions a sender can record within a time window.
+#[cw_serde]
+#[derive(Copy)]
+pub struct RateLimit {
+    /// The maximum number of decisions recorded by a sender within a window.
+    pub max_decisions: u64,
+    /// The duration of a window, in seconds.
+    pub window_seconds: u64,
+}
+
+/// The limits applied to the decisions recorded by `RecordDecision` and `RecordDecisions`.
+///
+/// They are declared by the constitution through `decision_limit/1` facts:
+///
+/// ```prolog
+/// decision_limit(max_case_bytes(4096)).
+/// decision_limit(max_motivation_bytes(2048)).
+/// decision_limit(rate(10, 3600)).    % at most 10 decisions per sender and hour
+/// ```
+///
+/// Sizes default to 16 KiB, and senders are not rate limited unless declared.
+#[cw_serde]
+pub struct DecisionLimits {
+    /// The maximum size of a recorded case, in bytes.
+    pub max_case_bytes: u64,
+    /// The maximum size of a recorded motivation, in bytes.
+    pub max_motivation_bytes: u64,
+    /// The rate limit applied to each sender, if any.
+    pub rate_limit: Option<RateLimit>,
+}
+
+impl Default for DecisionLimits {
+    fn default() -> Self {
+        Self {
+            max_case_bytes: DEFAULT_MAX_CASE_BYTES,
+            max_motivation_bytes: DEFAULT_MAX_MOTIVATION_BYTES,
+            rate_limit: None,
+        }
+    }
+}
+
+impl DecisionLimits {
+    /// Ensure a case term fits the case size limit.
+    pub fn ensure_case_size(&self, case: &str) -> AxoneGovResult<()> {
+        ensure_size("case", case, self.max_case_bytes)
+    }
+
+    /// Ensure a motivation term fits the motivation size limit.
+    pub fn ensure_motivation_size(&self, motivation: &str) -> AxoneGovResult<()> {
+        ensure_size("motivation", motivation, self.max_motivation_bytes)
+    }
+}
+
+fn ensure_size(payload: &str, value: &str, max: u64) -> AxoneGovResult<()> {
+    let size = value.len() as u64;
+    if size > max {
+        return Err(AxoneGovError::PayloadTooLarge {
+            payload: payload.to_string(),
+            size,
+            max,
+        });
+    }
+    Ok(())
+}
+
+/// Parse the collected `decision_limit/1` declarations of a constitution.
+pub(crate) fn parse_decision_limits(term: &Term) -> AxoneGovResult<DecisionLimits> {
+    let Term::List(items, None) = term else {
+        return Err(invalid(format!(
+            "expected a list of decision_limit/1 declarations, got {term}"
+        )));
+    };
+
+    let mut limits = DecisionLimits::default();
+    for item in items {
+        let Term::Compound(functor, args) = item else {
+            return Err(invalid(format!("unknown decision limit {item}")));
+        };
+        let values = args
+            .iter()
+            .map(Term::as_u64)
+            .collect::<Option<Vec<_>>>()
+            .filter(|values| values.iter().all(|value| *value > 0))
+            .ok_or_else(|| {
+                invalid(format!(
+                    "decision limit {item} expects positive integer arguments"
+                ))
+            })?;
+
+        match (functor.as_str(), values.as_slice()) {
+            (MAX_CASE_BYTES_FUNCTOR, [max]) => limits.max_case_bytes = *max,
+            (MAX_MOTIVATION_BYTES_FUNCTOR, [max]) => limits.max_motivation_bytes = *max,
+            (RATE_FUNCTOR, [max_decisions, window_seconds]) => {
+                limits.rate_limit = Some(RateLimit {
+                    max_decisions: *max_decisions,
+                    window_seconds: *window_seconds,
+                })
+            }
+            _ => return Err(invalid(format!("unknown decision limit {item}"))),
+        }
+    }
+
+    Ok(limits)
+}
+
+fn invalid(reason: String) -> AxoneGovError {
+    AxoneGovError::ConstitutionInvalid(reason)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prolog::parser::Parser;
+
+    #[test]
+    fn parses_decision_limits() {
+        let cases = vec![
+            ("[]", Ok(DecisionLimits::default())),
+            (
+                "[max_case_bytes(128), rate(2, 60)]",
+                Ok(DecisionLimits {
+                    max_case_bytes: 128,
+                    max_motivation_bytes: DEFAULT_MAX_MOTIVATION_BYTES,
+                    rate_limit: Some(RateLimit {
+                        max_decisions: 2,
+                        window_seconds: 60,
+                    }),
+                }),
+            ),
+            (
+                "[max_motivation_bytes(64)]",
+                Ok(DecisionLimits {
+                    max_motivation_bytes: 64,
+                    ..DecisionLimits::default()
+                }),
+            ),
+            ("[max_case_bytes(0)]", Err("expects positive integer")),
+            ("[rate(2)]", Err("unknown decision limit rate(2)")),
+            ("[unlimited]", Err("unknown decision limit unlimited")),
+        ];
+
+        for (declarations, expected) in cases {
+            let term = Parser::new(declarations)
+                .and_then(Parser::parse_root)
+                .expect("valid term");
+            let result = parse_decision_limits(&term);
+            match expected {
+                Ok(expected) => assert_eq!(result.expect(declarations), expected),
+                Err(reason) => {
+                    let err = result.expect_err(declarations);
+                    assert!(err.to_string().contains(reason), "{declarations}: {err}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn enforces_sizes() {
+        let limits = DecisionLimits {
+            max_case_bytes: 4,
+            max_motivation_bytes: 2,
+            rate_limit: None,
+        };
+        limits.ensure_case_size("abcd").expect("fits");
+        assert_eq!(
+            limits.ensure_case_size("abcde").expect_err("too large"),
+            AxoneGovError::PayloadTooLarge {
+                payload: "case".to_string(),
+                size: 5,
+                max: 4,
+            }
+        );
+        limits.ensure_motivation_size("ab").expect("fits");
+        limits.ensure_motivation_size("abc").expect_err("too large");
+    }
+}
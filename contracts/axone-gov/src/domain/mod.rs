@@ -1,7 +1,30 @@
+pub mod capability;
 pub mod case;
+pub mod config;
 pub mod constitution;
 pub mod decision;
+pub mod effective;
+pub mod fee;
+pub mod library;
+pub mod limits;
+pub mod pause;
+pub mod revision;
+pub mod schema;
+pub mod signature;
+pub mod subscription;
+pub mod verdict;
 
-pub use case::Case;
+pub use capability::CapabilityGrant;
+pub use case::{Case, CasePattern};
+pub use config::{Config, EnrichmentProvider};
 pub use constitution::Constitution;
 pub use decision::Decision;
+pub use effective::EffectivePeriod;
+pub use library::{Library, LibraryPin};
+pub use limits::{DecisionLimits, RateLimit};
+pub use pause::PauseAuthorization;
+pub use revision::{RevisionActivation, RevisionAuthorization};
+pub use schema::{CaseSchema, FieldSchema, IntentDeclaration, ValueKind};
+pub use signature::{CaseSignature, SignatureAlgorithm};
+pub use subscription::DecisionFilter;
+pub use verdict::{VerdictClass, VerdictKind};
@@ -0,0 +1,96 @@
+use crate::prolog::ast::Term;
+use crate::{GOV_VERDICT_PERMITTED, GOV_VERDICT_PERMITTED_WITH_EXEMPTIONS};
+
+/// How the constitution authorizes an emergency pause.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PauseAuthorization {
+    exempt_intents: Vec<String>,
+}
+
+impl PauseAuthorization {
+    /// Interpret a `gov:pause` verdict.
+    ///
+    /// The verdict `gov:permitted` pauses every governance act, while
+    /// `gov:permitted_with_exemptions(Intents)` keeps the listed intents (a list of atoms) available.
+    ///
+    /// Returns `None` if the verdict does not authorize the pause.
+    pub fn from_verdict(verdict: &Term) -> Option<Self> {
+        match verdict {
+            Term::Atom(atom) if atom == GOV_VERDICT_PERMITTED => Some(Self {
+                exempt_intents: vec![],
+            }),
+            Term::Compound(functor, args)
+                if functor == GOV_VERDICT_PERMITTED_WITH_EXEMPTIONS && args.len() == 1 =>
+            {
+                let Term::List(items, None) = &args[0] else {
+                    return None;
+                };
+                items
+                    .iter()
+                    .map(|item| match item {
+                        Term::Atom(intent) => Some(intent.clone()),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>()
+                    .map(|exempt_intents| Self { exempt_intents })
+            }
+            _ => None,
+        }
+    }
+
+    /// The intents that remain available while paused.
+    pub fn into_exempt_intents(self) -> Vec<String> {
+        self.exempt_intents
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prolog::term as t;
+
+    #[test]
+    fn interprets_pause_verdicts() {
+        let cases = vec![
+            (t::atom("gov:permitted"), Some(vec![])),
+            (
+                t::compound(
+                    "gov:permitted_with_exemptions",
+                    vec![t::list(vec![
+                        t::atom("gov:revise_constitution"),
+                        t::atom("withdraw"),
+                    ])],
+                ),
+                Some(vec![
+                    "gov:revise_constitution".to_string(),
+                    "withdraw".to_string(),
+                ]),
+            ),
+            (
+                t::compound("gov:permitted_with_exemptions", vec![t::list(vec![])]),
+                Some(vec![]),
+            ),
+            (t::atom("gov:forbidden"), None),
+            (
+                t::compound("gov:permitted_with_exemptions", vec![t::atom("withdraw")]),
+                None,
+            ),
+            (
+                t::compound(
+                    "gov:permitted_with_exemptions",
+                    vec![t::list(vec![1u64.into()])],
+                ),
+                None,
+            ),
+        ];
+
+        for (verdict, expected) in cases {
+            assert_eq!(
+                PauseAuthorization::from_verdict(&verdict)
+                    .map(PauseAuthorization::into_exempt_intents),
+                expected,
+                "unexpected authorization for verdict {verdict}"
+            );
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use std::fmt;
+
+use crate::prolog::ast::Term;
+use crate::{GOV_VERDICT_PERMITTED, GOV_VERDICT_PERMITTED_AFTER};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::BlockInfo;
+
+/// The chain time from which a pending constitutional revision may be activated.
+#[cw_serde]
+#[derive(Copy)]
+pub enum RevisionActivation {
+    /// The revision can be activated once the block height reaches this value.
+    Height(u64),
+    /// The revision can be activated once the block time (seconds since epoch) reaches this value.
+    TimeSeconds(u64),
+}
+
+impl RevisionActivation {
+    /// Returns true if the given block satisfies the activation condition.
+    pub fn is_reached(&self, block: &BlockInfo) -> bool {
+        match self {
+            Self::Height(height) => block.height >= *height,
+            Self::TimeSeconds(seconds) => block.time.seconds() >= *seconds,
+        }
+    }
+
+    pub(crate) fn try_from_term(term: &Term) -> Option<Self> {
+        match term {
+            Term::Integer(_) => term.as_u64().map(Self::Height),
+            Term::Compound(functor, args) if args.len() == 1 => match functor.as_str() {
+                "height" => args[0].as_u64().map(Self::Height),
+                "time_seconds" => args[0].as_u64().map(Self::TimeSeconds),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for RevisionActivation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Height(height) => write!(f, "height {height}"),
+            Self::TimeSeconds(seconds) => write!(f, "time_seconds {seconds}"),
+        }
+    }
+}
+
+/// How the current constitution authorizes a constitutional revision.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RevisionAuthorization {
+    /// The verdict is `gov:permitted`: the revision is applied right away.
+    Immediate,
+    /// The verdict is `gov:permitted_after(Activation)`: the revision is staged until activation.
+    Deferred(RevisionActivation),
+}
+
+impl RevisionAuthorization {
+    /// Interpret a `gov:revise_constitution` verdict.
+    ///
+    /// Returns `None` if the verdict does not authorize the revision.
+    pub fn from_verdict(verdict: &Term) -> Option<Self> {
+        match verdict {
+            Term::Atom(atom) if atom == GOV_VERDICT_PERMITTED => Some(Self::Immediate),
+            Term::Compound(functor, args)
+                if functor == GOV_VERDICT_PERMITTED_AFTER && args.len() == 1 =>
+            {
+                RevisionActivation::try_from_term(&args[0]).map(Self::Deferred)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prolog::term as t;
+    use cosmwasm_std::Timestamp;
+
+    #[test]
+    fn interprets_revision_verdicts() {
+        let cases = vec![
+            (
+                t::atom("gov:permitted"),
+                Some(RevisionAuthorization::Immediate),
+            ),
+            (
+                t::compound("gov:permitted_after", vec![100u64.into()]),
+                Some(RevisionAuthorization::Deferred(RevisionActivation::Height(
+                    100,
+                ))),
+            ),
+            (
+                t::compound(
+                    "gov:permitted_after",
+                    vec![t::compound("height", vec![100u64.into()])],
+                ),
+                Some(RevisionAuthorization::Deferred(RevisionActivation::Height(
+                    100,
+                ))),
+            ),
+            (
+                t::compound(
+                    "gov:permitted_after",
+                    vec![t::compound("time_seconds", vec![1_700_000_000u64.into()])],
+                ),
+                Some(RevisionAuthorization::Deferred(
+                    RevisionActivation::TimeSeconds(1_700_000_000),
+                )),
+            ),
+            (t::atom("gov:forbidden"), None),
+            (t::compound("gov:permitted_after", vec![(-1).into()]), None),
+            (
+                t::compound("gov:permitted_after", vec![t::atom("tomorrow")]),
+                None,
+            ),
+            (
+                t::compound("gov:permitted_after", vec![1u64.into(), 2u64.into()]),
+                None,
+            ),
+        ];
+
+        for (verdict, expected) in cases {
+            assert_eq!(
+                RevisionAuthorization::from_verdict(&verdict),
+                expected,
+                "unexpected authorization for verdict {verdict}"
+            );
+        }
+    }
+
+    #[test]
+    fn checks_activation_against_block() {
+        let block = BlockInfo {
+            height: 100,
+            time: Timestamp::from_seconds(1_000),
+            chain_id: "test-chain".to_string(),
+        };
+
+        let cases = vec![
+            (RevisionActivation::Height(99), true),
+            (RevisionActivation::Height(100), true),
+            (RevisionActivation::Height(101), false),
+            (RevisionActivation::TimeSeconds(1_000), true),
+            (RevisionActivation::TimeSeconds(1_001), false),
+        ];
+
+        for (activation, expected) in cases {
+            assert_eq!(
+                activation.is_reached(&block),
+                expected,
+                "unexpected result for {activation}"
+            );
+        }
+    }
+}
@@ -0,0 +1,411 @@
+use std::fmt;
+
+use crate::contract::AxoneGovResult;
+use crate::error::AxoneGovError;
+use crate::gateway::logic::Substitution;
+use crate::prolog::ast::Term;
+use cosmwasm_schema::cw_serde;
+
+const INTENT_FUNCTOR: &str = "intent";
+const CASE_SCHEMA_FUNCTOR: &str = "case_schema";
+const REQUIRED_FIELD_FUNCTOR: &str = "required";
+const OPTIONAL_FIELD_FUNCTOR: &str = "optional";
+const RESERVED_KEY_PREFIXES: [&str; 2] = ["gov:", "cw:"];
+
+/// The kind of term expected as the value of a case key.
+#[cw_serde]
+#[derive(Copy)]
+pub enum ValueKind {
+    Atom,
+    Integer,
+    Float,
+    /// An integer or a float.
+    Number,
+    List,
+    Dict,
+    Compound,
+    /// Any ground term.
+    Any,
+}
+
+impl ValueKind {
+    fn from_atom(atom: &str) -> Option<Self> {
+        match atom {
+            "atom" => Some(Self::Atom),
+            "integer" => Some(Self::Integer),
+            "float" => Some(Self::Float),
+            "number" => Some(Self::Number),
+            "list" => Some(Self::List),
+            "dict" => Some(Self::Dict),
+            "compound" => Some(Self::Compound),
+            "any" => Some(Self::Any),
+            _ => None,
+        }
+    }
+}
+
+impl ValueKind {
+    /// Whether `term` is of this kind.
+    pub fn matches(&self, term: &Term) -> bool {
+        match self {
+            Self::Atom => matches!(term, Term::Atom(_)),
+            Self::Integer => matches!(term, Term::Integer(_)),
+            Self::Float => matches!(term, Term::Float(_)),
+            Self::Number => matches!(term, Term::Integer(_) | Term::Float(_)),
+            Self::List => matches!(term, Term::List(_, _)),
+            Self::Dict => matches!(term, Term::Dict(_, _)),
+            Self::Compound => matches!(term, Term::Compound(_, _)),
+            Self::Any => true,
+        }
+    }
+}
+
+impl fmt::Display for ValueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Atom => "atom",
+            Self::Integer => "integer",
+            Self::Float => "float",
+            Self::Number => "number",
+            Self::List => "list",
+            Self::Dict => "dict",
+            Self::Compound => "compound",
+            Self::Any => "any",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// An intent handled by the constitution, declared with an `intent(Intent, Description)` fact.
+#[cw_serde]
+pub struct IntentDeclaration {
+    /// The intent atom, as expected under the `intent` key of a case.
+    pub intent: String,
+    /// A human readable description of the intent.
+    pub description: String,
+}
+
+/// A key expected in the cases of an intent.
+#[cw_serde]
+pub struct FieldSchema {
+    /// The case key.
+    pub key: String,
+    /// The kind of term expected as value.
+    pub kind: ValueKind,
+    /// Whether the key must be present.
+    pub required: bool,
+}
+
+/// The keys expected in the cases of an intent, declared with a `case_schema(Intent, Fields)` fact.
+///
+/// `Fields` is a list of `required(Key, Kind)` and `optional(Key, Kind)` terms. The `intent` key and
+/// the keys injected by the contract (`gov:*`, `cw:*`) are implicit and cannot be declared.
+#[cw_serde]
+pub struct CaseSchema {
+    /// The intent the schema applies to.
+    pub intent: String,
+    /// The expected keys.
+    pub fields: Vec<FieldSchema>,
+}
+
+/// Whether a case key is implicit in every schema: the `intent` key and the keys injected by the
+/// contract.
+pub fn is_implicit_key(key: &str) -> bool {
+    key == INTENT_FUNCTOR || is_reserved_key(key)
+}
+
+/// Whether a case key is reserved to the facts injected by the contract (`gov:*`, `cw:*`).
+pub fn is_reserved_key(key: &str) -> bool {
+    RESERVED_KEY_PREFIXES
+        .iter()
+        .any(|prefix| key.starts_with(prefix))
+}
+
+/// Parse the `intent/2` declarations collected as a list of `intent(Intent, Description)` terms.
+///
+/// Returns an error if a declaration is malformed or an intent is declared more than once.
+pub fn parse_intent_declarations(term: &Term) -> AxoneGovResult<Vec<IntentDeclaration>> {
+    let mut intents: Vec<IntentDeclaration> = Vec::new();
+    for item in declaration_items(term, INTENT_FUNCTOR)? {
+        let declaration = match item {
+            Term::Compound(functor, args) if functor == INTENT_FUNCTOR && args.len() == 2 => {
+                match (&args[0], &args[1]) {
+                    (Term::Atom(intent), Term::Atom(description)) => IntentDeclaration {
+                        intent: intent.clone(),
+                        description: description.clone(),
+                    },
+                    _ => return Err(invalid_declaration(item, "expected atom arguments")),
+                }
+            }
+            _ => {
+                return Err(invalid_declaration(
+                    item,
+                    "expected intent(Intent, Description)",
+                ))
+            }
+        };
+        if intents.iter().any(|i| i.intent == declaration.intent) {
+            return Err(invalid_declaration(item, "intent declared more than once"));
+        }
+        intents.push(declaration);
+    }
+
+    Ok(intents)
+}
+
+/// Parse the `case_schema/2` declarations collected as a list of `case_schema(Intent, Fields)` terms.
+///
+/// Returns an error if a declaration is malformed, declares the same key twice, declares a reserved
+/// key, or if an intent has more than one schema.
+pub fn parse_case_schemas(term: &Term) -> AxoneGovResult<Vec<CaseSchema>> {
+    let mut schemas: Vec<CaseSchema> = Vec::new();
+    for item in declaration_items(term, CASE_SCHEMA_FUNCTOR)? {
+        let schema = match item {
+            Term::Compound(functor, args) if functor == CASE_SCHEMA_FUNCTOR && args.len() == 2 => {
+                let Term::Atom(intent) = &args[0] else {
+                    return Err(invalid_declaration(item, "expected an atom intent"));
+                };
+                let Term::List(fields, None) = &args[1] else {
+                    return Err(invalid_declaration(item, "expected a list of fields"));
+                };
+                CaseSchema {
+                    intent: intent.clone(),
+                    fields: parse_fields(item, fields)?,
+                }
+            }
+            _ => {
+                return Err(invalid_declaration(
+                    item,
+                    "expected case_schema(Intent, Fields)",
+                ))
+            }
+        };
+        if schemas.iter().any(|s| s.intent == schema.intent) {
+            return Err(invalid_declaration(
+                item,
+                "intent has more than one case schema",
+            ));
+        }
+        schemas.push(schema);
+    }
+
+    Ok(schemas)
+}
+
+/// Ensure every case schema applies to an intent declared with `intent/2`.
+pub fn ensure_schemas_declared(
+    intents: &[IntentDeclaration],
+    schemas: &[CaseSchema],
+) -> AxoneGovResult<()> {
+    match schemas
+        .iter()
+        .find(|schema| !intents.iter().any(|i| i.intent == schema.intent))
+    {
+        Some(schema) => Err(AxoneGovError::ConstitutionInvalid(format!(
+            "case schema declared for undeclared intent '{}'",
+            schema.intent
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Get the list of declarations collected by `findall/3` into `variable`.
+///
+/// A missing binding is read as no declarations.
+pub(crate) fn collected_declarations(
+    substitutions: &[Substitution],
+    variable: &str,
+) -> AxoneGovResult<Term> {
+    substitutions
+        .iter()
+        .find(|s| s.variable == variable)
+        .map_or(Ok(Term::List(vec![], None)), |s| {
+            s.expression_term().map_err(|err| {
+                AxoneGovError::PrologEngineError(format!(
+                    "invalid {variable} term at offset {}: {}",
+                    err.at, err.msg
+                ))
+            })
+        })
+}
+
+fn declaration_items<'a>(term: &'a Term, functor: &str) -> AxoneGovResult<&'a [Term]> {
+    match term {
+        Term::List(items, None) => Ok(items),
+        _ => Err(AxoneGovError::ConstitutionInvalid(format!(
+            "expected a list of {functor}/2 declarations, got {term}"
+        ))),
+    }
+}
+
+fn parse_fields(declaration: &Term, fields: &[Term]) -> AxoneGovResult<Vec<FieldSchema>> {
+    let mut parsed: Vec<FieldSchema> = Vec::with_capacity(fields.len());
+    for field in fields {
+        let (required, key, kind) = match field {
+            Term::Compound(functor, args)
+                if (functor == REQUIRED_FIELD_FUNCTOR || functor == OPTIONAL_FIELD_FUNCTOR)
+                    && args.len() == 2 =>
+            {
+                match (&args[0], &args[1]) {
+                    (Term::Atom(key), Term::Atom(kind)) => {
+                        (functor == REQUIRED_FIELD_FUNCTOR, key, kind)
+                    }
+                    _ => {
+                        return Err(invalid_declaration(
+                            declaration,
+                            &format!("invalid field {field}: expected atom arguments"),
+                        ))
+                    }
+                }
+            }
+            _ => {
+                return Err(invalid_declaration(
+                    declaration,
+                    &format!(
+                    "invalid field {field}: expected required(Key, Kind) or optional(Key, Kind)"
+                ),
+                ))
+            }
+        };
+
+        let kind = ValueKind::from_atom(kind).ok_or_else(|| {
+            invalid_declaration(
+                declaration,
+                &format!("unknown kind '{kind}' for key '{key}'"),
+            )
+        })?;
+        if is_implicit_key(key) {
+            return Err(invalid_declaration(
+                declaration,
+                &format!("key '{key}' is reserved"),
+            ));
+        }
+        if parsed.iter().any(|f| f.key == *key) {
+            return Err(invalid_declaration(
+                declaration,
+                &format!("key '{key}' declared more than once"),
+            ));
+        }
+
+        parsed.push(FieldSchema {
+            key: key.clone(),
+            kind,
+            required,
+        });
+    }
+
+    Ok(parsed)
+}
+
+fn invalid_declaration(declaration: &Term, reason: &str) -> AxoneGovError {
+    AxoneGovError::ConstitutionInvalid(format!("invalid declaration {declaration}: {reason}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prolog::parser::Parser;
+
+    fn parse(input: &str) -> Term {
+        Parser::new(input)
+            .and_then(Parser::parse_root)
+            .expect("valid term")
+    }
+
+    #[test]
+    fn parses_declarations() {
+        let intents = parse_intent_declarations(&parse(
+            "[intent(transfer, 'Transfer funds'), intent('gov:pause', 'Pause governance')]",
+        ))
+        .expect("valid intents");
+        assert_eq!(
+            intents,
+            vec![
+                IntentDeclaration {
+                    intent: "transfer".to_string(),
+                    description: "Transfer funds".to_string(),
+                },
+                IntentDeclaration {
+                    intent: "gov:pause".to_string(),
+                    description: "Pause governance".to_string(),
+                },
+            ]
+        );
+
+        let schemas = parse_case_schemas(&parse(
+            "[case_schema(transfer, [required(amount, integer), optional(memo, atom)])]",
+        ))
+        .expect("valid schemas");
+        assert_eq!(
+            schemas,
+            vec![CaseSchema {
+                intent: "transfer".to_string(),
+                fields: vec![
+                    FieldSchema {
+                        key: "amount".to_string(),
+                        kind: ValueKind::Integer,
+                        required: true,
+                    },
+                    FieldSchema {
+                        key: "memo".to_string(),
+                        kind: ValueKind::Atom,
+                        required: false,
+                    },
+                ],
+            }]
+        );
+        ensure_schemas_declared(&intents, &schemas).expect("declared intents");
+        ensure_schemas_declared(&[], &schemas).expect_err("undeclared intent");
+    }
+
+    #[test]
+    fn rejects_invalid_declarations() {
+        let cases = vec![
+            ("[intent(1, 'Transfer')]", "expected atom arguments"),
+            ("[intent(transfer)]", "expected intent(Intent, Description)"),
+            (
+                "[intent(transfer, a), intent(transfer, b)]",
+                "intent declared more than once",
+            ),
+        ];
+        for (input, reason) in cases {
+            let err = parse_intent_declarations(&parse(input)).expect_err(input);
+            assert!(err.to_string().contains(reason), "{input}: {err}");
+        }
+
+        let cases = vec![
+            (
+                "[case_schema(transfer, amount)]",
+                "expected a list of fields",
+            ),
+            (
+                "[case_schema(transfer, [amount])]",
+                "expected required(Key, Kind) or optional(Key, Kind)",
+            ),
+            (
+                "[case_schema(transfer, [required(amount, money)])]",
+                "unknown kind 'money' for key 'amount'",
+            ),
+            (
+                "[case_schema(transfer, [required(intent, atom)])]",
+                "key 'intent' is reserved",
+            ),
+            (
+                "[case_schema(transfer, [optional('gov:signer', dict)])]",
+                "key 'gov:signer' is reserved",
+            ),
+            (
+                "[case_schema(transfer, [required(a, atom), optional(a, any)])]",
+                "key 'a' declared more than once",
+            ),
+            (
+                "[case_schema(transfer, []), case_schema(transfer, [])]",
+                "intent has more than one case schema",
+            ),
+        ];
+        for (input, reason) in cases {
+            let err = parse_case_schemas(&parse(input)).expect_err(input);
+            assert!(err.to_string().contains(reason), "{input}: {err}");
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use std::fmt;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Binary;
+
+/// The scheme used to sign a case off-chain.
+#[cw_serde]
+#[derive(Copy)]
+pub enum SignatureAlgorithm {
+    /// ECDSA over secp256k1 of the sha256 of the sign bytes, with a 33 bytes compressed public key.
+    Secp256k1,
+    /// Ed25519 over the sign bytes, with a 32 bytes public key.
+    Ed25519,
+    /// ECDSA over secp256k1 of an ADR-36 (`sign/MsgSignData`) document holding the sign bytes, as
+    /// produced by wallets `signArbitrary`, with a 33 bytes compressed public key.
+    Adr36,
+}
+
+impl fmt::Display for SignatureAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Secp256k1 => write!(f, "secp256k1"),
+            Self::Ed25519 => write!(f, "ed25519"),
+            Self::Adr36 => write!(f, "adr36"),
+        }
+    }
+}
+
+/// An off-chain signature of a case by the actor the decision is made for.
+///
+/// The signed payload (the sign bytes) is the UTF-8 string
+/// `<chain_id>\n<contract_address>\n<nonce>\n<case>`, where `case` is the case exactly as submitted.
+#[cw_serde]
+pub struct CaseSignature {
+    /// The signature scheme.
+    pub algorithm: SignatureAlgorithm,
+    /// The signer public key.
+    pub public_key: Binary,
+    /// The signature of the sign bytes.
+    pub signature: Binary,
+    /// The signer nonce, which must be greater than the last nonce used by the signer.
+    pub nonce: u64,
+}
+
+impl CaseSignature {
+    /// The payload covered by the signature.
+    pub fn sign_bytes(&self, chain_id: &str, contract_address: &str, case: &str) -> Vec<u8> {
+        format!("{chain_id}\n{contract_address}\n{}\n{case}", self.nonce).into_bytes()
+    }
+}
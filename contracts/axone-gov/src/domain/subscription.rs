@@ -0,0 +1,113 @@
+use crate::domain::VerdictClass;
+use crate::prolog::ast::Term;
+use crate::prolog::term as t;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+
+/// The filter selecting the recorded decisions notified to a subscriber.
+///
+/// An empty filter selects every recorded decision.
+#[cw_serde]
+#[derive(Default)]
+pub struct DecisionFilter {
+    /// Optional intent the case of notified decisions must have.
+    pub intent: Option<String>,
+    /// Optional kind the verdict of notified decisions must have.
+    pub verdict_kind: Option<VerdictClass>,
+}
+
+impl DecisionFilter {
+    /// Returns true if a decision on a case of intent `intent` with a verdict of kind
+    /// `verdict_kind` is selected by the filter.
+    pub fn matches(&self, intent: Option<&str>, verdict_kind: VerdictClass) -> bool {
+        self.intent
+            .as_deref()
+            .is_none_or(|expected| intent == Some(expected))
+            && self
+                .verdict_kind
+                .is_none_or(|expected| verdict_kind == expected)
+    }
+
+    /// The term describing a subscription of `contract` with this filter to the constitution.
+    pub fn subscription_term(&self, contract: &Addr) -> Term {
+        let mut pairs = vec![t::kv("contract", t::atom(contract.as_str()))];
+        if let Some(intent) = &self.intent {
+            pairs.push(t::kv("intent", t::atom(intent)));
+        }
+        if let Some(verdict_kind) = self.verdict_kind {
+            pairs.push(t::kv("verdict_kind", t::atom(verdict_kind.to_string())));
+        }
+
+        t::dict("subscription", pairs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_decisions() {
+        let cases = vec![
+            (DecisionFilter::default(), None, VerdictClass::Other, true),
+            (
+                DecisionFilter {
+                    intent: Some("transfer".to_string()),
+                    verdict_kind: None,
+                },
+                Some("transfer"),
+                VerdictClass::Forbidden,
+                true,
+            ),
+            (
+                DecisionFilter {
+                    intent: Some("transfer".to_string()),
+                    verdict_kind: None,
+                },
+                None,
+                VerdictClass::Forbidden,
+                false,
+            ),
+            (
+                DecisionFilter {
+                    intent: Some("transfer".to_string()),
+                    verdict_kind: Some(VerdictClass::Permitted),
+                },
+                Some("transfer"),
+                VerdictClass::Forbidden,
+                false,
+            ),
+            (
+                DecisionFilter {
+                    intent: None,
+                    verdict_kind: Some(VerdictClass::Permitted),
+                },
+                Some("read"),
+                VerdictClass::Permitted,
+                true,
+            ),
+        ];
+
+        for (filter, intent, verdict_kind, expected) in cases {
+            assert_eq!(
+                filter.matches(intent, verdict_kind),
+                expected,
+                "unexpected match of {filter:?} with {intent:?} / {verdict_kind}"
+            );
+        }
+    }
+
+    #[test]
+    fn describes_subscription() {
+        let filter = DecisionFilter {
+            intent: Some("transfer".to_string()),
+            verdict_kind: Some(VerdictClass::PermittedIf),
+        };
+        assert_eq!(
+            filter
+                .subscription_term(&Addr::unchecked("contract1"))
+                .to_string(),
+            "subscription{contract: contract1, intent: transfer, verdict_kind: permitted_if}"
+        );
+    }
+}
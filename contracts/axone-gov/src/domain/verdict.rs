@@ -0,0 +1,230 @@
+use std::fmt;
+
+use crate::domain::RevisionActivation;
+use crate::prolog::ast::Term;
+use crate::prolog::parser::Parser;
+use crate::{
+    GOV_VERDICT_EFFECTIVE, GOV_VERDICT_FORBIDDEN, GOV_VERDICT_GRANT, GOV_VERDICT_OBLIGED,
+    GOV_VERDICT_PERMITTED, GOV_VERDICT_PERMITTED_AFTER, GOV_VERDICT_PERMITTED_IF,
+    GOV_VERDICT_PERMITTED_WITH_EXEMPTIONS, GOV_VERDICT_UNDECIDED,
+};
+use cosmwasm_schema::cw_serde;
+
+/// The kind of a verdict in the governance verdict vocabulary, along with its arguments.
+///
+/// Constitutions remain free to return any term as verdict; terms outside of the vocabulary are of
+/// kind `Other`. Conditions and obligations are given as Prolog term strings.
+#[cw_serde]
+pub enum VerdictKind {
+    /// `gov:permitted`, along with the governance acts modality `gov:permitted_with_exemptions(Intents)`,
+    /// and capability grants `gov:grant(Capability, Holder, Uses)`.
+    Permitted,
+    /// `gov:permitted_after(Activation)`: permitted once the chain reaches the activation.
+    PermittedAfter { activation: RevisionActivation },
+    /// `gov:permitted_if(Conditions)`: permitted provided the conditions are met.
+    PermittedIf { conditions: Vec<String> },
+    /// `gov:obliged(...)`: the subject of the case is obliged to act as described by the obligations.
+    Obliged { obligations: Vec<String> },
+    /// `gov:forbidden`.
+    Forbidden,
+    /// `gov:undecided`: the constitution cannot decide the case.
+    Undecided,
+    /// Any verdict outside of the vocabulary.
+    Other,
+}
+
+impl VerdictKind {
+    /// Classify a verdict term.
+    pub fn of(verdict: &Term) -> Self {
+        match verdict {
+            Term::Atom(atom) if atom == GOV_VERDICT_PERMITTED => Self::Permitted,
+            Term::Atom(atom) if atom == GOV_VERDICT_FORBIDDEN => Self::Forbidden,
+            Term::Atom(atom) if atom == GOV_VERDICT_UNDECIDED => Self::Undecided,
+            Term::Compound(functor, args)
+                if args.len() == 1 && functor == GOV_VERDICT_PERMITTED_AFTER =>
+            {
+                RevisionActivation::try_from_term(&args[0]).map_or(Self::Permitted, |activation| {
+                    Self::PermittedAfter { activation }
+                })
+            }
+            Term::Compound(functor, args)
+                if args.len() == 1 && functor == GOV_VERDICT_PERMITTED_WITH_EXEMPTIONS =>
+            {
+                Self::Permitted
+            }
+            Term::Compound(functor, args) if functor == GOV_VERDICT_GRANT && args.len() == 3 => {
+                Self::Permitted
+            }
+            Term::Compound(functor, args)
+                if functor == GOV_VERDICT_PERMITTED_IF && args.len() == 1 =>
+            {
+                let conditions = match &args[0] {
+                    Term::List(items, None) => items.iter().map(Term::to_string).collect(),
+                    condition => vec![condition.to_string()],
+                };
+                Self::PermittedIf { conditions }
+            }
+            Term::Compound(functor, args) if functor == GOV_VERDICT_OBLIGED => Self::Obliged {
+                obligations: args.iter().map(Term::to_string).collect(),
+            },
+            Term::Compound(functor, args)
+                if functor == GOV_VERDICT_EFFECTIVE && args.len() == 2 =>
+            {
+                Self::of(&args[0])
+            }
+            _ => Self::Other,
+        }
+    }
+
+    /// Classify a verdict as returned by the logic module.
+    ///
+    /// A verdict that cannot be parsed is of kind `Other`.
+    pub fn parse(verdict: &str) -> Self {
+        Parser::new(verdict)
+            .and_then(Parser::parse_root)
+            .map_or(Self::Other, |term| Self::of(&term))
+    }
+
+    /// The class of the verdict, regardless of its arguments.
+    pub fn class(&self) -> VerdictClass {
+        match self {
+            Self::Permitted | Self::PermittedAfter { .. } => VerdictClass::Permitted,
+            Self::PermittedIf { .. } => VerdictClass::PermittedIf,
+            Self::Obliged { .. } => VerdictClass::Obliged,
+            Self::Forbidden => VerdictClass::Forbidden,
+            Self::Undecided => VerdictClass::Undecided,
+            Self::Other => VerdictClass::Other,
+        }
+    }
+}
+
+/// The class of a verdict kind, regardless of its arguments, by which decisions are filtered.
+#[cw_serde]
+#[derive(Copy)]
+pub enum VerdictClass {
+    /// `gov:permitted`, `gov:permitted_after(Activation)`, `gov:permitted_with_exemptions(Intents)`
+    /// and `gov:grant(Capability, Holder, Uses)`.
+    Permitted,
+    /// `gov:permitted_if(Conditions)`.
+    PermittedIf,
+    /// `gov:obliged(...)`.
+    Obliged,
+    /// `gov:forbidden`.
+    Forbidden,
+    /// `gov:undecided`.
+    Undecided,
+    /// Any verdict outside of the vocabulary.
+    Other,
+}
+
+impl fmt::Display for VerdictClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Permitted => "permitted",
+            Self::PermittedIf => "permitted_if",
+            Self::Obliged => "obliged",
+            Self::Forbidden => "forbidden",
+            Self::Undecided => "undecided",
+            Self::Other => "other",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_verdicts() {
+        let strings = |items: &[&str]| items.iter().map(|item| item.to_string()).collect();
+        let cases = vec![
+            ("'gov:permitted'", VerdictKind::Permitted),
+            (
+                "'gov:permitted_after'(height(10))",
+                VerdictKind::PermittedAfter {
+                    activation: RevisionActivation::Height(10),
+                },
+            ),
+            (
+                "'gov:permitted_after'(time_seconds(1700000000))",
+                VerdictKind::PermittedAfter {
+                    activation: RevisionActivation::TimeSeconds(1_700_000_000),
+                },
+            ),
+            ("'gov:permitted_after'(tomorrow)", VerdictKind::Permitted),
+            (
+                "'gov:permitted_with_exemptions'([withdraw])",
+                VerdictKind::Permitted,
+            ),
+            (
+                "'gov:permitted_if'([kyc(alice), quorum(2)])",
+                VerdictKind::PermittedIf {
+                    conditions: strings(&["kyc(alice)", "quorum(2)"]),
+                },
+            ),
+            (
+                "'gov:permitted_if'(kyc(alice))",
+                VerdictKind::PermittedIf {
+                    conditions: strings(&["kyc(alice)"]),
+                },
+            ),
+            (
+                "'gov:obliged'(pay(alice, 100))",
+                VerdictKind::Obliged {
+                    obligations: strings(&["pay(alice, 100)"]),
+                },
+            ),
+            (
+                "'gov:obliged'(pay(alice, 100), 42)",
+                VerdictKind::Obliged {
+                    obligations: strings(&["pay(alice, 100)", "42"]),
+                },
+            ),
+            ("'gov:grant'(read, alice, 3)", VerdictKind::Permitted),
+            ("'gov:forbidden'", VerdictKind::Forbidden),
+            (
+                "'gov:effective'('gov:forbidden', until(10))",
+                VerdictKind::Forbidden,
+            ),
+            ("'gov:undecided'", VerdictKind::Undecided),
+            ("allowed", VerdictKind::Other),
+            ("'gov:permitted'(now)", VerdictKind::Other),
+            ("'gov:permitted_if'", VerdictKind::Other),
+            ("'gov:obliged'", VerdictKind::Other),
+            ("not a term", VerdictKind::Other),
+        ];
+
+        for (verdict, expected) in cases {
+            assert_eq!(
+                VerdictKind::parse(verdict),
+                expected,
+                "unexpected kind for {verdict}"
+            );
+        }
+    }
+
+    #[test]
+    fn classes_ignore_verdict_arguments() {
+        let cases = vec![
+            ("'gov:permitted'", VerdictClass::Permitted),
+            ("'gov:permitted_after'(height(10))", VerdictClass::Permitted),
+            (
+                "'gov:permitted_if'([kyc(alice)])",
+                VerdictClass::PermittedIf,
+            ),
+            ("'gov:obliged'(pay(alice, 100))", VerdictClass::Obliged),
+            ("'gov:forbidden'", VerdictClass::Forbidden),
+            ("'gov:undecided'", VerdictClass::Undecided),
+            ("allowed", VerdictClass::Other),
+        ];
+
+        for (verdict, expected) in cases {
+            assert_eq!(
+                VerdictKind::parse(verdict).class(),
+                expected,
+                "unexpected class for {verdict}"
+            );
+        }
+    }
+}
@@ -111,4 +111,234 @@ pub enum AxoneGovError {
         /// The motivation returned by the constitution (a Prolog term, may be empty).
         motivation: String,
     },
+    /// A constitutional revision is already pending activation.
+    ///
+    /// Only one revision can be staged at a time: the pending revision must be activated
+    /// (`ActivateRevision`) or cancelled (`CancelRevision`) before another one is proposed.
+    #[error("a constitutional revision is already pending: {constitution_hash}")]
+    RevisionAlreadyPending {
+        /// The hash (hex) of the pending constitution.
+        constitution_hash: String,
+    },
+
+    /// There is no pending constitutional revision.
+    #[error("no pending constitutional revision")]
+    NoPendingRevision,
+
+    /// The pending constitutional revision cannot be activated yet.
+    ///
+    /// This is raised when `ActivateRevision` is executed before the activation condition
+    /// returned by the constitution is reached.
+    #[error("pending revision cannot be activated before {activation}")]
+    RevisionNotActivatable {
+        /// The activation condition of the pending revision.
+        activation: String,
+    },
+
+    /// The library module is invalid.
+    ///
+    /// This error is raised when a library fails validation checks, such as an invalid name,
+    /// non UTF-8 content or a program the Prolog engine cannot load.
+    #[error("library is invalid: {0}")]
+    LibraryInvalid(String),
+
+    /// The referenced library module does not exist.
+    ///
+    /// This is raised when a constitution uses a library (optionally pinned by hash) that is not
+    /// stored in the contract.
+    #[error("library not found: {reference}")]
+    LibraryNotFound {
+        /// The library reference, as `name` or `name@hash`.
+        reference: String,
+    },
+
+    /// Governance is paused.
+    ///
+    /// This is raised when a governance act is attempted while the constitution has paused the
+    /// resource, unless its intent was exempted by the pause decision.
+    #[error("governance is paused by decision {decision_id}: intent {intent} is not exempt")]
+    Paused {
+        /// The identifier of the recorded `gov:pause` decision.
+        decision_id: u64,
+        /// The intent of the rejected act (`none` when the case has no intent).
+        intent: String,
+    },
+
+    /// Governance is already paused.
+    #[error("governance is already paused by decision {decision_id}")]
+    AlreadyPaused {
+        /// The identifier of the recorded `gov:pause` decision.
+        decision_id: u64,
+    },
+
+    /// Governance is not paused.
+    #[error("governance is not paused")]
+    NotPaused,
+
+    /// The batch of cases is invalid.
+    ///
+    /// This is raised when a `RecordDecisions` batch is empty or exceeds the maximum batch size.
+    #[error("invalid batch: {0}")]
+    InvalidBatch(String),
+
+    /// A case of an atomic batch failed.
+    ///
+    /// In atomic mode, the first failing case aborts the whole batch and no decision is recorded.
+    #[error("batch case {index} failed: {error}")]
+    BatchCaseFailed {
+        /// The index of the failing case in the batch.
+        index: usize,
+        /// The error of the failing case.
+        error: String,
+    },
+
+    /// The off-chain signature of a case is invalid.
+    ///
+    /// This is raised when the public key is malformed or the signature does not match the case.
+    #[error("invalid case signature: {0}")]
+    InvalidSignature(String),
+
+    /// The nonce of a signed case was already used.
+    ///
+    /// Signer nonces must strictly increase to prevent signed cases from being replayed.
+    #[error("nonce {nonce} already used by signer {signer}: last nonce is {last_nonce}")]
+    NonceAlreadyUsed {
+        /// The Bech32 address of the signer.
+        signer: String,
+        /// The submitted nonce.
+        nonce: u64,
+        /// The last nonce used by the signer.
+        last_nonce: u64,
+    },
+
+    /// The appealed decision has already been appealed.
+    ///
+    /// Appeal chains are linear: a further appeal must target the latest decision of the chain.
+    #[error("decision {decision_id} is already appealed by decision {appeal_id}")]
+    DecisionAlreadyAppealed {
+        /// The appealed decision identifier.
+        decision_id: u64,
+        /// The identifier of the decision appealing it.
+        appeal_id: u64,
+    },
+
+    /// The revoked decision has already been revoked.
+    #[error("decision {decision_id} is already revoked by decision {revocation_id}")]
+    DecisionAlreadyRevoked {
+        /// The revoked decision identifier.
+        decision_id: u64,
+        /// The identifier of the decision revoking it.
+        revocation_id: u64,
+    },
+
+    /// The effective period returned along with a verdict is invalid.
+    ///
+    /// Constitutions express it as `gov:effective(Verdict, Period)`, with `Period` one of
+    /// `until(Until)`, `for(Seconds)`, `from(From)` or `period(From, Until)`.
+    #[error("invalid effective period: {0}")]
+    InvalidEffectivePeriod(String),
+
+    /// The contract is not subscribed to recorded decisions.
+    #[error("contract {contract} is not subscribed")]
+    SubscriptionNotFound {
+        /// The Bech32 address of the contract.
+        contract: String,
+    },
+
+    /// The maximum number of subscriptions is reached.
+    ///
+    /// Subscriptions are bounded so that notifying them keeps a predictable cost.
+    #[error("too many subscriptions: at most {max} contracts can subscribe")]
+    TooManySubscriptions {
+        /// The maximum number of subscriptions.
+        max: usize,
+    },
+
+    /// The configuration is invalid.
+    #[error("invalid config: {0}")]
+    InvalidConfig(String),
+
+    /// An enrichment provider failed to provide the facts of its key.
+    #[error("enrichment of '{key}' failed: {reason}")]
+    EnrichmentFailed {
+        /// The case key of the provider.
+        key: String,
+        /// The failure reason.
+        reason: String,
+    },
+
+    /// The `gov:grant(Capability, Holder, Uses)` verdict returned by the constitution is invalid.
+    #[error("invalid capability grant: {0}")]
+    InvalidCapabilityGrant(String),
+
+    /// The consumed capability is not held by the caller.
+    #[error("capability {capability_id} is not held by {consumer}")]
+    CapabilityNotHeld {
+        /// The capability identifier.
+        capability_id: u64,
+        /// The Bech32 address of the caller.
+        consumer: String,
+    },
+
+    /// The consumed capability has no remaining use.
+    #[error("capability {capability_id} is exhausted")]
+    CapabilityExhausted {
+        /// The capability identifier.
+        capability_id: u64,
+    },
+
+    /// The decision granting the consumed capability is not in force.
+    ///
+    /// It may be outside of its effective period, revoked, or superseded by an appeal.
+    #[error("capability {capability_id} is not in force: decision {decision_id} is not in force")]
+    CapabilityNotInForce {
+        /// The capability identifier.
+        capability_id: u64,
+        /// The identifier of the decision granting the capability.
+        decision_id: u64,
+    },
+
+    /// A payload exceeds the size limit declared by the constitution.
+    #[error("{payload} is too large: {size} bytes exceeds the limit of {max} bytes")]
+    PayloadTooLarge {
+        /// The kind of payload (`case` or `motivation`).
+        payload: String,
+        /// The payload size, in bytes.
+        size: u64,
+        /// The maximum payload size, in bytes.
+        max: u64,
+    },
+
+    /// The fee returned by `decision_fee/2` is invalid.
+    #[error("invalid decision fee: {0}")]
+    InvalidDecisionFee(String),
+
+    /// The funds sent along with the message do not cover the required decision fee.
+    #[error("insufficient fee: required {required}, paid {paid}")]
+    InsufficientFee {
+        /// The required fee.
+        required: String,
+        /// The funds sent along with the message.
+        paid: String,
+    },
+
+    /// The stored state layout is newer than the one supported by the migrated code.
+    #[error("unsupported state version {stored}: the latest supported version is {current}")]
+    UnsupportedStateVersion {
+        /// The version of the stored state layout.
+        stored: u64,
+        /// The latest state layout version supported.
+        current: u64,
+    },
+
+    /// The sender exceeded the rate limit declared by the constitution.
+    #[error("rate limit exceeded by {sender}: at most {max_decisions} decisions per {window_seconds} seconds")]
+    RateLimited {
+        /// The Bech32 address of the sender.
+        sender: String,
+        /// The maximum number of decisions within a window.
+        max_decisions: u64,
+        /// The duration of a window, in seconds.
+        window_seconds: u64,
+    },
 }
@@ -1,29 +1,79 @@
 use crate::{
     contract::{AxoneGov, AxoneGovResult},
-    domain::{Case, Constitution},
+    domain::{
+        constitution::ConstitutionStatus,
+        effective::split_effective_verdict,
+        fee::{fee_refund, parse_decision_fee},
+        schema::{collected_declarations, is_reserved_key},
+        CapabilityGrant, Case, CaseSignature, Config, Constitution, DecisionFilter,
+        EffectivePeriod, Library, PauseAuthorization, RevisionActivation, RevisionAuthorization,
+    },
     error::AxoneGovError,
     gateway::logic::{query_service_ask, AxoneLogicQuery, QueryServiceAskRequest},
-    msg::AxoneGovExecuteMsg,
-    queries::decision::{build_decide_query, build_decide_query_with_motivation},
+    msg::{
+        AxoneGovExecuteMsg, CaseEnrichmentResponse, ConsumeCapabilityResponse, EnrichmentQueryMsg,
+    },
+    queries::decision::{build_record_decision_query, FEE_VARIABLE},
     services::decision::{
-        build_governance_case, decide_case_with_motivation, module_term, tx_term,
+        build_governance_case, build_intent_case, caller_case, decide_case_with_motivation,
+        module_term, tx_term,
     },
-    state::{load_constitution, load_constitution_status, save_revised_constitution},
-    GOV_CTX_MODULE, GOV_CTX_TX, GOV_INTENT_ESTABLISH, GOV_INTENT_REVISE_CONSTITUTION,
-    GOV_VERDICT_PERMITTED, RESPONSE_KEY_CASE_HASH, RESPONSE_KEY_CONSTITUTION_HASH,
-    RESPONSE_KEY_CONSTITUTION_REVISER, RESPONSE_KEY_CONSTITUTION_REVISION,
-    RESPONSE_KEY_DECISION_ID, RESPONSE_KEY_MOTIVATION_HASH, RESPONSE_KEY_VERDICT,
-    RESPONSE_KEY_VERDICT_HASH,
+    services::hook::decision_hooks,
+    services::signature::verify_case_signature,
+    state::{
+        advance_index_backfill, check_sender_rate, consume_capability, current_decision_id,
+        ensure_not_appealed, ensure_not_paused, load_config, load_constitution,
+        load_constitution_status, load_decision, load_library, load_pause, load_pending_revision,
+        load_subscription, remove_subscription, revoke_decision, save_capability, save_config,
+        save_library, save_pause, save_pending_revision, save_revised_constitution,
+        save_subscription, take_pause, take_pending_revision, use_sender_rate, use_signer_nonce,
+        INDEX_BACKFILL_BATCH,
+    },
+    GOV_CTX_APPEALED_DECISION, GOV_CTX_LIBRARY_NAME, GOV_CTX_LIBRARY_SHA256, GOV_CTX_MODULE,
+    GOV_CTX_PROPOSED_CONFIG, GOV_CTX_REVOKED_DECISION, GOV_CTX_SIGNER, GOV_CTX_SUBSCRIPTION,
+    GOV_CTX_TX, GOV_INTENT_APPEAL, GOV_INTENT_CANCEL_REVISION, GOV_INTENT_CONFIGURE,
+    GOV_INTENT_ESTABLISH, GOV_INTENT_PAUSE, GOV_INTENT_RESUME, GOV_INTENT_REVISE_CONSTITUTION,
+    GOV_INTENT_REVOKE_DECISION, GOV_INTENT_STORE_LIBRARY, GOV_INTENT_SUBSCRIBE,
+    GOV_INTENT_UNSUBSCRIBE, RESPONSE_KEY_ACTIVATION_HEIGHT, RESPONSE_KEY_ACTIVATION_TIME_SECONDS,
+    RESPONSE_KEY_APPEAL_OF, RESPONSE_KEY_CAPABILITY_ID, RESPONSE_KEY_CASE_HASH,
+    RESPONSE_KEY_CONSTITUTION_HASH, RESPONSE_KEY_CONSTITUTION_REVISER,
+    RESPONSE_KEY_CONSTITUTION_REVISION, RESPONSE_KEY_DECISION_ID,
+    RESPONSE_KEY_EFFECTIVE_FROM_SECONDS, RESPONSE_KEY_EFFECTIVE_UNTIL_SECONDS,
+    RESPONSE_KEY_EXEMPT_INTENTS, RESPONSE_KEY_FAILED, RESPONSE_KEY_FEE, RESPONSE_KEY_LIBRARY_HASH,
+    RESPONSE_KEY_LIBRARY_NAME, RESPONSE_KEY_MOTIVATION_HASH,
+    RESPONSE_KEY_PROPOSED_CONSTITUTION_HASH, RESPONSE_KEY_RECORDED, RESPONSE_KEY_REMAINING_USES,
+    RESPONSE_KEY_REVISION_ACTIVATOR, RESPONSE_KEY_REVOKED_DECISION_ID, RESPONSE_KEY_SIGNER,
+    RESPONSE_KEY_SUBSCRIBER, RESPONSE_KEY_VERDICT, RESPONSE_KEY_VERDICT_HASH,
+    RESPONSE_KEY_VERDICT_KIND,
 };
 
 use crate::domain::Decision;
+use crate::prolog::ast::Term;
+use crate::prolog::parser::Parser;
 use crate::prolog::term as t;
-use crate::state::record_decision;
+use crate::state::{record_decision, Capability, DecisionRecord};
+
 use abstract_app::traits::AbstractResponse;
-use cosmwasm_std::{Binary, DepsMut, Env, MessageInfo, QuerierWrapper};
+use cosmwasm_std::{
+    to_json_binary, Addr, BankMsg, Binary, Coins, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
+    QuerierWrapper, Storage,
+};
 
-#[allow(clippy::unnecessary_wraps)]
 pub fn execute_handler(
+    mut deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    module: AxoneGov,
+    msg: AxoneGovExecuteMsg,
+) -> AxoneGovResult {
+    advance_index_backfill(deps.storage, INDEX_BACKFILL_BATCH)?;
+    let last_decision_id = current_decision_id(deps.storage)?;
+    let response = dispatch(deps.branch(), env, info, module, msg)?;
+
+    Ok(response.add_submessages(decision_hooks(deps.storage, last_decision_id)?))
+}
+
+fn dispatch(
     deps: DepsMut<'_>,
     env: Env,
     info: MessageInfo,
@@ -34,8 +84,72 @@ pub fn execute_handler(
         AxoneGovExecuteMsg::ReviseConstitution { constitution, case } => {
             execute_revise_constitution(deps, env, info, module, constitution, case)
         }
-        AxoneGovExecuteMsg::RecordDecision { case, motivated } => {
-            execute_record_decision(deps, env, info, module, case, motivated.unwrap_or(false))
+        AxoneGovExecuteMsg::RecordDecision {
+            case,
+            motivated,
+            signature,
+        } => execute_record_decision(
+            deps,
+            env,
+            info,
+            module,
+            case,
+            motivated.unwrap_or(false),
+            signature,
+        ),
+        AxoneGovExecuteMsg::RecordDecisions {
+            cases,
+            motivated,
+            atomic,
+        } => execute_record_decisions(
+            deps,
+            env,
+            info,
+            module,
+            cases,
+            motivated.unwrap_or(false),
+            atomic.unwrap_or(true),
+        ),
+        AxoneGovExecuteMsg::ActivateRevision {} => {
+            execute_activate_revision(deps, env, info, module)
+        }
+        AxoneGovExecuteMsg::CancelRevision { case } => {
+            execute_cancel_revision(deps, env, info, module, case)
+        }
+        AxoneGovExecuteMsg::StoreLibrary {
+            name,
+            library,
+            case,
+        } => execute_store_library(deps, env, info, module, name, library, case),
+        AxoneGovExecuteMsg::Pause { case } => execute_pause(deps, env, info, module, case),
+        AxoneGovExecuteMsg::Resume { case } => execute_resume(deps, env, info, module, case),
+        AxoneGovExecuteMsg::AppealDecision { decision_id, case } => {
+            execute_appeal_decision(deps, env, info, module, decision_id, case)
+        }
+        AxoneGovExecuteMsg::RevokeDecision { decision_id, case } => {
+            execute_revoke_decision(deps, env, info, module, decision_id, case)
+        }
+        AxoneGovExecuteMsg::Subscribe {
+            contract,
+            filter,
+            case,
+        } => execute_subscribe(
+            deps,
+            env,
+            info,
+            module,
+            &contract,
+            filter.unwrap_or_default(),
+            case,
+        ),
+        AxoneGovExecuteMsg::UpdateConfig { config, case } => {
+            execute_update_config(deps, env, info, module, config, case)
+        }
+        AxoneGovExecuteMsg::Unsubscribe { contract, case } => {
+            execute_unsubscribe(deps, env, info, module, &contract, case)
+        }
+        AxoneGovExecuteMsg::ConsumeCapability { capability_id } => {
+            execute_consume_capability(deps, env, info, module, capability_id)
         }
     }
 }
@@ -48,8 +162,19 @@ fn execute_revise_constitution(
     constitution_bytes: Binary,
     case_input: Option<String>,
 ) -> AxoneGovResult {
+    ensure_not_paused(deps.storage, Some(GOV_INTENT_REVISE_CONSTITUTION))?;
+    if let Some(pending) = load_pending_revision(deps.storage)? {
+        return Err(AxoneGovError::RevisionAlreadyPending {
+            constitution_hash: pending.constitution_hash_hex(),
+        });
+    }
+
     let querier = QuerierWrapper::<AxoneLogicQuery>::new(&*deps.querier);
-    let revised_constitution = Constitution::try_new(constitution_bytes, &querier)?;
+    let revised_constitution = Constitution::try_new(
+        constitution_bytes,
+        |name, hash| load_library(deps.storage, name, hash),
+        &querier,
+    )?;
     let current_constitution = load_constitution(deps.storage)?;
     let current_status = load_constitution_status(deps.storage)?;
 
@@ -63,15 +188,10 @@ fn execute_revise_constitution(
         &info,
     )?;
     let current_decision =
-        decide_case_with_motivation(&querier, current_constitution.source(), &current_case)?;
+        decide_case_with_motivation(&querier, &current_constitution, &current_case)?;
 
-    if current_decision.verdict != t::atom(GOV_VERDICT_PERMITTED) {
-        return Err(AxoneGovError::DecisionRefused {
-            intent: GOV_INTENT_REVISE_CONSTITUTION.to_string(),
-            verdict: current_decision.verdict.to_string(),
-            motivation: current_decision.motivation.to_string(),
-        });
-    }
+    let authorization = RevisionAuthorization::from_verdict(&current_decision.verdict)
+        .ok_or_else(|| current_decision.refusal(GOV_INTENT_REVISE_CONSTITUTION))?;
 
     let proposed_case = build_governance_case(
         case_input.as_deref(),
@@ -83,17 +203,10 @@ fn execute_revise_constitution(
         &info,
     )?;
     let proposed_decision =
-        decide_case_with_motivation(&querier, revised_constitution.source(), &proposed_case)?;
+        decide_case_with_motivation(&querier, &revised_constitution, &proposed_case)?;
+    proposed_decision.ensure_permitted(GOV_INTENT_ESTABLISH)?;
 
-    if proposed_decision.verdict != t::atom(GOV_VERDICT_PERMITTED) {
-        return Err(AxoneGovError::DecisionRefused {
-            intent: GOV_INTENT_ESTABLISH.to_string(),
-            verdict: proposed_decision.verdict.to_string(),
-            motivation: proposed_decision.motivation.to_string(),
-        });
-    }
-
-    record_decision(
+    let authorization_record = record_decision(
         deps.storage,
         Decision::new(
             &current_status,
@@ -106,19 +219,37 @@ fn execute_revise_constitution(
         ),
     )?;
 
-    let status = save_revised_constitution(deps.storage, &revised_constitution)?;
+    let activation = match authorization {
+        RevisionAuthorization::Immediate => {
+            let status = save_revised_constitution(deps.storage, &revised_constitution)?;
+            return Ok(module.custom_response(
+                "revise_constitution",
+                revision_attributes(&status, &info.sender),
+            ));
+        }
+        RevisionAuthorization::Deferred(activation) => activation,
+    };
+
+    let pending = save_pending_revision(
+        deps.storage,
+        &revised_constitution,
+        &current_status,
+        activation,
+        &authorization_record,
+    )?;
 
     Ok(module.custom_response(
-        "revise_constitution",
+        "schedule_revision",
         vec![
             (
-                RESPONSE_KEY_CONSTITUTION_REVISION.to_string(),
-                status.constitution_revision().to_string(),
+                RESPONSE_KEY_DECISION_ID.to_string(),
+                authorization_record.id().to_string(),
             ),
             (
-                RESPONSE_KEY_CONSTITUTION_HASH.to_string(),
-                status.constitution_hash_hex(),
+                RESPONSE_KEY_PROPOSED_CONSTITUTION_HASH.to_string(),
+                pending.constitution_hash_hex(),
             ),
+            activation_attribute(pending.activation()),
             (
                 RESPONSE_KEY_CONSTITUTION_REVISER.to_string(),
                 info.sender.to_string(),
@@ -127,110 +258,1144 @@ fn execute_revise_constitution(
     ))
 }
 
-fn execute_record_decision(
+fn execute_activate_revision(
     deps: DepsMut<'_>,
     env: Env,
     info: MessageInfo,
     module: AxoneGov,
-    case_input: String,
-    motivated: bool,
 ) -> AxoneGovResult {
-    let mut case = Case::new(&case_input)?;
-    let enrichment_term = t::dict(
-        "ctx",
+    ensure_not_paused(deps.storage, Some(GOV_INTENT_REVISE_CONSTITUTION))?;
+    let pending = take_pending_revision(deps.storage)?;
+    if !pending.activation().is_reached(&env.block) {
+        return Err(AxoneGovError::RevisionNotActivatable {
+            activation: pending.activation().to_string(),
+        });
+    }
+
+    let proposed_constitution = pending.proposed_constitution(deps.storage)?;
+    let status = save_revised_constitution(deps.storage, &proposed_constitution)?;
+
+    let mut attrs = revision_attributes(&status, pending.proposer());
+    attrs.push((
+        RESPONSE_KEY_REVISION_ACTIVATOR.to_string(),
+        info.sender.to_string(),
+    ));
+
+    Ok(module.custom_response("activate_revision", attrs))
+}
+
+fn execute_cancel_revision(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    module: AxoneGov,
+    case_input: Option<String>,
+) -> AxoneGovResult {
+    ensure_not_paused(deps.storage, Some(GOV_INTENT_CANCEL_REVISION))?;
+    let pending = take_pending_revision(deps.storage)?;
+    let querier = QuerierWrapper::<AxoneLogicQuery>::new(&*deps.querier);
+    let constitution = load_constitution(deps.storage)?;
+    let status = load_constitution_status(deps.storage)?;
+
+    let case = build_governance_case(
+        case_input.as_deref(),
+        GOV_INTENT_CANCEL_REVISION,
+        &pending.proposed_constitution(deps.storage)?,
+        Some(&status),
+        &module,
+        &env,
+        &info,
+    )?;
+    let decision = decide_case_with_motivation(&querier, &constitution, &case)?;
+    decision.ensure_permitted(GOV_INTENT_CANCEL_REVISION)?;
+
+    let record = record_decision(
+        deps.storage,
+        Decision::new(
+            &status,
+            case.to_string(),
+            decision.verdict.to_string(),
+            Some(decision.motivation.to_string()),
+            info.sender,
+            env.block.height,
+            env.block.time.seconds(),
+        ),
+    )?;
+
+    Ok(module.custom_response(
+        "cancel_revision",
+        vec![
+            (
+                RESPONSE_KEY_DECISION_ID.to_string(),
+                record.id().to_string(),
+            ),
+            (
+                RESPONSE_KEY_PROPOSED_CONSTITUTION_HASH.to_string(),
+                pending.constitution_hash_hex(),
+            ),
+        ],
+    ))
+}
+
+fn execute_store_library(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    module: AxoneGov,
+    name: String,
+    library_bytes: Binary,
+    case_input: Option<String>,
+) -> AxoneGovResult {
+    ensure_not_paused(deps.storage, Some(GOV_INTENT_STORE_LIBRARY))?;
+    let querier = QuerierWrapper::<AxoneLogicQuery>::new(&*deps.querier);
+    let library = Library::try_new(name, library_bytes, &querier)?;
+    let constitution = load_constitution(deps.storage)?;
+    let status = load_constitution_status(deps.storage)?;
+
+    let case = build_intent_case(
+        case_input.as_deref(),
+        GOV_INTENT_STORE_LIBRARY,
         vec![
-            t::kv(GOV_CTX_MODULE, module_term(&module)),
-            t::kv(GOV_CTX_TX, tx_term(&env, &info)),
+            t::kv(GOV_CTX_LIBRARY_NAME, t::atom(library.name().clone())),
+            t::kv(GOV_CTX_LIBRARY_SHA256, t::atom(library.pin().hash_hex())),
         ],
-    );
+        Some(&status),
+        &module,
+        &env,
+        &info,
+    )?;
+    let decision = decide_case_with_motivation(&querier, &constitution, &case)?;
+    decision.ensure_permitted(GOV_INTENT_STORE_LIBRARY)?;
+
+    let record = record_decision(
+        deps.storage,
+        Decision::new(
+            &status,
+            case.to_string(),
+            decision.verdict.to_string(),
+            Some(decision.motivation.to_string()),
+            info.sender,
+            env.block.height,
+            env.block.time.seconds(),
+        ),
+    )?;
+    let library_record = save_library(deps.storage, &library, &record)?;
 
-    let enrichment = Case::try_from(enrichment_term)?;
-    case.merge(&enrichment);
+    Ok(module.custom_response(
+        "store_library",
+        vec![
+            (
+                RESPONSE_KEY_DECISION_ID.to_string(),
+                record.id().to_string(),
+            ),
+            (
+                RESPONSE_KEY_LIBRARY_NAME.to_string(),
+                library_record.name().clone(),
+            ),
+            (
+                RESPONSE_KEY_LIBRARY_HASH.to_string(),
+                library_record.library_hash_hex(),
+            ),
+        ],
+    ))
+}
 
-    let case_term = case.to_string();
+fn execute_pause(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    module: AxoneGov,
+    case_input: Option<String>,
+) -> AxoneGovResult {
+    if let Some(pause) = load_pause(deps.storage)? {
+        return Err(AxoneGovError::AlreadyPaused {
+            decision_id: pause.decision_id(),
+        });
+    }
 
+    let querier = QuerierWrapper::<AxoneLogicQuery>::new(&*deps.querier);
     let constitution = load_constitution(deps.storage)?;
     let status = load_constitution_status(deps.storage)?;
-    let program = constitution.source();
-    let query = if motivated {
-        build_decide_query_with_motivation(&case)
-    } else {
-        build_decide_query(&case)
-    };
 
-    let request = QueryServiceAskRequest::one(program, query);
-    let response = query_service_ask(
-        &QuerierWrapper::<AxoneLogicQuery>::new(&*deps.querier),
-        request,
+    let case = build_intent_case(
+        case_input.as_deref(),
+        GOV_INTENT_PAUSE,
+        vec![],
+        Some(&status),
+        &module,
+        &env,
+        &info,
     )?;
-    let answer = response.answer.ok_or(AxoneGovError::PrologEngineNoAnswer)?;
+    let decision = decide_case_with_motivation(&querier, &constitution, &case)?;
+    let authorization = PauseAuthorization::from_verdict(&decision.verdict)
+        .ok_or_else(|| decision.refusal(GOV_INTENT_PAUSE))?;
 
-    if let Some(error) = answer
-        .results
-        .iter()
-        .find_map(|result| result.error.as_deref())
-    {
-        return Err(AxoneGovError::DecisionFailed(error.to_string()));
-    }
+    let record = record_decision(
+        deps.storage,
+        Decision::new(
+            &status,
+            case.to_string(),
+            decision.verdict.to_string(),
+            Some(decision.motivation.to_string()),
+            info.sender,
+            env.block.height,
+            env.block.time.seconds(),
+        ),
+    )?;
+    let pause = save_pause(deps.storage, authorization.into_exempt_intents(), &record)?;
 
-    let result = answer
-        .results
-        .first()
-        .ok_or(AxoneGovError::DecisionNoResult)?;
-    let verdict =
-        find_substitution(result, "Verdict").ok_or(AxoneGovError::DecisionMissingVerdict)?;
-    let motivation = if motivated {
-        Some(
-            find_substitution(result, "Motivation")
-                .ok_or(AxoneGovError::DecisionMissingMotivation)?,
-        )
-    } else {
-        None
-    };
+    Ok(module.custom_response(
+        "pause",
+        vec![
+            (
+                RESPONSE_KEY_DECISION_ID.to_string(),
+                record.id().to_string(),
+            ),
+            (
+                RESPONSE_KEY_EXEMPT_INTENTS.to_string(),
+                pause.exempt_intents().join(","),
+            ),
+        ],
+    ))
+}
 
-    let decision = Decision::new(
-        &status,
-        case_term,
-        verdict,
-        motivation,
-        info.sender,
-        env.block.height,
-        env.block.time.seconds(),
-    );
+fn execute_resume(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    module: AxoneGov,
+    case_input: Option<String>,
+) -> AxoneGovResult {
+    take_pause(deps.storage)?;
+    let querier = QuerierWrapper::<AxoneLogicQuery>::new(&*deps.querier);
+    let constitution = load_constitution(deps.storage)?;
+    let status = load_constitution_status(deps.storage)?;
+
+    let case = build_intent_case(
+        case_input.as_deref(),
+        GOV_INTENT_RESUME,
+        vec![],
+        Some(&status),
+        &module,
+        &env,
+        &info,
+    )?;
+    let decision = decide_case_with_motivation(&querier, &constitution, &case)?;
+    decision.ensure_permitted(GOV_INTENT_RESUME)?;
+
+    let record = record_decision(
+        deps.storage,
+        Decision::new(
+            &status,
+            case.to_string(),
+            decision.verdict.to_string(),
+            Some(decision.motivation.to_string()),
+            info.sender,
+            env.block.height,
+            env.block.time.seconds(),
+        ),
+    )?;
+
+    Ok(module.custom_response(
+        "resume",
+        vec![(
+            RESPONSE_KEY_DECISION_ID.to_string(),
+            record.id().to_string(),
+        )],
+    ))
+}
+
+fn execute_appeal_decision(
+    mut deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    module: AxoneGov,
+    decision_id: u64,
+    case_input: Option<String>,
+) -> AxoneGovResult {
+    ensure_not_paused(deps.storage, Some(GOV_INTENT_APPEAL))?;
+    let appealed = load_decision(deps.storage, decision_id)?;
+    ensure_not_appealed(deps.storage, decision_id)?;
+
+    let constitution = load_constitution(deps.storage)?;
+    let status = load_constitution_status(deps.storage)?;
+    let config = load_config(deps.storage)?;
+    ensure_sender_rate(deps.storage, &constitution, &env, &info.sender, 1)?;
+
+    let original_intent = original_intent(deps.storage, &appealed)?;
+    let appealed_term = decision_term(&appealed, original_intent.as_deref())?;
+    let mut case = Case::new(appealed.case())?;
+    case.remove_keys(|key| is_reserved_key(key) || config.is_enrichment_key(key));
+    case.merge(&build_intent_case(
+        case_input.as_deref(),
+        GOV_INTENT_APPEAL,
+        vec![t::kv(GOV_CTX_APPEALED_DECISION, appealed_term)],
+        Some(&status),
+        &module,
+        &env,
+        &info,
+    )?);
+    constitution.validate_governance_case(&case)?;
+
+    let case_term = case.to_string();
+    constitution.limits().ensure_case_size(&case_term)?;
+    let (verdict, motivation, fee) =
+        ask_record_decision(deps.as_ref(), &constitution, &case, true)?;
+    let (verdict, period) = split_effective_verdict(verdict, env.block.time.seconds())?;
+
+    let (record, capability) = record_decision_with_capability(
+        deps.branch(),
+        Decision::new(
+            &status,
+            case_term,
+            verdict,
+            motivation,
+            info.sender.clone(),
+            env.block.height,
+            env.block.time.seconds(),
+        )
+        .appealing(decision_id)
+        .effective_during(period),
+    )?;
 
-    let decision_record = record_decision(deps.storage, decision)?;
     let mut attrs = vec![
         (
             RESPONSE_KEY_DECISION_ID.to_string(),
-            decision_record.id().to_string(),
+            record.id().to_string(),
         ),
+        (RESPONSE_KEY_APPEAL_OF.to_string(), decision_id.to_string()),
         (
             RESPONSE_KEY_CONSTITUTION_REVISION.to_string(),
-            decision_record.constitution_revision().to_string(),
-        ),
-        (
-            RESPONSE_KEY_CONSTITUTION_HASH.to_string(),
-            decision_record.constitution_hash_hex(),
-        ),
-        (
-            RESPONSE_KEY_CASE_HASH.to_string(),
-            decision_record.case_hash_hex(),
-        ),
-        (
-            RESPONSE_KEY_VERDICT.to_string(),
-            decision_record.verdict().clone(),
+            record.constitution_revision().to_string(),
         ),
+        (RESPONSE_KEY_VERDICT.to_string(), record.verdict().clone()),
         (
-            RESPONSE_KEY_VERDICT_HASH.to_string(),
-            decision_record.verdict_hash_hex(),
+            RESPONSE_KEY_VERDICT_KIND.to_string(),
+            record.verdict_kind().class().to_string(),
         ),
     ];
+    attrs.extend(effective_period_attributes(record.effective_period()));
 
-    if let Some(h) = decision_record.motivation_hash_hex() {
-        attrs.push((RESPONSE_KEY_MOTIVATION_HASH.to_string(), h));
+    if let Some(capability) = capability {
+        attrs.push((
+            RESPONSE_KEY_CAPABILITY_ID.to_string(),
+            capability.id().to_string(),
+        ));
+    }
+
+    let fee_msgs = settle_fee(&deps, &config, &module, &info, &fee)?;
+    if !fee.is_empty() {
+        attrs.push((RESPONSE_KEY_FEE.to_string(), fee.to_string()));
+    }
+
+    Ok(module
+        .custom_response("appeal_decision", attrs)
+        .add_messages(fee_msgs))
+}
+
+fn execute_revoke_decision(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    module: AxoneGov,
+    decision_id: u64,
+    case_input: Option<String>,
+) -> AxoneGovResult {
+    ensure_not_paused(deps.storage, Some(GOV_INTENT_REVOKE_DECISION))?;
+    let revoked = load_decision(deps.storage, decision_id)?;
+    if let Some(revocation_id) = revoked.revoked_by() {
+        return Err(AxoneGovError::DecisionAlreadyRevoked {
+            decision_id,
+            revocation_id,
+        });
+    }
+
+    let querier = QuerierWrapper::<AxoneLogicQuery>::new(&*deps.querier);
+    let constitution = load_constitution(deps.storage)?;
+    let status = load_constitution_status(deps.storage)?;
+
+    let original_intent = original_intent(deps.storage, &revoked)?;
+    let case = build_intent_case(
+        case_input.as_deref(),
+        GOV_INTENT_REVOKE_DECISION,
+        vec![t::kv(
+            GOV_CTX_REVOKED_DECISION,
+            decision_term(&revoked, original_intent.as_deref())?,
+        )],
+        Some(&status),
+        &module,
+        &env,
+        &info,
+    )?;
+    let decision = decide_case_with_motivation(&querier, &constitution, &case)?;
+    decision.ensure_permitted(GOV_INTENT_REVOKE_DECISION)?;
+
+    let record = record_decision(
+        deps.storage,
+        Decision::new(
+            &status,
+            case.to_string(),
+            decision.verdict.to_string(),
+            Some(decision.motivation.to_string()),
+            info.sender,
+            env.block.height,
+            env.block.time.seconds(),
+        ),
+    )?;
+    revoke_decision(deps.storage, decision_id, record.id())?;
+
+    Ok(module.custom_response(
+        "revoke_decision",
+        vec![
+            (
+                RESPONSE_KEY_DECISION_ID.to_string(),
+                record.id().to_string(),
+            ),
+            (
+                RESPONSE_KEY_REVOKED_DECISION_ID.to_string(),
+                decision_id.to_string(),
+            ),
+        ],
+    ))
+}
+
+fn execute_subscribe(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    module: AxoneGov,
+    contract: &str,
+    filter: DecisionFilter,
+    case_input: Option<String>,
+) -> AxoneGovResult {
+    ensure_not_paused(deps.storage, Some(GOV_INTENT_SUBSCRIBE))?;
+    let contract = deps.api.addr_validate(contract)?;
+
+    let querier = QuerierWrapper::<AxoneLogicQuery>::new(&*deps.querier);
+    let constitution = load_constitution(deps.storage)?;
+    let status = load_constitution_status(deps.storage)?;
+
+    let case = build_intent_case(
+        case_input.as_deref(),
+        GOV_INTENT_SUBSCRIBE,
+        vec![t::kv(
+            GOV_CTX_SUBSCRIPTION,
+            filter.subscription_term(&contract),
+        )],
+        Some(&status),
+        &module,
+        &env,
+        &info,
+    )?;
+    let decision = decide_case_with_motivation(&querier, &constitution, &case)?;
+    decision.ensure_permitted(GOV_INTENT_SUBSCRIBE)?;
+
+    let record = record_decision(
+        deps.storage,
+        Decision::new(
+            &status,
+            case.to_string(),
+            decision.verdict.to_string(),
+            Some(decision.motivation.to_string()),
+            info.sender,
+            env.block.height,
+            env.block.time.seconds(),
+        ),
+    )?;
+    let subscription = save_subscription(deps.storage, contract, filter, &record)?;
+
+    Ok(module.custom_response(
+        "subscribe",
+        vec![
+            (
+                RESPONSE_KEY_DECISION_ID.to_string(),
+                record.id().to_string(),
+            ),
+            (
+                RESPONSE_KEY_SUBSCRIBER.to_string(),
+                subscription.contract().to_string(),
+            ),
+        ],
+    ))
+}
+
+fn execute_update_config(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    module: AxoneGov,
+    config: Config,
+    case_input: Option<String>,
+) -> AxoneGovResult {
+    ensure_not_paused(deps.storage, Some(GOV_INTENT_CONFIGURE))?;
+    config.validate(deps.api)?;
+
+    let querier = QuerierWrapper::<AxoneLogicQuery>::new(&*deps.querier);
+    let constitution = load_constitution(deps.storage)?;
+    let status = load_constitution_status(deps.storage)?;
+
+    let case = build_intent_case(
+        case_input.as_deref(),
+        GOV_INTENT_CONFIGURE,
+        vec![t::kv(GOV_CTX_PROPOSED_CONFIG, config.term())],
+        Some(&status),
+        &module,
+        &env,
+        &info,
+    )?;
+    let decision = decide_case_with_motivation(&querier, &constitution, &case)?;
+    decision.ensure_permitted(GOV_INTENT_CONFIGURE)?;
+
+    let record = record_decision(
+        deps.storage,
+        Decision::new(
+            &status,
+            case.to_string(),
+            decision.verdict.to_string(),
+            Some(decision.motivation.to_string()),
+            info.sender,
+            env.block.height,
+            env.block.time.seconds(),
+        ),
+    )?;
+    save_config(deps.storage, &config)?;
+
+    Ok(module.custom_response(
+        "update_config",
+        vec![(
+            RESPONSE_KEY_DECISION_ID.to_string(),
+            record.id().to_string(),
+        )],
+    ))
+}
+
+fn execute_unsubscribe(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    module: AxoneGov,
+    contract: &str,
+    case_input: Option<String>,
+) -> AxoneGovResult {
+    ensure_not_paused(deps.storage, Some(GOV_INTENT_UNSUBSCRIBE))?;
+    let contract = deps.api.addr_validate(contract)?;
+    let subscription = load_subscription(deps.storage, &contract)?;
+
+    let querier = QuerierWrapper::<AxoneLogicQuery>::new(&*deps.querier);
+    let constitution = load_constitution(deps.storage)?;
+    let status = load_constitution_status(deps.storage)?;
+
+    let case = build_intent_case(
+        case_input.as_deref(),
+        GOV_INTENT_UNSUBSCRIBE,
+        vec![t::kv(
+            GOV_CTX_SUBSCRIPTION,
+            subscription.filter().subscription_term(&contract),
+        )],
+        Some(&status),
+        &module,
+        &env,
+        &info,
+    )?;
+    let decision = decide_case_with_motivation(&querier, &constitution, &case)?;
+    decision.ensure_permitted(GOV_INTENT_UNSUBSCRIBE)?;
+
+    let record = record_decision(
+        deps.storage,
+        Decision::new(
+            &status,
+            case.to_string(),
+            decision.verdict.to_string(),
+            Some(decision.motivation.to_string()),
+            info.sender,
+            env.block.height,
+            env.block.time.seconds(),
+        ),
+    )?;
+    remove_subscription(deps.storage, &contract)?;
+
+    Ok(module.custom_response(
+        "unsubscribe",
+        vec![
+            (
+                RESPONSE_KEY_DECISION_ID.to_string(),
+                record.id().to_string(),
+            ),
+            (RESPONSE_KEY_SUBSCRIBER.to_string(), contract.to_string()),
+        ],
+    ))
+}
+
+fn execute_consume_capability(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    module: AxoneGov,
+    capability_id: u64,
+) -> AxoneGovResult {
+    let capability = consume_capability(
+        deps.storage,
+        capability_id,
+        &info.sender,
+        env.block.time.seconds(),
+    )?;
+
+    Ok(module
+        .custom_response(
+            "consume_capability",
+            vec![
+                (
+                    RESPONSE_KEY_CAPABILITY_ID.to_string(),
+                    capability.id().to_string(),
+                ),
+                (
+                    RESPONSE_KEY_DECISION_ID.to_string(),
+                    capability.decision_id().to_string(),
+                ),
+                (
+                    RESPONSE_KEY_REMAINING_USES.to_string(),
+                    capability.remaining_uses().to_string(),
+                ),
+            ],
+        )
+        .set_data(to_json_binary(&ConsumeCapabilityResponse {
+            capability_id: capability.id(),
+            capability: capability.capability().clone(),
+            decision_id: capability.decision_id(),
+            remaining_uses: capability.remaining_uses(),
+        })?))
+}
+
+fn effective_period_attributes(period: Option<EffectivePeriod>) -> Vec<(String, String)> {
+    let Some(period) = period else {
+        return vec![];
+    };
+    let mut attrs = vec![(
+        RESPONSE_KEY_EFFECTIVE_FROM_SECONDS.to_string(),
+        period.from_seconds.to_string(),
+    )];
+    if let Some(until) = period.until_seconds {
+        attrs.push((
+            RESPONSE_KEY_EFFECTIVE_UNTIL_SECONDS.to_string(),
+            until.to_string(),
+        ));
+    }
+
+    attrs
+}
+
+/// The intent of the original decision of the appeal chain `record` belongs to, if any.
+fn original_intent(
+    storage: &dyn Storage,
+    record: &DecisionRecord,
+) -> AxoneGovResult<Option<String>> {
+    let mut original = record.clone();
+    while let Some(appealed) = original.appeal_of() {
+        original = load_decision(storage, appealed)?;
+    }
+
+    Ok(Case::new(original.case())?.intent().map(str::to_string))
+}
+
+/// The term describing a recorded decision to the constitution deciding about it.
+fn decision_term(record: &DecisionRecord, intent: Option<&str>) -> AxoneGovResult<Term> {
+    let verdict = Parser::new(record.verdict())
+        .and_then(Parser::parse_root)
+        .map_err(|err| {
+            AxoneGovError::PrologEngineError(format!(
+                "invalid verdict term at offset {}: {}",
+                err.at, err.msg
+            ))
+        })?;
+
+    let mut pairs = vec![
+        t::kv("id", record.id().into()),
+        t::kv(
+            "constitution_revision",
+            record.constitution_revision().into(),
+        ),
+    ];
+    if let Some(intent) = intent {
+        pairs.push(t::kv("intent", t::atom(intent)));
+    }
+    pairs.push(t::kv("verdict", verdict));
+
+    Ok(t::dict("decision", pairs))
+}
+
+fn revision_attributes(status: &ConstitutionStatus, reviser: &Addr) -> Vec<(String, String)> {
+    vec![
+        (
+            RESPONSE_KEY_CONSTITUTION_REVISION.to_string(),
+            status.constitution_revision().to_string(),
+        ),
+        (
+            RESPONSE_KEY_CONSTITUTION_HASH.to_string(),
+            status.constitution_hash_hex(),
+        ),
+        (
+            RESPONSE_KEY_CONSTITUTION_REVISER.to_string(),
+            reviser.to_string(),
+        ),
+    ]
+}
+
+fn activation_attribute(activation: RevisionActivation) -> (String, String) {
+    match activation {
+        RevisionActivation::Height(height) => (
+            RESPONSE_KEY_ACTIVATION_HEIGHT.to_string(),
+            height.to_string(),
+        ),
+        RevisionActivation::TimeSeconds(seconds) => (
+            RESPONSE_KEY_ACTIVATION_TIME_SECONDS.to_string(),
+            seconds.to_string(),
+        ),
+    }
+}
+
+fn execute_record_decision(
+    mut deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    module: AxoneGov,
+    case_input: String,
+    motivated: bool,
+    signature: Option<CaseSignature>,
+) -> AxoneGovResult {
+    let constitution = load_constitution(deps.storage)?;
+    let status = load_constitution_status(deps.storage)?;
+    let config = load_config(deps.storage)?;
+    let mut enrichment = record_decision_enrichment(deps.as_ref(), &config, &module, &env, &info)?;
+
+    let signer = signature
+        .map(|signature| -> AxoneGovResult<Addr> {
+            let signer = verify_case_signature(deps.api, &env, &case_input, &signature)?;
+            use_signer_nonce(deps.storage, &signer, signature.nonce)?;
+            enrichment.merge(&Case::try_from(t::dict(
+                "ctx",
+                vec![t::kv(GOV_CTX_SIGNER, signer_term(&signer, &signature))],
+            ))?);
+            Ok(signer)
+        })
+        .transpose()?;
+    let author = signer.clone().unwrap_or_else(|| info.sender.clone());
+    ensure_sender_rate(deps.storage, &constitution, &env, &author, 1)?;
+
+    let (decision_record, capability, fee) = decide_and_record(
+        deps.branch(),
+        &constitution,
+        &status,
+        &enrichment,
+        &case_input,
+        motivated,
+        &author,
+        &env,
+    )?;
+
+    let mut attrs = vec![
+        (
+            RESPONSE_KEY_DECISION_ID.to_string(),
+            decision_record.id().to_string(),
+        ),
+        (
+            RESPONSE_KEY_CONSTITUTION_REVISION.to_string(),
+            decision_record.constitution_revision().to_string(),
+        ),
+        (
+            RESPONSE_KEY_CONSTITUTION_HASH.to_string(),
+            decision_record.constitution_hash_hex(),
+        ),
+        (
+            RESPONSE_KEY_CASE_HASH.to_string(),
+            decision_record.case_hash_hex(),
+        ),
+        (
+            RESPONSE_KEY_VERDICT.to_string(),
+            decision_record.verdict().clone(),
+        ),
+        (
+            RESPONSE_KEY_VERDICT_HASH.to_string(),
+            decision_record.verdict_hash_hex(),
+        ),
+        (
+            RESPONSE_KEY_VERDICT_KIND.to_string(),
+            decision_record.verdict_kind().class().to_string(),
+        ),
+    ];
+
+    if let Some(h) = decision_record.motivation_hash_hex() {
+        attrs.push((RESPONSE_KEY_MOTIVATION_HASH.to_string(), h));
+    }
+
+    if let Some(signer) = signer {
+        attrs.push((RESPONSE_KEY_SIGNER.to_string(), signer.to_string()));
+    }
+
+    attrs.extend(effective_period_attributes(
+        decision_record.effective_period(),
+    ));
+
+    if let Some(capability) = capability {
+        attrs.push((
+            RESPONSE_KEY_CAPABILITY_ID.to_string(),
+            capability.id().to_string(),
+        ));
+    }
+
+    let fee_msgs = settle_fee(&deps, &config, &module, &info, &fee)?;
+    if !fee.is_empty() {
+        attrs.push((RESPONSE_KEY_FEE.to_string(), fee.to_string()));
+    }
+
+    Ok(module
+        .custom_response("record_decision", attrs)
+        .add_messages(fee_msgs))
+}
+
+fn execute_record_decisions(
+    mut deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    module: AxoneGov,
+    cases: Vec<String>,
+    motivated: bool,
+    atomic: bool,
+) -> AxoneGovResult {
+    let config = load_config(deps.storage)?;
+    let max_batch_size = config.max_batch_size as usize;
+    if cases.is_empty() || cases.len() > max_batch_size {
+        return Err(AxoneGovError::InvalidBatch(format!(
+            "expected between 1 and {max_batch_size} cases, got {}",
+            cases.len()
+        )));
+    }
+
+    let constitution = load_constitution(deps.storage)?;
+    let status = load_constitution_status(deps.storage)?;
+    let enrichment = record_decision_enrichment(deps.as_ref(), &config, &module, &env, &info)?;
+
+    let mut attrs = Vec::with_capacity(cases.len() * 3 + 2);
+    let mut recorded = 0usize;
+    let mut fee = Coins::default();
+    for (index, case_input) in cases.iter().enumerate() {
+        let outcome = ensure_sender_rate_allows(
+            deps.storage,
+            &constitution,
+            &env,
+            &info.sender,
+            recorded as u64 + 1,
+        )
+        .and_then(|()| {
+            decide_and_record(
+                deps.branch(),
+                &constitution,
+                &status,
+                &enrichment,
+                case_input,
+                motivated,
+                &info.sender,
+                &env,
+            )
+        });
+
+        match outcome {
+            Ok((record, capability, case_fee)) => {
+                recorded += 1;
+                for coin in case_fee {
+                    fee.add(coin)?;
+                }
+                attrs.push((
+                    format!("case.{index}.{RESPONSE_KEY_DECISION_ID}"),
+                    record.id().to_string(),
+                ));
+                attrs.push((
+                    format!("case.{index}.{RESPONSE_KEY_VERDICT}"),
+                    record.verdict().clone(),
+                ));
+                attrs.push((
+                    format!("case.{index}.{RESPONSE_KEY_VERDICT_KIND}"),
+                    record.verdict_kind().class().to_string(),
+                ));
+                if let Some(capability) = capability {
+                    attrs.push((
+                        format!("case.{index}.{RESPONSE_KEY_CAPABILITY_ID}"),
+                        capability.id().to_string(),
+                    ));
+                }
+            }
+            Err(err) if atomic => {
+                return Err(AxoneGovError::BatchCaseFailed {
+                    index,
+                    error: err.to_string(),
+                })
+            }
+            Err(err) => attrs.push((format!("case.{index}.error"), err.to_string())),
+        }
+    }
+
+    attrs.push((RESPONSE_KEY_RECORDED.to_string(), recorded.to_string()));
+    attrs.push((
+        RESPONSE_KEY_FAILED.to_string(),
+        (cases.len() - recorded).to_string(),
+    ));
+
+    ensure_sender_rate(
+        deps.storage,
+        &constitution,
+        &env,
+        &info.sender,
+        recorded as u64,
+    )?;
+    let fee_msgs = settle_fee(&deps, &config, &module, &info, &fee)?;
+    if !fee.is_empty() {
+        attrs.push((RESPONSE_KEY_FEE.to_string(), fee.to_string()));
+    }
+
+    Ok(module
+        .custom_response("record_decisions", attrs)
+        .add_messages(fee_msgs))
+}
+
+/// Account for the decisions recorded by `sender` within the rate limit of the constitution, if
+/// any.
+fn ensure_sender_rate(
+    storage: &mut dyn Storage,
+    constitution: &Constitution,
+    env: &Env,
+    sender: &Addr,
+    decisions: u64,
+) -> AxoneGovResult<()> {
+    match constitution.limits().rate_limit {
+        Some(limit) => {
+            use_sender_rate(storage, sender, &limit, env.block.time.seconds(), decisions)
+        }
+        None => Ok(()),
+    }
+}
+
+/// Ensure `sender` may record `decisions` decisions within the rate limit of the constitution, if
+/// any, without accounting for them.
+fn ensure_sender_rate_allows(
+    storage: &dyn Storage,
+    constitution: &Constitution,
+    env: &Env,
+    sender: &Addr,
+    decisions: u64,
+) -> AxoneGovResult<()> {
+    match constitution.limits().rate_limit {
+        Some(limit) => {
+            check_sender_rate(storage, sender, &limit, env.block.time.seconds(), decisions)
+        }
+        None => Ok(()),
+    }
+}
+
+/// Collect the fee required to record decisions from the funds sent along with the message.
+///
+/// The fee is forwarded to the configured fee recipient, or else to the account hosting the module,
+/// and any excess is refunded to the sender.
+fn settle_fee(
+    deps: &DepsMut<'_>,
+    config: &Config,
+    module: &AxoneGov,
+    info: &MessageInfo,
+    fee: &Coins,
+) -> AxoneGovResult<Vec<CosmosMsg>> {
+    let refund = fee_refund(fee, &info.funds)?;
+
+    let mut msgs = Vec::with_capacity(2);
+    if !fee.is_empty() {
+        let recipient = match &config.fee_recipient {
+            Some(recipient) => recipient.clone(),
+            None => module.load_state(deps.storage)?.account.addr().to_string(),
+        };
+        msgs.push(
+            BankMsg::Send {
+                to_address: recipient,
+                amount: fee.to_vec(),
+            }
+            .into(),
+        );
+    }
+    if !refund.is_empty() {
+        msgs.push(
+            BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: refund,
+            }
+            .into(),
+        );
+    }
+
+    Ok(msgs)
+}
+
+/// The contract-derived facts injected into every case recorded by a message, along with the facts
+/// of the configured enrichment providers.
+fn record_decision_enrichment(
+    deps: Deps<'_>,
+    config: &Config,
+    module: &AxoneGov,
+    env: &Env,
+    info: &MessageInfo,
+) -> AxoneGovResult<Case> {
+    let mut pairs = config
+        .enrichment_providers
+        .iter()
+        .map(|provider| -> AxoneGovResult<(String, Term)> {
+            let failed = |reason: String| AxoneGovError::EnrichmentFailed {
+                key: provider.key.clone(),
+                reason,
+            };
+            let response: CaseEnrichmentResponse = deps
+                .querier
+                .query_wasm_smart(
+                    &provider.contract,
+                    &EnrichmentQueryMsg::CaseEnrichment {
+                        sender: info.sender.to_string(),
+                    },
+                )
+                .map_err(|err| failed(err.to_string()))?;
+            let term = Parser::new(&response.term)
+                .and_then(Parser::parse_root)
+                .map_err(|err| failed(format!("invalid term at offset {}: {}", err.at, err.msg)))?;
+
+            Ok(t::kv(&provider.key, term))
+        })
+        .collect::<AxoneGovResult<Vec<_>>>()?;
+    pairs.push(t::kv(GOV_CTX_MODULE, module_term(module)));
+    pairs.push(t::kv(GOV_CTX_TX, tx_term(env, info)));
+
+    Case::try_from(t::dict("ctx", pairs))
+}
+
+fn signer_term(signer: &Addr, signature: &CaseSignature) -> Term {
+    t::dict(
+        "signer",
+        vec![
+            t::kv("address", t::atom(signer.as_str())),
+            t::kv("algorithm", t::atom(signature.algorithm.to_string())),
+            t::kv("nonce", signature.nonce.into()),
+        ],
+    )
+}
+
+/// Decide a caller-provided case with the given constitution and record the decision, along with
+/// the capability it grants, if any. Returns the fee required by the constitution to record it.
+///
+/// Storage is only written once the decision succeeded and the capability it grants, if any, is
+/// valid, so a case failing before that leaves no trace.
+#[allow(clippy::too_many_arguments)]
+fn decide_and_record(
+    deps: DepsMut<'_>,
+    constitution: &Constitution,
+    status: &ConstitutionStatus,
+    enrichment: &Case,
+    case_input: &str,
+    motivated: bool,
+    author: &Addr,
+    env: &Env,
+) -> AxoneGovResult<(DecisionRecord, Option<Capability>, Coins)> {
+    let mut case = caller_case(case_input)?;
+    case.merge(enrichment);
+
+    ensure_not_paused(deps.storage, case.intent())?;
+    constitution.validate_case(&case, enrichment)?;
+
+    let case_term = case.to_string();
+    constitution.limits().ensure_case_size(&case_term)?;
+
+    let (verdict, motivation, fee) =
+        ask_record_decision(deps.as_ref(), constitution, &case, motivated)?;
+
+    let (verdict, period) = split_effective_verdict(verdict, env.block.time.seconds())?;
+    let decision = Decision::new(
+        status,
+        case_term,
+        verdict,
+        motivation,
+        author.clone(),
+        env.block.height,
+        env.block.time.seconds(),
+    )
+    .effective_during(period);
+
+    let (record, capability) = record_decision_with_capability(deps, decision)?;
+
+    Ok((record, capability, fee))
+}
+
+/// Record a decision along with the capability its verdict grants, if any.
+///
+/// The grant is checked before the decision is stored, so an invalid grant records nothing.
+fn record_decision_with_capability(
+    deps: DepsMut<'_>,
+    decision: Decision,
+) -> AxoneGovResult<(DecisionRecord, Option<Capability>)> {
+    let grant = CapabilityGrant::from_verdict(decision.verdict())?
+        .map(|grant| -> AxoneGovResult<_> {
+            let holder = deps.api.addr_validate(&grant.holder)?;
+            Ok((grant, holder))
+        })
+        .transpose()?;
+
+    let record = record_decision(deps.storage, decision)?;
+    let capability = grant
+        .map(|(grant, holder)| save_capability(deps.storage, grant, holder, &record))
+        .transpose()?;
+
+    Ok((record, capability))
+}
+
+/// Ask the constitution to decide a case to be recorded, returning its verdict and motivation (if
+/// `motivated`) along with the fee required to record the decision.
+fn ask_record_decision(
+    deps: Deps<'_>,
+    constitution: &Constitution,
+    case: &Case,
+    motivated: bool,
+) -> AxoneGovResult<(String, Option<String>, Coins)> {
+    let program = constitution.program();
+    let query = build_record_decision_query(case, motivated);
+
+    let request = QueryServiceAskRequest::one(program, query);
+    let response = query_service_ask(
+        &QuerierWrapper::<AxoneLogicQuery>::new(&*deps.querier),
+        request,
+    )?;
+    let answer = response.answer.ok_or(AxoneGovError::PrologEngineNoAnswer)?;
+
+    if let Some(error) = answer
+        .results
+        .iter()
+        .find_map(|result| result.error.as_deref())
+    {
+        return Err(AxoneGovError::DecisionFailed(error.to_string()));
+    }
+
+    let result = answer
+        .results
+        .first()
+        .ok_or(AxoneGovError::DecisionNoResult)?;
+    let verdict =
+        find_substitution(result, "Verdict").ok_or(AxoneGovError::DecisionMissingVerdict)?;
+    let motivation = if motivated {
+        Some(
+            find_substitution(result, "Motivation")
+                .ok_or(AxoneGovError::DecisionMissingMotivation)?,
+        )
+    } else {
+        None
+    };
+    if let Some(motivation) = &motivation {
+        constitution.limits().ensure_motivation_size(motivation)?;
     }
+    let fee = parse_decision_fee(&collected_declarations(
+        &result.substitutions,
+        FEE_VARIABLE,
+    )?)?;
 
-    Ok(module.custom_response("record_decision", attrs))
+    Ok((verdict, motivation, fee))
 }
 
 fn find_substitution(result: &crate::gateway::logic::Result, variable: &str) -> Option<String> {
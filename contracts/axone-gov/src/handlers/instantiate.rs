@@ -1,14 +1,14 @@
-use crate::prolog::term as t;
 use crate::{
     contract::{AxoneGov, AxoneGovResult},
     domain::Constitution,
     error::AxoneGovError,
     gateway::logic::AxoneLogicQuery,
     msg::AxoneGovInstantiateMsg,
-    services::decision::{build_governance_case, decide_case_with_motivation},
-    state::save_initial_constitution,
-    AXONE_GOV_ID, GOV_INTENT_ESTABLISH, GOV_VERDICT_PERMITTED, RESPONSE_KEY_CONSTITUTION_HASH,
-    RESPONSE_KEY_CONSTITUTION_REVISION,
+    prolog::term as t,
+    services::decision::{build_intent_case, decide_case_with_motivation},
+    state::{load_library, save_config, save_initial_constitution},
+    AXONE_GOV_ID, GOV_CTX_PROPOSED_CONFIG, GOV_CTX_PROPOSED_CONSTITUTION_SHA256,
+    GOV_INTENT_ESTABLISH, RESPONSE_KEY_CONSTITUTION_HASH, RESPONSE_KEY_CONSTITUTION_REVISION,
 };
 use abstract_app::sdk::prelude::*;
 use abstract_app::sdk::AbstractResponse;
@@ -22,29 +22,36 @@ pub fn instantiate_handler(
     msg: AxoneGovInstantiateMsg,
 ) -> AxoneGovResult {
     ensure_gov_uniqueness(deps.as_ref(), &env, &module)?;
+    let config = msg.config.unwrap_or_default();
+    config.validate(deps.api)?;
 
     let querier = QuerierWrapper::<AxoneLogicQuery>::new(&*deps.querier);
-    let constitution = Constitution::try_new(msg.constitution, &querier)?;
-    let case = build_governance_case(
-        None,
+    let constitution = Constitution::try_new(
+        msg.constitution,
+        |name, hash| load_library(deps.storage, name, hash),
+        &querier,
+    )?;
+    let case = build_intent_case(
+        msg.case.as_deref(),
         GOV_INTENT_ESTABLISH,
-        &constitution,
+        vec![
+            t::kv(
+                GOV_CTX_PROPOSED_CONSTITUTION_SHA256,
+                t::atom(constitution.hash_hex()),
+            ),
+            t::kv(GOV_CTX_PROPOSED_CONFIG, config.term()),
+        ],
         None,
         &module,
         &env,
         &info,
     )?;
-    let decision = decide_case_with_motivation(&querier, constitution.source(), &case)?;
+    let decision = decide_case_with_motivation(&querier, &constitution, &case)?;
 
-    if decision.verdict != t::atom(GOV_VERDICT_PERMITTED) {
-        return Err(AxoneGovError::DecisionRefused {
-            intent: GOV_INTENT_ESTABLISH.to_string(),
-            verdict: decision.verdict.to_string(),
-            motivation: decision.motivation.to_string(),
-        });
-    }
+    decision.ensure_permitted(GOV_INTENT_ESTABLISH)?;
 
     let status = save_initial_constitution(deps.storage, &constitution)?;
+    save_config(deps.storage, &config)?;
 
     Ok(module.custom_response(
         "instantiate",
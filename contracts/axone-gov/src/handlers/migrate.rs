@@ -1,19 +1,90 @@
 use crate::{
     contract::{AxoneGov, AxoneGovResult},
+    domain::Decision,
+    gateway::logic::AxoneLogicQuery,
     msg::AxoneGovMigrateMsg,
+    prolog::term as t,
+    services::decision::{build_intent_case, decide_case_with_motivation},
+    state::{
+        load_constitution, load_constitution_status, load_state_version, migrate_state,
+        record_decision, CURRENT_STATE_VERSION,
+    },
+    GOV_CTX_MIGRATION, GOV_INTENT_MIGRATE, RESPONSE_KEY_DECISION_ID,
+    RESPONSE_KEY_FROM_STATE_VERSION, RESPONSE_KEY_TO_STATE_VERSION,
 };
 
 use abstract_app::traits::AbstractResponse;
-use cosmwasm_std::{DepsMut, Env};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, QuerierWrapper};
 
 /// Handle the app migrate msg
 /// The top-level Abstract app does version checking and dispatches to this handler
-#[allow(clippy::unnecessary_wraps)]
 pub fn migrate_handler(
-    _deps: DepsMut<'_>,
-    _env: Env,
+    deps: DepsMut<'_>,
+    env: Env,
     module: AxoneGov,
-    _msg: AxoneGovMigrateMsg,
+    msg: AxoneGovMigrateMsg,
 ) -> AxoneGovResult {
-    Ok(module.response("migrate"))
+    let from_version = load_state_version(deps.storage)?;
+
+    // Modules are migrated by their hosting account, which is therefore the sender of the migration.
+    let info = MessageInfo {
+        sender: module.load_state(deps.storage)?.account.into_addr(),
+        funds: vec![],
+    };
+    let querier = QuerierWrapper::<AxoneLogicQuery>::new(&*deps.querier);
+    let constitution = load_constitution(deps.storage)?;
+    let status = load_constitution_status(deps.storage)?;
+
+    let case = build_intent_case(
+        msg.case.as_deref(),
+        GOV_INTENT_MIGRATE,
+        vec![t::kv(
+            GOV_CTX_MIGRATION,
+            t::dict(
+                "migration",
+                vec![
+                    t::kv("from_state_version", from_version.into()),
+                    t::kv("to_state_version", CURRENT_STATE_VERSION.into()),
+                ],
+            ),
+        )],
+        Some(&status),
+        &module,
+        &env,
+        &info,
+    )?;
+    let decision = decide_case_with_motivation(&querier, &constitution, &case)?;
+    decision.ensure_permitted(GOV_INTENT_MIGRATE)?;
+
+    let record = record_decision(
+        deps.storage,
+        Decision::new(
+            &status,
+            case.to_string(),
+            decision.verdict.to_string(),
+            Some(decision.motivation.to_string()),
+            info.sender,
+            env.block.height,
+            env.block.time.seconds(),
+        ),
+    )?;
+    migrate_state(deps.storage, &querier)?;
+
+    Ok(module.custom_response(
+        "migrate",
+        vec![
+            (
+                RESPONSE_KEY_DECISION_ID.to_string(),
+                record.id().to_string(),
+            ),
+            (
+                RESPONSE_KEY_FROM_STATE_VERSION.to_string(),
+                from_version.to_string(),
+            ),
+            (
+                RESPONSE_KEY_TO_STATE_VERSION.to_string(),
+                CURRENT_STATE_VERSION.to_string(),
+            ),
+        ],
+    ))
 }
@@ -1,20 +1,36 @@
 use crate::{
     contract::{AxoneGov, AxoneGovResult},
-    domain::Case,
+    domain::{
+        schema::{collected_declarations, parse_case_schemas, parse_intent_declarations},
+        Case, CasePattern, CaseSchema, VerdictClass, VerdictKind,
+    },
     error::AxoneGovError,
     gateway::logic::{query_service_ask, AxoneLogicQuery, QueryServiceAskRequest},
     msg::{AxoneGovQueryMsg, ConstitutionResponse, ConstitutionStatusResponse, DecideResponse},
+    prolog::ast::Term,
     queries::decision::{build_decide_query, build_decide_query_with_motivation},
+    queries::validation::{
+        build_case_schema_query, build_intents_query, CASE_SCHEMAS_VARIABLE, INTENTS_VARIABLE,
+    },
     state::load_constitution,
 };
 
-use crate::msg::{DecisionResponse, DecisionsResponse};
-use crate::state::{load_constitution_status, load_decision, load_decisions};
-use cosmwasm_std::{to_json_binary, Binary, Deps, Env, QuerierWrapper};
+use crate::msg::{
+    CapabilitiesResponse, CapabilityResponse, DecisionResponse, DecisionsResponse,
+    EffectiveDecisionsResponse, IntentsResponse, LibrariesResponse, LibraryResponse, PauseResponse,
+    PendingRevisionResponse, SignerNonceResponse, SubscriptionResponse, SubscriptionsResponse,
+};
+use crate::state::{
+    is_capability_in_force, load_capabilities, load_capability, load_config,
+    load_constitution_status, load_decision, load_decision_chain, load_decisions,
+    load_effective_decisions, load_libraries, load_library_record, load_pause,
+    load_pending_revision, load_signer_nonce, load_subscriptions,
+};
+use cosmwasm_std::{to_hex, to_json_binary, Binary, Deps, Env, QuerierWrapper};
 
 pub fn query_handler(
     deps: Deps<'_>,
-    _env: Env,
+    env: Env,
     _module: &AxoneGov,
     msg: AxoneGovQueryMsg,
 ) -> AxoneGovResult<Binary> {
@@ -23,14 +39,61 @@ pub fn query_handler(
         AxoneGovQueryMsg::ConstitutionStatus {} => {
             to_json_binary(&query_constitution_status(deps)?)
         }
+        AxoneGovQueryMsg::PendingRevision {} => to_json_binary(&query_pending_revision(deps)?),
+        AxoneGovQueryMsg::SignerNonce { signer } => {
+            to_json_binary(&query_signer_nonce(deps, signer)?)
+        }
+        AxoneGovQueryMsg::PauseStatus {} => to_json_binary(&query_pause_status(deps)?),
+        AxoneGovQueryMsg::Intents {} => to_json_binary(&query_intents(deps)?),
+        AxoneGovQueryMsg::CaseSchema { intent } => {
+            to_json_binary(&query_case_schema(deps, &intent)?)
+        }
+        AxoneGovQueryMsg::Config {} => to_json_binary(&load_config(deps.storage)?),
+        AxoneGovQueryMsg::DecisionLimits {} => {
+            to_json_binary(load_constitution(deps.storage)?.limits())
+        }
         AxoneGovQueryMsg::Decide { case, motivated } => {
             to_json_binary(&query_decide(deps, &case, motivated.unwrap_or(false))?)
         }
         AxoneGovQueryMsg::Decision { decision_id } => {
             to_json_binary(&query_decision(deps, decision_id)?)
         }
-        AxoneGovQueryMsg::Decisions { start_after, limit } => {
-            to_json_binary(&query_decisions(deps, start_after, limit)?)
+        AxoneGovQueryMsg::Decisions {
+            start_after,
+            limit,
+            verdict_kind,
+        } => to_json_binary(&query_decisions(deps, start_after, limit, verdict_kind)?),
+        AxoneGovQueryMsg::DecisionChain { decision_id } => {
+            to_json_binary(&query_decision_chain(deps, decision_id)?)
+        }
+        AxoneGovQueryMsg::EffectiveDecisions {
+            case_pattern,
+            at,
+            start_after,
+            limit,
+        } => to_json_binary(&query_effective_decisions(
+            deps,
+            case_pattern,
+            at.unwrap_or_else(|| env.block.time.seconds()),
+            start_after,
+            limit,
+        )?),
+        AxoneGovQueryMsg::Subscriptions { start_after, limit } => {
+            to_json_binary(&query_subscriptions(deps, start_after, limit)?)
+        }
+        AxoneGovQueryMsg::Capability { capability_id } => {
+            to_json_binary(&query_capability(deps, &env, capability_id)?)
+        }
+        AxoneGovQueryMsg::Capabilities {
+            holder,
+            start_after,
+            limit,
+        } => to_json_binary(&query_capabilities(deps, &env, holder, start_after, limit)?),
+        AxoneGovQueryMsg::Library { name, hash } => {
+            to_json_binary(&query_library(deps, &name, hash)?)
+        }
+        AxoneGovQueryMsg::Libraries { start_after, limit } => {
+            to_json_binary(&query_libraries(deps, start_after, limit)?)
         }
     }
     .map_err(Into::into)
@@ -48,11 +111,76 @@ fn query_constitution_status(deps: Deps<'_>) -> AxoneGovResult<ConstitutionStatu
     )?))
 }
 
+fn query_pending_revision(deps: Deps<'_>) -> AxoneGovResult<Option<PendingRevisionResponse>> {
+    Ok(load_pending_revision(deps.storage)?
+        .as_ref()
+        .map(PendingRevisionResponse::from))
+}
+
+fn query_signer_nonce(deps: Deps<'_>, signer: String) -> AxoneGovResult<SignerNonceResponse> {
+    let addr = deps.api.addr_validate(&signer)?;
+    let nonce = load_signer_nonce(deps.storage, &addr)?;
+
+    Ok(SignerNonceResponse {
+        signer: addr.to_string(),
+        nonce,
+    })
+}
+
+fn query_pause_status(deps: Deps<'_>) -> AxoneGovResult<Option<PauseResponse>> {
+    Ok(load_pause(deps.storage)?.as_ref().map(PauseResponse::from))
+}
+
+fn query_intents(deps: Deps<'_>) -> AxoneGovResult<IntentsResponse> {
+    let declarations = ask_declarations(deps, build_intents_query(), INTENTS_VARIABLE)?;
+
+    Ok(IntentsResponse {
+        intents: parse_intent_declarations(&declarations)?,
+    })
+}
+
+fn query_case_schema(deps: Deps<'_>, intent: &str) -> AxoneGovResult<Option<CaseSchema>> {
+    let declarations =
+        ask_declarations(deps, build_case_schema_query(intent), CASE_SCHEMAS_VARIABLE)?;
+
+    Ok(parse_case_schemas(&declarations)?.into_iter().next())
+}
+
+/// Evaluate a query collecting constitution declarations into `variable`.
+fn ask_declarations(deps: Deps<'_>, query: String, variable: &str) -> AxoneGovResult<Term> {
+    let constitution = load_constitution(deps.storage)?;
+    let request = QueryServiceAskRequest::one(constitution.program(), query);
+    let response = query_service_ask(
+        &QuerierWrapper::<AxoneLogicQuery>::new(&*deps.querier),
+        request,
+    )?;
+    let answer = response.answer.ok_or(AxoneGovError::PrologEngineNoAnswer)?;
+
+    if let Some(error) = answer
+        .results
+        .iter()
+        .find_map(|result| result.error.as_deref())
+    {
+        return Err(AxoneGovError::PrologEngineError(error.to_string()));
+    }
+
+    match answer.results.first() {
+        Some(result) => collected_declarations(&result.substitutions, variable),
+        None => Ok(Term::List(vec![], None)),
+    }
+}
+
 fn query_decide(deps: Deps<'_>, case: &str, motivated: bool) -> AxoneGovResult<DecideResponse> {
     let case = Case::new(case)?;
 
     let constitution = load_constitution(deps.storage)?;
-    let program = constitution.source();
+    let config = load_config(deps.storage)?;
+    // The caller simulates the enrichment by providing the enrichment keys along with the case.
+    let mut enrichment = case.clone();
+    enrichment.remove_keys(|key| !config.is_enrichment_key(key));
+    constitution.validate_case(&case, &enrichment)?;
+
+    let program = constitution.program();
     let query = if motivated {
         build_decide_query_with_motivation(&case)
     } else {
@@ -90,6 +218,7 @@ fn query_decide(deps: Deps<'_>, case: &str, motivated: bool) -> AxoneGovResult<D
     };
 
     Ok(DecideResponse {
+        verdict_kind: VerdictKind::parse(&verdict),
         verdict,
         motivation,
     })
@@ -101,12 +230,88 @@ fn query_decision(deps: Deps<'_>, decision_id: u64) -> AxoneGovResult<DecisionRe
     Ok(DecisionResponse::from(&record))
 }
 
+fn query_decision_chain(deps: Deps<'_>, decision_id: u64) -> AxoneGovResult<DecisionsResponse> {
+    let decisions = load_decision_chain(deps.storage, decision_id)?
+        .into_iter()
+        .map(DecisionResponse::from)
+        .collect();
+
+    Ok(DecisionsResponse { decisions })
+}
+
+fn query_effective_decisions(
+    deps: Deps<'_>,
+    case_pattern: Option<String>,
+    at: u64,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> AxoneGovResult<EffectiveDecisionsResponse> {
+    let pattern = case_pattern.as_deref().map(CasePattern::new).transpose()?;
+    let (decisions, next_start_after) =
+        load_effective_decisions(deps.storage, at, pattern.as_ref(), start_after, limit)?;
+
+    Ok(EffectiveDecisionsResponse {
+        decisions: decisions.into_iter().map(DecisionResponse::from).collect(),
+        next_start_after,
+    })
+}
+
+fn query_subscriptions(
+    deps: Deps<'_>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> AxoneGovResult<SubscriptionsResponse> {
+    let start_after = start_after
+        .map(|contract| deps.api.addr_validate(&contract))
+        .transpose()?;
+    let subscriptions = load_subscriptions(deps.storage, start_after.as_ref(), limit)?
+        .iter()
+        .map(SubscriptionResponse::from)
+        .collect();
+
+    Ok(SubscriptionsResponse { subscriptions })
+}
+
+fn query_capability(
+    deps: Deps<'_>,
+    env: &Env,
+    capability_id: u64,
+) -> AxoneGovResult<CapabilityResponse> {
+    let capability = load_capability(deps.storage, capability_id)?;
+    let in_force = is_capability_in_force(deps.storage, &capability, env.block.time.seconds())?;
+
+    Ok(CapabilityResponse::new(&capability, in_force))
+}
+
+fn query_capabilities(
+    deps: Deps<'_>,
+    env: &Env,
+    holder: Option<String>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> AxoneGovResult<CapabilitiesResponse> {
+    let holder = holder
+        .map(|holder| deps.api.addr_validate(&holder))
+        .transpose()?;
+    let capabilities = load_capabilities(deps.storage, holder.as_ref(), start_after, limit)?
+        .iter()
+        .map(|capability| {
+            let in_force =
+                is_capability_in_force(deps.storage, capability, env.block.time.seconds())?;
+            Ok(CapabilityResponse::new(capability, in_force))
+        })
+        .collect::<AxoneGovResult<_>>()?;
+
+    Ok(CapabilitiesResponse { capabilities })
+}
+
 fn query_decisions(
     deps: Deps<'_>,
     start_after: Option<u64>,
     limit: Option<u32>,
+    verdict_kind: Option<VerdictClass>,
 ) -> AxoneGovResult<DecisionsResponse> {
-    let decisions = load_decisions(deps.storage, start_after, limit)?
+    let decisions = load_decisions(deps.storage, start_after, limit, verdict_kind)?
         .into_iter()
         .map(DecisionResponse::from)
         .collect();
@@ -114,6 +319,36 @@ fn query_decisions(
     Ok(DecisionsResponse { decisions })
 }
 
+fn query_library(
+    deps: Deps<'_>,
+    name: &str,
+    hash: Option<Binary>,
+) -> AxoneGovResult<LibraryResponse> {
+    let hash = hash
+        .map(|hash| {
+            <[u8; 32]>::try_from(hash.as_slice()).map_err(|_| AxoneGovError::LibraryNotFound {
+                reference: format!("{name}@{}", to_hex(hash.as_slice())),
+            })
+        })
+        .transpose()?;
+    let record = load_library_record(deps.storage, name, hash.as_ref())?;
+
+    Ok(LibraryResponse::from(&record))
+}
+
+fn query_libraries(
+    deps: Deps<'_>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> AxoneGovResult<LibrariesResponse> {
+    let libraries = load_libraries(deps.storage, start_after, limit)?
+        .into_iter()
+        .map(LibraryResponse::from)
+        .collect();
+
+    Ok(LibrariesResponse { libraries })
+}
+
 fn find_substitution(result: &crate::gateway::logic::Result, variable: &str) -> Option<String> {
     result
         .substitutions
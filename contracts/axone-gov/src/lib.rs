@@ -27,16 +27,64 @@ pub const GOV_CTX_MODULE: &str = "gov:module";
 pub const GOV_CTX_PROPOSED_CONSTITUTION_SHA256: &str = "gov:proposed_constitution_sha256";
 pub const GOV_CTX_CURRENT_CONSTITUTION_SHA256: &str = "gov:current_constitution_sha256";
 pub const GOV_CTX_CURRENT_CONSTITUTION_REVISION: &str = "gov:current_constitution_revision";
+pub const GOV_CTX_LIBRARY_NAME: &str = "gov:library_name";
+pub const GOV_CTX_LIBRARY_SHA256: &str = "gov:library_sha256";
+pub const GOV_CTX_SIGNER: &str = "gov:signer";
+pub const GOV_CTX_APPEALED_DECISION: &str = "gov:appealed_decision";
+pub const GOV_CTX_REVOKED_DECISION: &str = "gov:revoked_decision";
+pub const GOV_CTX_SUBSCRIPTION: &str = "gov:subscription";
+pub const GOV_CTX_MIGRATION: &str = "gov:migration";
+pub const GOV_CTX_PROPOSED_CONFIG: &str = "gov:proposed_config";
 pub const GOV_INTENT_ESTABLISH: &str = "gov:establish";
 pub const GOV_INTENT_REVISE_CONSTITUTION: &str = "gov:revise_constitution";
+pub const GOV_INTENT_CANCEL_REVISION: &str = "gov:cancel_revision";
+pub const GOV_INTENT_STORE_LIBRARY: &str = "gov:store_library";
+pub const GOV_INTENT_PAUSE: &str = "gov:pause";
+pub const GOV_INTENT_RESUME: &str = "gov:resume";
+pub const GOV_INTENT_APPEAL: &str = "gov:appeal";
+pub const GOV_INTENT_REVOKE_DECISION: &str = "gov:revoke_decision";
+pub const GOV_INTENT_SUBSCRIBE: &str = "gov:subscribe";
+pub const GOV_INTENT_UNSUBSCRIBE: &str = "gov:unsubscribe";
+pub const GOV_INTENT_MIGRATE: &str = "gov:migrate";
+pub const GOV_INTENT_CONFIGURE: &str = "gov:configure";
 pub const GOV_VERDICT_PERMITTED: &str = "gov:permitted";
+pub const GOV_VERDICT_PERMITTED_AFTER: &str = "gov:permitted_after";
+pub const GOV_VERDICT_PERMITTED_WITH_EXEMPTIONS: &str = "gov:permitted_with_exemptions";
+pub const GOV_VERDICT_PERMITTED_IF: &str = "gov:permitted_if";
+pub const GOV_VERDICT_OBLIGED: &str = "gov:obliged";
+pub const GOV_VERDICT_FORBIDDEN: &str = "gov:forbidden";
+pub const GOV_VERDICT_UNDECIDED: &str = "gov:undecided";
+pub const GOV_VERDICT_EFFECTIVE: &str = "gov:effective";
+pub const GOV_VERDICT_GRANT: &str = "gov:grant";
 
 // Response constants
+pub const RESPONSE_KEY_APPEAL_OF: &str = "appeal_of";
+pub const RESPONSE_KEY_ACTIVATION_HEIGHT: &str = "activation_height";
+pub const RESPONSE_KEY_ACTIVATION_TIME_SECONDS: &str = "activation_time_seconds";
+pub const RESPONSE_KEY_CAPABILITY_ID: &str = "capability_id";
 pub const RESPONSE_KEY_CASE_HASH: &str = "case_hash";
 pub const RESPONSE_KEY_CONSTITUTION_HASH: &str = "constitution_hash";
 pub const RESPONSE_KEY_CONSTITUTION_REVISER: &str = "constitution_reviser";
 pub const RESPONSE_KEY_CONSTITUTION_REVISION: &str = "constitution_revision";
 pub const RESPONSE_KEY_DECISION_ID: &str = "decision_id";
+pub const RESPONSE_KEY_EFFECTIVE_FROM_SECONDS: &str = "effective_from_seconds";
+pub const RESPONSE_KEY_EFFECTIVE_UNTIL_SECONDS: &str = "effective_until_seconds";
+pub const RESPONSE_KEY_ERROR: &str = "error";
+pub const RESPONSE_KEY_EXEMPT_INTENTS: &str = "exempt_intents";
+pub const RESPONSE_KEY_FAILED: &str = "failed";
+pub const RESPONSE_KEY_FEE: &str = "fee";
+pub const RESPONSE_KEY_FROM_STATE_VERSION: &str = "from_state_version";
+pub const RESPONSE_KEY_LIBRARY_HASH: &str = "library_hash";
+pub const RESPONSE_KEY_LIBRARY_NAME: &str = "library_name";
 pub const RESPONSE_KEY_MOTIVATION_HASH: &str = "motivation_hash";
+pub const RESPONSE_KEY_RECORDED: &str = "recorded";
+pub const RESPONSE_KEY_PROPOSED_CONSTITUTION_HASH: &str = "proposed_constitution_hash";
+pub const RESPONSE_KEY_REMAINING_USES: &str = "remaining_uses";
+pub const RESPONSE_KEY_REVOKED_DECISION_ID: &str = "revoked_decision_id";
+pub const RESPONSE_KEY_SIGNER: &str = "signer";
+pub const RESPONSE_KEY_SUBSCRIBER: &str = "subscriber";
+pub const RESPONSE_KEY_TO_STATE_VERSION: &str = "to_state_version";
+pub const RESPONSE_KEY_REVISION_ACTIVATOR: &str = "revision_activator";
 pub const RESPONSE_KEY_VERDICT_HASH: &str = "verdict_hash";
 pub const RESPONSE_KEY_VERDICT: &str = "verdict";
+pub const RESPONSE_KEY_VERDICT_KIND: &str = "verdict_kind";
@@ -1,11 +1,19 @@
 use crate::contract::AxoneGov;
 
 use crate::domain::constitution::ConstitutionStatus;
-use crate::domain::Constitution;
-use crate::state::DecisionRecord;
+use crate::domain::{Constitution, LibraryPin};
+use crate::state::{
+    Capability, DecisionRecord, LibraryRecord, Pause, PendingRevision, Subscription,
+};
 use cosmwasm_schema::QueryResponses;
 use cosmwasm_std::Binary;
 
+pub use crate::domain::{
+    CaseSchema, CaseSignature, Config, DecisionFilter, DecisionLimits, EffectivePeriod,
+    EnrichmentProvider, FieldSchema, IntentDeclaration, RateLimit, RevisionActivation,
+    SignatureAlgorithm, ValueKind, VerdictClass, VerdictKind,
+};
+
 abstract_app::app_msg_types!(AxoneGov, AxoneGovExecuteMsg, AxoneGovQueryMsg);
 
 /// Instantiate message.
@@ -43,12 +51,22 @@ abstract_app::app_msg_types!(AxoneGov, AxoneGovExecuteMsg, AxoneGovQueryMsg);
 ///      findings, interpretation rules).
 ///
 /// The case structure used for `gov:establish` follows the same conventions as other governance acts
-/// (see `ReviseConstitution`), including `gov:module` metadata and the `cw:tx` runtime context.
+/// (see `ReviseConstitution`), including `gov:module` metadata and the `cw:tx` runtime context. The
+/// establishment case is also enriched with a `'gov:proposed_config'` fact describing the initial
+/// configuration, and can be extended by the caller (e.g. with founders, initial roles or
+/// parameters).
 #[cosmwasm_schema::cw_serde]
 #[derive(Default)]
 pub struct AxoneGovInstantiateMsg {
     /// The constitution (UTF-8 Prolog program bytes).
     pub constitution: Binary,
+    /// Optional additional establishment context provided by the caller.
+    ///
+    /// This is a Prolog dict term string (typically `ctx{...}`) merged into the case used to
+    /// evaluate the `gov:establish` intent.
+    pub case: Option<String>,
+    /// Optional initial configuration, defaulting to `Config::default()`.
+    pub config: Option<Config>,
 }
 
 /// Execute messages.
@@ -85,6 +103,22 @@ pub enum AxoneGovExecuteMsg {
     /// The contract evaluates `governance:decide/2` or `governance:decide/3` depending on
     /// `motivated`, and records the resulting verdict (and optional motivation) as a durable
     /// decision record.
+    ///
+    /// A relayer may submit a case signed off-chain by the actor the decision is made for (who may
+    /// hold no gas tokens). The contract verifies the `signature`, consumes its nonce and injects the
+    /// verified signer under the reserved key `gov:signer`:
+    ///
+    /// ```prolog
+    /// 'gov:signer': signer{
+    ///   address: <atom>,    % Bech32 address derived from the signer public key
+    ///   algorithm: <atom>,  % secp256k1, ed25519 or adr36
+    ///   nonce: <integer>
+    /// }
+    /// ```
+    ///
+    /// The decision is then attributed to the signer rather than to the message sender, which
+    /// remains available as `cw:tx.message.sender`, and counts toward the rate limit of the signer
+    /// rather than of the relayer. No reserved key (`gov:*`, `cw:*`) can be provided by the caller.
     RecordDecision {
         /// The decision context.
         case: String,
@@ -93,6 +127,29 @@ pub enum AxoneGovExecuteMsg {
         ///   - If `false`, the contract calls `governance:decide/2` and records only the verdict.
         ///   - If `true`, the contract calls `governance:decide/3` and records both verdict and motivation.
         motivated: Option<bool>,
+        /// Optional off-chain signature of `case` by the actor the decision is made for.
+        signature: Option<CaseSignature>,
+    },
+    /// Record many decisions in a single transaction.
+    ///
+    /// Each case is decided and recorded as with `RecordDecision`, and every case of the batch is
+    /// enriched with the same `gov:module` and `cw:tx` context. Decision identifiers are assigned in
+    /// the order of `cases`. The sender rate limit applies to each case in turn, so the cases beyond
+    /// it fail.
+    ///
+    /// The response carries, for each case at index `i`, either `case.i.decision_id`,
+    /// `case.i.verdict` and `case.i.verdict_kind` attributes, or a `case.i.error` attribute, along with the `recorded` and
+    /// `failed` counts.
+    RecordDecisions {
+        /// The decision contexts (at most 50).
+        cases: Vec<String>,
+        /// Whether to request motivated decisions (defaults to `false`).
+        motivated: Option<bool>,
+        /// Whether the batch is all-or-nothing (defaults to `true`).
+        ///
+        ///   - If `true`, the first failing case aborts the batch and nothing is recorded.
+        ///   - If `false`, failing cases are reported in the response and the others are recorded.
+        atomic: Option<bool>,
     },
     /// Propose a constitutional revision (constitutional amendment).
     ///
@@ -131,9 +188,23 @@ pub enum AxoneGovExecuteMsg {
     /// }
     /// ```
     ///
-    /// The revision is applied only if both decisions return the verdict `gov:permitted`. The
-    /// authorization decision returned by the current constitution is recorded with its current
-    /// constitution revision and hash in the same transaction as the constitutional revision.
+    /// The proposed constitution must decide `gov:establish` with the verdict `gov:permitted`. The
+    /// verdict returned by the current constitution for `gov:revise_constitution` determines when
+    /// the revision takes effect:
+    ///
+    ///   - `gov:permitted`: the revision is applied in the same transaction.
+    ///
+    ///   - `gov:permitted_after(Activation)`: the revision is stored as a pending amendment that
+    ///     anyone can apply with `ActivateRevision` once `Activation` is reached, leaving
+    ///     stakeholders an exit window before the new rules take effect. `Activation` is either a
+    ///     block height (`gov:permitted_after(12345)` or `gov:permitted_after(height(12345))`) or a
+    ///     block time in seconds since epoch (`gov:permitted_after(time_seconds(1700000000))`).
+    ///
+    /// Any other verdict refuses the revision. The authorization decision returned by the current
+    /// constitution is recorded with its current constitution revision and hash.
+    ///
+    /// Only one revision can be pending at a time: proposing a revision while another one is pending
+    /// fails.
     ReviseConstitution {
         /// The proposed new constitution (UTF-8 Prolog program bytes).
         constitution: Binary,
@@ -143,13 +214,319 @@ pub enum AxoneGovExecuteMsg {
         /// evaluate the `gov:revise_constitution` intent.
         case: Option<String>,
     },
+    /// Apply the pending constitutional revision once its activation condition is reached.
+    ///
+    /// Anyone can execute this message: the revision was already authorized by the constitution when
+    /// it was proposed. The pending constitution becomes the current one and the constitution revision
+    /// is incremented.
+    ActivateRevision {},
+    /// Cancel the pending constitutional revision.
+    ///
+    /// The contract asks the **current** constitution to decide the intent `gov:cancel_revision`,
+    /// using the same case structure as `ReviseConstitution` where `gov:proposed_constitution_sha256`
+    /// is the hash of the pending constitution. The pending revision is discarded only if the verdict
+    /// is `gov:permitted`, and the decision is recorded.
+    CancelRevision {
+        /// Optional additional decision context provided by the caller.
+        ///
+        /// This is a Prolog dict term string (typically `ctx{...}`) merged into the case used to
+        /// evaluate the `gov:cancel_revision` intent.
+        case: Option<String>,
+    },
+    /// Store a new version of a named Prolog library module.
+    ///
+    /// Libraries hold rule sets shared by constitutions (voting helpers, role checks, credential
+    /// checks, ...). A constitution imports a library with a directive standing alone on its line:
+    ///
+    /// ```prolog
+    /// :- use_library(voting).               % latest stored version of `voting`
+    /// :- use_library(voting, '<sha256>').   % exact version of `voting`
+    /// ```
+    ///
+    /// Libraries are resolved and pinned by hash when a constitution is instantiated or revised: the
+    /// program evaluated by the logic module is the pinned libraries followed by the constitution, so
+    /// storing a new version of a library never changes the rules of the current constitution.
+    ///
+    /// The contract asks the **current** constitution to decide the intent `gov:store_library`
+    /// using the following case:
+    ///
+    /// ```prolog
+    /// ctx{
+    ///   intent: 'gov:store_library',
+    ///   'gov:library_name': <atom>,
+    ///   'gov:library_sha256': <hex_atom>,
+    ///   'gov:current_constitution_sha256': <hex_atom>,
+    ///   'gov:current_constitution_revision': <integer>,
+    ///   'gov:module': module{ id: <atom>, version: <atom> },
+    ///   'cw:tx': tx{ ... },
+    ///   <caller_provided_keys>: <caller_provided_values>
+    /// }
+    /// ```
+    ///
+    /// The library is stored only if the verdict is `gov:permitted`, and the decision is recorded.
+    StoreLibrary {
+        /// The library name, matching `[a-z][a-z0-9_]*`.
+        name: String,
+        /// The library module (UTF-8 Prolog program bytes).
+        ///
+        /// A library cannot use other libraries.
+        library: Binary,
+        /// Optional additional decision context provided by the caller.
+        ///
+        /// This is a Prolog dict term string (typically `ctx{...}`) merged into the case used to
+        /// evaluate the `gov:store_library` intent.
+        case: Option<String>,
+    },
+    /// Pause governance in an emergency.
+    ///
+    /// The contract asks the **current** constitution to decide the intent `gov:pause`, using a case
+    /// enriched with the current constitution metadata, `gov:module` and `cw:tx` (as for
+    /// `ReviseConstitution`, without `gov:proposed_constitution_sha256`). The verdict determines the
+    /// pause:
+    ///
+    ///   - `gov:permitted`: every paused governance act is rejected.
+    ///
+    ///   - `gov:permitted_with_exemptions(Intents)`: the intents in the `Intents` list of atoms
+    ///     remain available (e.g. `['gov:revise_constitution']` to keep the recovery path open).
+    ///
+    /// Any other verdict refuses the pause. While paused, `RecordDecision` (by the `intent` of its
+    /// case), `ReviseConstitution` and `ActivateRevision` (both as `gov:revise_constitution`), and
+    /// every other governance act (by its own `gov:*` intent, e.g. `gov:cancel_revision` or
+    /// `gov:store_library`) are rejected unless their intent is exempt. `Resume` and
+    /// `ConsumeCapability`, which records no decision, are always available.
+    Pause {
+        /// Optional additional decision context provided by the caller.
+        ///
+        /// This is a Prolog dict term string (typically `ctx{...}`) merged into the case used to
+        /// evaluate the `gov:pause` intent.
+        case: Option<String>,
+    },
+    /// Resume paused governance.
+    ///
+    /// The contract asks the **current** constitution to decide the intent `gov:resume`, using the
+    /// same case structure as `Pause`. The pause is lifted only if the verdict is `gov:permitted`,
+    /// and the decision is recorded.
+    Resume {
+        /// Optional additional decision context provided by the caller.
+        ///
+        /// This is a Prolog dict term string (typically `ctx{...}`) merged into the case used to
+        /// evaluate the `gov:resume` intent.
+        case: Option<String>,
+    },
+    /// Appeal a recorded decision.
+    ///
+    /// The contract asks the **current** constitution to decide again the case of the appealed
+    /// decision, under the intent `gov:appeal`. The case holds the keys of the appealed case, except
+    /// the ones injected by the contract (`gov:*`, `cw:*` and the enrichment provider keys), the
+    /// caller-provided appeal keys, and the appealed decision as:
+    ///
+    /// ```prolog
+    /// 'gov:appealed_decision': decision{
+    ///     id: Id,
+    ///     constitution_revision: Revision,
+    ///     intent: Intent,        % intent of the original decision, if any
+    ///     verdict: Verdict
+    /// }
+    /// ```
+    ///
+    /// Whatever the verdict, the new decision is recorded with a link to the appealed one. A decision
+    /// can be appealed only once: further appeals target the latest decision of the chain. As with
+    /// `RecordDecision`, the sender rate limit and the case size limits apply, the fee required by
+    /// `decision_fee/2` must be sent along, and a `gov:grant` verdict mints the granted capability.
+    AppealDecision {
+        /// The identifier of the appealed decision.
+        decision_id: u64,
+        /// Optional appeal-specific decision context provided by the caller.
+        ///
+        /// This is a Prolog dict term string (typically `ctx{...}`) merged into the appealed case.
+        case: Option<String>,
+    },
+    /// Revoke a recorded decision, putting an end to its effects.
+    ///
+    /// The contract asks the constitution to decide the intent `gov:revoke_decision` with the
+    /// revoked decision injected in the case as:
+    ///
+    /// ```prolog
+    /// 'gov:revoked_decision': decision{
+    ///     id: Id,
+    ///     constitution_revision: Revision,
+    ///     intent: Intent,        % intent of the original decision, if any
+    ///     verdict: Verdict
+    /// }
+    /// ```
+    ///
+    /// The revocation is applied only if the verdict is exactly `gov:permitted`. It is recorded as a
+    /// decision, and the revoked decision is no longer in force from then on. A decision can be
+    /// revoked only once.
+    RevokeDecision {
+        /// The identifier of the revoked decision.
+        decision_id: u64,
+        /// Optional revocation-specific decision context provided by the caller.
+        ///
+        /// This is a Prolog dict term string (typically `ctx{...}`) merged into the case used to
+        /// evaluate the `gov:revoke_decision` intent.
+        case: Option<String>,
+    },
+    /// Subscribe a contract to the recorded decisions.
+    ///
+    /// The contract asks the constitution to decide the intent `gov:subscribe` with the requested
+    /// subscription injected in the case as:
+    ///
+    /// ```prolog
+    /// 'gov:subscription': subscription{
+    ///     contract: Contract,
+    ///     intent: Intent,             % if filtered by intent
+    ///     verdict_kind: VerdictClass  % if filtered by verdict class
+    /// }
+    /// ```
+    ///
+    /// The subscription is saved only if the verdict is exactly `gov:permitted`, replacing any
+    /// previous subscription of the contract. From then on, every recorded decision selected by the
+    /// filter is notified to the contract with a `DecisionHookExecuteMsg`. A failing notification
+    /// is reported in the response events and does not revert the decision.
+    Subscribe {
+        /// The Bech32 address of the subscribed contract.
+        contract: String,
+        /// Optional filter selecting the notified decisions (default: every decision).
+        filter: Option<DecisionFilter>,
+        /// Optional subscription-specific decision context provided by the caller.
+        ///
+        /// This is a Prolog dict term string (typically `ctx{...}`) merged into the case used to
+        /// evaluate the `gov:subscribe` intent.
+        case: Option<String>,
+    },
+    /// Unsubscribe a contract from the recorded decisions.
+    ///
+    /// The contract asks the constitution to decide the intent `gov:unsubscribe` with the current
+    /// subscription injected in the case as `'gov:subscription'`. The subscription is removed only if
+    /// the verdict is exactly `gov:permitted`.
+    Unsubscribe {
+        /// The Bech32 address of the subscribed contract.
+        contract: String,
+        /// Optional unsubscription-specific decision context provided by the caller.
+        ///
+        /// This is a Prolog dict term string (typically `ctx{...}`) merged into the case used to
+        /// evaluate the `gov:unsubscribe` intent.
+        case: Option<String>,
+    },
+    /// Consume one use of a capability held by the caller.
+    ///
+    /// Capabilities are granted by decisions recorded with a `gov:grant(Capability, Holder, Uses)`
+    /// verdict. A use can be consumed only by the holder, while uses remain and the granting decision
+    /// is in force (within its effective period, neither revoked nor superseded by an appeal).
+    ///
+    /// The response data holds a `ConsumeCapabilityResponse`.
+    ConsumeCapability {
+        /// The identifier of the consumed capability.
+        capability_id: u64,
+    },
+    /// Replace the configuration of the contract.
+    ///
+    /// The constitution decides the intent `gov:configure` using a contract-enriched case with a
+    /// `'gov:proposed_config'` fact describing the new configuration, along with the current
+    /// constitution metadata, `gov:module` and `cw:tx`. The configuration is replaced only if the
+    /// verdict is exactly `gov:permitted`.
+    UpdateConfig {
+        /// The new configuration.
+        config: Config,
+        /// Optional configuration-specific decision context provided by the caller.
+        ///
+        /// This is a Prolog dict term string (typically `ctx{...}`) merged into the case used to
+        /// evaluate the `gov:configure` intent.
+        case: Option<String>,
+    },
+}
+
+/// The notification of a recorded decision sent to subscribed contracts.
+#[cosmwasm_schema::cw_serde]
+pub struct DecisionHook {
+    /// The unique decision identifier.
+    pub decision_id: u64,
+    /// The constitution revision number at the time of decision.
+    pub constitution_revision: u64,
+    /// The intent of the decided case, if any.
+    pub intent: Option<String>,
+    /// The case term as a Prolog term string.
+    pub case: String,
+    /// The verdict term as a Prolog term string.
+    pub verdict: String,
+    /// The kind of the verdict in the governance verdict vocabulary, along with its arguments.
+    pub verdict_kind: VerdictKind,
+    /// The author Bech32 address.
+    pub author: String,
+    /// The block height at which the decision was recorded.
+    pub block_height: u64,
+    /// The block time (seconds since epoch) at which the decision was recorded.
+    pub block_time_seconds: u64,
+}
+
+impl DecisionHook {
+    pub fn new(record: &DecisionRecord, intent: Option<String>) -> Self {
+        Self {
+            decision_id: record.id(),
+            constitution_revision: record.constitution_revision(),
+            intent,
+            case: record.case().clone(),
+            verdict: record.verdict().clone(),
+            verdict_kind: record.verdict_kind(),
+            author: record.author().to_string(),
+            block_height: record.block_height(),
+            block_time_seconds: record.block_time_seconds(),
+        }
+    }
+}
+
+/// Execute message sent to subscribed contracts when a decision is recorded.
+///
+/// Subscribed contracts handle it by declaring a `DecisionHook(DecisionHook)` variant in their own
+/// execute message, serialized as `{"decision_hook": {...}}`.
+#[cosmwasm_schema::cw_serde]
+pub enum DecisionHookExecuteMsg {
+    DecisionHook(DecisionHook),
+}
+
+/// Query message sent to enrichment providers when recording decisions.
+///
+/// Enrichment providers handle it by declaring a `CaseEnrichment { sender }` variant in their own
+/// query message, serialized as `{"case_enrichment": {...}}`.
+#[cosmwasm_schema::cw_serde]
+#[derive(QueryResponses)]
+pub enum EnrichmentQueryMsg {
+    /// Return the facts to merge into the cases recorded by `sender`.
+    #[returns(CaseEnrichmentResponse)]
+    CaseEnrichment {
+        /// The Bech32 address of the sender recording decisions.
+        sender: String,
+    },
+}
+
+/// Response returned by enrichment providers to `EnrichmentQueryMsg::CaseEnrichment`.
+#[cosmwasm_schema::cw_serde]
+pub struct CaseEnrichmentResponse {
+    /// The provided facts, as a Prolog term string.
+    pub term: String,
 }
 
 /// Migrate message.
 ///
-/// Reserved for future migrations.
+/// A migration runs the steps upgrading the stored state layout to the version of the migrated code,
+/// once authorized by the constitution under the intent `gov:migrate`. The case is enriched with a
+/// `'gov:migration'` fact holding the `from_state_version` and `to_state_version`, the current
+/// constitution metadata and the `gov:module` metadata of the migrated code. The `cw:tx` context has
+/// the hosting account as sender, migrations being performed by it.
+///
+/// Steps walking every recorded entry are bounded: the migration to state version 4 indexes at
+/// most 200 decisions and capabilities, and each executed message then indexes the next 200 ones,
+/// until every entry is indexed. Meanwhile, the decisions filtered by verdict kind and the
+/// capabilities filtered by holder may miss entries recorded before the migration.
 #[cosmwasm_schema::cw_serde]
-pub struct AxoneGovMigrateMsg {}
+pub struct AxoneGovMigrateMsg {
+    /// Optional additional decision context provided by the caller.
+    ///
+    /// This is a Prolog dict term string (typically `ctx{...}`) merged into the case used to
+    /// evaluate the `gov:migrate` intent.
+    pub case: Option<String>,
+}
 
 /// Query messages.
 #[cosmwasm_schema::cw_serde]
@@ -159,10 +536,59 @@ pub enum AxoneGovQueryMsg {
     #[returns(ConstitutionResponse)]
     Constitution {},
 
-    /// Return the current constitution metadata (revision and hash).
+    /// Return the current constitution metadata (revision, hash and pinned libraries).
     #[returns(ConstitutionStatusResponse)]
     ConstitutionStatus {},
 
+    /// Return the pending constitutional revision, if any.
+    ///
+    /// A revision is pending when the constitution authorized it with a `gov:permitted_after(Activation)`
+    /// verdict and it has been neither activated nor cancelled yet.
+    #[returns(Option<PendingRevisionResponse>)]
+    PendingRevision {},
+
+    /// Return the last nonce used by a signer of off-chain signed cases (`0` if none).
+    #[returns(SignerNonceResponse)]
+    SignerNonce {
+        /// The Bech32 address of the signer.
+        signer: String,
+    },
+
+    /// Return the current pause, if governance is paused.
+    #[returns(Option<PauseResponse>)]
+    PauseStatus {},
+
+    /// Return the intents handled by the constitution, as declared by its `intent(Intent, Description)`
+    /// facts.
+    ///
+    /// The declarations are optional: a constitution declaring none returns an empty list. Once any is
+    /// declared, the cases of `RecordDecision` and `Decide` must hold a declared intent.
+    #[returns(IntentsResponse)]
+    Intents {},
+
+    /// Return the case schema of an intent, as declared by a `case_schema(Intent, Fields)` fact of the
+    /// constitution, if any.
+    ///
+    /// `Fields` is a list of `required(Key, Kind)` and `optional(Key, Kind)` terms, where `Kind` is one
+    /// of `atom`, `integer`, `float`, `number`, `list`, `dict`, `compound` or `any`.
+    #[returns(Option<CaseSchema>)]
+    CaseSchema {
+        /// The intent atom.
+        intent: String,
+    },
+
+    /// Return the limits applied to recorded decisions, as declared by the `decision_limit/1` facts
+    /// of the constitution.
+    ///
+    /// Limits are `max_case_bytes(N)`, `max_motivation_bytes(N)` and `rate(MaxDecisions,
+    /// WindowSeconds)`. Sizes default to 16 KiB, and senders are not rate limited unless declared.
+    #[returns(DecisionLimits)]
+    DecisionLimits {},
+
+    /// Return the configuration of the contract.
+    #[returns(Config)]
+    Config {},
+
     /// Decide a case using the stored constitution.
     ///
     /// The `case` parameter is a Prolog dict term string (typically `ctx{...}`) representing the decision context.
@@ -187,6 +613,9 @@ pub enum AxoneGovQueryMsg {
     ///
     /// Injected keys are authoritative and overwrite any caller-provided value under the same keys.
     ///
+    /// The case schemas accept the keys of the configured enrichment providers, so that the caller
+    /// can simulate the enrichment of recorded cases.
+    ///
     #[returns(DecideResponse)]
     Decide {
         /// The decision context.
@@ -217,6 +646,96 @@ pub enum AxoneGovQueryMsg {
         start_after: Option<u64>,
         /// Optional maximum number of decisions to return (default: 10).
         limit: Option<u32>,
+        /// Optional verdict class the returned decisions must have, regardless of the verdict
+        /// arguments.
+        verdict_kind: Option<VerdictClass>,
+    },
+
+    /// Return the appeal chain a decision belongs to.
+    ///
+    /// Decisions are ordered from the original decision to its latest appeal.
+    #[returns(DecisionsResponse)]
+    DecisionChain {
+        /// The identifier of any decision of the chain.
+        decision_id: u64,
+    },
+
+    /// Return a paginated list of the decisions in force at a given time.
+    ///
+    /// A decision is in force when the time is within its effective period, and it has neither been
+    /// revoked nor superseded by an appeal recorded at or before that time. Decisions are ordered by
+    /// their unique identifier in ascending order.
+    ///
+    /// A single query scans at most 100 decisions, so a page may hold fewer decisions than `limit`
+    /// while more remain: the scan continues from the returned `next_start_after`, if any.
+    #[returns(EffectiveDecisionsResponse)]
+    EffectiveDecisions {
+        /// Optional Prolog dict term string (typically `ctx{...}`) the case of the returned decisions
+        /// must match.
+        ///
+        /// Every key of the pattern must be present in the case with a unifying value; variables
+        /// match any value.
+        case_pattern: Option<String>,
+        /// Optional time (seconds since epoch) at which decisions are in force (default: the
+        /// current block time).
+        at: Option<u64>,
+        /// Optional decision ID to start after (exclusive).
+        start_after: Option<u64>,
+        /// Optional maximum number of decisions to return (default: 10).
+        limit: Option<u32>,
+    },
+
+    /// Return a paginated list of the contracts subscribed to recorded decisions.
+    ///
+    /// Subscriptions are ordered by contract address in ascending order.
+    #[returns(SubscriptionsResponse)]
+    Subscriptions {
+        /// Optional contract address to start after (exclusive).
+        start_after: Option<String>,
+        /// Optional maximum number of subscriptions to return (default: 10).
+        limit: Option<u32>,
+    },
+
+    /// Return a capability granted by a decision.
+    #[returns(CapabilityResponse)]
+    Capability {
+        /// The capability identifier.
+        capability_id: u64,
+    },
+
+    /// Return a paginated list of the capabilities granted by decisions.
+    ///
+    /// Capabilities are ordered by their identifier in ascending order.
+    #[returns(CapabilitiesResponse)]
+    Capabilities {
+        /// Optional Bech32 address of the holder of the returned capabilities.
+        holder: Option<String>,
+        /// Optional capability ID to start after (exclusive).
+        start_after: Option<u64>,
+        /// Optional maximum number of capabilities to return (default: 10).
+        limit: Option<u32>,
+    },
+
+    /// Return a stored library module.
+    ///
+    /// Without `hash`, the latest stored version of the library is returned.
+    #[returns(LibraryResponse)]
+    Library {
+        /// The library name.
+        name: String,
+        /// Optional library hash (32 bytes, sha256) selecting a specific version.
+        hash: Option<Binary>,
+    },
+
+    /// Return a paginated list of the latest version of each stored library.
+    ///
+    /// Libraries are ordered by name in ascending order.
+    #[returns(LibrariesResponse)]
+    Libraries {
+        /// Optional library name to start after (exclusive).
+        start_after: Option<String>,
+        /// Optional maximum number of libraries to return (default: 10).
+        limit: Option<u32>,
     },
 }
 
@@ -237,6 +756,8 @@ pub struct ConstitutionStatusResponse {
     pub constitution_revision: u64,
     /// The stored constitution hash (32 bytes, sha256).
     pub constitution_hash: Binary,
+    /// The libraries used by the constitution, pinned by hash, in order of use.
+    pub libraries: Vec<LibraryReference>,
 }
 
 impl From<&ConstitutionStatus> for ConstitutionStatusResponse {
@@ -244,10 +765,31 @@ impl From<&ConstitutionStatus> for ConstitutionStatusResponse {
         Self {
             constitution_revision: status.constitution_revision(),
             constitution_hash: Binary::from(status.constitution_hash()),
+            libraries: LibraryReference::from_pins(status.libraries()),
         }
     }
 }
 
+/// A library module reference pinned by hash.
+#[cosmwasm_schema::cw_serde]
+pub struct LibraryReference {
+    /// The library name.
+    pub name: String,
+    /// The library hash (32 bytes, sha256).
+    pub hash: Binary,
+}
+
+impl LibraryReference {
+    fn from_pins(pins: &[LibraryPin]) -> Vec<Self> {
+        pins.iter()
+            .map(|pin| Self {
+                name: pin.name().clone(),
+                hash: Binary::from(pin.hash()),
+            })
+            .collect()
+    }
+}
+
 impl From<&Constitution> for ConstitutionResponse {
     fn from(constitution: &Constitution) -> Self {
         Self {
@@ -256,6 +798,88 @@ impl From<&Constitution> for ConstitutionResponse {
     }
 }
 
+/// Response returned by `QueryMsg::PendingRevision`.
+#[cosmwasm_schema::cw_serde]
+pub struct PendingRevisionResponse {
+    /// The pending constitution (raw Prolog program bytes).
+    pub constitution: Binary,
+    /// The pending constitution hash (32 bytes, sha256).
+    pub constitution_hash: Binary,
+    /// The libraries used by the pending constitution, pinned by hash, in order of use.
+    pub libraries: Vec<LibraryReference>,
+    /// The constitution revision the pending revision was authorized against.
+    pub base_revision: u64,
+    /// The condition from which the revision can be activated.
+    pub activation: RevisionActivation,
+    /// The identifier of the recorded `gov:revise_constitution` decision that authorized the revision.
+    pub decision_id: u64,
+    /// The Bech32 address of the revision proposer.
+    pub proposer: String,
+    /// The block height at which the revision was proposed.
+    pub block_height: u64,
+    /// The block time (seconds since epoch) at which the revision was proposed.
+    pub block_time_seconds: u64,
+}
+
+impl From<&PendingRevision> for PendingRevisionResponse {
+    fn from(pending: &PendingRevision) -> Self {
+        Self {
+            constitution: pending.constitution().clone(),
+            constitution_hash: Binary::from(pending.constitution_hash()),
+            libraries: LibraryReference::from_pins(pending.libraries()),
+            base_revision: pending.base_revision(),
+            activation: pending.activation(),
+            decision_id: pending.decision_id(),
+            proposer: pending.proposer().to_string(),
+            block_height: pending.block_height(),
+            block_time_seconds: pending.block_time_seconds(),
+        }
+    }
+}
+
+/// Response returned by `QueryMsg::PauseStatus`.
+#[cosmwasm_schema::cw_serde]
+pub struct PauseResponse {
+    /// The identifier of the recorded `gov:pause` decision that set the pause.
+    pub decision_id: u64,
+    /// The intents that remain available while paused.
+    pub exempt_intents: Vec<String>,
+    /// The Bech32 address of the account that paused governance.
+    pub paused_by: String,
+    /// The block height at which governance was paused.
+    pub block_height: u64,
+    /// The block time (seconds since epoch) at which governance was paused.
+    pub block_time_seconds: u64,
+}
+
+impl From<&Pause> for PauseResponse {
+    fn from(pause: &Pause) -> Self {
+        Self {
+            decision_id: pause.decision_id(),
+            exempt_intents: pause.exempt_intents().clone(),
+            paused_by: pause.paused_by().to_string(),
+            block_height: pause.block_height(),
+            block_time_seconds: pause.block_time_seconds(),
+        }
+    }
+}
+
+/// Response returned by `QueryMsg::SignerNonce`.
+#[cosmwasm_schema::cw_serde]
+pub struct SignerNonceResponse {
+    /// The Bech32 address of the signer.
+    pub signer: String,
+    /// The last nonce used by the signer; the next signed case must use a greater nonce.
+    pub nonce: u64,
+}
+
+/// Response returned by `QueryMsg::Intents`.
+#[cosmwasm_schema::cw_serde]
+pub struct IntentsResponse {
+    /// The declared intents, in declaration order.
+    pub intents: Vec<IntentDeclaration>,
+}
+
 /// Response returned by `QueryMsg::Decide`.
 #[cosmwasm_schema::cw_serde]
 pub struct DecideResponse {
@@ -263,6 +887,8 @@ pub struct DecideResponse {
     pub verdict: String,
     /// Optional motivation term returned as the third argument of `decide/3`.
     pub motivation: Option<String>,
+    /// The kind of the verdict in the governance verdict vocabulary, along with its arguments.
+    pub verdict_kind: VerdictKind,
 }
 
 /// Response returned by `QueryMsg::Decision`.
@@ -274,6 +900,8 @@ pub struct DecisionResponse {
     pub constitution_revision: u64,
     /// The constitution hash at the time of decision (32 bytes, sha256).
     pub constitution_hash: Binary,
+    /// The libraries used by the constitution at the time of decision, pinned by hash.
+    pub libraries: Vec<LibraryReference>,
     /// The case term as a Prolog term string.
     pub case: String,
     /// The case hash (32 bytes, sha256).
@@ -282,6 +910,8 @@ pub struct DecisionResponse {
     pub verdict: String,
     /// The verdict hash (32 bytes, sha256).
     pub verdict_hash: Binary,
+    /// The kind of the verdict in the governance verdict vocabulary, along with its arguments.
+    pub verdict_kind: VerdictKind,
     /// Optional motivation term as a Prolog term string.
     pub motivation: Option<String>,
     /// The motivation hash (32 bytes, sha256).
@@ -292,6 +922,12 @@ pub struct DecisionResponse {
     pub block_height: u64,
     /// The block time (seconds since epoch) at which the decision was recorded.
     pub block_time_seconds: u64,
+    /// The identifier of the decision this decision appeals, if any.
+    pub appeal_of: Option<u64>,
+    /// The period during which the decision is in force.
+    pub effective_period: EffectivePeriod,
+    /// The identifier of the decision revoking this decision, if any.
+    pub revoked_by: Option<u64>,
 }
 
 impl From<&DecisionRecord> for DecisionResponse {
@@ -300,15 +936,20 @@ impl From<&DecisionRecord> for DecisionResponse {
             decision_id: value.id(),
             constitution_revision: value.constitution_revision(),
             constitution_hash: Binary::from(value.constitution_hash()),
+            libraries: LibraryReference::from_pins(value.libraries()),
             case: value.case().clone(),
             case_hash: Binary::from(value.case_hash()),
             verdict: value.verdict().clone(),
             verdict_hash: Binary::from(value.verdict_hash()),
+            verdict_kind: value.verdict_kind(),
             motivation: value.motivation().clone(),
             motivation_hash: value.motivation_hash().map(Binary::from),
             author: value.author().to_string(),
             block_height: value.block_height(),
             block_time_seconds: value.block_time_seconds(),
+            appeal_of: value.appeal_of(),
+            effective_period: value.effective_during(),
+            revoked_by: value.revoked_by(),
         }
     }
 }
@@ -324,3 +965,142 @@ impl From<DecisionRecord> for DecisionResponse {
 pub struct DecisionsResponse {
     pub decisions: Vec<DecisionResponse>,
 }
+
+/// Response returned by `QueryMsg::EffectiveDecisions`.
+#[cosmwasm_schema::cw_serde]
+pub struct EffectiveDecisionsResponse {
+    pub decisions: Vec<DecisionResponse>,
+    /// The identifier of the last scanned decision, to pass as `start_after` to continue the scan,
+    /// when it stopped before the last recorded decision.
+    pub next_start_after: Option<u64>,
+}
+
+/// Response returned by `QueryMsg::Library`.
+#[cosmwasm_schema::cw_serde]
+pub struct LibraryResponse {
+    /// The library name.
+    pub name: String,
+    /// The library module (raw Prolog program bytes).
+    pub library: Binary,
+    /// The library hash (32 bytes, sha256).
+    pub library_hash: Binary,
+    /// The identifier of the recorded `gov:store_library` decision that authorized this version.
+    pub decision_id: u64,
+    /// The Bech32 address of the library author.
+    pub author: String,
+    /// The block height at which the library was stored.
+    pub block_height: u64,
+    /// The block time (seconds since epoch) at which the library was stored.
+    pub block_time_seconds: u64,
+}
+
+impl From<&LibraryRecord> for LibraryResponse {
+    fn from(value: &LibraryRecord) -> Self {
+        Self {
+            name: value.name().clone(),
+            library: value.library().clone(),
+            library_hash: Binary::from(value.library_hash()),
+            decision_id: value.decision_id(),
+            author: value.author().to_string(),
+            block_height: value.block_height(),
+            block_time_seconds: value.block_time_seconds(),
+        }
+    }
+}
+
+impl From<LibraryRecord> for LibraryResponse {
+    fn from(r: LibraryRecord) -> Self {
+        Self::from(&r)
+    }
+}
+
+/// Response returned by `QueryMsg::Libraries`.
+#[cosmwasm_schema::cw_serde]
+pub struct LibrariesResponse {
+    pub libraries: Vec<LibraryResponse>,
+}
+
+/// A subscription returned by `QueryMsg::Subscriptions`.
+#[cosmwasm_schema::cw_serde]
+pub struct SubscriptionResponse {
+    /// The Bech32 address of the subscribed contract.
+    pub contract: String,
+    /// The filter selecting the notified decisions.
+    pub filter: DecisionFilter,
+    /// The identifier of the recorded `gov:subscribe` decision that authorized the subscription.
+    pub decision_id: u64,
+    /// The block height at which the contract subscribed.
+    pub block_height: u64,
+    /// The block time (seconds since epoch) at which the contract subscribed.
+    pub block_time_seconds: u64,
+}
+
+impl From<&Subscription> for SubscriptionResponse {
+    fn from(value: &Subscription) -> Self {
+        Self {
+            contract: value.contract().to_string(),
+            filter: value.filter().clone(),
+            decision_id: value.decision_id(),
+            block_height: value.block_height(),
+            block_time_seconds: value.block_time_seconds(),
+        }
+    }
+}
+
+/// Response returned by `QueryMsg::Subscriptions`.
+#[cosmwasm_schema::cw_serde]
+pub struct SubscriptionsResponse {
+    pub subscriptions: Vec<SubscriptionResponse>,
+}
+
+/// Response returned by `QueryMsg::Capability`.
+#[cosmwasm_schema::cw_serde]
+pub struct CapabilityResponse {
+    /// The unique capability identifier.
+    pub capability_id: u64,
+    /// The granted capability, as a Prolog term string.
+    pub capability: String,
+    /// The Bech32 address of the holder.
+    pub holder: String,
+    /// The number of uses granted.
+    pub uses: u64,
+    /// The number of uses that remain.
+    pub remaining_uses: u64,
+    /// The identifier of the decision that granted the capability.
+    pub decision_id: u64,
+    /// Whether the granting decision is in force at the current block time.
+    pub in_force: bool,
+}
+
+impl CapabilityResponse {
+    pub fn new(capability: &Capability, in_force: bool) -> Self {
+        Self {
+            capability_id: capability.id(),
+            capability: capability.capability().clone(),
+            holder: capability.holder().to_string(),
+            uses: capability.uses(),
+            remaining_uses: capability.remaining_uses(),
+            decision_id: capability.decision_id(),
+            in_force,
+        }
+    }
+}
+
+/// Response returned by `QueryMsg::Capabilities`.
+#[cosmwasm_schema::cw_serde]
+pub struct CapabilitiesResponse {
+    pub capabilities: Vec<CapabilityResponse>,
+}
+
+/// Data of the response to `ExecuteMsg::ConsumeCapability`.
+#[cosmwasm_schema::cw_serde]
+pub struct ConsumeCapabilityResponse {
+    /// The identifier of the consumed capability.
+    pub capability_id: u64,
+    /// The consumed capability, as a Prolog term string.
+    pub capability: String,
+    /// The identifier of the decision that granted the capability.
+    pub decision_id: u64,
+    /// The number of uses that remain.
+    pub remaining_uses: u64,
+}
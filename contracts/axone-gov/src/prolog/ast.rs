@@ -1,6 +1,6 @@
 use std::fmt;
 
-use cosmwasm_std::{Int256, SignedDecimal};
+use cosmwasm_std::{Int256, SignedDecimal, Uint64};
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Term {
@@ -33,6 +33,14 @@ impl Term {
             Term::Dict(_, pairs) => pairs.iter().all(|(_, value)| value.is_ground()),
         }
     }
+
+    /// Returns the value of an integer term if it fits into an `u64`.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Term::Integer(i) => Uint64::try_from(*i).ok().map(|v| v.u64()),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for Term {
@@ -208,6 +216,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn as_u64() {
+        let test_cases = vec![
+            (42.into(), Some(42), "positive integer"),
+            (0.into(), Some(0), "zero"),
+            (u64::MAX.into(), Some(u64::MAX), "maximum u64"),
+            ((-1).into(), None, "negative integer"),
+            (
+                Term::Integer(Int256::from_i128(i128::from(u64::MAX) + 1)),
+                None,
+                "integer overflowing u64",
+            ),
+            (t::atom("42"), None, "atom"),
+            (Term::Float(SignedDecimal::one()), None, "float"),
+        ];
+
+        for (term, expected, description) in test_cases {
+            assert_eq!(term.as_u64(), expected, "Failed for case: {}", description);
+        }
+    }
+
     #[test]
     fn display_term() {
         let test_cases = vec![
@@ -680,6 +680,7 @@ fn pow10_i128(exp: u32) -> Result<i128, String> {
 }
 
 #[cfg(test)]
+#[allow(clippy::expect_fun_call)]
 mod tests {
     #[test]
     fn parses_numeric_literals() {
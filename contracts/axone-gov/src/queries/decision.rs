@@ -9,3 +9,19 @@ pub fn build_decide_query(case: &Case) -> String {
 pub fn build_decide_query_with_motivation(case: &Case) -> String {
     format!("decide({}, Verdict, Motivation).", case)
 }
+
+/// The variable bound to the fee required to record a decision.
+pub const FEE_VARIABLE: &str = "Fee";
+
+/// Build a Prolog query deciding a case to be recorded, along with the fee required to record the
+/// decision as returned by the optional `decision_fee/2` predicate (`[]` if undefined or failing).
+pub fn build_record_decision_query(case: &Case, motivated: bool) -> String {
+    let decide = if motivated {
+        format!("decide({case}, Verdict, Motivation)")
+    } else {
+        format!("decide({case}, Verdict)")
+    };
+    format!(
+        "{decide}, (current_predicate(decision_fee/2), decision_fee({case}, {FEE_VARIABLE}) -> true ; {FEE_VARIABLE} = [])."
+    )
+}
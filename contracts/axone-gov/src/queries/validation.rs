@@ -1,9 +1,79 @@
-/// Build a Prolog query to check for required predicates.
-pub fn build_required_predicates_query(predicates: &[&str]) -> String {
-    let checks = predicates
+use crate::prolog::ast::Term;
+
+/// The variable bound to the `intent/2` declarations of a constitution.
+pub const INTENTS_VARIABLE: &str = "Intents";
+/// The variable bound to the `case_schema/2` declarations of a constitution.
+pub const CASE_SCHEMAS_VARIABLE: &str = "Schemas";
+/// The variable bound to the `decision_limit/1` declarations of a constitution.
+pub const DECISION_LIMITS_VARIABLE: &str = "Limits";
+
+/// Build a Prolog query to check for required predicates and collect the optional `intent/2`,
+/// `case_schema/2` and `decision_limit/1` declarations.
+pub fn build_constitution_validation_query(predicates: &[&str]) -> String {
+    format!(
+        "{}, {}, {}, {}.",
+        required_predicates_goal(predicates),
+        intents_goal(),
+        case_schemas_goal("I"),
+        decision_limits_goal()
+    )
+}
+
+/// Build a Prolog query collecting the `intent/2` declarations.
+pub fn build_intents_query() -> String {
+    format!("{}.", intents_goal())
+}
+
+/// Build a Prolog query collecting the `case_schema/2` declarations of an intent.
+pub fn build_case_schema_query(intent: &str) -> String {
+    format!(
+        "{}.",
+        case_schemas_goal(&Term::Atom(intent.to_string()).to_string())
+    )
+}
+
+fn required_predicates_goal(predicates: &[&str]) -> String {
+    predicates
         .iter()
         .map(|p| format!("current_predicate({})", p))
         .collect::<Vec<_>>()
-        .join(", ");
-    format!("{checks}.")
+        .join(", ")
+}
+
+fn intents_goal() -> String {
+    format!(
+        "findall(intent(I, D), (current_predicate(intent/2), intent(I, D)), {INTENTS_VARIABLE})"
+    )
+}
+
+fn case_schemas_goal(intent: &str) -> String {
+    format!(
+        "findall(case_schema({intent}, S), (current_predicate(case_schema/2), case_schema({intent}, S)), {CASE_SCHEMAS_VARIABLE})"
+    )
+}
+
+fn decision_limits_goal() -> String {
+    format!(
+        "findall(L, (current_predicate(decision_limit/1), decision_limit(L)), {DECISION_LIMITS_VARIABLE})"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_declaration_queries() {
+        assert_eq!(
+            build_case_schema_query("gov:pause"),
+            "findall(case_schema('gov:pause', S), (current_predicate(case_schema/2), case_schema('gov:pause', S)), Schemas)."
+        );
+        assert_eq!(
+            build_constitution_validation_query(&["decide/2"]),
+            "current_predicate(decide/2), \
+findall(intent(I, D), (current_predicate(intent/2), intent(I, D)), Intents), \
+findall(case_schema(I, S), (current_predicate(case_schema/2), case_schema(I, S)), Schemas), \
+findall(L, (current_predicate(decision_limit/1), decision_limit(L)), Limits)."
+        );
+    }
 }
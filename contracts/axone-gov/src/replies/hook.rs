@@ -0,0 +1,39 @@
+use crate::contract::{AxoneGov, AxoneGovResult};
+use crate::{RESPONSE_KEY_DECISION_ID, RESPONSE_KEY_ERROR, RESPONSE_KEY_SUBSCRIBER};
+
+use abstract_app::traits::AbstractResponse;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{from_json, DepsMut, Env, Reply, SubMsgResult};
+
+/// The notification a decision hook reply refers to.
+#[cw_serde]
+pub struct DecisionHookPayload {
+    pub contract: String,
+    pub decision_id: u64,
+}
+
+/// Report a failed decision hook without reverting the notified decision.
+pub fn decision_hook_reply(
+    _deps: DepsMut<'_>,
+    _env: Env,
+    module: AxoneGov,
+    reply: Reply,
+) -> AxoneGovResult {
+    let payload: DecisionHookPayload = from_json(&reply.payload)?;
+    let error = match reply.result {
+        SubMsgResult::Err(error) => error,
+        SubMsgResult::Ok(_) => String::new(),
+    };
+
+    Ok(module.custom_response(
+        "decision_hook_failed",
+        vec![
+            (RESPONSE_KEY_SUBSCRIBER.to_string(), payload.contract),
+            (
+                RESPONSE_KEY_DECISION_ID.to_string(),
+                payload.decision_id.to_string(),
+            ),
+            (RESPONSE_KEY_ERROR.to_string(), error),
+        ],
+    ))
+}
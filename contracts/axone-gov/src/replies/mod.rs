@@ -1,5 +1,8 @@
+mod hook;
 mod instantiate;
 
+pub use hook::{decision_hook_reply, DecisionHookPayload};
 pub use instantiate::instantiate_reply;
 
 pub const INSTANTIATE_REPLY_ID: u64 = 1u64;
+pub const DECISION_HOOK_REPLY_ID: u64 = 2u64;
@@ -1,6 +1,6 @@
 use crate::{
     contract::{AxoneGov, AxoneGovResult},
-    domain::{constitution::ConstitutionStatus, Case, Constitution},
+    domain::{constitution::ConstitutionStatus, schema::is_reserved_key, Case, Constitution},
     error::AxoneGovError,
     gateway::logic::{query_service_ask, AxoneLogicQuery, QueryServiceAskRequest},
     prolog::ast::Term,
@@ -8,7 +8,7 @@ use crate::{
     prolog::term as t,
     queries::decision::build_decide_query_with_motivation,
     GOV_CTX_CURRENT_CONSTITUTION_REVISION, GOV_CTX_CURRENT_CONSTITUTION_SHA256, GOV_CTX_MODULE,
-    GOV_CTX_PROPOSED_CONSTITUTION_SHA256, GOV_CTX_TX,
+    GOV_CTX_PROPOSED_CONSTITUTION_SHA256, GOV_CTX_SIGNER, GOV_CTX_TX, GOV_VERDICT_PERMITTED,
 };
 use cosmwasm_std::{Coin, Env, MessageInfo, QuerierWrapper};
 
@@ -40,15 +40,35 @@ impl DecisionOutcome {
             motivation: motivation_term,
         })
     }
+
+    /// Build the error reported when this outcome does not authorize the governance act `intent`.
+    pub(crate) fn refusal(&self, intent: &str) -> AxoneGovError {
+        AxoneGovError::DecisionRefused {
+            intent: intent.to_string(),
+            verdict: self.verdict.to_string(),
+            motivation: self.motivation.to_string(),
+        }
+    }
+
+    /// Ensure the verdict is exactly `gov:permitted` for the governance act `intent`.
+    pub(crate) fn ensure_permitted(&self, intent: &str) -> AxoneGovResult<()> {
+        if self.verdict != t::atom(GOV_VERDICT_PERMITTED) {
+            return Err(self.refusal(intent));
+        }
+        Ok(())
+    }
 }
 
+/// Decide a case with motivation, once validated against the case schema of its intent.
 pub(crate) fn decide_case_with_motivation(
     querier: &QuerierWrapper<'_, AxoneLogicQuery>,
-    program: &str,
+    constitution: &Constitution,
     case: &Case,
 ) -> AxoneGovResult<DecisionOutcome> {
+    constitution.validate_governance_case(case)?;
+
     let query = build_decide_query_with_motivation(case);
-    let request = QueryServiceAskRequest::one(program, query);
+    let request = QueryServiceAskRequest::one(constitution.program(), query);
     let response = query_service_ask(querier, request)?;
     let answer = response.answer.ok_or(AxoneGovError::PrologEngineNoAnswer)?;
 
@@ -90,19 +110,41 @@ pub(crate) fn build_governance_case(
     module: &AxoneGov,
     env: &Env,
     info: &MessageInfo,
+) -> AxoneGovResult<Case> {
+    build_intent_case(
+        case_input,
+        intent,
+        vec![t::kv(
+            GOV_CTX_PROPOSED_CONSTITUTION_SHA256,
+            t::atom(proposed_constitution.hash_hex()),
+        )],
+        current_status,
+        module,
+        env,
+        info,
+    )
+}
+
+/// Build the case of a governance act on `intent`.
+///
+/// The caller-provided case is enriched with the intent, the act specific `facts`, the current
+/// constitution metadata (if any), the `gov:module` metadata and the `cw:tx` runtime context.
+pub(crate) fn build_intent_case(
+    case_input: Option<&str>,
+    intent: &str,
+    facts: Vec<(String, Term)>,
+    current_status: Option<&ConstitutionStatus>,
+    module: &AxoneGov,
+    env: &Env,
+    info: &MessageInfo,
 ) -> AxoneGovResult<Case> {
     let mut case = match case_input {
-        Some(input) => Case::new(input)?,
+        Some(input) => caller_case(input)?,
         None => Case::default(),
     };
 
-    let mut pairs = vec![
-        t::kv("intent", t::atom(intent)),
-        t::kv(
-            GOV_CTX_PROPOSED_CONSTITUTION_SHA256,
-            t::atom(proposed_constitution.hash_hex()),
-        ),
-    ];
+    let mut pairs = vec![t::kv("intent", t::atom(intent))];
+    pairs.extend(facts);
 
     if let Some(status) = current_status {
         pairs.push(t::kv(
@@ -124,6 +166,21 @@ pub(crate) fn build_governance_case(
     Ok(case)
 }
 
+/// Parse a caller-provided case, rejecting the reserved keys only the contract may set
+/// (`gov:*`, `cw:*`).
+pub(crate) fn caller_case(input: &str) -> AxoneGovResult<Case> {
+    let case = Case::new(input)?;
+    match case.find_key(is_reserved_key) {
+        Some(GOV_CTX_SIGNER) => Err(AxoneGovError::InvalidCase(format!(
+            "the '{GOV_CTX_SIGNER}' key is reserved to signed cases"
+        ))),
+        Some(key) => Err(AxoneGovError::InvalidCase(format!(
+            "the '{key}' key is reserved to the contract"
+        ))),
+        None => Ok(case),
+    }
+}
+
 fn coin_term(c: &Coin) -> Term {
     t::compound2("coin", c.amount.into(), t::atom(c.denom.clone()))
 }
@@ -170,6 +227,33 @@ mod tests {
     use super::*;
     use cosmwasm_std::{Addr, BlockInfo, ContractInfo, Timestamp, TransactionInfo, Uint128};
 
+    #[test]
+    fn caller_case_rejects_reserved_keys() {
+        for (input, expected) in [
+            (
+                "case{intent: transfer, 'gov:signer': signer{address: alice}}",
+                "the 'gov:signer' key is reserved to signed cases",
+            ),
+            (
+                "case{intent: transfer, 'gov:module': module{id: fake}}",
+                "the 'gov:module' key is reserved to the contract",
+            ),
+            (
+                "case{'cw:tx': tx{message: msg{sender: alice}}}",
+                "the 'cw:tx' key is reserved to the contract",
+            ),
+        ] {
+            let err = caller_case(input).expect_err("reserved key should be rejected");
+            assert_eq!(
+                err,
+                AxoneGovError::InvalidCase(expected.to_string()),
+                "{input}"
+            );
+        }
+
+        assert!(caller_case("case{intent: transfer, 'gov_like': 1}").is_ok());
+    }
+
     #[test]
     fn builds_transaction_term_correctly() {
         let cases = vec![
@@ -0,0 +1,57 @@
+use crate::{
+    contract::AxoneGovResult,
+    domain::Case,
+    msg::{DecisionHook, DecisionHookExecuteMsg},
+    replies::{DecisionHookPayload, DECISION_HOOK_REPLY_ID},
+    state::{load_all_subscriptions, load_decisions_after},
+};
+use cosmwasm_std::{to_json_binary, Storage, SubMsg, WasmMsg};
+
+/// The gas available to a subscribed contract to handle a decision hook.
+const DECISION_HOOK_GAS_LIMIT: u64 = 500_000;
+
+/// Build the notifications of the decisions recorded after the decision `after_id` to the
+/// subscribed contracts whose filter selects them.
+///
+/// Notifications reply on error only, so that a failing subscriber cannot revert the decision.
+pub(crate) fn decision_hooks(storage: &dyn Storage, after_id: u64) -> AxoneGovResult<Vec<SubMsg>> {
+    let subscriptions = load_all_subscriptions(storage)?;
+    if subscriptions.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut hooks = vec![];
+    for record in load_decisions_after(storage, after_id)? {
+        let intent = Case::new(record.case())?.intent().map(str::to_string);
+        let verdict_kind = record.verdict_kind().class();
+        let msg = to_json_binary(&DecisionHookExecuteMsg::DecisionHook(DecisionHook::new(
+            &record,
+            intent.clone(),
+        )))?;
+
+        for subscription in subscriptions.iter().filter(|subscription| {
+            subscription
+                .filter()
+                .matches(intent.as_deref(), verdict_kind)
+        }) {
+            let payload = DecisionHookPayload {
+                contract: subscription.contract().to_string(),
+                decision_id: record.id(),
+            };
+            hooks.push(
+                SubMsg::reply_on_error(
+                    WasmMsg::Execute {
+                        contract_addr: subscription.contract().to_string(),
+                        msg: msg.clone(),
+                        funds: vec![],
+                    },
+                    DECISION_HOOK_REPLY_ID,
+                )
+                .with_gas_limit(DECISION_HOOK_GAS_LIMIT)
+                .with_payload(to_json_binary(&payload)?),
+            );
+        }
+    }
+
+    Ok(hooks)
+}
@@ -1 +1,3 @@
 pub mod decision;
+pub mod hook;
+pub mod signature;
@@ -0,0 +1,193 @@
+use crate::{
+    contract::AxoneGovResult,
+    domain::signature::{CaseSignature, SignatureAlgorithm},
+    error::AxoneGovError,
+};
+use cosmwasm_std::{Addr, Api, Binary, CanonicalAddr, Checksum, Env};
+use ripemd::{Digest, Ripemd160};
+
+const SECP256K1_PUBKEY_LENGTH: usize = 33;
+const ED25519_PUBKEY_LENGTH: usize = 32;
+const ADDRESS_LENGTH: usize = 20;
+
+/// Verify the off-chain signature of a case and derive the signer address.
+///
+/// The address is derived from the public key the same way Cosmos SDK accounts are:
+/// `ripemd160(sha256(pubkey))` for secp256k1 keys and `sha256(pubkey)[..20]` for ed25519 keys.
+pub(crate) fn verify_case_signature(
+    api: &dyn Api,
+    env: &Env,
+    case: &str,
+    signature: &CaseSignature,
+) -> AxoneGovResult<Addr> {
+    let invalid = |reason: String| AxoneGovError::InvalidSignature(reason);
+    let public_key = signature.public_key.as_slice();
+    let expected_length = match signature.algorithm {
+        SignatureAlgorithm::Secp256k1 | SignatureAlgorithm::Adr36 => SECP256K1_PUBKEY_LENGTH,
+        SignatureAlgorithm::Ed25519 => ED25519_PUBKEY_LENGTH,
+    };
+    if public_key.len() != expected_length {
+        return Err(invalid(format!(
+            "expected a {expected_length} bytes {} public key, got {} bytes",
+            signature.algorithm,
+            public_key.len()
+        )));
+    }
+
+    let signer = signer_address(api, signature)?;
+    let sign_bytes = signature.sign_bytes(&env.block.chain_id, env.contract.address.as_str(), case);
+
+    let verified = match signature.algorithm {
+        SignatureAlgorithm::Secp256k1 => api.secp256k1_verify(
+            Checksum::generate(&sign_bytes).as_slice(),
+            &signature.signature,
+            public_key,
+        ),
+        SignatureAlgorithm::Ed25519 => {
+            api.ed25519_verify(&sign_bytes, &signature.signature, public_key)
+        }
+        SignatureAlgorithm::Adr36 => api.secp256k1_verify(
+            Checksum::generate(adr36_sign_doc(&signer, &sign_bytes).as_bytes()).as_slice(),
+            &signature.signature,
+            public_key,
+        ),
+    }
+    .map_err(|err| invalid(err.to_string()))?;
+
+    if !verified {
+        return Err(invalid(format!(
+            "{} signature verification failed",
+            signature.algorithm
+        )));
+    }
+
+    Ok(signer)
+}
+
+fn signer_address(api: &dyn Api, signature: &CaseSignature) -> AxoneGovResult<Addr> {
+    let sha = Checksum::generate(&signature.public_key);
+    let canonical: Vec<u8> = match signature.algorithm {
+        SignatureAlgorithm::Secp256k1 | SignatureAlgorithm::Adr36 => {
+            Ripemd160::digest(sha.as_slice()).to_vec()
+        }
+        SignatureAlgorithm::Ed25519 => sha.as_slice()[..ADDRESS_LENGTH].to_vec(),
+    };
+
+    Ok(api.addr_humanize(&CanonicalAddr::from(canonical))?)
+}
+
+/// Build the amino JSON document signed by wallets for arbitrary data (ADR-36).
+fn adr36_sign_doc(signer: &Addr, data: &[u8]) -> String {
+    format!(
+        r#"{{"account_number":"0","chain_id":"","fee":{{"amount":[],"gas":"0"}},"memo":"","msgs":[{{"type":"sign/MsgSignData","value":{{"data":"{}","signer":"{}"}}}}],"sequence":"0"}}"#,
+        Binary::from(data).to_base64(),
+        signer
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use ed25519_zebra::{SigningKey as Ed25519SigningKey, VerificationKey};
+    use k256::ecdsa::{signature::Signer, Signature, SigningKey};
+
+    const CASE: &str = "ctx{intent: transfer}";
+
+    fn secp256k1_key() -> SigningKey {
+        SigningKey::from_bytes(&[7u8; 32].into()).expect("valid secp256k1 key")
+    }
+
+    fn secp256k1_signature(algorithm: SignatureAlgorithm, key: &SigningKey) -> CaseSignature {
+        CaseSignature {
+            algorithm,
+            public_key: Binary::from(key.verifying_key().to_sec1_bytes().to_vec()),
+            signature: Binary::default(),
+            nonce: 1,
+        }
+    }
+
+    #[test]
+    fn verifies_secp256k1_signature() {
+        let deps = mock_dependencies();
+        let env = mock_env();
+        let key = secp256k1_key();
+        let mut signature = secp256k1_signature(SignatureAlgorithm::Secp256k1, &key);
+        let sign_bytes =
+            signature.sign_bytes(&env.block.chain_id, env.contract.address.as_str(), CASE);
+        let sig: Signature = key.sign(&sign_bytes);
+        signature.signature = Binary::from(sig.to_bytes().to_vec());
+
+        let signer = verify_case_signature(&deps.api, &env, CASE, &signature)
+            .expect("valid secp256k1 signature");
+        assert_eq!(
+            signer,
+            signer_address(&deps.api, &signature).expect("valid address")
+        );
+
+        let err = verify_case_signature(&deps.api, &env, "ctx{intent: other}", &signature)
+            .expect_err("tampered case");
+        assert!(matches!(err, AxoneGovError::InvalidSignature(_)));
+    }
+
+    #[test]
+    fn verifies_adr36_signature() {
+        let deps = mock_dependencies();
+        let env = mock_env();
+        let key = secp256k1_key();
+        let mut signature = secp256k1_signature(SignatureAlgorithm::Adr36, &key);
+        let signer = signer_address(&deps.api, &signature).expect("valid address");
+        let sign_bytes =
+            signature.sign_bytes(&env.block.chain_id, env.contract.address.as_str(), CASE);
+        let sig: Signature = key.sign(adr36_sign_doc(&signer, &sign_bytes).as_bytes());
+        signature.signature = Binary::from(sig.to_bytes().to_vec());
+
+        assert_eq!(
+            verify_case_signature(&deps.api, &env, CASE, &signature)
+                .expect("valid ADR-36 signature"),
+            signer
+        );
+
+        signature.nonce = 2;
+        verify_case_signature(&deps.api, &env, CASE, &signature).expect_err("nonce is signed");
+    }
+
+    #[test]
+    fn verifies_ed25519_signature() {
+        let deps = mock_dependencies();
+        let env = mock_env();
+        let key = Ed25519SigningKey::from([9u8; 32]);
+        let mut signature = CaseSignature {
+            algorithm: SignatureAlgorithm::Ed25519,
+            public_key: Binary::from(<[u8; 32]>::from(VerificationKey::from(&key)).to_vec()),
+            signature: Binary::default(),
+            nonce: 1,
+        };
+        let sign_bytes =
+            signature.sign_bytes(&env.block.chain_id, env.contract.address.as_str(), CASE);
+        signature.signature = Binary::from(key.sign(&sign_bytes).to_bytes().to_vec());
+
+        verify_case_signature(&deps.api, &env, CASE, &signature).expect("valid ed25519 signature");
+    }
+
+    #[test]
+    fn rejects_malformed_public_key() {
+        let deps = mock_dependencies();
+        let env = mock_env();
+        let signature = CaseSignature {
+            algorithm: SignatureAlgorithm::Ed25519,
+            public_key: Binary::from(vec![1u8; 33]),
+            signature: Binary::from(vec![0u8; 64]),
+            nonce: 1,
+        };
+
+        let err = verify_case_signature(&deps.api, &env, CASE, &signature)
+            .expect_err("invalid public key");
+        assert_eq!(
+            err,
+            AxoneGovError::InvalidSignature(
+                "expected a 32 bytes ed25519 public key, got 33 bytes".to_string()
+            )
+        );
+    }
+}
@@ -1,15 +1,31 @@
 use crate::domain::constitution::ConstitutionStatus;
-use crate::domain::{Constitution, Decision};
+use crate::domain::{
+    CapabilityGrant, Case, CasePattern, CaseSchema, Config, Constitution, Decision, DecisionFilter,
+    DecisionLimits, EffectivePeriod, IntentDeclaration, Library, LibraryPin, RateLimit,
+    RevisionActivation, VerdictClass, VerdictKind,
+};
 use crate::error::AxoneGovError;
+use crate::gateway::logic::AxoneLogicQuery;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    to_hex, Addr, Binary, Checksum, Order, OverflowError, OverflowOperation, StdError, Storage,
+    to_hex, Addr, Binary, Checksum, Order, OverflowError, OverflowOperation, QuerierWrapper,
+    StdError, Storage,
 };
-use cw_storage_plus::{Bound, Item, Map};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 use getset::{CopyGetters, Getters};
 
 const MAX_DECISIONS_LIMIT: u32 = 50;
 const DEFAULT_DECISIONS_LIMIT: u32 = 10;
+/// The maximum number of decisions scanned by a single effective decisions query.
+const MAX_EFFECTIVE_DECISIONS_SCANNED: usize = 100;
+const MAX_LIBRARIES_LIMIT: u32 = 50;
+const DEFAULT_LIBRARIES_LIMIT: u32 = 10;
+const MAX_SUBSCRIPTIONS_LIMIT: u32 = 50;
+const DEFAULT_SUBSCRIPTIONS_LIMIT: u32 = 10;
+const MAX_CAPABILITIES_LIMIT: u32 = 50;
+const DEFAULT_CAPABILITIES_LIMIT: u32 = 10;
+/// The maximum number of contracts subscribed to recorded decisions.
+const MAX_SUBSCRIPTIONS: usize = 20;
 
 pub(crate) struct StateAccess(());
 impl StateAccess {
@@ -20,12 +36,78 @@ impl StateAccess {
 
 const CONSTITUTION: Item<Binary> = Item::new("constitution");
 const CONSTITUTION_STATUS: Item<ConstitutionStatus> = Item::new("constitution_status");
+const INTENTS: Item<Vec<IntentDeclaration>> = Item::new("intents");
+const CASE_SCHEMAS: Item<Vec<CaseSchema>> = Item::new("case_schemas");
+const DECISION_LIMITS: Item<DecisionLimits> = Item::new("decision_limits");
+const CONFIG: Item<Config> = Item::new("config");
+const PENDING_REVISION: Item<PendingRevision> = Item::new("pending_revision");
+const PAUSE: Item<Pause> = Item::new("pause");
+
+/// The version of the state layout written by this code.
+pub const CURRENT_STATE_VERSION: u64 = 4;
+/// The version of the state layout of deployments predating state versioning.
+const UNVERSIONED_STATE_VERSION: u64 = 1;
+const STATE_VERSION: Item<u64> = Item::new("state_version");
+/// The maximum number of decisions and capabilities indexed at once by the index backfill.
+pub const INDEX_BACKFILL_BATCH: usize = 200;
+/// The progress of the index backfill started by the migration to state version 4, removed once
+/// every decision and capability is indexed.
+const INDEX_BACKFILL: Item<IndexBackfill> = Item::new("index_backfill");
+
+type MigrationStep =
+    fn(&mut dyn Storage, &QuerierWrapper<'_, AxoneLogicQuery>) -> Result<(), AxoneGovError>;
+
+/// The ordered migration steps, each upgrading the state layout to its version from the preceding
+/// one.
+const MIGRATION_STEPS: &[(u64, MigrationStep)] = &[
+    (2, backfill_constitution_metadata),
+    (3, backfill_config),
+    (4, backfill_indexes),
+];
 
 const INITIAL_CONSTITUTION_REVISION: u64 = 0;
 const INITIAL_DECISION_ID_COUNTER: u64 = 0;
 
 const DECISION_ID_COUNTER: Item<u64> = Item::new("decision_id_counter");
-const DECISIONS: Map<u64, DecisionRecord> = Map::new("decisions");
+const DECISIONS: IndexedMap<u64, DecisionRecord, DecisionIndexes<'static>> = IndexedMap::new(
+    "decisions",
+    DecisionIndexes {
+        verdict_kind: MultiIndex::new(
+            |_, record| record.verdict_kind().class().to_string(),
+            "decisions",
+            "decisions__verdict_kind",
+        ),
+    },
+);
+/// The appeal of each appealed decision, keyed by the appealed decision identifier.
+const APPEALS: Map<u64, u64> = Map::new("appeals");
+
+/// Library versions by (name, hash). Entries are never removed so pinned references stay valid.
+const LIBRARIES: Map<(&str, &[u8]), LibraryRecord> = Map::new("libraries");
+/// The hash of the latest stored version of each library, by name.
+const LIBRARY_HEADS: Map<&str, [u8; 32]> = Map::new("library_heads");
+
+/// The last nonce used by each signer of off-chain signed cases.
+const SIGNER_NONCES: Map<&Addr, u64> = Map::new("signer_nonces");
+
+/// The decisions recorded by each sender within its current rate limit window.
+const SENDER_RATES: Map<&Addr, SenderRate> = Map::new("sender_rates");
+
+/// The contracts notified of recorded decisions, by address.
+const SUBSCRIPTIONS: Map<&Addr, Subscription> = Map::new("subscriptions");
+
+const CAPABILITY_ID_COUNTER: Item<u64> = Item::new("capability_id_counter");
+/// The capabilities granted by decisions, by identifier.
+const CAPABILITIES: IndexedMap<u64, Capability, CapabilityIndexes<'static>> = IndexedMap::new(
+    "capabilities",
+    CapabilityIndexes {
+        holder: MultiIndex::new(
+            |_, capability| capability.holder.to_string(),
+            "capabilities",
+            "capabilities__holder",
+        ),
+    },
+);
 
 #[cw_serde]
 #[derive(CopyGetters, Getters)]
@@ -36,6 +118,9 @@ pub struct DecisionRecord {
     constitution_revision: u64,
     #[getset(get = "pub")]
     constitution_hash: [u8; 32],
+    #[serde(default)]
+    #[getset(get = "pub")]
+    libraries: Vec<LibraryPin>,
     #[getset(get = "pub")]
     case: String,
     #[getset(get = "pub")]
@@ -54,6 +139,15 @@ pub struct DecisionRecord {
     block_height: u64,
     #[getset(get_copy = "pub")]
     block_time_seconds: u64,
+    #[serde(default)]
+    #[getset(get_copy = "pub")]
+    appeal_of: Option<u64>,
+    #[serde(default)]
+    #[getset(get_copy = "pub")]
+    effective_period: Option<EffectivePeriod>,
+    #[serde(default)]
+    #[getset(get_copy = "pub")]
+    revoked_by: Option<u64>,
 }
 
 impl DecisionRecord {
@@ -69,11 +163,197 @@ impl DecisionRecord {
         to_hex(self.verdict_hash)
     }
 
+    pub fn verdict_kind(&self) -> VerdictKind {
+        VerdictKind::parse(&self.verdict)
+    }
+
+    /// The period during which the decision is in force, from its recording on unless restricted.
+    pub fn effective_during(&self) -> EffectivePeriod {
+        self.effective_period.unwrap_or(EffectivePeriod {
+            from_seconds: self.block_time_seconds,
+            until_seconds: None,
+        })
+    }
+
     pub fn motivation_hash_hex(&self) -> Option<String> {
         self.motivation_hash.map(to_hex)
     }
 }
 
+pub struct DecisionIndexes<'a> {
+    pub verdict_kind: MultiIndex<'a, String, DecisionRecord, u64>,
+}
+
+impl IndexList<DecisionRecord> for DecisionIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<DecisionRecord>> + '_> {
+        let indexes: Vec<&dyn Index<DecisionRecord>> = vec![&self.verdict_kind];
+        Box::new(indexes.into_iter())
+    }
+}
+
+pub struct CapabilityIndexes<'a> {
+    pub holder: MultiIndex<'a, String, Capability, u64>,
+}
+
+impl IndexList<Capability> for CapabilityIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Capability>> + '_> {
+        let indexes: Vec<&dyn Index<Capability>> = vec![&self.holder];
+        Box::new(indexes.into_iter())
+    }
+}
+
+#[cw_serde]
+#[derive(CopyGetters, Getters)]
+pub struct PendingRevision {
+    #[getset(get = "pub")]
+    constitution: Binary,
+    #[getset(get = "pub")]
+    constitution_hash: [u8; 32],
+    #[serde(default)]
+    #[getset(get = "pub")]
+    libraries: Vec<LibraryPin>,
+    #[serde(default)]
+    #[getset(get = "pub")]
+    intents: Vec<IntentDeclaration>,
+    #[serde(default)]
+    #[getset(get = "pub")]
+    schemas: Vec<CaseSchema>,
+    #[serde(default)]
+    #[getset(get = "pub")]
+    limits: DecisionLimits,
+    #[getset(get_copy = "pub")]
+    base_revision: u64,
+    #[getset(get_copy = "pub")]
+    activation: RevisionActivation,
+    #[getset(get_copy = "pub")]
+    decision_id: u64,
+    #[getset(get = "pub")]
+    proposer: Addr,
+    #[getset(get_copy = "pub")]
+    block_height: u64,
+    #[getset(get_copy = "pub")]
+    block_time_seconds: u64,
+}
+
+impl PendingRevision {
+    pub fn constitution_hash_hex(&self) -> String {
+        to_hex(self.constitution_hash)
+    }
+
+    pub(crate) fn proposed_constitution(
+        &self,
+        storage: &dyn Storage,
+    ) -> Result<Constitution, AxoneGovError> {
+        Constitution::from_state(
+            self.constitution.clone(),
+            load_pinned_libraries(storage, &self.libraries)?,
+            self.intents.clone(),
+            self.schemas.clone(),
+            self.limits.clone(),
+            &StateAccess::new(),
+        )
+    }
+}
+
+#[cw_serde]
+#[derive(CopyGetters, Getters)]
+pub struct Pause {
+    #[getset(get_copy = "pub")]
+    decision_id: u64,
+    #[getset(get = "pub")]
+    exempt_intents: Vec<String>,
+    #[getset(get = "pub")]
+    paused_by: Addr,
+    #[getset(get_copy = "pub")]
+    block_height: u64,
+    #[getset(get_copy = "pub")]
+    block_time_seconds: u64,
+}
+
+impl Pause {
+    /// Returns true if the governance act with the given intent remains available while paused.
+    pub fn is_exempt(&self, intent: Option<&str>) -> bool {
+        intent.is_some_and(|intent| self.exempt_intents.iter().any(|exempt| exempt == intent))
+    }
+}
+
+#[cw_serde]
+struct SenderRate {
+    window_start_seconds: u64,
+    decisions: u64,
+}
+
+#[cw_serde]
+#[derive(CopyGetters, Getters)]
+pub struct Subscription {
+    #[getset(get = "pub")]
+    contract: Addr,
+    #[getset(get = "pub")]
+    filter: DecisionFilter,
+    #[getset(get_copy = "pub")]
+    decision_id: u64,
+    #[getset(get_copy = "pub")]
+    block_height: u64,
+    #[getset(get_copy = "pub")]
+    block_time_seconds: u64,
+}
+
+#[cw_serde]
+#[derive(CopyGetters, Getters)]
+pub struct Capability {
+    #[getset(get_copy = "pub")]
+    id: u64,
+    #[getset(get = "pub")]
+    capability: String,
+    #[getset(get = "pub")]
+    holder: Addr,
+    #[getset(get_copy = "pub")]
+    uses: u64,
+    #[getset(get_copy = "pub")]
+    remaining_uses: u64,
+    #[getset(get_copy = "pub")]
+    decision_id: u64,
+}
+
+#[cw_serde]
+#[derive(CopyGetters, Getters)]
+pub struct LibraryRecord {
+    #[getset(get = "pub")]
+    name: String,
+    #[getset(get = "pub")]
+    library: Binary,
+    #[getset(get = "pub")]
+    library_hash: [u8; 32],
+    #[getset(get_copy = "pub")]
+    decision_id: u64,
+    #[getset(get = "pub")]
+    author: Addr,
+    #[getset(get_copy = "pub")]
+    block_height: u64,
+    #[getset(get_copy = "pub")]
+    block_time_seconds: u64,
+}
+
+impl LibraryRecord {
+    pub fn library_hash_hex(&self) -> String {
+        to_hex(self.library_hash)
+    }
+
+    fn to_library(&self) -> Library {
+        Library::from_state(self.name.clone(), self.library.clone(), &StateAccess::new())
+    }
+}
+
+pub fn save_config(storage: &mut dyn Storage, config: &Config) -> Result<(), AxoneGovError> {
+    CONFIG.save(storage, config)?;
+    Ok(())
+}
+
+pub fn load_config(storage: &dyn Storage) -> Result<Config, AxoneGovError> {
+    let config = CONFIG.may_load(storage)?.unwrap_or_default();
+    Ok(config)
+}
+
 pub fn save_initial_constitution(
     storage: &mut dyn Storage,
     constitution: &Constitution,
@@ -81,10 +361,14 @@ pub fn save_initial_constitution(
     if CONSTITUTION_STATUS.may_load(storage)?.is_some() {
         return Err(StdError::generic_err("constitution already initialized").into());
     }
-    let status = ConstitutionStatus::new(INITIAL_CONSTITUTION_REVISION, constitution.hash());
+    let status = ConstitutionStatus::new(INITIAL_CONSTITUTION_REVISION, constitution);
 
     CONSTITUTION.save(storage, constitution.bytes())?;
     CONSTITUTION_STATUS.save(storage, &status)?;
+    INTENTS.save(storage, constitution.intents())?;
+    CASE_SCHEMAS.save(storage, constitution.schemas())?;
+    DECISION_LIMITS.save(storage, constitution.limits())?;
+    STATE_VERSION.save(storage, &CURRENT_STATE_VERSION)?;
 
     Ok(status)
 }
@@ -98,19 +382,304 @@ pub fn save_revised_constitution(
         .constitution_revision()
         .checked_add(1)
         .ok_or_else(|| StdError::overflow(OverflowError::new(OverflowOperation::Add)))?;
-    let status = ConstitutionStatus::new(next_revision, constitution.hash());
+    let status = ConstitutionStatus::new(next_revision, constitution);
 
     CONSTITUTION.save(storage, constitution.bytes())?;
     CONSTITUTION_STATUS.save(storage, &status)?;
+    INTENTS.save(storage, constitution.intents())?;
+    CASE_SCHEMAS.save(storage, constitution.schemas())?;
+    DECISION_LIMITS.save(storage, constitution.limits())?;
 
     Ok(status)
 }
 
+pub fn save_pending_revision(
+    storage: &mut dyn Storage,
+    constitution: &Constitution,
+    base_status: &ConstitutionStatus,
+    activation: RevisionActivation,
+    authorization: &DecisionRecord,
+) -> Result<PendingRevision, AxoneGovError> {
+    if let Some(pending) = load_pending_revision(storage)? {
+        return Err(AxoneGovError::RevisionAlreadyPending {
+            constitution_hash: pending.constitution_hash_hex(),
+        });
+    }
+
+    let pending = PendingRevision {
+        constitution: constitution.bytes().clone(),
+        constitution_hash: constitution.hash(),
+        libraries: constitution.library_pins(),
+        intents: constitution.intents().clone(),
+        schemas: constitution.schemas().clone(),
+        limits: constitution.limits().clone(),
+        base_revision: base_status.constitution_revision(),
+        activation,
+        decision_id: authorization.id(),
+        proposer: authorization.author().clone(),
+        block_height: authorization.block_height(),
+        block_time_seconds: authorization.block_time_seconds(),
+    };
+
+    PENDING_REVISION.save(storage, &pending)?;
+
+    Ok(pending)
+}
+
+pub fn load_pending_revision(
+    storage: &dyn Storage,
+) -> Result<Option<PendingRevision>, AxoneGovError> {
+    let pending = PENDING_REVISION.may_load(storage)?;
+    Ok(pending)
+}
+
+pub fn take_pending_revision(storage: &mut dyn Storage) -> Result<PendingRevision, AxoneGovError> {
+    let pending = load_pending_revision(storage)?.ok_or(AxoneGovError::NoPendingRevision)?;
+    PENDING_REVISION.remove(storage);
+    Ok(pending)
+}
+
+pub fn save_pause(
+    storage: &mut dyn Storage,
+    exempt_intents: Vec<String>,
+    authorization: &DecisionRecord,
+) -> Result<Pause, AxoneGovError> {
+    if let Some(pause) = load_pause(storage)? {
+        return Err(AxoneGovError::AlreadyPaused {
+            decision_id: pause.decision_id(),
+        });
+    }
+
+    let pause = Pause {
+        decision_id: authorization.id(),
+        exempt_intents,
+        paused_by: authorization.author().clone(),
+        block_height: authorization.block_height(),
+        block_time_seconds: authorization.block_time_seconds(),
+    };
+
+    PAUSE.save(storage, &pause)?;
+
+    Ok(pause)
+}
+
+pub fn load_pause(storage: &dyn Storage) -> Result<Option<Pause>, AxoneGovError> {
+    let pause = PAUSE.may_load(storage)?;
+    Ok(pause)
+}
+
+pub fn take_pause(storage: &mut dyn Storage) -> Result<Pause, AxoneGovError> {
+    let pause = load_pause(storage)?.ok_or(AxoneGovError::NotPaused)?;
+    PAUSE.remove(storage);
+    Ok(pause)
+}
+
+/// Subscribe `contract` to the recorded decisions selected by `filter`, replacing any previous
+/// subscription of the contract.
+pub fn save_subscription(
+    storage: &mut dyn Storage,
+    contract: Addr,
+    filter: DecisionFilter,
+    authorization: &DecisionRecord,
+) -> Result<Subscription, AxoneGovError> {
+    if !SUBSCRIPTIONS.has(storage, &contract)
+        && SUBSCRIPTIONS
+            .keys_raw(storage, None, None, Order::Ascending)
+            .count()
+            >= MAX_SUBSCRIPTIONS
+    {
+        return Err(AxoneGovError::TooManySubscriptions {
+            max: MAX_SUBSCRIPTIONS,
+        });
+    }
+
+    let subscription = Subscription {
+        contract,
+        filter,
+        decision_id: authorization.id(),
+        block_height: authorization.block_height(),
+        block_time_seconds: authorization.block_time_seconds(),
+    };
+    SUBSCRIPTIONS.save(storage, &subscription.contract, &subscription)?;
+
+    Ok(subscription)
+}
+
+pub fn load_subscription(
+    storage: &dyn Storage,
+    contract: &Addr,
+) -> Result<Subscription, AxoneGovError> {
+    SUBSCRIPTIONS
+        .may_load(storage, contract)?
+        .ok_or_else(|| AxoneGovError::SubscriptionNotFound {
+            contract: contract.to_string(),
+        })
+}
+
+pub fn remove_subscription(
+    storage: &mut dyn Storage,
+    contract: &Addr,
+) -> Result<Subscription, AxoneGovError> {
+    let subscription = load_subscription(storage, contract)?;
+    SUBSCRIPTIONS.remove(storage, contract);
+    Ok(subscription)
+}
+
+/// Load a page of the subscriptions, ordered by contract address.
+pub fn load_subscriptions(
+    storage: &dyn Storage,
+    start_after: Option<&Addr>,
+    limit: Option<u32>,
+) -> Result<Vec<Subscription>, AxoneGovError> {
+    let start = start_after.map(Bound::exclusive);
+    let limit = limit
+        .unwrap_or(DEFAULT_SUBSCRIPTIONS_LIMIT)
+        .min(MAX_SUBSCRIPTIONS_LIMIT) as usize;
+
+    SUBSCRIPTIONS
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| Ok(item?.1))
+        .collect()
+}
+
+/// Load every subscription, ordered by contract address.
+pub fn load_all_subscriptions(storage: &dyn Storage) -> Result<Vec<Subscription>, AxoneGovError> {
+    SUBSCRIPTIONS
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| Ok(item?.1))
+        .collect()
+}
+
+/// Save the capability granted to `holder` by the decision `authorization`.
+pub fn save_capability(
+    storage: &mut dyn Storage,
+    grant: CapabilityGrant,
+    holder: Addr,
+    authorization: &DecisionRecord,
+) -> Result<Capability, AxoneGovError> {
+    let id = CAPABILITY_ID_COUNTER
+        .may_load(storage)?
+        .unwrap_or_default()
+        .checked_add(1)
+        .ok_or_else(|| StdError::overflow(OverflowError::new(OverflowOperation::Add)))?;
+    CAPABILITY_ID_COUNTER.save(storage, &id)?;
+
+    let capability = Capability {
+        id,
+        capability: grant.capability,
+        holder,
+        uses: grant.uses,
+        remaining_uses: grant.uses,
+        decision_id: authorization.id(),
+    };
+    CAPABILITIES.save(storage, id, &capability)?;
+
+    Ok(capability)
+}
+
+pub fn load_capability(
+    storage: &dyn Storage,
+    capability_id: u64,
+) -> Result<Capability, AxoneGovError> {
+    let capability = CAPABILITIES.load(storage, capability_id)?;
+    Ok(capability)
+}
+
+/// Whether the decision granting a capability is in force at the time `at`.
+pub fn is_capability_in_force(
+    storage: &dyn Storage,
+    capability: &Capability,
+    at: u64,
+) -> Result<bool, AxoneGovError> {
+    is_in_force(
+        storage,
+        &load_decision(storage, capability.decision_id)?,
+        at,
+    )
+}
+
+/// Consume one use of the capability `capability_id` held by `consumer` at the time `at`.
+pub fn consume_capability(
+    storage: &mut dyn Storage,
+    capability_id: u64,
+    consumer: &Addr,
+    at: u64,
+) -> Result<Capability, AxoneGovError> {
+    let mut capability = load_capability(storage, capability_id)?;
+    if capability.holder != consumer {
+        return Err(AxoneGovError::CapabilityNotHeld {
+            capability_id,
+            consumer: consumer.to_string(),
+        });
+    }
+    if capability.remaining_uses == 0 {
+        return Err(AxoneGovError::CapabilityExhausted { capability_id });
+    }
+    if !is_capability_in_force(storage, &capability, at)? {
+        return Err(AxoneGovError::CapabilityNotInForce {
+            capability_id,
+            decision_id: capability.decision_id,
+        });
+    }
+
+    capability.remaining_uses -= 1;
+    CAPABILITIES.save(storage, capability_id, &capability)?;
+
+    Ok(capability)
+}
+
+/// Load a page of the capabilities, optionally restricted to the ones held by `holder`.
+pub fn load_capabilities(
+    storage: &dyn Storage,
+    holder: Option<&Addr>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<Vec<Capability>, AxoneGovError> {
+    let start = start_after.map(Bound::exclusive);
+    let limit = limit
+        .unwrap_or(DEFAULT_CAPABILITIES_LIMIT)
+        .min(MAX_CAPABILITIES_LIMIT) as usize;
+
+    let capabilities = match holder {
+        Some(holder) => CAPABILITIES.idx.holder.prefix(holder.to_string()).range(
+            storage,
+            start,
+            None,
+            Order::Ascending,
+        ),
+        None => CAPABILITIES.range(storage, start, None, Order::Ascending),
+    };
+
+    capabilities
+        .map(|item| item.map(|(_id, capability)| capability).map_err(Into::into))
+        .take(limit)
+        .collect()
+}
+
+/// Ensure a governance act with the given intent is not blocked by a pause.
+pub fn ensure_not_paused(storage: &dyn Storage, intent: Option<&str>) -> Result<(), AxoneGovError> {
+    match load_pause(storage)? {
+        Some(pause) if !pause.is_exempt(intent) => Err(AxoneGovError::Paused {
+            decision_id: pause.decision_id(),
+            intent: intent.unwrap_or("none").to_string(),
+        }),
+        _ => Ok(()),
+    }
+}
+
 pub fn load_constitution(storage: &dyn Storage) -> Result<Constitution, AxoneGovError> {
     let bytes = CONSTITUTION.load(storage)?;
-    let _status = load_constitution_status(storage)?;
+    let status = load_constitution_status(storage)?;
 
-    Ok(Constitution::from_state(bytes, &StateAccess::new()))
+    Constitution::from_state(
+        bytes,
+        load_pinned_libraries(storage, status.libraries())?,
+        INTENTS.may_load(storage)?.unwrap_or_default(),
+        CASE_SCHEMAS.may_load(storage)?.unwrap_or_default(),
+        DECISION_LIMITS.may_load(storage)?.unwrap_or_default(),
+        &StateAccess::new(),
+    )
 }
 
 pub fn load_constitution_status(
@@ -130,6 +699,7 @@ pub fn record_decision(
         id: decision_id,
         constitution_revision: decision.constitution_revision(),
         constitution_hash: decision.constitution_hash(),
+        libraries: decision.libraries().clone(),
         case: decision.case().to_string(),
         case_hash: *Checksum::generate(decision.case().as_bytes()).as_ref(),
         verdict: decision.verdict().to_string(),
@@ -142,8 +712,15 @@ pub fn record_decision(
         author: decision.author().clone(),
         block_height: decision.height(),
         block_time_seconds: decision.time_seconds(),
+        appeal_of: decision.appeal_of(),
+        effective_period: decision.effective_period(),
+        revoked_by: None,
     };
 
+    if let Some(appealed) = record.appeal_of {
+        ensure_not_appealed(storage, appealed)?;
+        APPEALS.save(storage, appealed, &record.id)?;
+    }
     DECISIONS.save(storage, record.id, &record)?;
 
     Ok(record)
@@ -157,20 +734,160 @@ pub fn load_decision(
     Ok(record)
 }
 
+/// Ensure the decision `decision_id` has not been appealed yet, so that appeal chains stay linear.
+pub fn ensure_not_appealed(storage: &dyn Storage, decision_id: u64) -> Result<(), AxoneGovError> {
+    match APPEALS.may_load(storage, decision_id)? {
+        Some(appeal_id) => Err(AxoneGovError::DecisionAlreadyAppealed {
+            decision_id,
+            appeal_id,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Mark the decision `decision_id` as revoked by the decision `revocation_id`.
+pub fn revoke_decision(
+    storage: &mut dyn Storage,
+    decision_id: u64,
+    revocation_id: u64,
+) -> Result<DecisionRecord, AxoneGovError> {
+    let mut record = load_decision(storage, decision_id)?;
+    if let Some(revoked_by) = record.revoked_by {
+        return Err(AxoneGovError::DecisionAlreadyRevoked {
+            decision_id,
+            revocation_id: revoked_by,
+        });
+    }
+    record.revoked_by = Some(revocation_id);
+    DECISIONS.save(storage, decision_id, &record)?;
+
+    Ok(record)
+}
+
+/// Whether a decision is in force at the time `at`: within its effective period, and neither
+/// revoked nor superseded by an appeal recorded at or before `at`.
+fn is_in_force(
+    storage: &dyn Storage,
+    record: &DecisionRecord,
+    at: u64,
+) -> Result<bool, AxoneGovError> {
+    if !record.effective_during().covers(at) {
+        return Ok(false);
+    }
+    for ended_by in [record.revoked_by, APPEALS.may_load(storage, record.id)?]
+        .into_iter()
+        .flatten()
+    {
+        if load_decision(storage, ended_by)?.block_time_seconds <= at {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Load a page of the decisions in force at the time `at`, optionally restricted to the decisions
+/// whose case matches `pattern`.
+///
+/// At most [`MAX_EFFECTIVE_DECISIONS_SCANNED`] decisions are scanned. Along with the page, the
+/// identifier of the last scanned decision is returned when the scan stopped before the last
+/// decision, to continue from.
+pub fn load_effective_decisions(
+    storage: &dyn Storage,
+    at: u64,
+    pattern: Option<&CasePattern>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<(Vec<DecisionRecord>, Option<u64>), AxoneGovError> {
+    let start = start_after.map(Bound::exclusive);
+    let limit = limit
+        .unwrap_or(DEFAULT_DECISIONS_LIMIT)
+        .min(MAX_DECISIONS_LIMIT) as usize;
+
+    let mut decisions = Vec::with_capacity(limit);
+    let mut last_scanned = None;
+    for (scanned, item) in DECISIONS
+        .range(storage, start, None, Order::Ascending)
+        .enumerate()
+    {
+        if decisions.len() == limit || scanned == MAX_EFFECTIVE_DECISIONS_SCANNED {
+            return Ok((decisions, last_scanned));
+        }
+        let (id, record) = item?;
+        last_scanned = Some(id);
+        if !is_in_force(storage, &record, at)? {
+            continue;
+        }
+        if let Some(pattern) = pattern {
+            if !pattern.matches(&Case::new(&record.case)?) {
+                continue;
+            }
+        }
+        decisions.push(record);
+    }
+
+    Ok((decisions, None))
+}
+
+/// Load the appeal chain a decision belongs to, from the original decision to its latest appeal.
+pub fn load_decision_chain(
+    storage: &dyn Storage,
+    decision_id: u64,
+) -> Result<Vec<DecisionRecord>, AxoneGovError> {
+    let mut chain = vec![load_decision(storage, decision_id)?];
+    while let Some(appealed) = chain.first().and_then(DecisionRecord::appeal_of) {
+        chain.insert(0, load_decision(storage, appealed)?);
+    }
+    let mut last = decision_id;
+    while let Some(appeal_id) = APPEALS.may_load(storage, last)? {
+        chain.push(load_decision(storage, appeal_id)?);
+        last = appeal_id;
+    }
+
+    Ok(chain)
+}
+
+/// Load a page of decisions, optionally restricted to the verdicts of a given class.
 pub fn load_decisions(
     storage: &dyn Storage,
     start_after: Option<u64>,
     limit: Option<u32>,
+    verdict_kind: Option<VerdictClass>,
 ) -> Result<Vec<DecisionRecord>, AxoneGovError> {
     let start = start_after.map(Bound::exclusive);
     let limit = limit
         .unwrap_or(DEFAULT_DECISIONS_LIMIT)
         .min(MAX_DECISIONS_LIMIT) as usize;
 
-    DECISIONS
-        .range(storage, start, None, Order::Ascending)
-        .take(limit)
+    let records = match verdict_kind {
+        Some(kind) => DECISIONS.idx.verdict_kind.prefix(kind.to_string()).range(
+            storage,
+            start,
+            None,
+            Order::Ascending,
+        ),
+        None => DECISIONS.range(storage, start, None, Order::Ascending),
+    };
+
+    records
         .map(|item| item.map(|(_id, record)| record).map_err(Into::into))
+        .take(limit)
+        .collect()
+}
+
+/// Load the decisions recorded after the decision `after_id`, in ascending order.
+pub fn load_decisions_after(
+    storage: &dyn Storage,
+    after_id: u64,
+) -> Result<Vec<DecisionRecord>, AxoneGovError> {
+    DECISIONS
+        .range(
+            storage,
+            Some(Bound::exclusive(after_id)),
+            None,
+            Order::Ascending,
+        )
+        .map(|item| Ok(item?.1))
         .collect()
 }
 
@@ -183,9 +900,396 @@ fn next_decision_id(storage: &mut dyn Storage) -> Result<u64, AxoneGovError> {
     Ok(next)
 }
 
-fn current_decision_id(storage: &dyn Storage) -> Result<u64, AxoneGovError> {
+/// The identifier of the last recorded decision, or `0` when none was recorded.
+pub fn current_decision_id(storage: &dyn Storage) -> Result<u64, AxoneGovError> {
     let current = DECISION_ID_COUNTER
         .may_load(storage)?
         .unwrap_or(INITIAL_DECISION_ID_COUNTER);
     Ok(current)
 }
+
+pub fn save_library(
+    storage: &mut dyn Storage,
+    library: &Library,
+    authorization: &DecisionRecord,
+) -> Result<LibraryRecord, AxoneGovError> {
+    let record = LibraryRecord {
+        name: library.name().clone(),
+        library: library.bytes().clone(),
+        library_hash: library.hash(),
+        decision_id: authorization.id(),
+        author: authorization.author().clone(),
+        block_height: authorization.block_height(),
+        block_time_seconds: authorization.block_time_seconds(),
+    };
+
+    LIBRARIES.save(
+        storage,
+        (record.name.as_str(), record.library_hash.as_slice()),
+        &record,
+    )?;
+    LIBRARY_HEADS.save(storage, record.name.as_str(), &record.library_hash)?;
+
+    Ok(record)
+}
+
+/// Load a stored library version, either the latest one or the one matching `hash`.
+pub fn load_library_record(
+    storage: &dyn Storage,
+    name: &str,
+    hash: Option<&[u8; 32]>,
+) -> Result<LibraryRecord, AxoneGovError> {
+    let not_found = || AxoneGovError::LibraryNotFound {
+        reference: match hash {
+            Some(hash) => format!("{name}@{}", to_hex(hash)),
+            None => name.to_string(),
+        },
+    };
+
+    let hash = match hash {
+        Some(hash) => *hash,
+        None => LIBRARY_HEADS
+            .may_load(storage, name)?
+            .ok_or_else(not_found)?,
+    };
+
+    LIBRARIES
+        .may_load(storage, (name, hash.as_slice()))?
+        .ok_or_else(not_found)
+}
+
+pub fn load_library(
+    storage: &dyn Storage,
+    name: &str,
+    hash: Option<&[u8; 32]>,
+) -> Result<Library, AxoneGovError> {
+    Ok(load_library_record(storage, name, hash)?.to_library())
+}
+
+/// Load the latest version of each stored library, ordered by name.
+pub fn load_libraries(
+    storage: &dyn Storage,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<LibraryRecord>, AxoneGovError> {
+    let start = start_after.as_deref().map(Bound::exclusive);
+    let limit = limit
+        .unwrap_or(DEFAULT_LIBRARIES_LIMIT)
+        .min(MAX_LIBRARIES_LIMIT) as usize;
+
+    LIBRARY_HEADS
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (name, hash) = item?;
+            load_library_record(storage, &name, Some(&hash))
+        })
+        .collect()
+}
+
+fn load_pinned_libraries(
+    storage: &dyn Storage,
+    pins: &[LibraryPin],
+) -> Result<Vec<Library>, AxoneGovError> {
+    pins.iter()
+        .map(|pin| load_library(storage, pin.name(), Some(pin.hash())))
+        .collect()
+}
+
+pub fn load_signer_nonce(storage: &dyn Storage, signer: &Addr) -> Result<u64, AxoneGovError> {
+    let nonce = SIGNER_NONCES.may_load(storage, signer)?.unwrap_or_default();
+    Ok(nonce)
+}
+
+/// Consume a signer nonce, which must be greater than the last nonce used by the signer.
+pub fn use_signer_nonce(
+    storage: &mut dyn Storage,
+    signer: &Addr,
+    nonce: u64,
+) -> Result<(), AxoneGovError> {
+    let last_nonce = load_signer_nonce(storage, signer)?;
+    if nonce <= last_nonce {
+        return Err(AxoneGovError::NonceAlreadyUsed {
+            signer: signer.to_string(),
+            nonce,
+            last_nonce,
+        });
+    }
+
+    SIGNER_NONCES.save(storage, signer, &nonce)?;
+    Ok(())
+}
+
+/// Account for `decisions` decisions recorded by `sender` at the time `now`, within the rate limit
+/// declared by the constitution. Windows are fixed and start at the first decision of the sender
+/// once the previous window elapsed.
+pub fn use_sender_rate(
+    storage: &mut dyn Storage,
+    sender: &Addr,
+    limit: &RateLimit,
+    now: u64,
+    decisions: u64,
+) -> Result<(), AxoneGovError> {
+    let rate = sender_rate_after(storage, sender, limit, now, decisions)?;
+    SENDER_RATES.save(storage, sender, &rate)?;
+    Ok(())
+}
+
+/// Ensure `sender` may record `decisions` decisions at the time `now` within the rate limit
+/// declared by the constitution, without accounting for them.
+pub fn check_sender_rate(
+    storage: &dyn Storage,
+    sender: &Addr,
+    limit: &RateLimit,
+    now: u64,
+    decisions: u64,
+) -> Result<(), AxoneGovError> {
+    sender_rate_after(storage, sender, limit, now, decisions).map(|_| ())
+}
+
+/// The rate of `sender` once `decisions` decisions are recorded at the time `now`, failing if it
+/// exceeds the rate limit.
+fn sender_rate_after(
+    storage: &dyn Storage,
+    sender: &Addr,
+    limit: &RateLimit,
+    now: u64,
+    decisions: u64,
+) -> Result<SenderRate, AxoneGovError> {
+    let rate = match SENDER_RATES.may_load(storage, sender)? {
+        Some(rate)
+            if now
+                < rate
+                    .window_start_seconds
+                    .saturating_add(limit.window_seconds) =>
+        {
+            rate
+        }
+        _ => SenderRate {
+            window_start_seconds: now,
+            decisions: 0,
+        },
+    };
+
+    let decisions = rate.decisions.saturating_add(decisions);
+    if decisions > limit.max_decisions {
+        return Err(AxoneGovError::RateLimited {
+            sender: sender.to_string(),
+            max_decisions: limit.max_decisions,
+            window_seconds: limit.window_seconds,
+        });
+    }
+
+    Ok(SenderRate { decisions, ..rate })
+}
+
+/// Load the version of the stored state layout, ensuring this code is able to migrate it.
+pub fn load_state_version(storage: &dyn Storage) -> Result<u64, AxoneGovError> {
+    let stored = STATE_VERSION
+        .may_load(storage)?
+        .unwrap_or(UNVERSIONED_STATE_VERSION);
+    if stored > CURRENT_STATE_VERSION {
+        return Err(AxoneGovError::UnsupportedStateVersion {
+            stored,
+            current: CURRENT_STATE_VERSION,
+        });
+    }
+
+    Ok(stored)
+}
+
+/// Run the migration steps upgrading the stored state layout to [`CURRENT_STATE_VERSION`], in
+/// order. Returns the version the state was migrated from.
+pub fn migrate_state(
+    storage: &mut dyn Storage,
+    querier: &QuerierWrapper<'_, AxoneLogicQuery>,
+) -> Result<u64, AxoneGovError> {
+    let from = load_state_version(storage)?;
+    for (version, step) in MIGRATION_STEPS
+        .iter()
+        .filter(|(version, _)| *version > from)
+    {
+        step(storage, querier)?;
+        STATE_VERSION.save(storage, version)?;
+    }
+    STATE_VERSION.save(storage, &CURRENT_STATE_VERSION)?;
+
+    Ok(from)
+}
+
+/// Backfill the intents, case schemas and decision limits kept along with the constitution, which
+/// unversioned deployments lack, by parsing them again from the stored constitution and its pinned
+/// libraries. The migration fails if the stored constitution cannot be parsed again.
+fn backfill_constitution_metadata(
+    storage: &mut dyn Storage,
+    querier: &QuerierWrapper<'_, AxoneLogicQuery>,
+) -> Result<(), AxoneGovError> {
+    let status = load_constitution_status(storage)?;
+    let constitution = Constitution::try_new(
+        CONSTITUTION.load(storage)?,
+        |name, hash| {
+            let pinned = status
+                .libraries()
+                .iter()
+                .find(|pin| pin.name() == name)
+                .map(LibraryPin::hash);
+            load_library(storage, name, pinned.or(hash))
+        },
+        querier,
+    )?;
+
+    INTENTS.save(storage, constitution.intents())?;
+    CASE_SCHEMAS.save(storage, constitution.schemas())?;
+    DECISION_LIMITS.save(storage, constitution.limits())?;
+
+    Ok(())
+}
+
+/// Backfill the configuration, introduced along with the `gov:configure` intent.
+fn backfill_config(
+    storage: &mut dyn Storage,
+    _querier: &QuerierWrapper<'_, AxoneLogicQuery>,
+) -> Result<(), AxoneGovError> {
+    if CONFIG.may_load(storage)?.is_none() {
+        CONFIG.save(storage, &Config::default())?;
+    }
+
+    Ok(())
+}
+
+/// Start indexing the decisions recorded before their verdict kind was indexed, and the
+/// capabilities granted before their holder was.
+///
+/// To keep the migration bounded, only the first [`INDEX_BACKFILL_BATCH`] entries are indexed
+/// here, the remaining ones being indexed by the following executed messages (see
+/// [`advance_index_backfill`]).
+fn backfill_indexes(
+    storage: &mut dyn Storage,
+    _querier: &QuerierWrapper<'_, AxoneLogicQuery>,
+) -> Result<(), AxoneGovError> {
+    INDEX_BACKFILL.save(storage, &IndexBackfill::default())?;
+    advance_index_backfill(storage, INDEX_BACKFILL_BATCH)
+}
+
+/// The first decision and capability identifiers the index backfill has yet to index.
+#[cw_serde]
+#[derive(Default)]
+struct IndexBackfill {
+    next_decision_id: u64,
+    next_capability_id: u64,
+}
+
+/// Index at most `limit` of the decisions and capabilities left to index by the index backfill, if
+/// it is pending, ending it once every entry is indexed.
+///
+/// Until it ends, the decisions filtered by verdict kind and the capabilities filtered by holder may
+/// miss entries recorded before the migration to state version 4.
+pub fn advance_index_backfill(
+    storage: &mut dyn Storage,
+    limit: usize,
+) -> Result<(), AxoneGovError> {
+    let Some(mut backfill) = INDEX_BACKFILL.may_load(storage)? else {
+        return Ok(());
+    };
+
+    let decision_ids = DECISIONS
+        .keys(
+            storage,
+            Some(Bound::inclusive(backfill.next_decision_id)),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<Result<Vec<_>, _>>()?;
+    for id in &decision_ids {
+        let record = DECISIONS.load(storage, *id)?;
+        DECISIONS.replace(storage, *id, Some(&record), None)?;
+        backfill.next_decision_id = id.saturating_add(1);
+    }
+
+    let capability_ids = CAPABILITIES
+        .keys(
+            storage,
+            Some(Bound::inclusive(backfill.next_capability_id)),
+            None,
+            Order::Ascending,
+        )
+        .take(limit - decision_ids.len())
+        .collect::<Result<Vec<_>, _>>()?;
+    for id in &capability_ids {
+        let capability = CAPABILITIES.load(storage, *id)?;
+        CAPABILITIES.replace(storage, *id, Some(&capability), None)?;
+        backfill.next_capability_id = id.saturating_add(1);
+    }
+
+    if decision_ids.len() + capability_ids.len() < limit {
+        INDEX_BACKFILL.remove(storage);
+    } else {
+        INDEX_BACKFILL.save(storage, &backfill)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::MockStorage;
+
+    fn save_unindexed_decision(storage: &mut dyn Storage, id: u64) {
+        let record = DecisionRecord {
+            id,
+            constitution_revision: 0,
+            constitution_hash: [0; 32],
+            libraries: vec![],
+            case: "ctx{}".to_string(),
+            case_hash: [0; 32],
+            verdict: "'gov:permitted'".to_string(),
+            verdict_hash: [0; 32],
+            motivation: None,
+            motivation_hash: None,
+            author: Addr::unchecked("author"),
+            block_height: 1,
+            block_time_seconds: 1,
+            appeal_of: None,
+            effective_period: None,
+            revoked_by: None,
+        };
+        Map::<u64, DecisionRecord>::new("decisions")
+            .save(storage, id, &record)
+            .expect("decision should be saved");
+    }
+
+    fn indexed_permitted_decisions(storage: &dyn Storage) -> Vec<u64> {
+        DECISIONS
+            .idx
+            .verdict_kind
+            .prefix(VerdictClass::Permitted.to_string())
+            .keys(storage, None, None, Order::Ascending)
+            .collect::<Result<_, _>>()
+            .expect("index should be readable")
+    }
+
+    #[test]
+    fn index_backfill_indexes_by_batches_until_done() {
+        let mut storage = MockStorage::new();
+        for id in 1..=3 {
+            save_unindexed_decision(&mut storage, id);
+        }
+        INDEX_BACKFILL
+            .save(&mut storage, &IndexBackfill::default())
+            .expect("backfill should be saved");
+
+        advance_index_backfill(&mut storage, 2).expect("backfill should advance");
+        assert_eq!(indexed_permitted_decisions(&storage), vec![1, 2]);
+        assert!(INDEX_BACKFILL.exists(&storage));
+
+        advance_index_backfill(&mut storage, 2).expect("backfill should advance");
+        assert_eq!(indexed_permitted_decisions(&storage), vec![1, 2, 3]);
+        assert!(!INDEX_BACKFILL.exists(&storage));
+
+        save_unindexed_decision(&mut storage, 4);
+        advance_index_backfill(&mut storage, 2).expect("ended backfill should be a no-op");
+        assert_eq!(indexed_permitted_decisions(&storage), vec![1, 2, 3]);
+    }
+}
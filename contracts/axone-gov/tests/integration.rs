@@ -6,10 +6,21 @@ use axone_gov::{
         set_query_service_ask_handler, Answer, QueryServiceAskMockGuard, QueryServiceAskResponse,
         Result as LogicResult, Substitution,
     },
-    msg::{AxoneGovExecuteMsgFns, AxoneGovInstantiateMsg, AxoneGovQueryMsgFns, DecisionResponse},
+    msg::{
+        AxoneGovExecuteMsg, AxoneGovExecuteMsgFns, AxoneGovInstantiateMsg, AxoneGovMigrateMsg,
+        AxoneGovQueryMsgFns, CaseEnrichmentResponse, CaseSchema, CaseSignature, Config,
+        ConsumeCapabilityResponse, DecisionFilter, DecisionHook, DecisionLimits, DecisionResponse,
+        EffectivePeriod, EnrichmentProvider, EnrichmentQueryMsg, FieldSchema, IntentDeclaration,
+        MigrateMsg, RateLimit, RevisionActivation, SignatureAlgorithm, ValueKind, VerdictClass,
+        VerdictKind,
+    },
     AxoneGovInterface, AXONE_GOV_ID, AXONE_NAMESPACE,
 };
-use cosmwasm_std::{to_hex, Binary, Checksum};
+use cosmwasm_std::{
+    coin, from_json, to_hex, to_json_binary, to_json_vec, Binary, Checksum, Deps, DepsMut, Empty,
+    Env, MessageInfo, Order, Response, StdError, StdResult,
+};
+use cw_orch::mock::cw_multi_test::{AppResponse, Contract, ContractWrapper, MockApiBech32};
 use cw_orch::{anyhow, prelude::*};
 use std::{cell::RefCell, collections::VecDeque, rc::Rc};
 
@@ -32,6 +43,17 @@ fn record_decision_case(case_body: &str) -> String {
     format!("case{{{case_body}, {}}}", record_decision_context())
 }
 
+fn record_decision_query(case_term: &str, motivated: bool) -> String {
+    let decide = if motivated {
+        format!("decide({case_term}, Verdict, Motivation)")
+    } else {
+        format!("decide({case_term}, Verdict)")
+    };
+    format!(
+        "{decide}, (current_predicate(decision_fee/2), decision_fee({case_term}, Fee) -> true ; Fee = [])."
+    )
+}
+
 fn revise_constitution_case(
     proposed_constitution: &Binary,
     current_constitution: &Binary,
@@ -67,9 +89,11 @@ impl Drop for LogicAskExpectations {
     }
 }
 
+type QueryAssertion = (usize, Box<dyn Fn(&str)>);
+
 struct LogicAskScenario {
     expected: Rc<RefCell<VecDeque<(String, QueryServiceAskResponse)>>>,
-    assertions: Rc<RefCell<Vec<(usize, Box<dyn Fn(&str)>)>>>,
+    assertions: Rc<RefCell<Vec<QueryAssertion>>>,
 }
 
 impl LogicAskScenario {
@@ -136,6 +160,21 @@ impl TestEnv<MockBech32> {
         constitution: Binary,
         hook: QueryServiceAskMockGuard,
         expectations: LogicAskExpectations,
+    ) -> anyhow::Result<Self> {
+        Self::setup_with(
+            AxoneGovInstantiateMsg {
+                constitution,
+                ..Default::default()
+            },
+            hook,
+            expectations,
+        )
+    }
+
+    fn setup_with(
+        msg: AxoneGovInstantiateMsg,
+        hook: QueryServiceAskMockGuard,
+        expectations: LogicAskExpectations,
     ) -> anyhow::Result<Self> {
         let chain = MockBech32::new("mock");
         let client = AbstractClient::builder(chain.clone()).build()?;
@@ -148,10 +187,7 @@ impl TestEnv<MockBech32> {
 
         let app = publisher
             .account()
-            .install_app::<AxoneGovInterface<MockBech32>>(
-                &AxoneGovInstantiateMsg { constitution },
-                &[],
-            )?;
+            .install_app::<AxoneGovInterface<MockBech32>>(&msg, &[])?;
 
         Ok(Self {
             _hook: hook,
@@ -449,6 +485,7 @@ fn instantiate_succeeds_without_registered_gov() {
         },
         module: AxoneGovInstantiateMsg {
             constitution: constitution.clone(),
+            ..Default::default()
         },
     };
 
@@ -491,6 +528,7 @@ fn instantiate_fails_when_gov_already_registered() {
         .install_app::<AxoneGovInterface<MockBech32>>(
             &AxoneGovInstantiateMsg {
                 constitution: constitution.clone(),
+                ..Default::default()
             },
             &[],
         )
@@ -504,7 +542,10 @@ fn instantiate_fails_when_gov_already_registered() {
         base: app::BaseInstantiateMsg {
             account: registry::Account::new(account_addr),
         },
-        module: AxoneGovInstantiateMsg { constitution },
+        module: AxoneGovInstantiateMsg {
+            constitution,
+            ..Default::default()
+        },
     };
 
     let err = contract
@@ -767,7 +808,7 @@ decide(case{action:withdraw}, denied)."
     let case = "case{action:transfer}";
     let case_term = record_decision_case("action: transfer");
     let verdict = "allowed";
-    let expected_query = format!("decide({case_term}, Verdict).");
+    let expected_query = record_decision_query(&case_term, false);
 
     let (hook, expectations) = LogicAskScenario::new()
         .then(program, ask_ok())
@@ -782,7 +823,7 @@ decide(case{action:withdraw}, denied)."
 
     let response = env
         .app
-        .record_decision(case.to_string(), None)
+        .record_decision(case.to_string(), None, None)
         .expect("Failed to execute decide");
 
     assert_eq!(
@@ -852,8 +893,8 @@ decide(case{action:withdraw}, denied)."
     let case_two = "case{action:withdraw}";
     let case_one_term = record_decision_case("action: transfer");
     let case_two_term = record_decision_case("action: withdraw");
-    let expected_query_one = format!("decide({case_one_term}, Verdict, Motivation).");
-    let expected_query_two = format!("decide({case_two_term}, Verdict).");
+    let expected_query_one = record_decision_query(&case_one_term, true);
+    let expected_query_two = record_decision_query(&case_two_term, false);
 
     let (hook, expectations) = LogicAskScenario::new()
         .then(program, ask_ok())
@@ -872,11 +913,11 @@ decide(case{action:withdraw}, denied)."
 
     let response_one = env
         .app
-        .record_decision(case_one.to_string(), Some(true))
+        .record_decision(case_one.to_string(), Some(true), None)
         .expect("Failed to execute decide with motivation");
     let response_two = env
         .app
-        .record_decision(case_two.to_string(), None)
+        .record_decision(case_two.to_string(), None, None)
         .expect("Failed to execute decide without motivation");
 
     assert_eq!(
@@ -907,7 +948,7 @@ fn execute_decide_fails_with_missing_motivation() {
 
     let err = env
         .app
-        .record_decision("case{action:test}".to_string(), Some(true))
+        .record_decision("case{action:test}".to_string(), Some(true), None)
         .expect_err("Expected missing motivation error");
 
     let msg = format!("{err:?}");
@@ -934,7 +975,7 @@ fn query_decision_returns_recorded_decision_without_motivation() {
         TestEnv::setup(constitution.clone(), hook, expectations).expect("Failed to setup test");
 
     env.app
-        .record_decision(case_input.to_string(), None)
+        .record_decision(case_input.to_string(), None, None)
         .expect("Failed to record decision");
 
     let response = AxoneGovQueryMsgFns::decision(&env.app, 1).expect("Failed to query decision");
@@ -960,7 +1001,7 @@ fn query_decision_returns_recorded_decision_with_motivation() {
         TestEnv::setup(constitution.clone(), hook, expectations).expect("Failed to setup test");
 
     env.app
-        .record_decision(case_input.to_string(), Some(true))
+        .record_decision(case_input.to_string(), Some(true), None)
         .expect("Failed to record decision");
 
     let response = AxoneGovQueryMsgFns::decision(&env.app, 1).expect("Failed to query decision");
@@ -1008,8 +1049,8 @@ fn query_decisions_returns_empty_when_no_records() {
     let env =
         TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
 
-    let response =
-        AxoneGovQueryMsgFns::decisions(&env.app, None, None).expect("Failed to query decisions");
+    let response = AxoneGovQueryMsgFns::decisions(&env.app, None, None, None)
+        .expect("Failed to query decisions");
 
     assert!(response.decisions.is_empty());
 }
@@ -1034,17 +1075,17 @@ decide(case{action:mint}, allowed)."
         TestEnv::setup(constitution.clone(), hook, expectations).expect("Failed to setup test");
 
     env.app
-        .record_decision("case{action:transfer}".to_string(), None)
+        .record_decision("case{action:transfer}".to_string(), None, None)
         .expect("Failed to record decision 1");
     env.app
-        .record_decision("case{action:withdraw}".to_string(), Some(true))
+        .record_decision("case{action:withdraw}".to_string(), Some(true), None)
         .expect("Failed to record decision 2");
     env.app
-        .record_decision("case{action:mint}".to_string(), None)
+        .record_decision("case{action:mint}".to_string(), None, None)
         .expect("Failed to record decision 3");
 
-    let response =
-        AxoneGovQueryMsgFns::decisions(&env.app, None, None).expect("Failed to query decisions");
+    let response = AxoneGovQueryMsgFns::decisions(&env.app, None, None, None)
+        .expect("Failed to query decisions");
     assert_eq!(response.decisions.len(), 3);
 
     assert_decision_response(
@@ -1075,7 +1116,7 @@ decide(case{action:mint}, allowed)."
         None,
     );
 
-    let page = AxoneGovQueryMsgFns::decisions(&env.app, Some(1), Some(1))
+    let page = AxoneGovQueryMsgFns::decisions(&env.app, Some(1), Some(1), None)
         .expect("Failed to query decisions page");
     assert_eq!(page.decisions.len(), 1);
     assert_eq!(page.decisions[0].decision_id, 2);
@@ -1310,7 +1351,7 @@ fn revise_constitution_fails_when_proposed_constitution_refuses_establish() {
         .expect("Failed to query constitution");
     assert_eq!(constitution_got.constitution, constitution);
 
-    let decisions = AxoneGovQueryMsgFns::decisions(&env.app, None, None)
+    let decisions = AxoneGovQueryMsgFns::decisions(&env.app, None, None, None)
         .expect("Failed to query recorded decisions");
     assert!(decisions.decisions.is_empty());
 }
@@ -1697,3 +1738,3126 @@ fn revise_constitution_increments_revision_number() {
         Some("'Second revision allowed'"),
     );
 }
+
+#[test]
+fn revise_constitution_schedules_revision_with_permitted_after_verdict() {
+    let constitution = Binary::from(b"decide(_, 'gov:permitted_after'(12400), delayed).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let new_constitution = Binary::from(b"decide(_, allowed).".to_vec());
+    let new_constitution_program = std::str::from_utf8(new_constitution.as_slice()).unwrap();
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(new_constitution_program, ask_ok())
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted_after'(12400)", "delayed"),
+        )
+        .then(new_constitution_program, ask_establish_permitted())
+        .install();
+    let env = TestEnv::setup(constitution.clone(), hook, expectations)
+        .expect("Failed to setup test environment");
+
+    let response = env
+        .app
+        .revise_constitution(new_constitution.clone(), None)
+        .expect("Failed to schedule revision");
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "action")
+            .expect("Missing action attribute"),
+        "schedule_revision"
+    );
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "activation_height")
+            .expect("Missing activation_height attribute"),
+        "12400"
+    );
+
+    let constitution_got = env
+        .app
+        .constitution()
+        .expect("Failed to query constitution");
+    assert_eq!(constitution_got.constitution, constitution);
+
+    let pending = env
+        .app
+        .pending_revision()
+        .expect("Failed to query pending revision")
+        .expect("Expected a pending revision");
+    assert_eq!(pending.constitution, new_constitution);
+    assert_hash_matches(new_constitution.as_slice(), &pending.constitution_hash);
+    assert_eq!(pending.base_revision, 0);
+    assert_eq!(pending.activation, RevisionActivation::Height(12400));
+    assert_eq!(pending.decision_id, 1);
+    assert_eq!(pending.proposer, MOCK_SENDER);
+
+    let err = env
+        .app
+        .activate_revision()
+        .expect_err("Expected early activation to fail");
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("pending revision cannot be activated before height 12400"),
+        "expected not activatable error, got: {msg}"
+    );
+
+    env.app
+        .environment()
+        .wait_blocks(55)
+        .expect("Failed to advance blocks");
+    env.app
+        .activate_revision()
+        .expect("Failed to activate revision");
+
+    let constitution_got = env
+        .app
+        .constitution()
+        .expect("Failed to query constitution");
+    assert_eq!(constitution_got.constitution, new_constitution);
+    let status = env
+        .app
+        .constitution_status()
+        .expect("Failed to query constitution status");
+    assert_eq!(status.constitution_revision, 1);
+    assert!(env
+        .app
+        .pending_revision()
+        .expect("Failed to query pending revision")
+        .is_none());
+}
+
+#[test]
+fn revise_constitution_schedules_revision_with_time_activation() {
+    let constitution = Binary::from(b"decide(_, 'gov:permitted', ok).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let new_constitution = Binary::from(b"decide(_, allowed).".to_vec());
+    let new_constitution_program = std::str::from_utf8(new_constitution.as_slice()).unwrap();
+    let activation_time = MOCK_BLOCK_TIME + 3600;
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(new_constitution_program, ask_ok())
+        .then(
+            program,
+            ask_decision_with_motivation(
+                format!("'gov:permitted_after'(time_seconds({activation_time}))"),
+                "ok",
+            ),
+        )
+        .then(new_constitution_program, ask_establish_permitted())
+        .install();
+    let env = TestEnv::setup(constitution.clone(), hook, expectations)
+        .expect("Failed to setup test environment");
+
+    env.app
+        .revise_constitution(new_constitution.clone(), None)
+        .expect("Failed to schedule revision");
+
+    let pending = env
+        .app
+        .pending_revision()
+        .expect("Failed to query pending revision")
+        .expect("Expected a pending revision");
+    assert_eq!(
+        pending.activation,
+        RevisionActivation::TimeSeconds(activation_time)
+    );
+
+    env.app
+        .environment()
+        .wait_seconds(3599)
+        .expect("Failed to advance time");
+    env.app
+        .activate_revision()
+        .expect_err("Expected early activation to fail");
+
+    env.app
+        .environment()
+        .wait_seconds(1)
+        .expect("Failed to advance time");
+    env.app
+        .activate_revision()
+        .expect("Failed to activate revision");
+
+    let constitution_got = env
+        .app
+        .constitution()
+        .expect("Failed to query constitution");
+    assert_eq!(constitution_got.constitution, new_constitution);
+}
+
+#[test]
+fn revise_constitution_fails_while_revision_is_pending() {
+    let constitution = Binary::from(b"decide(_, 'gov:permitted_after'(12400), ok).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let new_constitution = Binary::from(b"decide(_, allowed).".to_vec());
+    let new_constitution_program = std::str::from_utf8(new_constitution.as_slice()).unwrap();
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(new_constitution_program, ask_ok())
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted_after'(12400)", "ok"),
+        )
+        .then(new_constitution_program, ask_establish_permitted())
+        .install();
+    let env = TestEnv::setup(constitution.clone(), hook, expectations)
+        .expect("Failed to setup test environment");
+
+    env.app
+        .revise_constitution(new_constitution.clone(), None)
+        .expect("Failed to schedule revision");
+
+    let err = env
+        .app
+        .revise_constitution(Binary::from(b"decide(_, denied).".to_vec()), None)
+        .expect_err("Expected pending revision error");
+    let msg = format!("{err:?}");
+    let pending_hash = to_hex(Checksum::generate(new_constitution.as_slice()).as_ref());
+    assert!(
+        msg.contains(&format!(
+            "a constitutional revision is already pending: {pending_hash}"
+        )),
+        "expected pending revision error, got: {msg}"
+    );
+}
+
+#[test]
+fn activate_revision_fails_without_pending_revision() {
+    let constitution = Binary::from(b"decide(_, 'gov:permitted', ok).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    assert!(env
+        .app
+        .pending_revision()
+        .expect("Failed to query pending revision")
+        .is_none());
+
+    let err = env
+        .app
+        .activate_revision()
+        .expect_err("Expected missing pending revision error");
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("no pending constitutional revision"),
+        "expected no pending revision error, got: {msg}"
+    );
+}
+
+#[test]
+fn cancel_revision_discards_pending_revision_when_permitted() {
+    let constitution = Binary::from(b"decide(_, 'gov:permitted_after'(12400), ok).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let new_constitution = Binary::from(b"decide(_, allowed).".to_vec());
+    let new_constitution_program = std::str::from_utf8(new_constitution.as_slice()).unwrap();
+    let proposed_hash = atom_literal(&to_hex(
+        Checksum::generate(new_constitution.as_slice()).as_ref(),
+    ));
+    let current_hash = atom_literal(&to_hex(
+        Checksum::generate(constitution.as_slice()).as_ref(),
+    ));
+    let expected_cancel_case = format!(
+        "ctx{{reason: exploit, intent: 'gov:cancel_revision', 'gov:proposed_constitution_sha256': {proposed_hash}, \
+'gov:current_constitution_sha256': {current_hash}, 'gov:current_constitution_revision': 0, {}}}",
+        record_decision_context()
+    );
+    let expected_cancel_query = format!("decide({expected_cancel_case}, Verdict, Motivation).");
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(new_constitution_program, ask_ok())
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted_after'(12400)", "ok"),
+        )
+        .then(new_constitution_program, ask_establish_permitted())
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted'", "'Exploit found'"),
+        )
+        .assert_query(5, move |query| {
+            assert_eq!(query, expected_cancel_query, "unexpected cancel query");
+        })
+        .install();
+    let env = TestEnv::setup(constitution.clone(), hook, expectations)
+        .expect("Failed to setup test environment");
+
+    env.app
+        .revise_constitution(new_constitution.clone(), None)
+        .expect("Failed to schedule revision");
+
+    let response = env
+        .app
+        .cancel_revision(Some("ctx{reason: exploit}".to_string()))
+        .expect("Failed to cancel revision");
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "decision_id")
+            .expect("Missing decision_id attribute"),
+        "2"
+    );
+
+    assert!(env
+        .app
+        .pending_revision()
+        .expect("Failed to query pending revision")
+        .is_none());
+
+    let decision =
+        AxoneGovQueryMsgFns::decision(&env.app, 2).expect("Failed to query cancel decision");
+    assert_decision_response(
+        &decision,
+        2,
+        0,
+        &constitution,
+        &expected_cancel_case,
+        "'gov:permitted'",
+        Some("'Exploit found'"),
+    );
+
+    let err = env
+        .app
+        .activate_revision()
+        .expect_err("Expected missing pending revision error");
+    assert!(format!("{err:?}").contains("no pending constitutional revision"));
+}
+
+#[test]
+fn cancel_revision_fails_with_denied_verdict() {
+    let constitution = Binary::from(b"decide(_, 'gov:permitted_after'(12400), ok).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let new_constitution = Binary::from(b"decide(_, allowed).".to_vec());
+    let new_constitution_program = std::str::from_utf8(new_constitution.as_slice()).unwrap();
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(new_constitution_program, ask_ok())
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted_after'(12400)", "ok"),
+        )
+        .then(new_constitution_program, ask_establish_permitted())
+        .then(program, ask_decision_with_motivation("denied", "'No'"))
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    env.app
+        .revise_constitution(new_constitution, None)
+        .expect("Failed to schedule revision");
+
+    let err = env
+        .app
+        .cancel_revision(None)
+        .expect_err("Expected decision refused error");
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("decision refused: intent=gov:cancel_revision"),
+        "expected cancel refusal, got: {msg}"
+    );
+    assert!(env
+        .app
+        .pending_revision()
+        .expect("Failed to query pending revision")
+        .is_some());
+}
+
+fn store_library_case(name: &str, library: &Binary, current_constitution: &Binary) -> String {
+    let library_hash = atom_literal(&to_hex(Checksum::generate(library.as_slice()).as_ref()));
+    let current_hash = atom_literal(&to_hex(
+        Checksum::generate(current_constitution.as_slice()).as_ref(),
+    ));
+
+    format!(
+        "ctx{{intent: 'gov:store_library', 'gov:library_name': {name}, 'gov:library_sha256': {library_hash}, \
+'gov:current_constitution_sha256': {current_hash}, 'gov:current_constitution_revision': 0, {}}}",
+        record_decision_context()
+    )
+}
+
+#[test]
+fn store_library_succeeds_with_permitted_verdict() {
+    let constitution = Binary::from(b"decide(_, 'gov:permitted', ok).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let library = Binary::from(b"majority(Yes, No) :- Yes > No.".to_vec());
+    let library_program = std::str::from_utf8(library.as_slice()).unwrap();
+    let expected_case = store_library_case("voting", &library, &constitution);
+    let expected_query = format!("decide({expected_case}, Verdict, Motivation).");
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(library_program, ask_ok())
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted'", "ok"),
+        )
+        .assert_query(3, move |query| {
+            assert_eq!(query, expected_query, "unexpected store library query");
+        })
+        .install();
+    let env = TestEnv::setup(constitution.clone(), hook, expectations)
+        .expect("Failed to setup test environment");
+
+    let response = env
+        .app
+        .store_library(library.clone(), "voting".to_string(), None)
+        .expect("Failed to store library");
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "action")
+            .expect("Missing action attribute"),
+        "store_library"
+    );
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "library_hash")
+            .expect("Missing library_hash attribute"),
+        to_hex(Checksum::generate(library.as_slice()).as_ref())
+    );
+
+    let stored = env
+        .app
+        .library("voting".to_string(), None)
+        .expect("Failed to query library");
+    assert_eq!(stored.name, "voting");
+    assert_eq!(stored.library, library);
+    assert_hash_matches(library.as_slice(), &stored.library_hash);
+    assert_eq!(stored.decision_id, 1);
+    assert_eq!(stored.author, MOCK_SENDER);
+
+    let libraries = env
+        .app
+        .libraries(None, None)
+        .expect("Failed to query libraries");
+    assert_eq!(libraries.libraries, vec![stored]);
+
+    let decision = AxoneGovQueryMsgFns::decision(&env.app, 1).expect("Failed to query decision");
+    assert_decision_response(
+        &decision,
+        1,
+        0,
+        &constitution,
+        &expected_case,
+        "'gov:permitted'",
+        Some("ok"),
+    );
+    assert!(decision.libraries.is_empty());
+}
+
+#[test]
+fn store_library_fails_with_denied_verdict() {
+    let constitution = Binary::from(b"decide(_, denied, no).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let library = Binary::from(b"majority(Yes, No) :- Yes > No.".to_vec());
+    let library_program = std::str::from_utf8(library.as_slice()).unwrap();
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(library_program, ask_ok())
+        .then(program, ask_decision_with_motivation("denied", "no"))
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let err = env
+        .app
+        .store_library(library, "voting".to_string(), None)
+        .expect_err("Expected decision refused error");
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("decision refused: intent=gov:store_library"),
+        "expected store library refusal, got: {msg}"
+    );
+
+    let err = env
+        .app
+        .library("voting".to_string(), None)
+        .expect_err("Expected missing library");
+    assert!(format!("{err:?}").contains("library not found: voting"));
+}
+
+#[test]
+fn store_library_rejects_invalid_library() {
+    let constitution = Binary::from(b"decide(_, 'gov:permitted', ok).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let library = Binary::from(b"broken(".to_vec());
+    let library_program = std::str::from_utf8(library.as_slice()).unwrap();
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(library_program, ask_error("syntax error"))
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let cases = vec![
+        (
+            "Voting",
+            Binary::from(b"ok.".to_vec()),
+            "invalid library name",
+        ),
+        (
+            "voting",
+            Binary::from(b":- use_library(roles).\nok.".to_vec()),
+            "a library cannot use other libraries",
+        ),
+        ("voting", library, "program cannot be loaded: syntax error"),
+    ];
+
+    for (name, library, expected) in cases {
+        let err = env
+            .app
+            .store_library(library, name.to_string(), None)
+            .expect_err("Expected invalid library error");
+        let msg = format!("{err:?}");
+        assert!(
+            msg.contains(expected),
+            "expected '{expected}' error, got: {msg}"
+        );
+    }
+}
+
+#[test]
+fn revise_constitution_composes_and_pins_libraries() {
+    let constitution = Binary::from(b"decide(_, 'gov:permitted', ok).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let library_v1 = Binary::from(b"majority(Yes, No) :- Yes > No.".to_vec());
+    let library_v1_program = std::str::from_utf8(library_v1.as_slice()).unwrap();
+    let library_v2 = Binary::from(b"majority(Yes, No) :- Yes >= No.".to_vec());
+    let library_v2_program = std::str::from_utf8(library_v2.as_slice()).unwrap();
+    let new_constitution =
+        Binary::from(b":- use_library(voting).\ndecide(_, 'gov:permitted', ok).".to_vec());
+    let composed_program = format!("{library_v1_program}\n\ndecide(_, 'gov:permitted', ok).");
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(library_v1_program, ask_ok())
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted'", "ok"),
+        )
+        .then(composed_program.clone(), ask_ok())
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted'", "ok"),
+        )
+        .then(composed_program.clone(), ask_establish_permitted())
+        .then(library_v2_program, ask_ok())
+        .then(
+            composed_program.clone(),
+            ask_decision_with_motivation("'gov:permitted'", "ok"),
+        )
+        .then(
+            composed_program.clone(),
+            ask_decision_without_motivation("allowed"),
+        )
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    env.app
+        .store_library(library_v1.clone(), "voting".to_string(), None)
+        .expect("Failed to store library v1");
+    env.app
+        .revise_constitution(new_constitution.clone(), None)
+        .expect("Failed to revise constitution");
+
+    let status = env
+        .app
+        .constitution_status()
+        .expect("Failed to query constitution status");
+    assert_eq!(status.constitution_revision, 1);
+    assert_eq!(status.libraries.len(), 1);
+    assert_eq!(status.libraries[0].name, "voting");
+    assert_hash_matches(library_v1.as_slice(), &status.libraries[0].hash);
+
+    // Storing a new version does not change the rules of the current constitution.
+    env.app
+        .store_library(library_v2.clone(), "voting".to_string(), None)
+        .expect("Failed to store library v2");
+    env.app
+        .record_decision("case{action: vote}".to_string(), None, None)
+        .expect("Failed to record decision");
+
+    let decision = AxoneGovQueryMsgFns::decision(&env.app, 4).expect("Failed to query decision");
+    assert_eq!(decision.constitution_revision, 1);
+    assert_eq!(decision.libraries, status.libraries);
+
+    let latest = env
+        .app
+        .library("voting".to_string(), None)
+        .expect("Failed to query latest library");
+    assert_eq!(latest.library, library_v2);
+    let pinned = env
+        .app
+        .library("voting".to_string(), Some(status.libraries[0].hash.clone()))
+        .expect("Failed to query pinned library");
+    assert_eq!(pinned.library, library_v1);
+}
+
+#[test]
+fn revise_constitution_fails_with_unknown_library() {
+    let constitution = Binary::from(b"decide(_, 'gov:permitted', ok).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let unknown_hash = to_hex([7u8; 32]);
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let cases = vec![
+        (
+            ":- use_library(voting).\ndecide(_, allowed).".to_string(),
+            "library not found: voting".to_string(),
+        ),
+        (
+            format!(":- use_library(voting, '{unknown_hash}').\ndecide(_, allowed)."),
+            format!("library not found: voting@{unknown_hash}"),
+        ),
+        (
+            ":- use_library(voting)\ndecide(_, allowed).".to_string(),
+            "invalid library directive".to_string(),
+        ),
+    ];
+
+    for (new_constitution, expected) in cases {
+        let err = env
+            .app
+            .revise_constitution(Binary::from(new_constitution.into_bytes()), None)
+            .expect_err("Expected library resolution error");
+        let msg = format!("{err:?}");
+        assert!(
+            msg.contains(&expected),
+            "expected '{expected}' error, got: {msg}"
+        );
+    }
+}
+
+#[test]
+fn pause_blocks_governance_acts_until_resumed() {
+    let constitution = Binary::from(b"decide(_, 'gov:permitted', ok).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let current_hash = atom_literal(&to_hex(
+        Checksum::generate(constitution.as_slice()).as_ref(),
+    ));
+    let expected_pause_case = format!(
+        "ctx{{reason: exploit, intent: 'gov:pause', 'gov:current_constitution_sha256': {current_hash}, \
+'gov:current_constitution_revision': 0, {}}}",
+        record_decision_context()
+    );
+    let expected_pause_query = format!("decide({expected_pause_case}, Verdict, Motivation).");
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted'", "'Exploit found'"),
+        )
+        .assert_query(2, move |query| {
+            assert_eq!(query, expected_pause_query, "unexpected pause query");
+        })
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted'", "fixed"),
+        )
+        .then(program, ask_decision_without_motivation("allowed"))
+        .install();
+    let env = TestEnv::setup(constitution.clone(), hook, expectations)
+        .expect("Failed to setup test environment");
+
+    assert!(env
+        .app
+        .pause_status()
+        .expect("Failed to query pause status")
+        .is_none());
+
+    let response = env
+        .app
+        .pause(Some("ctx{reason: exploit}".to_string()))
+        .expect("Failed to pause");
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "action")
+            .expect("Missing action attribute"),
+        "pause"
+    );
+
+    let pause = env
+        .app
+        .pause_status()
+        .expect("Failed to query pause status")
+        .expect("Expected governance to be paused");
+    assert_eq!(pause.decision_id, 1);
+    assert!(pause.exempt_intents.is_empty());
+    assert_eq!(pause.paused_by, MOCK_SENDER);
+
+    let decision = AxoneGovQueryMsgFns::decision(&env.app, 1).expect("Failed to query decision");
+    assert_decision_response(
+        &decision,
+        1,
+        0,
+        &constitution,
+        &expected_pause_case,
+        "'gov:permitted'",
+        Some("'Exploit found'"),
+    );
+
+    let err = env
+        .app
+        .record_decision("case{intent: transfer}".to_string(), None, None)
+        .expect_err("Expected paused error");
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("governance is paused by decision 1: intent transfer is not exempt"),
+        "expected paused error, got: {msg}"
+    );
+
+    let err = env
+        .app
+        .revise_constitution(Binary::from(b"decide(_, allowed).".to_vec()), None)
+        .expect_err("Expected paused error");
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("intent gov:revise_constitution is not exempt"),
+        "expected paused error, got: {msg}"
+    );
+
+    let err = env
+        .app
+        .cancel_revision(None)
+        .expect_err("Expected paused error");
+    assert!(
+        format!("{err:?}").contains("intent gov:cancel_revision is not exempt"),
+        "expected paused error, got: {err:?}"
+    );
+
+    let err = env
+        .app
+        .store_library(
+            Binary::from(b"quorum(3).".to_vec()),
+            "voting".to_string(),
+            None,
+        )
+        .expect_err("Expected paused error");
+    assert!(
+        format!("{err:?}").contains("intent gov:store_library is not exempt"),
+        "expected paused error, got: {err:?}"
+    );
+
+    let err = env.app.pause(None).expect_err("Expected already paused");
+    assert!(format!("{err:?}").contains("governance is already paused by decision 1"));
+
+    let response = env.app.resume(None).expect("Failed to resume");
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "decision_id")
+            .expect("Missing decision_id attribute"),
+        "2"
+    );
+    assert!(env
+        .app
+        .pause_status()
+        .expect("Failed to query pause status")
+        .is_none());
+
+    env.app
+        .record_decision("case{intent: transfer}".to_string(), None, None)
+        .expect("Failed to record decision after resume");
+}
+
+#[test]
+fn pause_keeps_exempt_intents_available() {
+    let constitution = Binary::from(b"decide(_, 'gov:permitted', ok).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let new_constitution = Binary::from(b"decide(_, allowed).".to_vec());
+    let new_constitution_program = std::str::from_utf8(new_constitution.as_slice()).unwrap();
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(
+            program,
+            ask_decision_with_motivation(
+                "'gov:permitted_with_exemptions'(['gov:revise_constitution',withdraw])",
+                "ok",
+            ),
+        )
+        .then(program, ask_decision_without_motivation("allowed"))
+        .then(new_constitution_program, ask_ok())
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted'", "ok"),
+        )
+        .then(new_constitution_program, ask_establish_permitted())
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let response = env.app.pause(None).expect("Failed to pause");
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "exempt_intents")
+            .expect("Missing exempt_intents attribute"),
+        "gov:revise_constitution,withdraw"
+    );
+    let pause = env
+        .app
+        .pause_status()
+        .expect("Failed to query pause status")
+        .expect("Expected governance to be paused");
+    assert_eq!(
+        pause.exempt_intents,
+        vec![
+            "gov:revise_constitution".to_string(),
+            "withdraw".to_string()
+        ]
+    );
+
+    env.app
+        .record_decision("case{intent: withdraw}".to_string(), None, None)
+        .expect("Failed to record exempt decision");
+    let err = env
+        .app
+        .record_decision("case{action: transfer}".to_string(), None, None)
+        .expect_err("Expected paused error");
+    assert!(format!("{err:?}").contains("intent none is not exempt"));
+
+    env.app
+        .revise_constitution(new_constitution.clone(), None)
+        .expect("Failed to revise constitution while paused");
+    let constitution_got = env
+        .app
+        .constitution()
+        .expect("Failed to query constitution");
+    assert_eq!(constitution_got.constitution, new_constitution);
+}
+
+#[test]
+fn pause_and_resume_fail_when_not_authorized() {
+    let constitution = Binary::from(b"decide(_, denied, no).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(program, ask_decision_with_motivation("denied", "no"))
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let err = env.app.resume(None).expect_err("Expected not paused error");
+    assert!(format!("{err:?}").contains("governance is not paused"));
+
+    let err = env.app.pause(None).expect_err("Expected pause refusal");
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("decision refused: intent=gov:pause"),
+        "expected pause refusal, got: {msg}"
+    );
+    assert!(env
+        .app
+        .pause_status()
+        .expect("Failed to query pause status")
+        .is_none());
+}
+
+#[test]
+fn record_decisions_records_batch_with_shared_context() {
+    let constitution = Binary::from(b"decide(case{action:_}, allowed).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(program, ask_decision_with_motivation("allowed", "one"))
+        .then(program, ask_decision_with_motivation("denied", "two"))
+        .then(program, ask_decision_with_motivation("allowed", "three"))
+        .install();
+    let env = TestEnv::setup(constitution.clone(), hook, expectations)
+        .expect("Failed to setup test environment");
+
+    let cases = vec![
+        "case{action: transfer}".to_string(),
+        "case{action: withdraw}".to_string(),
+        "case{action: mint}".to_string(),
+    ];
+    let response = env
+        .app
+        .record_decisions(cases, None, Some(true))
+        .expect("Failed to record decisions");
+    for (attr, expected) in [
+        ("action", "record_decisions"),
+        ("case.0.decision_id", "1"),
+        ("case.1.decision_id", "2"),
+        ("case.1.verdict", "denied"),
+        ("case.2.decision_id", "3"),
+        ("recorded", "3"),
+        ("failed", "0"),
+    ] {
+        assert_eq!(
+            response
+                .event_attr_value(ABSTRACT_EVENT_TYPE, attr)
+                .unwrap_or_else(|_| panic!("Missing {attr} attribute")),
+            expected,
+            "unexpected {attr} attribute"
+        );
+    }
+
+    let response = AxoneGovQueryMsgFns::decisions(&env.app, None, None, None)
+        .expect("Failed to query decisions");
+    let expected = [
+        ("action: transfer", "allowed", "one"),
+        ("action: withdraw", "denied", "two"),
+        ("action: mint", "allowed", "three"),
+    ];
+    assert_eq!(response.decisions.len(), expected.len());
+    for (index, (case_body, verdict, motivation)) in expected.into_iter().enumerate() {
+        assert_decision_response(
+            &response.decisions[index],
+            index as u64 + 1,
+            0,
+            &constitution,
+            &record_decision_case(case_body),
+            verdict,
+            Some(motivation),
+        );
+    }
+}
+
+#[test]
+fn record_decisions_atomic_batch_is_all_or_nothing() {
+    let constitution = Binary::from(b"decide(case{action:_}, allowed).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(program, ask_decision_without_motivation("allowed"))
+        .then(program, ask_error("boom"))
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let err = env
+        .app
+        .record_decisions(
+            vec![
+                "case{action: transfer}".to_string(),
+                "case{action: withdraw}".to_string(),
+                "case{action: mint}".to_string(),
+            ],
+            None,
+            None,
+        )
+        .expect_err("Expected atomic batch failure");
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("batch case 1 failed: decision failed: boom"),
+        "expected batch failure, got: {msg}"
+    );
+
+    let response = AxoneGovQueryMsgFns::decisions(&env.app, None, None, None)
+        .expect("Failed to query decisions");
+    assert!(response.decisions.is_empty());
+}
+
+#[test]
+fn record_decisions_non_atomic_batch_reports_failures() {
+    let constitution = Binary::from(b"decide(case{action:_}, allowed).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(program, ask_decision_without_motivation("allowed"))
+        .then(program, ask_decision_without_motivation("allowed"))
+        .install();
+    let env = TestEnv::setup(constitution.clone(), hook, expectations)
+        .expect("Failed to setup test environment");
+
+    let response = env
+        .app
+        .record_decisions(
+            vec![
+                "case{action: transfer}".to_string(),
+                "case{action: X}".to_string(),
+                "case{action: mint}".to_string(),
+            ],
+            Some(false),
+            None,
+        )
+        .expect("Failed to record decisions");
+    for (attr, expected) in [
+        ("case.0.decision_id", "1"),
+        (
+            "case.1.error",
+            "invalid case: case must be ground (no variables)",
+        ),
+        ("case.2.decision_id", "2"),
+        ("recorded", "2"),
+        ("failed", "1"),
+    ] {
+        assert_eq!(
+            response
+                .event_attr_value(ABSTRACT_EVENT_TYPE, attr)
+                .unwrap_or_else(|_| panic!("Missing {attr} attribute")),
+            expected,
+            "unexpected {attr} attribute"
+        );
+    }
+
+    let decision = AxoneGovQueryMsgFns::decision(&env.app, 2).expect("Failed to query decision");
+    assert_decision_response(
+        &decision,
+        2,
+        0,
+        &constitution,
+        &record_decision_case("action: mint"),
+        "allowed",
+        None,
+    );
+}
+
+#[test]
+fn record_decisions_rejects_invalid_batch_size() {
+    let constitution = Binary::from(b"decide(_, allowed).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    for size in [0, 51] {
+        let err = env
+            .app
+            .record_decisions(vec!["case{action: transfer}".to_string(); size], None, None)
+            .expect_err("Expected invalid batch error");
+        let msg = format!("{err:?}");
+        assert!(
+            msg.contains(&format!(
+                "invalid batch: expected between 1 and 50 cases, got {size}"
+            )),
+            "expected invalid batch error, got: {msg}"
+        );
+    }
+}
+
+fn sign_case(
+    env: &TestEnv<MockBech32>,
+    key: &k256::ecdsa::SigningKey,
+    case: &str,
+    nonce: u64,
+) -> CaseSignature {
+    use k256::ecdsa::{signature::Signer, Signature};
+
+    let chain_id = env
+        .app
+        .environment()
+        .block_info()
+        .expect("Failed to query block info")
+        .chain_id;
+    let mut signature = CaseSignature {
+        algorithm: SignatureAlgorithm::Secp256k1,
+        public_key: Binary::from(key.verifying_key().to_sec1_bytes().to_vec()),
+        signature: Binary::default(),
+        nonce,
+    };
+    let sig: Signature =
+        key.sign(&signature.sign_bytes(&chain_id, env.app.address().unwrap().as_str(), case));
+    signature.signature = Binary::from(sig.to_bytes().to_vec());
+    signature
+}
+
+#[test]
+fn record_decision_accepts_relayed_signed_case() {
+    let constitution = Binary::from(b"decide(_, allowed).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let case = "case{intent: transfer}";
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(program, ask_decision_without_motivation("allowed"))
+        .assert_query(2, |query| {
+            assert!(
+                query.contains("'gov:signer': signer{address: mock1"),
+                "expected signer in case, got: {query}"
+            );
+            assert!(
+                query.contains("algorithm: secp256k1, nonce: 1}"),
+                "expected signer algorithm and nonce in case, got: {query}"
+            );
+        })
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let key = k256::ecdsa::SigningKey::from_bytes(&[7u8; 32].into()).expect("valid key");
+    let signature = sign_case(&env, &key, case, 1);
+
+    let response = env
+        .app
+        .record_decision(case.to_string(), None, Some(signature.clone()))
+        .expect("Failed to record signed decision");
+    let signer = response
+        .event_attr_value(ABSTRACT_EVENT_TYPE, "signer")
+        .expect("Missing signer attribute");
+    assert!(signer.starts_with("mock1"), "unexpected signer: {signer}");
+    assert_ne!(signer, MOCK_SENDER);
+
+    let decision = AxoneGovQueryMsgFns::decision(&env.app, 1).expect("Failed to query decision");
+    assert_eq!(decision.author, signer);
+
+    let nonce = env
+        .app
+        .signer_nonce(signer.clone())
+        .expect("Failed to query signer nonce");
+    assert_eq!(nonce.signer, signer);
+    assert_eq!(nonce.nonce, 1);
+
+    let err = env
+        .app
+        .record_decision(case.to_string(), None, Some(signature))
+        .expect_err("Expected replayed nonce to be rejected");
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains(&format!("nonce 1 already used by signer {signer}")),
+        "expected nonce error, got: {msg}"
+    );
+
+    let err = env
+        .app
+        .record_decision(
+            "case{intent: withdraw}".to_string(),
+            None,
+            Some(sign_case(&env, &key, case, 2)),
+        )
+        .expect_err("Expected tampered case to be rejected");
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("invalid case signature: secp256k1 signature verification failed"),
+        "expected signature error, got: {msg}"
+    );
+}
+
+#[test]
+fn record_decision_rejects_unsigned_signer_claim() {
+    let constitution = Binary::from(b"decide(_, allowed).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let err = env
+        .app
+        .record_decision(
+            format!("case{{intent: transfer, 'gov:signer': signer{{address: {MOCK_SENDER}}}}}"),
+            None,
+            None,
+        )
+        .expect_err("Expected reserved key to be rejected");
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("the 'gov:signer' key is reserved to signed cases"),
+        "expected reserved key error, got: {msg}"
+    );
+}
+
+#[test]
+fn record_decision_rejects_reserved_keys_in_signed_cases() {
+    let constitution = Binary::from(b"decide(_, allowed).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let case = "case{intent: transfer, 'gov:module': module{id: fake}}";
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let err = env
+        .app
+        .record_decision(
+            "case{intent: transfer, 'cw:tx': forged}".to_string(),
+            None,
+            None,
+        )
+        .expect_err("Expected reserved key to be rejected");
+    assert!(
+        format!("{err:?}").contains("the 'cw:tx' key is reserved to the contract"),
+        "expected reserved key error, got: {err:?}"
+    );
+
+    let key = k256::ecdsa::SigningKey::from_bytes(&[7u8; 32].into()).expect("valid key");
+    let err = env
+        .app
+        .record_decision(case.to_string(), None, Some(sign_case(&env, &key, case, 1)))
+        .expect_err("Expected reserved key to be rejected");
+    assert!(
+        format!("{err:?}").contains("the 'gov:module' key is reserved to the contract"),
+        "expected reserved key error, got: {err:?}"
+    );
+}
+
+#[test]
+fn record_decisions_rejects_reserved_keys_per_case() {
+    let constitution = Binary::from(b"decide(case{action:_}, allowed).".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(program, ask_decision_without_motivation("allowed"))
+        .then(program, ask_decision_without_motivation("allowed"))
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let cases = vec![
+        "case{action: transfer}".to_string(),
+        "case{action: mint, 'gov:decision_id': 7}".to_string(),
+    ];
+    let err = env
+        .app
+        .record_decisions(cases.clone(), None, None)
+        .expect_err("Expected atomic batch failure");
+    assert!(
+        format!("{err:?}").contains(
+            "batch case 1 failed: invalid case: the 'gov:decision_id' key is reserved to the contract"
+        ),
+        "expected reserved key error, got: {err:?}"
+    );
+
+    let response = env
+        .app
+        .record_decisions(cases, Some(false), None)
+        .expect("Failed to record decisions");
+    for (attr, expected) in [
+        ("case.0.decision_id", "1"),
+        (
+            "case.1.error",
+            "invalid case: the 'gov:decision_id' key is reserved to the contract",
+        ),
+        ("recorded", "1"),
+        ("failed", "1"),
+    ] {
+        assert_eq!(
+            response
+                .event_attr_value(ABSTRACT_EVENT_TYPE, attr)
+                .unwrap_or_else(|_| panic!("Missing {attr} attribute")),
+            expected,
+            "unexpected {attr} attribute"
+        );
+    }
+}
+
+fn ask_declarations(intents: &str, schemas: &str) -> QueryServiceAskResponse {
+    ask_with_substitutions(vec![
+        Substitution {
+            variable: "Intents".to_string(),
+            expression: intents.to_string(),
+        },
+        Substitution {
+            variable: "Schemas".to_string(),
+            expression: schemas.to_string(),
+        },
+    ])
+}
+
+#[test]
+fn query_intents_and_case_schema() {
+    let constitution = Binary::from(
+        b"intent(transfer, 'Transfer funds').
+case_schema(transfer, [required(amount, integer), optional(memo, atom)]).
+decide(_, 'gov:permitted')."
+            .to_vec(),
+    );
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let intents = "[intent(transfer,'Transfer funds')]";
+    let schemas = "[case_schema(transfer,[required(amount,integer),optional(memo,atom)])]";
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_declarations(intents, schemas))
+        .then(program, ask_establish_permitted())
+        .then(
+            program,
+            ask_with_substitutions(vec![Substitution {
+                variable: "Intents".to_string(),
+                expression: intents.to_string(),
+            }]),
+        )
+        .then(
+            program,
+            ask_with_substitutions(vec![Substitution {
+                variable: "Schemas".to_string(),
+                expression: schemas.to_string(),
+            }]),
+        )
+        .then(
+            program,
+            ask_with_substitutions(vec![Substitution {
+                variable: "Schemas".to_string(),
+                expression: "[]".to_string(),
+            }]),
+        )
+        .assert_query(0, |query| {
+            assert!(
+                query.starts_with("current_predicate(decide/2), current_predicate(decide/3), findall(intent(I, D)"),
+                "unexpected validation query: {query}"
+            );
+        })
+        .assert_query(2, |query| {
+            assert_eq!(
+                query,
+                "findall(intent(I, D), (current_predicate(intent/2), intent(I, D)), Intents)."
+            );
+        })
+        .assert_query(3, |query| {
+            assert_eq!(
+                query,
+                "findall(case_schema(transfer, S), (current_predicate(case_schema/2), case_schema(transfer, S)), Schemas)."
+            );
+        })
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let response = env.app.intents().expect("Failed to query intents");
+    assert_eq!(
+        response.intents,
+        vec![IntentDeclaration {
+            intent: "transfer".to_string(),
+            description: "Transfer funds".to_string(),
+        }]
+    );
+
+    let schema = env
+        .app
+        .case_schema("transfer".to_string())
+        .expect("Failed to query case schema")
+        .expect("Expected a case schema");
+    assert_eq!(
+        schema,
+        CaseSchema {
+            intent: "transfer".to_string(),
+            fields: vec![
+                FieldSchema {
+                    key: "amount".to_string(),
+                    kind: ValueKind::Integer,
+                    required: true,
+                },
+                FieldSchema {
+                    key: "memo".to_string(),
+                    kind: ValueKind::Atom,
+                    required: false,
+                },
+            ],
+        }
+    );
+
+    assert!(env
+        .app
+        .case_schema("withdraw".to_string())
+        .expect("Failed to query case schema")
+        .is_none());
+}
+
+#[test]
+fn revise_constitution_fails_with_invalid_declarations() {
+    let constitution = Binary::from(b"decide(_, 'gov:permitted', 'Revision allowed').".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let new_constitution = Binary::from(
+        b"case_schema(transfer, [required(amount, integer)]).
+decide(_, 'gov:permitted')."
+            .to_vec(),
+    );
+    let new_program = std::str::from_utf8(new_constitution.as_slice()).unwrap();
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(
+            new_program,
+            ask_declarations("[]", "[case_schema(transfer,[required(amount,integer)])]"),
+        )
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let err = env
+        .app
+        .revise_constitution(new_constitution, None)
+        .expect_err("Expected invalid declarations error");
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("case schema declared for undeclared intent 'transfer'"),
+        "expected undeclared intent error, got: {msg}"
+    );
+}
+
+#[test]
+fn record_decision_validates_case_against_declared_schema() {
+    let constitution = Binary::from(
+        b"intent(transfer, 'Transfer funds').
+case_schema(transfer, [required(amount, integer), optional(memo, atom)]).
+decide(_, 'gov:permitted')."
+            .to_vec(),
+    );
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(
+            program,
+            ask_declarations(
+                "[intent(transfer,'Transfer funds')]",
+                "[case_schema(transfer,[required(amount,integer),optional(memo,atom)])]",
+            ),
+        )
+        .then(program, ask_establish_permitted())
+        .then(program, ask_decision_without_motivation("'gov:permitted'"))
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let rejected = vec![
+        (
+            "case{intent: transfer}",
+            "missing required key 'amount' for intent 'transfer'",
+        ),
+        (
+            "case{intent: transfer, amount: ten}",
+            "key 'amount' of intent 'transfer' must be of kind integer, got ten",
+        ),
+        (
+            "case{intent: transfer, amout: 10}",
+            "missing required key 'amount' for intent 'transfer'",
+        ),
+        (
+            "case{intent: transfer, amount: 10, to: bob}",
+            "unexpected key 'to' for intent 'transfer'",
+        ),
+    ];
+    for (case, expected) in rejected {
+        let err = env
+            .app
+            .record_decision(case.to_string(), None, None)
+            .expect_err("Expected invalid case");
+        let msg = format!("{err:?}");
+        assert!(
+            msg.contains(&format!("invalid case: {expected}")),
+            "unexpected error for {case}: {msg}"
+        );
+    }
+
+    let err = env
+        .app
+        .decide("case{intent: transfer}".to_string(), None)
+        .expect_err("Expected invalid case");
+    assert!(format!("{err:?}").contains("missing required key 'amount' for intent 'transfer'"));
+
+    env.app
+        .record_decision(
+            "case{intent: transfer, amount: 10, memo: rent}".to_string(),
+            None,
+            None,
+        )
+        .expect("Failed to record valid case");
+}
+
+#[test]
+fn record_decision_rejects_undeclared_intents() {
+    let constitution = Binary::from(
+        b"intent(transfer, 'Transfer funds').
+decide(_, 'gov:permitted')."
+            .to_vec(),
+    );
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(
+            program,
+            ask_declarations("[intent(transfer,'Transfer funds')]", "[]"),
+        )
+        .then(program, ask_establish_permitted())
+        .then(program, ask_decision_without_motivation("'gov:permitted'"))
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let rejected = vec![
+        (
+            "case{amount: 10}",
+            "missing intent, the constitution only decides declared intents",
+        ),
+        (
+            "case{intent: 42, amount: 10}",
+            "the case intent must be an atom",
+        ),
+        (
+            "case{intent: withdraw, amount: 10}",
+            "intent 'withdraw' is not declared by the constitution",
+        ),
+    ];
+    for (case, expected) in rejected {
+        let err = env
+            .app
+            .record_decision(case.to_string(), None, None)
+            .expect_err("Expected invalid case");
+        let msg = format!("{err:?}");
+        assert!(
+            msg.contains(&format!("invalid case: {expected}")),
+            "unexpected error for {case}: {msg}"
+        );
+    }
+
+    let err = env
+        .app
+        .decide("case{intent: withdraw}".to_string(), None)
+        .expect_err("Expected invalid case");
+    assert!(format!("{err:?}").contains("intent 'withdraw' is not declared by the constitution"));
+
+    env.app
+        .record_decision("case{intent: transfer, amount: 10}".to_string(), None, None)
+        .expect("Failed to record declared intent");
+}
+
+#[test]
+fn decisions_expose_and_filter_verdict_kind() {
+    let constitution = Binary::from(b"decide(_, 'gov:undecided').".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(program, ask_decision_without_motivation("'gov:permitted'"))
+        .then(
+            program,
+            ask_decision_without_motivation("'gov:obliged'(pay(alice,100))"),
+        )
+        .then(program, ask_decision_without_motivation("'gov:forbidden'"))
+        .then(program, ask_decision_without_motivation("'gov:permitted'"))
+        .then(
+            program,
+            ask_decision_without_motivation("'gov:permitted_if'([kyc(alice)])"),
+        )
+        .then(
+            program,
+            ask_decision_without_motivation("'gov:permitted_after'(time_seconds(1700000000))"),
+        )
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let response = env
+        .app
+        .record_decision("case{action: transfer}".to_string(), None, None)
+        .expect("Failed to record decision");
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "verdict_kind")
+            .expect("Missing verdict_kind attribute"),
+        "permitted"
+    );
+
+    let response = env
+        .app
+        .record_decisions(
+            vec![
+                "case{action: pay}".to_string(),
+                "case{action: mint}".to_string(),
+                "case{action: vote}".to_string(),
+            ],
+            None,
+            None,
+        )
+        .expect("Failed to record decisions");
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "case.0.verdict_kind")
+            .expect("Missing verdict_kind attribute"),
+        "obliged"
+    );
+
+    let decide = env
+        .app
+        .decide("case{action: kyc}".to_string(), None)
+        .expect("Failed to decide");
+    assert_eq!(
+        decide.verdict_kind,
+        VerdictKind::PermittedIf {
+            conditions: vec!["kyc(alice)".to_string()]
+        }
+    );
+    let decide = env
+        .app
+        .decide("case{action: launch}".to_string(), None)
+        .expect("Failed to decide");
+    assert_eq!(
+        decide.verdict_kind,
+        VerdictKind::PermittedAfter {
+            activation: RevisionActivation::TimeSeconds(1_700_000_000)
+        }
+    );
+
+    let decision = AxoneGovQueryMsgFns::decision(&env.app, 2).expect("Failed to query decision");
+    assert_eq!(
+        decision.verdict_kind,
+        VerdictKind::Obliged {
+            obligations: vec!["pay(alice, 100)".to_string()]
+        }
+    );
+    let decision = AxoneGovQueryMsgFns::decision(&env.app, 3).expect("Failed to query decision");
+    assert_eq!(decision.verdict_kind, VerdictKind::Forbidden);
+
+    let permitted =
+        AxoneGovQueryMsgFns::decisions(&env.app, None, None, Some(VerdictClass::Permitted))
+            .expect("Failed to query decisions");
+    assert_eq!(
+        permitted
+            .decisions
+            .iter()
+            .map(|d| d.decision_id)
+            .collect::<Vec<_>>(),
+        vec![1, 4]
+    );
+
+    let page =
+        AxoneGovQueryMsgFns::decisions(&env.app, Some(1), Some(1), Some(VerdictClass::Permitted))
+            .expect("Failed to query decisions page");
+    assert_eq!(page.decisions.len(), 1);
+    assert_eq!(page.decisions[0].decision_id, 4);
+}
+
+#[test]
+fn appeal_decision_strips_contract_injected_facts_of_the_appealed_case() {
+    let constitution = Binary::from(b"decide(_, 'gov:forbidden', 'No evidence').".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let case = "case{intent: transfer, amount: 10}";
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(program, ask_decision_without_motivation("'gov:forbidden'"))
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted'", "'Evidence accepted'"),
+        )
+        .assert_query(2, |query| {
+            assert!(query.contains("'gov:signer'"), "expected signer in {query}");
+        })
+        .assert_query(3, |query| {
+            assert!(
+                !query.contains("'gov:signer'"),
+                "unexpected signer in appeal query: {query}"
+            );
+            for expected in ["amount: 10", "intent: 'gov:appeal'", "'gov:module'"] {
+                assert!(query.contains(expected), "expected {expected} in {query}");
+            }
+        })
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let key = k256::ecdsa::SigningKey::from_bytes(&[7u8; 32].into()).expect("valid key");
+    env.app
+        .record_decision(case.to_string(), None, Some(sign_case(&env, &key, case, 1)))
+        .expect("Failed to record signed decision");
+
+    env.app
+        .appeal_decision(1, None)
+        .expect("Failed to appeal decision");
+    let appeal = AxoneGovQueryMsgFns::decision(&env.app, 2).expect("Failed to query appeal");
+    assert!(
+        !appeal.case.contains("'gov:signer'"),
+        "unexpected signer in appeal case: {}",
+        appeal.case
+    );
+    assert_eq!(appeal.author, MOCK_SENDER);
+}
+
+#[test]
+fn appeal_decision_records_reconsideration_chain() {
+    let constitution = Binary::from(b"decide(_, 'gov:forbidden', 'No evidence').".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(program, ask_decision_without_motivation("'gov:forbidden'"))
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:forbidden'", "'Evidence insufficient'"),
+        )
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted'", "'Evidence accepted'"),
+        )
+        .assert_query(3, |query| {
+            for expected in [
+                "amount: 10",
+                "evidence: receipt",
+                "intent: 'gov:appeal'",
+                "'gov:appealed_decision': decision{id: 1, constitution_revision: 0, intent: transfer, verdict: 'gov:forbidden'}",
+            ] {
+                assert!(query.contains(expected), "expected {expected} in {query}");
+            }
+        })
+        .assert_query(4, |query| {
+            assert!(
+                query.contains("'gov:appealed_decision': decision{id: 2, constitution_revision: 0, intent: transfer, verdict: 'gov:forbidden'}"),
+                "unexpected second appeal query: {query}"
+            );
+        })
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    env.app
+        .record_decision("case{intent: transfer, amount: 10}".to_string(), None, None)
+        .expect("Failed to record decision");
+
+    let response = env
+        .app
+        .appeal_decision(1, Some("ctx{evidence: receipt}".to_string()))
+        .expect("Failed to appeal decision");
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "action")
+            .expect("Missing action attribute"),
+        "appeal_decision"
+    );
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "appeal_of")
+            .expect("Missing appeal_of attribute"),
+        "1"
+    );
+
+    let err = env
+        .app
+        .appeal_decision(1, None)
+        .expect_err("Expected already appealed error");
+    assert!(
+        format!("{err:?}").contains("decision 1 is already appealed by decision 2"),
+        "unexpected error: {err:?}"
+    );
+
+    let err = env
+        .app
+        .appeal_decision(
+            2,
+            Some(format!(
+                "ctx{{'gov:signer': signer{{address: {MOCK_SENDER}}}}}"
+            )),
+        )
+        .expect_err("Expected reserved key error");
+    assert!(format!("{err:?}").contains("the 'gov:signer' key is reserved to signed cases"));
+
+    let err = env
+        .app
+        .appeal_decision(42, None)
+        .expect_err("Expected unknown decision error");
+    assert!(format!("{err:?}").contains("not found"));
+
+    let response = env
+        .app
+        .appeal_decision(2, None)
+        .expect("Failed to appeal the appeal");
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "verdict_kind")
+            .expect("Missing verdict_kind attribute"),
+        "permitted"
+    );
+
+    let appeal = AxoneGovQueryMsgFns::decision(&env.app, 2).expect("Failed to query decision");
+    assert_eq!(appeal.appeal_of, Some(1));
+    assert_eq!(
+        appeal.motivation.as_deref(),
+        Some("'Evidence insufficient'")
+    );
+
+    for decision_id in [1, 2, 3] {
+        let chain = env
+            .app
+            .decision_chain(decision_id)
+            .expect("Failed to query decision chain");
+        assert_eq!(
+            chain
+                .decisions
+                .iter()
+                .map(|d| (d.decision_id, d.appeal_of))
+                .collect::<Vec<_>>(),
+            vec![(1, None), (2, Some(1)), (3, Some(2))]
+        );
+    }
+}
+
+#[test]
+fn appeal_decision_mints_the_capability_it_grants() {
+    let constitution = Binary::from(b"decide(_, 'gov:forbidden', 'No evidence').".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(program, ask_decision_without_motivation("'gov:forbidden'"))
+        .then(
+            program,
+            ask_decision_with_motivation(
+                format!("'gov:grant'(read(dataset), {MOCK_SENDER}, 1)"),
+                "'Evidence accepted'",
+            ),
+        )
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    env.app
+        .record_decision("case{intent: access}".to_string(), None, None)
+        .expect("Failed to record decision");
+    let response = env
+        .app
+        .appeal_decision(1, Some("ctx{evidence: receipt}".to_string()))
+        .expect("Failed to appeal decision");
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "capability_id")
+            .expect("Missing capability_id attribute"),
+        "1"
+    );
+
+    let capability = env.app.capability(1).expect("Failed to query capability");
+    assert_eq!(capability.capability, "read(dataset)");
+    assert_eq!(capability.holder, MOCK_SENDER);
+    assert_eq!(capability.decision_id, 2);
+    assert!(capability.in_force);
+
+    env.app
+        .consume_capability(1)
+        .expect("Failed to consume capability");
+}
+
+fn effective_decision_ids(
+    env: &TestEnv<MockBech32>,
+    case_pattern: Option<&str>,
+    at: Option<u64>,
+) -> Vec<u64> {
+    env.app
+        .effective_decisions(at, case_pattern.map(str::to_string), None, None)
+        .expect("Failed to query effective decisions")
+        .decisions
+        .iter()
+        .map(|d| d.decision_id)
+        .collect()
+}
+
+#[test]
+fn effective_decisions_follow_periods_revocations_and_appeals() {
+    let constitution = Binary::from(b"decide(_, 'gov:permitted', 'Granted').".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(
+            program,
+            ask_decision_without_motivation("'gov:effective'('gov:permitted', for(100))"),
+        )
+        .then(program, ask_decision_without_motivation("'gov:permitted'"))
+        .then(program, ask_decision_without_motivation("'gov:forbidden'"))
+        .then(
+            program,
+            ask_decision_without_motivation("'gov:effective'(allowed, until(1))"),
+        )
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted'", "'Access withdrawn'"),
+        )
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted'", "'Evidence accepted'"),
+        )
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:forbidden'", "'Still needed'"),
+        )
+        .assert_query(6, |query| {
+            for expected in [
+                "intent: 'gov:revoke_decision'",
+                "'gov:revoked_decision': decision{id: 2, constitution_revision: 0, intent: access, verdict: 'gov:permitted'}",
+            ] {
+                assert!(query.contains(expected), "expected {expected} in {query}");
+            }
+        })
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let response = env
+        .app
+        .record_decision("case{intent: access, user: alice}".to_string(), None, None)
+        .expect("Failed to record decision");
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "effective_until_seconds")
+            .expect("Missing effective_until_seconds attribute"),
+        (MOCK_BLOCK_TIME + 100).to_string()
+    );
+    env.app
+        .record_decision("case{intent: access, user: bob}".to_string(), None, None)
+        .expect("Failed to record decision");
+    env.app
+        .record_decision("case{intent: transfer, amount: 10}".to_string(), None, None)
+        .expect("Failed to record decision");
+
+    let err = env
+        .app
+        .record_decision("case{intent: access, user: carol}".to_string(), None, None)
+        .expect_err("Expected invalid effective period error");
+    assert!(
+        format!("{err:?}")
+            .contains("invalid effective period: the period ends (1) before it starts"),
+        "unexpected error: {err:?}"
+    );
+
+    let alice = AxoneGovQueryMsgFns::decision(&env.app, 1).expect("Failed to query decision");
+    assert_eq!(alice.verdict, "'gov:permitted'");
+    assert_eq!(alice.verdict_kind, VerdictKind::Permitted);
+    assert_eq!(
+        alice.effective_period,
+        EffectivePeriod {
+            from_seconds: MOCK_BLOCK_TIME,
+            until_seconds: Some(MOCK_BLOCK_TIME + 100),
+        }
+    );
+
+    assert_eq!(effective_decision_ids(&env, None, None), vec![1, 2, 3]);
+    assert_eq!(
+        effective_decision_ids(&env, Some("ctx{intent: access}"), None),
+        vec![1, 2]
+    );
+    assert_eq!(
+        effective_decision_ids(&env, Some("ctx{user: alice}"), None),
+        vec![1]
+    );
+
+    env.app
+        .environment()
+        .wait_seconds(50)
+        .expect("Failed to advance time");
+
+    let response = env
+        .app
+        .revoke_decision(2, None)
+        .expect("Failed to revoke decision");
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "revoked_decision_id")
+            .expect("Missing revoked_decision_id attribute"),
+        "2"
+    );
+    let err = env
+        .app
+        .revoke_decision(2, None)
+        .expect_err("Expected already revoked error");
+    assert!(
+        format!("{err:?}").contains("decision 2 is already revoked by decision 4"),
+        "unexpected error: {err:?}"
+    );
+    let revoked = AxoneGovQueryMsgFns::decision(&env.app, 2).expect("Failed to query decision");
+    assert_eq!(revoked.revoked_by, Some(4));
+
+    env.app
+        .appeal_decision(3, None)
+        .expect("Failed to appeal decision");
+
+    let err = env
+        .app
+        .revoke_decision(1, None)
+        .expect_err("Expected refused revocation");
+    assert!(
+        format!("{err:?}").contains("gov:revoke_decision"),
+        "unexpected error: {err:?}"
+    );
+    let alice = AxoneGovQueryMsgFns::decision(&env.app, 1).expect("Failed to query decision");
+    assert_eq!(alice.revoked_by, None);
+
+    assert_eq!(effective_decision_ids(&env, None, None), vec![1, 4, 5]);
+    assert_eq!(
+        effective_decision_ids(&env, None, Some(MOCK_BLOCK_TIME + 10)),
+        vec![1, 2, 3]
+    );
+    assert_eq!(
+        effective_decision_ids(&env, Some("ctx{intent: access}"), None),
+        vec![1]
+    );
+
+    env.app
+        .environment()
+        .wait_seconds(50)
+        .expect("Failed to advance time");
+    assert_eq!(effective_decision_ids(&env, None, None), vec![4, 5]);
+    assert!(effective_decision_ids(&env, Some("ctx{intent: access}"), None).is_empty());
+}
+
+#[test]
+fn effective_decisions_bound_the_scan_and_return_a_cursor() {
+    let constitution = Binary::from(b"decide(_, 'gov:permitted').".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+
+    let mut scenario = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted());
+    for _ in 0..101 {
+        scenario = scenario.then(program, ask_decision_without_motivation("'gov:permitted'"));
+    }
+    let (hook, expectations) = scenario.install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    for _ in 0..100 {
+        env.app
+            .record_decision("case{intent: transfer}".to_string(), None, None)
+            .expect("Failed to record decision");
+    }
+    env.app
+        .record_decision("case{intent: access}".to_string(), None, None)
+        .expect("Failed to record decision");
+
+    let pattern = Some("ctx{intent: access}".to_string());
+    let page = env
+        .app
+        .effective_decisions(None, pattern.clone(), None, None)
+        .expect("Failed to query effective decisions");
+    assert!(page.decisions.is_empty());
+    assert_eq!(page.next_start_after, Some(100));
+
+    let page = env
+        .app
+        .effective_decisions(None, pattern, None, page.next_start_after)
+        .expect("Failed to query effective decisions");
+    assert_eq!(
+        page.decisions
+            .iter()
+            .map(|d| d.decision_id)
+            .collect::<Vec<_>>(),
+        vec![101]
+    );
+    assert_eq!(page.next_start_after, None);
+
+    let page = env
+        .app
+        .effective_decisions(None, None, Some(2), None)
+        .expect("Failed to query effective decisions");
+    assert_eq!(page.next_start_after, Some(2));
+}
+
+const SUBSCRIBER_RECEIVED_KEY: &[u8] = b"received";
+
+#[cosmwasm_schema::cw_serde]
+enum SubscriberExecuteMsg {
+    DecisionHook(DecisionHook),
+}
+
+/// A subscriber recording the notified decisions, and failing on forbidden verdicts.
+fn subscriber_contract() -> Box<dyn Contract<Empty, Empty>> {
+    Box::new(ContractWrapper::new_with_empty(
+        |deps: DepsMut<'_>, _env: Env, _info: MessageInfo, msg: SubscriberExecuteMsg| {
+            let SubscriberExecuteMsg::DecisionHook(hook) = msg;
+            if hook.verdict_kind == VerdictKind::Forbidden {
+                return Err(StdError::generic_err(
+                    "subscriber rejects forbidden verdicts",
+                ));
+            }
+            let mut received: Vec<u64> = deps
+                .storage
+                .get(SUBSCRIBER_RECEIVED_KEY)
+                .map(from_json)
+                .transpose()?
+                .unwrap_or_default();
+            received.push(hook.decision_id);
+            deps.storage
+                .set(SUBSCRIBER_RECEIVED_KEY, &to_json_vec(&received)?);
+            Ok(Response::new())
+        },
+        |_deps: DepsMut<'_>, _env: Env, _info: MessageInfo, _msg: Empty| -> StdResult<Response> {
+            Ok(Response::new())
+        },
+        |deps: Deps<'_>, _env: Env, _msg: Empty| -> StdResult<Binary> {
+            Ok(Binary::from(
+                deps.storage
+                    .get(SUBSCRIBER_RECEIVED_KEY)
+                    .unwrap_or_else(|| b"[]".to_vec()),
+            ))
+        },
+    ))
+}
+
+#[test]
+fn subscribers_are_notified_of_matching_decisions() {
+    let constitution = Binary::from(b"decide(_, 'gov:permitted', 'Granted').".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted'", "'Subscription granted'"),
+        )
+        .then(program, ask_decision_without_motivation("'gov:permitted'"))
+        .then(program, ask_decision_without_motivation("'gov:permitted'"))
+        .then(program, ask_decision_without_motivation("'gov:forbidden'"))
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted'", "'Subscription ended'"),
+        )
+        .then(program, ask_decision_without_motivation("'gov:permitted'"))
+        .assert_query(6, |query| {
+            assert!(
+                query.contains("intent: 'gov:unsubscribe'"),
+                "unexpected unsubscribe query: {query}"
+            );
+        })
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let chain = env.app.environment();
+    chain
+        .upload_custom("subscriber", subscriber_contract())
+        .expect("Failed to upload subscriber");
+    let code_id = chain
+        .state()
+        .borrow()
+        .get_code_id("subscriber")
+        .expect("Missing subscriber code id");
+    let subscriber = chain
+        .instantiate(code_id, &Empty {}, Some("subscriber"), None, &[])
+        .expect("Failed to instantiate subscriber")
+        .instantiated_contract_address()
+        .expect("Missing subscriber address");
+    let received = || -> Vec<u64> {
+        chain
+            .wasm_querier()
+            .smart_query(&subscriber, &Empty {})
+            .expect("Failed to query subscriber")
+    };
+
+    let response = env
+        .app
+        .subscribe(
+            subscriber.to_string(),
+            None,
+            Some(DecisionFilter {
+                intent: Some("transfer".to_string()),
+                verdict_kind: None,
+            }),
+        )
+        .expect("Failed to subscribe");
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "subscriber")
+            .expect("Missing subscriber attribute"),
+        subscriber.to_string()
+    );
+    let subscriptions = env
+        .app
+        .subscriptions(None, None)
+        .expect("Failed to query subscriptions")
+        .subscriptions;
+    assert_eq!(subscriptions.len(), 1);
+    assert_eq!(subscriptions[0].contract, subscriber.to_string());
+    assert_eq!(subscriptions[0].decision_id, 1);
+
+    env.app
+        .record_decision("case{intent: transfer, amount: 10}".to_string(), None, None)
+        .expect("Failed to record decision");
+    env.app
+        .record_decision("case{intent: read}".to_string(), None, None)
+        .expect("Failed to record decision");
+    assert_eq!(received(), vec![2]);
+
+    let response = env
+        .app
+        .record_decision("case{intent: transfer, amount: 99}".to_string(), None, None)
+        .expect("A failing subscriber must not revert the decision");
+    assert_eq!(
+        response.event_attr_values(ABSTRACT_EVENT_TYPE, "subscriber"),
+        vec![subscriber.to_string()]
+    );
+    assert!(response
+        .event_attr_value(ABSTRACT_EVENT_TYPE, "error")
+        .expect("Missing error attribute")
+        .contains("subscriber rejects forbidden verdicts"));
+    AxoneGovQueryMsgFns::decision(&env.app, 4).expect("Failed decision must be recorded");
+    assert_eq!(received(), vec![2]);
+
+    env.app
+        .unsubscribe(subscriber.to_string(), None)
+        .expect("Failed to unsubscribe");
+    let err = env
+        .app
+        .unsubscribe(subscriber.to_string(), None)
+        .expect_err("Expected subscription not found");
+    assert!(
+        format!("{err:?}").contains("is not subscribed"),
+        "unexpected error: {err:?}"
+    );
+
+    env.app
+        .record_decision("case{intent: transfer, amount: 10}".to_string(), None, None)
+        .expect("Failed to record decision");
+    assert_eq!(received(), vec![2]);
+    assert!(env
+        .app
+        .subscriptions(None, None)
+        .expect("Failed to query subscriptions")
+        .subscriptions
+        .is_empty());
+}
+
+#[test]
+fn capabilities_granted_by_decisions_are_consumed_by_their_holder() {
+    let constitution = Binary::from(b"decide(_, 'gov:permitted', 'Granted').".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let other_holder = MockApiBech32::new("mock").addr_make("holder");
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(
+            program,
+            ask_decision_without_motivation(format!(
+                "'gov:grant'(read(dataset), {MOCK_SENDER}, 2)"
+            )),
+        )
+        .then(
+            program,
+            ask_decision_without_motivation(format!(
+                "'gov:grant'(write(dataset), {other_holder}, 1)"
+            )),
+        )
+        .then(
+            program,
+            ask_decision_without_motivation("'gov:grant'(read(dataset), 'not-an-address', 1)"),
+        )
+        .then(
+            program,
+            ask_decision_without_motivation(format!(
+                "'gov:effective'('gov:grant'(read(archive), {MOCK_SENDER}, 3), for(10))"
+            )),
+        )
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let response = env
+        .app
+        .record_decision("case{intent: access}".to_string(), None, None)
+        .expect("Failed to record decision");
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "capability_id")
+            .expect("Missing capability_id attribute"),
+        "1"
+    );
+    let decision = AxoneGovQueryMsgFns::decision(&env.app, 1).expect("Failed to query decision");
+    assert_eq!(decision.verdict_kind, VerdictKind::Permitted);
+
+    let capability = env.app.capability(1).expect("Failed to query capability");
+    assert_eq!(capability.capability, "read(dataset)");
+    assert_eq!(capability.holder, MOCK_SENDER);
+    assert_eq!(capability.decision_id, 1);
+    assert_eq!((capability.uses, capability.remaining_uses), (2, 2));
+    assert!(capability.in_force);
+
+    let response = env
+        .app
+        .consume_capability(1)
+        .expect("Failed to consume capability");
+    let consumed: ConsumeCapabilityResponse =
+        from_json(response.data.expect("Missing response data")).expect("Invalid response data");
+    assert_eq!(
+        consumed,
+        ConsumeCapabilityResponse {
+            capability_id: 1,
+            capability: "read(dataset)".to_string(),
+            decision_id: 1,
+            remaining_uses: 1,
+        }
+    );
+    env.app
+        .consume_capability(1)
+        .expect("Failed to consume capability");
+    let err = env
+        .app
+        .consume_capability(1)
+        .expect_err("Expected exhausted capability");
+    assert!(
+        format!("{err:?}").contains("capability 1 is exhausted"),
+        "unexpected error: {err:?}"
+    );
+
+    env.app
+        .record_decision("case{intent: access}".to_string(), None, None)
+        .expect("Failed to record decision");
+    let err = env
+        .app
+        .consume_capability(2)
+        .expect_err("Expected capability not held");
+    assert!(
+        format!("{err:?}").contains(&format!("capability 2 is not held by {MOCK_SENDER}")),
+        "unexpected error: {err:?}"
+    );
+    let held = env
+        .app
+        .capabilities(Some(other_holder.to_string()), None, None)
+        .expect("Failed to query capabilities")
+        .capabilities;
+    assert_eq!(
+        held.iter().map(|c| c.capability_id).collect::<Vec<_>>(),
+        vec![2]
+    );
+
+    env.app
+        .record_decision("case{intent: access}".to_string(), None, None)
+        .expect_err("Expected invalid holder");
+    assert!(
+        AxoneGovQueryMsgFns::decision(&env.app, 3).is_err(),
+        "an invalid grant must not be recorded"
+    );
+
+    env.app
+        .record_decision("case{intent: access}".to_string(), None, None)
+        .expect("Failed to record decision");
+    env.app
+        .consume_capability(3)
+        .expect("Failed to consume capability");
+    env.app
+        .environment()
+        .wait_seconds(10)
+        .expect("Failed to advance time");
+    let err = env
+        .app
+        .consume_capability(3)
+        .expect_err("Expected capability not in force");
+    assert!(
+        format!("{err:?}").contains("capability 3 is not in force"),
+        "unexpected error: {err:?}"
+    );
+    assert!(
+        !env.app
+            .capability(3)
+            .expect("Failed to query capability")
+            .in_force
+    );
+}
+
+#[test]
+fn recorded_decisions_charge_the_fee_decided_by_the_constitution() {
+    let constitution = Binary::from(
+        b"decide(_, 'gov:permitted').
+decision_fee(_, [coin(100, uaxone)])."
+            .to_vec(),
+    );
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let ask_decision_with_fee = || {
+        ask_with_substitutions(vec![
+            Substitution {
+                variable: "Verdict".to_string(),
+                expression: "'gov:permitted'".to_string(),
+            },
+            Substitution {
+                variable: "Fee".to_string(),
+                expression: "[coin(100,uaxone)]".to_string(),
+            },
+        ])
+    };
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(program, ask_decision_with_fee())
+        .then(program, ask_decision_with_fee())
+        .then(program, ask_decision_with_fee())
+        .then(program, ask_decision_with_fee())
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+    let chain = env.app.environment().clone();
+    let sender = chain.sender_addr();
+    let account = env.app.account().address().expect("Failed to get account");
+    chain
+        .set_balance(&sender, vec![coin(1000, "uaxone"), coin(10, "uatom")])
+        .expect("Failed to fund sender");
+    let balance = |addr: &Addr, denom: &str| {
+        chain
+            .bank_querier()
+            .balance(addr, Some(denom.to_string()))
+            .expect("Failed to query balance")[0]
+            .amount
+            .u128()
+    };
+    let record = |case: &str, funds: &[Coin]| {
+        env.app.execute(
+            &AxoneGovExecuteMsg::RecordDecision {
+                case: case.to_string(),
+                motivated: None,
+                signature: None,
+            }
+            .into(),
+            funds,
+        )
+    };
+
+    let response = record(
+        "case{intent: publish}",
+        &[coin(150, "uaxone"), coin(10, "uatom")],
+    )
+    .expect("Failed to record decision");
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "fee")
+            .expect("Missing fee attribute"),
+        "100uaxone"
+    );
+    assert_eq!(balance(&account, "uaxone"), 100);
+    assert_eq!(balance(&sender, "uaxone"), 900);
+    assert_eq!(balance(&sender, "uatom"), 10);
+
+    let err = record("case{intent: publish}", &[coin(99, "uaxone")])
+        .expect_err("Expected insufficient fee");
+    assert!(
+        format!("{err:?}").contains("insufficient fee: required 100uaxone, paid 99uaxone"),
+        "unexpected error: {err:?}"
+    );
+    assert_eq!(balance(&account, "uaxone"), 100);
+
+    let response = env
+        .app
+        .execute(
+            &AxoneGovExecuteMsg::RecordDecisions {
+                cases: vec![
+                    "case{intent: publish}".to_string(),
+                    "case{intent: publish}".to_string(),
+                ],
+                motivated: None,
+                atomic: None,
+            }
+            .into(),
+            &[coin(250, "uaxone")],
+        )
+        .expect("Failed to record decisions");
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "fee")
+            .expect("Missing fee attribute"),
+        "200uaxone"
+    );
+    assert_eq!(balance(&account, "uaxone"), 300);
+    assert_eq!(balance(&sender, "uaxone"), 700);
+}
+
+#[test]
+fn signed_cases_count_toward_the_rate_limit_of_their_signer() {
+    let constitution = Binary::from(
+        b"decide(_, 'gov:permitted').
+decision_limit(rate(1, 60))."
+            .to_vec(),
+    );
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let case = "case{intent: publish}";
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(
+            program,
+            ask_with_substitutions(vec![Substitution {
+                variable: "Limits".to_string(),
+                expression: "[rate(1,60)]".to_string(),
+            }]),
+        )
+        .then(program, ask_establish_permitted())
+        .then(program, ask_decision_without_motivation("'gov:permitted'"))
+        .then(program, ask_decision_without_motivation("'gov:permitted'"))
+        .then(program, ask_decision_without_motivation("'gov:permitted'"))
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let alice = k256::ecdsa::SigningKey::from_bytes(&[7u8; 32].into()).expect("valid key");
+    let bob = k256::ecdsa::SigningKey::from_bytes(&[8u8; 32].into()).expect("valid key");
+    let response = env
+        .app
+        .record_decision(
+            case.to_string(),
+            None,
+            Some(sign_case(&env, &alice, case, 1)),
+        )
+        .expect("Failed to record decision signed by alice");
+    let alice_address = response
+        .event_attr_value(ABSTRACT_EVENT_TYPE, "signer")
+        .expect("Missing signer attribute");
+    env.app
+        .record_decision(case.to_string(), None, Some(sign_case(&env, &bob, case, 1)))
+        .expect("Failed to record decision signed by bob through the same relayer");
+
+    let err = env
+        .app
+        .record_decision(
+            case.to_string(),
+            None,
+            Some(sign_case(&env, &alice, case, 2)),
+        )
+        .expect_err("Expected rate limit of alice");
+    assert!(
+        format!("{err:?}").contains(&format!(
+            "rate limit exceeded by {alice_address}: at most 1 decisions per 60 seconds"
+        )),
+        "unexpected error: {err:?}"
+    );
+
+    env.app
+        .record_decision(case.to_string(), None, None)
+        .expect("Failed to record decision of the relayer");
+}
+
+#[test]
+fn recorded_decisions_are_bounded_by_the_constitution_limits() {
+    let constitution = Binary::from(
+        b"decide(_, 'gov:permitted', 'Recorded').
+decision_limit(max_case_bytes(400)).
+decision_limit(max_motivation_bytes(8)).
+decision_limit(rate(2, 60))."
+            .to_vec(),
+    );
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(
+            program,
+            ask_with_substitutions(vec![Substitution {
+                variable: "Limits".to_string(),
+                expression: "[max_case_bytes(400),max_motivation_bytes(8),rate(2,60)]".to_string(),
+            }]),
+        )
+        .then(program, ask_establish_permitted())
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted'", "'Recorded with a long motivation'"),
+        )
+        .then(program, ask_decision_without_motivation("'gov:permitted'"))
+        .then(program, ask_decision_without_motivation("'gov:permitted'"))
+        .then(program, ask_decision_without_motivation("'gov:permitted'"))
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    assert_eq!(
+        env.app
+            .decision_limits()
+            .expect("Failed to query decision limits"),
+        DecisionLimits {
+            max_case_bytes: 400,
+            max_motivation_bytes: 8,
+            rate_limit: Some(RateLimit {
+                max_decisions: 2,
+                window_seconds: 60,
+            }),
+        }
+    );
+
+    let err = env
+        .app
+        .record_decision(format!("case{{memo: '{}'}}", "x".repeat(300)), None, None)
+        .expect_err("Expected oversized case");
+    assert!(
+        format!("{err:?}").contains("case is too large"),
+        "unexpected error: {err:?}"
+    );
+
+    let err = env
+        .app
+        .record_decision("case{intent: publish}".to_string(), Some(true), None)
+        .expect_err("Expected oversized motivation");
+    assert!(
+        format!("{err:?}").contains("motivation is too large: 33 bytes exceeds the limit of 8"),
+        "unexpected error: {err:?}"
+    );
+
+    for _ in 0..2 {
+        env.app
+            .record_decision("case{intent: publish}".to_string(), None, None)
+            .expect("Failed to record decision");
+    }
+    let err = env
+        .app
+        .record_decision("case{intent: publish}".to_string(), None, None)
+        .expect_err("Expected rate limit");
+    assert!(
+        format!("{err:?}").contains(&format!(
+            "rate limit exceeded by {MOCK_SENDER}: at most 2 decisions per 60 seconds"
+        )),
+        "unexpected error: {err:?}"
+    );
+
+    env.app
+        .environment()
+        .wait_seconds(60)
+        .expect("Failed to advance time");
+    env.app
+        .record_decision("case{intent: publish}".to_string(), None, None)
+        .expect("Failed to record decision once the window elapsed");
+}
+
+#[test]
+fn record_decisions_applies_the_rate_limit_per_case() {
+    let constitution = Binary::from(
+        b"decision_limit(rate(2, 60)).
+decide(_, 'gov:permitted')."
+            .to_vec(),
+    );
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(
+            program,
+            ask_with_substitutions(vec![Substitution {
+                variable: "Limits".to_string(),
+                expression: "[rate(2,60)]".to_string(),
+            }]),
+        )
+        .then(program, ask_establish_permitted())
+        .then(program, ask_decision_without_motivation("'gov:permitted'"))
+        .then(program, ask_decision_without_motivation("'gov:permitted'"))
+        .then(program, ask_decision_without_motivation("'gov:permitted'"))
+        .then(program, ask_decision_without_motivation("'gov:permitted'"))
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+    let cases = vec!["case{intent: publish}".to_string(); 3];
+    let rate_limited =
+        format!("rate limit exceeded by {MOCK_SENDER}: at most 2 decisions per 60 seconds");
+
+    let err = env
+        .app
+        .record_decisions(cases.clone(), None, None)
+        .expect_err("Expected atomic batch failure");
+    assert!(
+        format!("{err:?}").contains(&format!("batch case 2 failed: {rate_limited}")),
+        "unexpected error: {err:?}"
+    );
+
+    let response = env
+        .app
+        .record_decisions(cases, Some(false), None)
+        .expect("Failed to record decisions");
+    for (attr, expected) in [
+        ("case.0.decision_id", "1"),
+        ("case.1.decision_id", "2"),
+        ("case.2.error", rate_limited.as_str()),
+        ("recorded", "2"),
+        ("failed", "1"),
+    ] {
+        assert_eq!(
+            response
+                .event_attr_value(ABSTRACT_EVENT_TYPE, attr)
+                .unwrap_or_else(|_| panic!("Missing {attr} attribute")),
+            expected,
+            "unexpected {attr} attribute"
+        );
+    }
+
+    let err = env
+        .app
+        .record_decision("case{intent: publish}".to_string(), None, None)
+        .expect_err("Expected rate limit");
+    assert!(
+        format!("{err:?}").contains(&rate_limited),
+        "unexpected error: {err:?}"
+    );
+}
+
+/// Make the deployed module look like a previous release predating state versioning.
+fn downgrade_to_unversioned_release(env: &TestEnv<MockBech32>) {
+    let address = env.app.address().expect("Failed to get module address");
+    let chain = env.app.environment();
+    let mut app = chain.app.borrow_mut();
+    let mut storage = app.contract_storage_mut(&address);
+    storage.set(
+        b"contract_info",
+        format!(r#"{{"contract":"{AXONE_GOV_ID}","version":"0.1.0"}}"#).as_bytes(),
+    );
+    storage.remove(b"state_version");
+    storage.remove(b"intents");
+    storage.remove(b"case_schemas");
+    storage.remove(b"decision_limits");
+
+    let verdict_index = b"\x00\x17decisions__verdict_kind";
+    let index_keys: Vec<_> = storage
+        .range_keys(Some(verdict_index), None, Order::Ascending)
+        .take_while(|key| key.starts_with(verdict_index))
+        .collect();
+    for key in index_keys {
+        storage.remove(&key);
+    }
+}
+
+fn migrate(env: &TestEnv<MockBech32>, case: Option<String>) -> Result<AppResponse, CwOrchError> {
+    let chain = env.app.environment();
+    let account = env.app.account().address().expect("Failed to get account");
+    let address = env.app.address().expect("Failed to get module address");
+    let code_id = chain
+        .wasm_querier()
+        .contract_info(&address)
+        .expect("Failed to query contract info")
+        .code_id;
+    chain.call_as(&account).migrate(
+        &MigrateMsg {
+            base: app::BaseMigrateMsg {},
+            module: AxoneGovMigrateMsg { case },
+        },
+        code_id,
+        &address,
+    )
+}
+
+#[test]
+fn migration_upgrades_the_state_once_authorized_by_the_constitution() {
+    let constitution = Binary::from(
+        b"intent(publish, 'Publish a document').
+decision_limit(max_case_bytes(4096)).
+decide(_, 'gov:permitted', 'Migrated')."
+            .to_vec(),
+    );
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let declarations = || {
+        ask_with_substitutions(vec![
+            Substitution {
+                variable: "Intents".to_string(),
+                expression: "[intent(publish,'Publish a document')]".to_string(),
+            },
+            Substitution {
+                variable: "Limits".to_string(),
+                expression: "[max_case_bytes(4096)]".to_string(),
+            },
+        ])
+    };
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, declarations())
+        .then(program, ask_establish_permitted())
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted'", "'Migrated'"),
+        )
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted'", "'Migrated'"),
+        )
+        .then(program, declarations())
+        .assert_query(3, |query| {
+            for expected in [
+                "intent: 'gov:migrate'",
+                "'gov:migration': migration{from_state_version: 1, to_state_version: 4}",
+                "reason: upgrade",
+            ] {
+                assert!(
+                    query.contains(expected),
+                    "unexpected migrate query: {query}"
+                );
+            }
+        })
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+    env.app
+        .record_decision("case{intent: publish}".to_string(), None, None)
+        .expect("Failed to record decision");
+    downgrade_to_unversioned_release(&env);
+
+    let response =
+        migrate(&env, Some("ctx{reason: upgrade}".to_string())).expect("Failed to migrate");
+    for (key, value) in [
+        ("decision_id", "2"),
+        ("from_state_version", "1"),
+        ("to_state_version", "4"),
+    ] {
+        assert_eq!(
+            response
+                .event_attr_value(ABSTRACT_EVENT_TYPE, key)
+                .unwrap_or_else(|_| panic!("Missing {key} attribute")),
+            value
+        );
+    }
+
+    let account = env.app.account().address().expect("Failed to get account");
+    let decision = AxoneGovQueryMsgFns::decision(&env.app, 2).expect("Failed to query decision");
+    assert_eq!(decision.author, account.to_string());
+    let permitted = env
+        .app
+        .decisions(None, None, Some(VerdictClass::Permitted))
+        .expect("Failed to query permitted decisions");
+    assert_eq!(
+        permitted
+            .decisions
+            .iter()
+            .map(|decision| decision.decision_id)
+            .collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+    assert_eq!(
+        env.app
+            .decision_limits()
+            .expect("Failed to query decision limits"),
+        DecisionLimits {
+            max_case_bytes: 4096,
+            ..DecisionLimits::default()
+        }
+    );
+    let err = env
+        .app
+        .record_decision("case{intent: withdraw}".to_string(), None, None)
+        .expect_err("Expected undeclared intent");
+    assert!(
+        format!("{err:?}").contains("intent 'withdraw' is not declared by the constitution"),
+        "unexpected error: {err:?}"
+    );
+}
+
+#[test]
+fn migration_is_refused_by_the_constitution_or_from_a_newer_state() {
+    let constitution = Binary::from(b"decide(_, 'gov:forbidden', 'Frozen').".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:forbidden'", "'Frozen'"),
+        )
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+    downgrade_to_unversioned_release(&env);
+
+    let err = migrate(&env, None).expect_err("Expected refused migration");
+    assert!(
+        format!("{err:?}").contains("gov:migrate"),
+        "unexpected error: {err:?}"
+    );
+
+    let address = env.app.address().expect("Failed to get module address");
+    env.app
+        .environment()
+        .app
+        .borrow_mut()
+        .contract_storage_mut(&address)
+        .set(b"state_version", b"99");
+    let err = migrate(&env, None).expect_err("Expected unsupported state version");
+    assert!(
+        format!("{err:?}")
+            .contains("unsupported state version 99: the latest supported version is 4"),
+        "unexpected error: {err:?}"
+    );
+}
+
+#[test]
+fn instantiate_decides_the_establishment_case_and_config() {
+    let constitution = Binary::from(b"decide(_, 'gov:permitted', 'Established').".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let treasury = MockApiBech32::new("mock").addr_make("treasury");
+    let config = Config {
+        max_batch_size: 2,
+        fee_recipient: Some(treasury.to_string()),
+        enrichment_providers: vec![],
+    };
+    let expected_config = format!(
+        "'gov:proposed_config': config{{max_batch_size: 2, fee_recipient: {treasury}, enrichment_providers: []}}"
+    );
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .assert_query(1, move |query| {
+            for expected in [
+                "founders: [alice, bob]",
+                "intent: 'gov:establish'",
+                expected_config.as_str(),
+            ] {
+                assert!(
+                    query.contains(expected),
+                    "unexpected establish query: {query}"
+                );
+            }
+        })
+        .install();
+    let env = TestEnv::setup_with(
+        AxoneGovInstantiateMsg {
+            constitution,
+            case: Some("ctx{founders: [alice, bob]}".to_string()),
+            config: Some(config.clone()),
+        },
+        hook,
+        expectations,
+    )
+    .expect("Failed to setup test environment");
+
+    assert_eq!(env.app.config().expect("Failed to query config"), config);
+
+    let err = env
+        .app
+        .record_decisions(vec!["case{intent: publish}".to_string(); 3], None, None)
+        .expect_err("Expected oversized batch");
+    assert!(
+        format!("{err:?}").contains("expected between 1 and 2 cases, got 3"),
+        "unexpected error: {err:?}"
+    );
+}
+
+/// An enrichment provider granting roles to every sender.
+fn enrichment_provider_contract() -> Box<dyn Contract<Empty, Empty>> {
+    Box::new(ContractWrapper::new_with_empty(
+        |_deps: DepsMut<'_>, _env: Env, _info: MessageInfo, _msg: Empty| -> StdResult<Response> {
+            Ok(Response::new())
+        },
+        |_deps: DepsMut<'_>, _env: Env, _info: MessageInfo, _msg: Empty| -> StdResult<Response> {
+            Ok(Response::new())
+        },
+        |_deps: Deps<'_>, _env: Env, msg: EnrichmentQueryMsg| -> StdResult<Binary> {
+            let EnrichmentQueryMsg::CaseEnrichment { sender } = msg;
+            to_json_binary(&CaseEnrichmentResponse {
+                term: format!("roles{{holder: {sender}, roles: [admin, auditor]}}"),
+            })
+        },
+    ))
+}
+
+#[test]
+fn enrichment_keys_are_accepted_by_case_schemas() {
+    let constitution = Binary::from(
+        b"intent(publish, 'Publish a document').
+case_schema(publish, [required(title, atom)]).
+decide(_, 'gov:permitted', 'Configured')."
+            .to_vec(),
+    );
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(
+            program,
+            ask_declarations(
+                "[intent(publish,'Publish a document')]",
+                "[case_schema(publish,[required(title,atom)])]",
+            ),
+        )
+        .then(program, ask_establish_permitted())
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted'", "'Configured'"),
+        )
+        .then(program, ask_decision_without_motivation("'gov:permitted'"))
+        .then(program, ask_decision_without_motivation("'gov:permitted'"))
+        .assert_query(3, |query| {
+            assert!(
+                query.contains("roles: roles{holder: "),
+                "expected enrichment in {query}"
+            );
+        })
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let chain = env.app.environment();
+    chain
+        .upload_custom("provider", enrichment_provider_contract())
+        .expect("Failed to upload provider");
+    let code_id = chain
+        .state()
+        .borrow()
+        .get_code_id("provider")
+        .expect("Missing provider code id");
+    let provider = chain
+        .instantiate(code_id, &Empty {}, Some("provider"), None, &[])
+        .expect("Failed to instantiate provider")
+        .instantiated_contract_address()
+        .expect("Missing provider address");
+    env.app
+        .update_config(
+            Config {
+                enrichment_providers: vec![EnrichmentProvider {
+                    key: "roles".to_string(),
+                    contract: provider.to_string(),
+                }],
+                ..Config::default()
+            },
+            None,
+        )
+        .expect("Failed to update config");
+
+    env.app
+        .record_decision(
+            "case{intent: publish, title: report}".to_string(),
+            None,
+            None,
+        )
+        .expect("Failed to record an enriched decision");
+
+    let err = env
+        .app
+        .record_decision(
+            "case{intent: publish, title: report, draft: true}".to_string(),
+            None,
+            None,
+        )
+        .expect_err("Expected unexpected key");
+    assert!(
+        format!("{err:?}").contains("unexpected key 'draft' for intent 'publish'"),
+        "unexpected error: {err:?}"
+    );
+
+    let response = env
+        .app
+        .decide(
+            "case{intent: publish, title: report, roles: roles{holder: admin}}".to_string(),
+            Some(false),
+        )
+        .expect("Failed to decide a case simulating its enrichment");
+    assert_eq!(response.verdict, "'gov:permitted'");
+
+    let err = env
+        .app
+        .decide(
+            "case{intent: publish, title: report, draft: true}".to_string(),
+            Some(false),
+        )
+        .expect_err("Expected unexpected key");
+    assert!(
+        format!("{err:?}").contains("unexpected key 'draft' for intent 'publish'"),
+        "unexpected error: {err:?}"
+    );
+}
+
+#[test]
+fn config_changes_are_decided_by_the_constitution() {
+    let constitution = Binary::from(b"decide(_, 'gov:permitted', 'Configured').".to_vec());
+    let program = std::str::from_utf8(constitution.as_slice()).unwrap();
+    let (hook, expectations) = LogicAskScenario::new()
+        .then(program, ask_ok())
+        .then(program, ask_establish_permitted())
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:forbidden'", "'Not now'"),
+        )
+        .then(
+            program,
+            ask_decision_with_motivation("'gov:permitted'", "'Configured'"),
+        )
+        .then(program, ask_decision_without_motivation("'gov:permitted'"))
+        .assert_query(3, |query| {
+            for expected in [
+                "intent: 'gov:configure'",
+                "enrichment_providers: [provider{key: roles, contract: ",
+            ] {
+                assert!(
+                    query.contains(expected),
+                    "unexpected configure query: {query}"
+                );
+            }
+        })
+        .assert_query(4, |query| {
+            let expected =
+                format!("roles: roles{{holder: {MOCK_SENDER}, roles: [admin, auditor]}}");
+            assert!(
+                query.contains(&expected),
+                "unexpected decide query: {query}"
+            );
+        })
+        .install();
+    let env =
+        TestEnv::setup(constitution, hook, expectations).expect("Failed to setup test environment");
+
+    let chain = env.app.environment();
+    chain
+        .upload_custom("provider", enrichment_provider_contract())
+        .expect("Failed to upload provider");
+    let code_id = chain
+        .state()
+        .borrow()
+        .get_code_id("provider")
+        .expect("Missing provider code id");
+    let provider = chain
+        .instantiate(code_id, &Empty {}, Some("provider"), None, &[])
+        .expect("Failed to instantiate provider")
+        .instantiated_contract_address()
+        .expect("Missing provider address");
+    let config = Config {
+        enrichment_providers: vec![EnrichmentProvider {
+            key: "roles".to_string(),
+            contract: provider.to_string(),
+        }],
+        ..Config::default()
+    };
+
+    let err = env
+        .app
+        .update_config(config.clone(), None)
+        .expect_err("Expected refused configuration");
+    assert!(
+        format!("{err:?}").contains("gov:configure"),
+        "unexpected error: {err:?}"
+    );
+    assert_eq!(
+        env.app.config().expect("Failed to query config"),
+        Config::default()
+    );
+
+    let err = env
+        .app
+        .update_config(
+            Config {
+                max_batch_size: 0,
+                ..Config::default()
+            },
+            None,
+        )
+        .expect_err("Expected invalid configuration");
+    assert!(
+        format!("{err:?}").contains("invalid config: max_batch_size must be between 1 and 50"),
+        "unexpected error: {err:?}"
+    );
+
+    let response = env
+        .app
+        .update_config(config.clone(), None)
+        .expect("Failed to update config");
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "decision_id")
+            .expect("Missing decision_id attribute"),
+        "1"
+    );
+    assert_eq!(env.app.config().expect("Failed to query config"), config);
+
+    env.app
+        .record_decision("case{intent: publish}".to_string(), None, None)
+        .expect("Failed to record an enriched decision");
+}
@@ -26,7 +26,7 @@ fn install(networks: Vec<ChainInfo>) -> anyhow::Result<()> {
         })?;
 
         let app: Application<Daemon, AxoneVcInterface<Daemon>> =
-            account.install_app::<AxoneVcInterface<_>>(&AxoneVcInstantiateMsg {}, &[])?;
+            account.install_app::<AxoneVcInterface<_>>(&AxoneVcInstantiateMsg::default(), &[])?;
 
         info!("axone-vc installed at {}", app.address()?);
     }
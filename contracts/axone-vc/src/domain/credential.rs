@@ -1,11 +1,30 @@
-use crate::translation::{DecodedCredential, DecodedUri};
+use crate::translation::{DecodedClaim, DecodedCredential, DecodedUri};
+use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Timestamp;
 use getset::{CopyGetters, Getters};
 use thiserror::Error;
 
 pub type Uri = String;
 
-const VC_VERIFIABLE_CREDENTIAL: &str = "https://www.w3.org/2018/credentials#VerifiableCredential";
+pub(crate) const VC_VERIFIABLE_CREDENTIAL: &str =
+    "https://www.w3.org/2018/credentials#VerifiableCredential";
+
+/// Version of the W3C Verifiable Credentials Data Model a credential follows.
+///
+/// Both versions share the `https://www.w3.org/2018/credentials#` vocabulary; they differ in
+/// their base context and validity terms.
+#[cw_serde]
+#[derive(Copy, Default)]
+pub enum DataModelVersion {
+    /// VC Data Model 1.1, whose validity is given by `issuanceDate` and `expirationDate`.
+    #[serde(rename = "v1")]
+    #[default]
+    V1,
+    /// VC Data Model 2.0, whose validity is given by `validFrom` and `validUntil` and whose
+    /// JSON-LD form uses the `https://www.w3.org/ns/credentials/v2` context.
+    #[serde(rename = "v2")]
+    V2,
+}
 
 #[derive(Debug, Error, PartialEq)]
 pub enum CredentialError {
@@ -42,6 +61,11 @@ pub struct Credential {
     subject_id: Uri,
     #[getset(get = "pub")]
     types: Vec<String>,
+    #[getset(get_copy = "pub")]
+    data_model: DataModelVersion,
+    /// The claims about the credential subject.
+    #[getset(get = "pub")]
+    claims: Vec<DecodedClaim>,
 }
 
 impl TryFrom<DecodedCredential> for Credential {
@@ -59,14 +83,18 @@ impl TryFrom<DecodedCredential> for Credential {
             }
         };
 
-        Self::try_new(
+        let mut credential = Self::try_new(
             id,
             issuer,
             *decoded.valid_from(),
             *decoded.valid_until(),
             decoded.subject_id(),
             decoded.types().clone(),
-        )
+            *decoded.data_model(),
+        )?;
+        credential.claims = decoded.subject_claims();
+
+        Ok(credential)
     }
 }
 
@@ -78,6 +106,7 @@ impl Credential {
         valid_until: Option<Timestamp>,
         subject_id: &DecodedUri,
         types: Vec<String>,
+        data_model: DataModelVersion,
     ) -> Result<Self, CredentialError> {
         let subject_id = match subject_id {
             DecodedUri::Uri(uri) => uri.clone(),
@@ -105,13 +134,15 @@ impl Credential {
             valid_until,
             subject_id,
             types,
+            data_model,
+            claims: Vec::new(),
         })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Credential;
+    use super::{Credential, DataModelVersion};
     use crate::{
         domain::CredentialError,
         translation::{DecodedCredential, DecodedUri},
@@ -256,6 +287,14 @@ mod tests {
         assert_eq!(err, CredentialError::NotVerifiableCredential);
     }
 
+    #[test]
+    fn try_from_keeps_the_data_model_of_the_decoded_credential() {
+        let decoded = decoded_credential().with_data_model(DataModelVersion::V2);
+        let credential = Credential::try_from(decoded).expect("credential should be valid");
+
+        assert_eq!(credential.data_model(), DataModelVersion::V2);
+    }
+
     #[test]
     fn try_from_rejects_inverted_validity_interval() {
         let decoded = DecodedCredential::new(
@@ -0,0 +1,153 @@
+use crate::domain::{Uri, VC_VERIFIABLE_CREDENTIAL};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Timestamp};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum DelegationError {
+    #[error("issuer delegation requires at least one credential type")]
+    NoCredentialTypes,
+
+    #[error("issuer delegation expiration is not in the future")]
+    InvalidExpiration,
+
+    #[error("issuer delegation expired")]
+    Expired,
+
+    #[error("issuer delegation unknown")]
+    UnknownIssuer,
+
+    #[error("credential type not granted to the issuer: {0}")]
+    CredentialTypeNotGranted(Uri),
+
+    #[error("credential has no type besides the base type")]
+    BaseTypeOnly,
+
+    #[error("anchored credential cannot be revoked by a delegated issuer")]
+    AnchoredCredential,
+}
+
+/// A delegation of the authority allowing an address to issue and revoke credentials of
+/// some types on its behalf.
+#[cw_serde]
+pub struct IssuerDelegation {
+    /// The delegated address.
+    pub address: Addr,
+    /// The credential types the delegate is allowed to issue and revoke.
+    pub credential_types: Vec<Uri>,
+    /// Optional instant from which the delegation no longer applies.
+    pub expires: Option<Timestamp>,
+}
+
+impl IssuerDelegation {
+    pub fn new(
+        address: Addr,
+        credential_types: Vec<Uri>,
+        expires: Option<Timestamp>,
+        now: Timestamp,
+    ) -> Result<Self, DelegationError> {
+        if credential_types.is_empty() {
+            return Err(DelegationError::NoCredentialTypes);
+        }
+        if expires.is_some_and(|expires| expires <= now) {
+            return Err(DelegationError::InvalidExpiration);
+        }
+
+        let mut credential_types = credential_types;
+        credential_types.sort();
+        credential_types.dedup();
+
+        Ok(Self {
+            address,
+            credential_types,
+            expires,
+        })
+    }
+
+    pub fn is_expired(&self, now: Timestamp) -> bool {
+        self.expires.is_some_and(|expires| expires <= now)
+    }
+
+    /// Check the delegate may act on a credential of the given types.
+    ///
+    /// The credential must have at least one type besides the base `VerifiableCredential`
+    /// type, and every such type must be granted.
+    pub fn authorize(&self, types: &[Uri]) -> Result<(), DelegationError> {
+        let mut specific_types = types
+            .iter()
+            .filter(|credential_type| credential_type.as_str() != VC_VERIFIABLE_CREDENTIAL)
+            .peekable();
+        if specific_types.peek().is_none() {
+            return Err(DelegationError::BaseTypeOnly);
+        }
+
+        match specific_types
+            .find(|credential_type| !self.credential_types.contains(credential_type))
+        {
+            Some(credential_type) => Err(DelegationError::CredentialTypeNotGranted(
+                credential_type.clone(),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DelegationError, IssuerDelegation};
+    use cosmwasm_std::{Addr, Timestamp};
+
+    const VC: &str = "https://www.w3.org/2018/credentials#VerifiableCredential";
+    const MEMBER: &str = "https://example.com/types/Member";
+    const ADMIN: &str = "https://example.com/types/Admin";
+
+    #[test]
+    fn new_rejects_empty_types_and_past_expirations() {
+        let now = Timestamp::from_seconds(10);
+
+        assert_eq!(
+            IssuerDelegation::new(Addr::unchecked("delegate"), vec![], None, now),
+            Err(DelegationError::NoCredentialTypes)
+        );
+        assert_eq!(
+            IssuerDelegation::new(
+                Addr::unchecked("delegate"),
+                vec![MEMBER.to_string()],
+                Some(now),
+                now
+            ),
+            Err(DelegationError::InvalidExpiration)
+        );
+    }
+
+    #[test]
+    fn authorize_requires_every_type_but_the_base_type_until_expiration() {
+        let delegation = IssuerDelegation::new(
+            Addr::unchecked("delegate"),
+            vec![MEMBER.to_string(), MEMBER.to_string()],
+            Some(Timestamp::from_seconds(20)),
+            Timestamp::from_seconds(10),
+        )
+        .expect("delegation should build");
+
+        assert_eq!(delegation.credential_types, vec![MEMBER.to_string()]);
+        assert_eq!(
+            delegation.authorize(&[VC.to_string(), MEMBER.to_string()]),
+            Ok(())
+        );
+        assert_eq!(
+            delegation.authorize(&[VC.to_string(), MEMBER.to_string(), ADMIN.to_string()]),
+            Err(DelegationError::CredentialTypeNotGranted(ADMIN.to_string()))
+        );
+        assert_eq!(
+            delegation.authorize(&[VC.to_string()]),
+            Err(DelegationError::BaseTypeOnly)
+        );
+        assert_eq!(
+            delegation.authorize(&[]),
+            Err(DelegationError::BaseTypeOnly)
+        );
+        assert!(!delegation.is_expired(Timestamp::from_seconds(19)));
+        assert!(delegation.is_expired(Timestamp::from_seconds(20)));
+    }
+}
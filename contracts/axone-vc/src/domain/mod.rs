@@ -1,5 +1,20 @@
 mod authority;
 mod credential;
+mod issuer;
+mod proof;
+mod shape;
+mod status;
 
 pub use authority::Authority;
-pub use credential::{Credential, CredentialError, Uri};
+pub(crate) use credential::VC_VERIFIABLE_CREDENTIAL;
+pub use credential::{Credential, CredentialError, DataModelVersion, Uri};
+pub use issuer::{DelegationError, IssuerDelegation};
+pub use proof::{Cryptosuite, DataIntegrityProof, ProofError, SignatureScheme, VerificationKey};
+pub use shape::{
+    CredentialShape, NodeKind, PropertyShape, ShapeConstraint, ShapeError, ShapeReport,
+    ShapeViolation,
+};
+pub use status::{
+    status_list_length, CredentialStatus, RevocationReasonCode, StatusListError,
+    StatusListLocation, StatusPurpose, MIN_STATUS_LIST_LENGTH,
+};
@@ -0,0 +1,292 @@
+use crate::{domain::Uri, translation::DecodedProof};
+use cosmwasm_schema::cw_serde;
+use thiserror::Error;
+
+const DID_KEY_PREFIX: &str = "did:key:";
+const MULTIBASE_BASE58BTC: char = 'z';
+const ASSERTION_METHOD: &str = "https://w3id.org/security#assertionMethod";
+const SIGNATURE_LENGTH: usize = 64;
+
+const ED25519_PUB_MULTICODEC: [u8; 2] = [0xed, 0x01];
+const SECP256K1_PUB_MULTICODEC: [u8; 2] = [0xe7, 0x01];
+const P256_PUB_MULTICODEC: [u8; 2] = [0x80, 0x24];
+const ED25519_PUBKEY_LENGTH: usize = 32;
+const COMPRESSED_EC_PUBKEY_LENGTH: usize = 33;
+
+/// Data Integrity cryptosuites whose proofs the contract is able to verify.
+#[cw_serde]
+#[derive(Copy)]
+pub enum Cryptosuite {
+    /// EdDSA over Ed25519 keys, with RDFC-1.0 canonicalization and SHA-256 hashing.
+    #[serde(rename = "eddsa-rdfc-2022")]
+    EddsaRdfc2022,
+    /// ECDSA over P-256 keys, with RDFC-1.0 canonicalization and SHA-256 hashing.
+    ///
+    /// The suite only defines P-256 and P-384 keys, secp256k1 ones being rejected.
+    #[serde(rename = "ecdsa-rdfc-2019")]
+    EcdsaRdfc2019,
+}
+
+impl Cryptosuite {
+    /// The cryptosuite identifier, as given in proofs.
+    pub fn name(self) -> &'static str {
+        match self {
+            Cryptosuite::EddsaRdfc2022 => "eddsa-rdfc-2022",
+            Cryptosuite::EcdsaRdfc2019 => "ecdsa-rdfc-2019",
+        }
+    }
+
+    fn parse(name: &str) -> Result<Self, ProofError> {
+        match name {
+            "eddsa-rdfc-2022" => Ok(Cryptosuite::EddsaRdfc2022),
+            "ecdsa-rdfc-2019" => Ok(Cryptosuite::EcdsaRdfc2019),
+            _ => Err(ProofError::UnsupportedCryptosuite(name.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum ProofError {
+    #[error("proof cryptosuite is not supported: {0}")]
+    UnsupportedCryptosuite(String),
+
+    #[error("proof purpose is not supported: {0}")]
+    UnsupportedProofPurpose(String),
+
+    #[error("proof value missing")]
+    MissingProofValue,
+
+    #[error("proof value is not a base58btc multibase signature")]
+    InvalidProofValue,
+
+    #[error("proof verification method cannot be resolved from the issuer: {0}")]
+    UnresolvableVerificationMethod(String),
+
+    #[error("proof verification method key does not match the cryptosuite")]
+    KeyCryptosuiteMismatch,
+
+    #[error("proof signature is invalid: {0}")]
+    InvalidSignature(String),
+}
+
+/// A verified Data Integrity proof, as recorded with an anchored credential.
+#[cw_serde]
+pub struct DataIntegrityProof {
+    /// The cryptosuite the proof was produced with.
+    pub cryptosuite: Cryptosuite,
+    /// The verification method the signature verifies against.
+    pub verification_method: Uri,
+    /// The purpose of the proof.
+    pub proof_purpose: Uri,
+    /// Optional creation date of the proof, as given by the issuer.
+    pub created: Option<String>,
+    /// The multibase encoded signature.
+    pub proof_value: String,
+}
+
+impl TryFrom<&DecodedProof> for DataIntegrityProof {
+    type Error = ProofError;
+
+    fn try_from(decoded: &DecodedProof) -> Result<Self, Self::Error> {
+        let cryptosuite = Cryptosuite::parse(decoded.cryptosuite())?;
+        if decoded.proof_purpose() != ASSERTION_METHOD {
+            return Err(ProofError::UnsupportedProofPurpose(
+                decoded.proof_purpose().clone(),
+            ));
+        }
+        let proof_value = decoded
+            .proof_value()
+            .clone()
+            .ok_or(ProofError::MissingProofValue)?;
+
+        Ok(Self {
+            cryptosuite,
+            verification_method: decoded.verification_method().clone(),
+            proof_purpose: decoded.proof_purpose().clone(),
+            created: decoded.created().clone(),
+            proof_value,
+        })
+    }
+}
+
+impl DataIntegrityProof {
+    /// Decode the raw signature carried by the proof value.
+    pub fn signature(&self) -> Result<Vec<u8>, ProofError> {
+        let signature = decode_multibase(&self.proof_value).ok_or(ProofError::InvalidProofValue)?;
+        if signature.len() != SIGNATURE_LENGTH {
+            return Err(ProofError::InvalidProofValue);
+        }
+
+        Ok(signature)
+    }
+
+    /// Resolve the key of the verification method from the DID of the credential issuer.
+    ///
+    /// Only `did:key` issuers are resolvable, the verification method being expected as
+    /// `did:key:<multibase>#<multibase>`.
+    pub fn verification_key(&self, issuer: &str) -> Result<VerificationKey, ProofError> {
+        let unresolvable =
+            || ProofError::UnresolvableVerificationMethod(self.verification_method.clone());
+        let (did, fragment) = self
+            .verification_method
+            .split_once('#')
+            .ok_or_else(unresolvable)?;
+        let multibase = did.strip_prefix(DID_KEY_PREFIX).ok_or_else(unresolvable)?;
+        if did != issuer || fragment != multibase {
+            return Err(unresolvable());
+        }
+
+        let key = decode_multibase(multibase).ok_or_else(unresolvable)?;
+        let (scheme, public_key) = match key.split_at_checked(2).ok_or_else(unresolvable)? {
+            (codec, public_key)
+                if codec == ED25519_PUB_MULTICODEC && public_key.len() == ED25519_PUBKEY_LENGTH =>
+            {
+                (SignatureScheme::Ed25519, public_key)
+            }
+            // No cryptosuite the contract verifies defines secp256k1 keys.
+            (codec, public_key)
+                if codec == SECP256K1_PUB_MULTICODEC
+                    && public_key.len() == COMPRESSED_EC_PUBKEY_LENGTH =>
+            {
+                return Err(ProofError::KeyCryptosuiteMismatch)
+            }
+            (codec, public_key)
+                if codec == P256_PUB_MULTICODEC
+                    && public_key.len() == COMPRESSED_EC_PUBKEY_LENGTH =>
+            {
+                (SignatureScheme::P256Sha256, public_key)
+            }
+            _ => return Err(unresolvable()),
+        };
+
+        let compatible = match self.cryptosuite {
+            Cryptosuite::EddsaRdfc2022 => scheme == SignatureScheme::Ed25519,
+            Cryptosuite::EcdsaRdfc2019 => scheme == SignatureScheme::P256Sha256,
+        };
+        if !compatible {
+            return Err(ProofError::KeyCryptosuiteMismatch);
+        }
+
+        Ok(VerificationKey {
+            scheme,
+            public_key: public_key.to_vec(),
+        })
+    }
+}
+
+/// Signature algorithms verification methods resolve to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SignatureScheme {
+    /// Ed25519 over the hash data itself.
+    Ed25519,
+    /// ECDSA over P-256 with the SHA-256 digest of the hash data.
+    P256Sha256,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct VerificationKey {
+    pub scheme: SignatureScheme,
+    pub public_key: Vec<u8>,
+}
+
+fn decode_multibase(value: &str) -> Option<Vec<u8>> {
+    let encoded = value.strip_prefix(MULTIBASE_BASE58BTC)?;
+    bs58::decode(encoded).into_vec().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Cryptosuite, DataIntegrityProof, ProofError, SignatureScheme, VerificationKey,
+        ASSERTION_METHOD,
+    };
+
+    fn did_key(codec: [u8; 2], public_key: &[u8]) -> String {
+        let mut key = codec.to_vec();
+        key.extend_from_slice(public_key);
+        format!("did:key:z{}", bs58::encode(key).into_string())
+    }
+
+    fn proof(cryptosuite: Cryptosuite, verification_method: String) -> DataIntegrityProof {
+        DataIntegrityProof {
+            cryptosuite,
+            verification_method,
+            proof_purpose: ASSERTION_METHOD.to_string(),
+            created: None,
+            proof_value: format!("z{}", bs58::encode([7u8; 64]).into_string()),
+        }
+    }
+
+    #[test]
+    fn verification_key_resolves_did_key_issuers() {
+        let cases = [
+            (
+                Cryptosuite::EddsaRdfc2022,
+                [0xed, 0x01],
+                vec![1u8; 32],
+                SignatureScheme::Ed25519,
+            ),
+            (
+                Cryptosuite::EcdsaRdfc2019,
+                [0x80, 0x24],
+                vec![3u8; 33],
+                SignatureScheme::P256Sha256,
+            ),
+        ];
+
+        for (cryptosuite, codec, public_key, scheme) in cases {
+            let issuer = did_key(codec, &public_key);
+            let multibase = issuer.trim_start_matches("did:key:").to_string();
+            let proof = proof(cryptosuite, format!("{issuer}#{multibase}"));
+
+            assert_eq!(
+                proof.verification_key(&issuer),
+                Ok(VerificationKey { scheme, public_key })
+            );
+            assert_eq!(proof.signature(), Ok(vec![7u8; 64]));
+        }
+    }
+
+    #[test]
+    fn verification_key_rejects_unrelated_or_mismatching_keys() {
+        let issuer = did_key([0xed, 0x01], &[1u8; 32]);
+        let multibase = issuer.trim_start_matches("did:key:").to_string();
+        let other = did_key([0xed, 0x01], &[4u8; 32]);
+
+        for verification_method in [
+            format!("{other}#{}", other.trim_start_matches("did:key:")),
+            format!("{issuer}#key-1"),
+            issuer.clone(),
+        ] {
+            assert_eq!(
+                proof(Cryptosuite::EddsaRdfc2022, verification_method.clone())
+                    .verification_key(&issuer),
+                Err(ProofError::UnresolvableVerificationMethod(
+                    verification_method
+                ))
+            );
+        }
+
+        assert_eq!(
+            proof(Cryptosuite::EcdsaRdfc2019, format!("{issuer}#{multibase}"))
+                .verification_key(&issuer),
+            Err(ProofError::KeyCryptosuiteMismatch)
+        );
+
+        let secp256k1 = did_key([0xe7, 0x01], &[2u8; 33]);
+        assert_eq!(
+            proof(
+                Cryptosuite::EcdsaRdfc2019,
+                format!("{secp256k1}#{}", secp256k1.trim_start_matches("did:key:"))
+            )
+            .verification_key(&secp256k1),
+            Err(ProofError::KeyCryptosuiteMismatch)
+        );
+
+        let mut short = proof(Cryptosuite::EddsaRdfc2022, format!("{issuer}#{multibase}"));
+        short.proof_value = format!("z{}", bs58::encode([7u8; 32]).into_string());
+        assert_eq!(short.signature(), Err(ProofError::InvalidProofValue));
+        short.proof_value = "uAAAA".to_string();
+        assert_eq!(short.signature(), Err(ProofError::InvalidProofValue));
+    }
+}
@@ -0,0 +1,391 @@
+use crate::{
+    domain::Uri,
+    translation::{DecodedClaim, DecodedClaimValue},
+};
+use cosmwasm_schema::cw_serde;
+use std::{collections::HashSet, fmt};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum ShapeError {
+    #[error("shape requires at least one property")]
+    NoProperties,
+
+    #[error("shape property declared more than once: {0}")]
+    DuplicateProperty(Uri),
+
+    #[error("shape property cardinality is invalid: {0}")]
+    InvalidCardinality(Uri),
+
+    #[error("shape property allows no value: {0}")]
+    NoAllowedValues(Uri),
+
+    #[error("shape unknown")]
+    UnknownShape,
+
+    #[error("credential does not conform to its shapes: {0}")]
+    NonConforming(ShapeReport),
+}
+
+/// Kinds of RDF terms a property value may be required to be, as `sh:nodeKind` does.
+#[cw_serde]
+#[derive(Copy)]
+pub enum NodeKind {
+    /// An IRI, `sh:IRI`.
+    #[serde(rename = "iri")]
+    Iri,
+    /// A blank node, `sh:BlankNode`.
+    #[serde(rename = "blank_node")]
+    BlankNode,
+    /// A literal, `sh:Literal`.
+    #[serde(rename = "literal")]
+    Literal,
+}
+
+impl NodeKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            NodeKind::Iri => "iri",
+            NodeKind::BlankNode => "blank_node",
+            NodeKind::Literal => "literal",
+        }
+    }
+
+    fn matches(self, value: &DecodedClaimValue) -> bool {
+        matches!(
+            (self, value),
+            (NodeKind::Iri, DecodedClaimValue::Iri(_))
+                | (NodeKind::BlankNode, DecodedClaimValue::BlankNode)
+                | (NodeKind::Literal, DecodedClaimValue::Literal { .. })
+        )
+    }
+}
+
+/// Constraints on the values of a property of the credential subject, a subset of the
+/// SHACL Core property shape constraints.
+#[cw_serde]
+pub struct PropertyShape {
+    /// The predicate IRI of the property, `sh:path`.
+    pub path: Uri,
+    /// Minimum number of values of the property, `sh:minCount`.
+    #[serde(default)]
+    pub min_count: Option<u32>,
+    /// Maximum number of values of the property, `sh:maxCount`.
+    #[serde(default)]
+    pub max_count: Option<u32>,
+    /// Datatype IRI every value must be a literal of, `sh:datatype`.
+    #[serde(default)]
+    pub datatype: Option<Uri>,
+    /// Kind of RDF term every value must be, `sh:nodeKind`.
+    #[serde(default)]
+    pub node_kind: Option<NodeKind>,
+    /// Values the property is restricted to, `sh:in`, matched against the IRI of IRI values
+    /// and the lexical form of literal values.
+    #[serde(default)]
+    pub allowed_values: Option<Vec<String>>,
+}
+
+impl PropertyShape {
+    fn validate(&self, values: &[&DecodedClaimValue]) -> Vec<ShapeConstraint> {
+        let mut violations = Vec::new();
+        let count = values.len() as u64;
+
+        if let Some(min_count) = self.min_count.filter(|min| count < u64::from(*min)) {
+            violations.push(ShapeConstraint::MinCount(min_count));
+        }
+        if let Some(max_count) = self.max_count.filter(|max| count > u64::from(*max)) {
+            violations.push(ShapeConstraint::MaxCount(max_count));
+        }
+        if let Some(datatype) = self.datatype.as_ref().filter(|expected| {
+            values.iter().any(|value| {
+                !matches!(value, DecodedClaimValue::Literal { datatype, .. } if datatype == *expected)
+            })
+        }) {
+            violations.push(ShapeConstraint::Datatype(datatype.clone()));
+        }
+        if let Some(node_kind) = self
+            .node_kind
+            .filter(|kind| values.iter().any(|value| !kind.matches(value)))
+        {
+            violations.push(ShapeConstraint::NodeKind(node_kind));
+        }
+        if let Some(allowed_values) = self.allowed_values.as_ref() {
+            if values.iter().any(|value| match value {
+                DecodedClaimValue::Iri(value) | DecodedClaimValue::Literal { value, .. } => {
+                    !allowed_values.contains(value)
+                }
+                DecodedClaimValue::BlankNode => true,
+            }) {
+                violations.push(ShapeConstraint::In);
+            }
+        }
+
+        violations
+    }
+}
+
+/// The shape the subject of the credentials of a type must conform to at issuance.
+#[cw_serde]
+pub struct CredentialShape {
+    /// The credential type URI the shape applies to.
+    pub credential_type: Uri,
+    /// The constraints on the properties of the credential subject.
+    pub properties: Vec<PropertyShape>,
+}
+
+impl CredentialShape {
+    pub fn new(credential_type: Uri, properties: Vec<PropertyShape>) -> Result<Self, ShapeError> {
+        if properties.is_empty() {
+            return Err(ShapeError::NoProperties);
+        }
+
+        let mut paths = HashSet::new();
+        for property in &properties {
+            if !paths.insert(property.path.as_str()) {
+                return Err(ShapeError::DuplicateProperty(property.path.clone()));
+            }
+            if matches!((property.min_count, property.max_count), (Some(min), Some(max)) if min > max)
+            {
+                return Err(ShapeError::InvalidCardinality(property.path.clone()));
+            }
+            if property
+                .allowed_values
+                .as_ref()
+                .is_some_and(|values| values.is_empty())
+            {
+                return Err(ShapeError::NoAllowedValues(property.path.clone()));
+            }
+        }
+
+        Ok(Self {
+            credential_type,
+            properties,
+        })
+    }
+
+    /// Return the constraints the claims about a credential subject do not satisfy.
+    pub fn validate(&self, claims: &[DecodedClaim]) -> Vec<ShapeViolation> {
+        self.properties
+            .iter()
+            .flat_map(|property| {
+                let values: Vec<_> = claims
+                    .iter()
+                    .filter(|claim| claim.predicate == property.path)
+                    .map(|claim| &claim.value)
+                    .collect();
+
+                property
+                    .validate(&values)
+                    .into_iter()
+                    .map(|constraint| ShapeViolation {
+                        credential_type: self.credential_type.clone(),
+                        path: property.path.clone(),
+                        constraint,
+                    })
+            })
+            .collect()
+    }
+}
+
+/// A constraint of a property shape.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ShapeConstraint {
+    MinCount(u32),
+    MaxCount(u32),
+    Datatype(Uri),
+    NodeKind(NodeKind),
+    In,
+}
+
+impl fmt::Display for ShapeConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShapeConstraint::MinCount(count) => write!(f, "min_count {count}"),
+            ShapeConstraint::MaxCount(count) => write!(f, "max_count {count}"),
+            ShapeConstraint::Datatype(datatype) => write!(f, "datatype {datatype}"),
+            ShapeConstraint::NodeKind(kind) => write!(f, "node_kind {}", kind.name()),
+            ShapeConstraint::In => write!(f, "allowed_values"),
+        }
+    }
+}
+
+/// A constraint of the shape of a credential type its subject does not satisfy.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShapeViolation {
+    pub credential_type: Uri,
+    pub path: Uri,
+    pub constraint: ShapeConstraint,
+}
+
+impl fmt::Display for ShapeViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}: {}",
+            self.credential_type, self.path, self.constraint
+        )
+    }
+}
+
+/// The violations of the shapes a credential must conform to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShapeReport(pub Vec<ShapeViolation>);
+
+impl fmt::Display for ShapeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, violation) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{violation}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CredentialShape, NodeKind, PropertyShape, ShapeConstraint, ShapeError, ShapeReport,
+        ShapeViolation,
+    };
+    use crate::translation::{DecodedClaim, DecodedClaimValue};
+
+    const MEMBER: &str = "https://example.com/types/Member";
+    const NAME: &str = "https://schema.org/name";
+    const LEVEL: &str = "https://example.com/vocab#level";
+    const XSD_STRING: &str = "http://www.w3.org/2001/XMLSchema#string";
+
+    fn property(path: &str) -> PropertyShape {
+        PropertyShape {
+            path: path.to_string(),
+            min_count: None,
+            max_count: None,
+            datatype: None,
+            node_kind: None,
+            allowed_values: None,
+        }
+    }
+
+    fn literal(predicate: &str, value: &str) -> DecodedClaim {
+        DecodedClaim {
+            predicate: predicate.to_string(),
+            value: DecodedClaimValue::Literal {
+                value: value.to_string(),
+                datatype: XSD_STRING.to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn new_rejects_inconsistent_shapes() {
+        assert_eq!(
+            CredentialShape::new(MEMBER.to_string(), vec![]),
+            Err(ShapeError::NoProperties)
+        );
+        assert_eq!(
+            CredentialShape::new(MEMBER.to_string(), vec![property(NAME), property(NAME)]),
+            Err(ShapeError::DuplicateProperty(NAME.to_string()))
+        );
+        assert_eq!(
+            CredentialShape::new(
+                MEMBER.to_string(),
+                vec![PropertyShape {
+                    min_count: Some(2),
+                    max_count: Some(1),
+                    ..property(NAME)
+                }]
+            ),
+            Err(ShapeError::InvalidCardinality(NAME.to_string()))
+        );
+        assert_eq!(
+            CredentialShape::new(
+                MEMBER.to_string(),
+                vec![PropertyShape {
+                    allowed_values: Some(vec![]),
+                    ..property(NAME)
+                }]
+            ),
+            Err(ShapeError::NoAllowedValues(NAME.to_string()))
+        );
+    }
+
+    #[test]
+    fn validate_reports_every_failed_constraint() {
+        let shape = CredentialShape::new(
+            MEMBER.to_string(),
+            vec![
+                PropertyShape {
+                    min_count: Some(1),
+                    max_count: Some(1),
+                    datatype: Some(XSD_STRING.to_string()),
+                    ..property(NAME)
+                },
+                PropertyShape {
+                    min_count: Some(1),
+                    node_kind: Some(NodeKind::Literal),
+                    allowed_values: Some(vec!["gold".to_string(), "silver".to_string()]),
+                    ..property(LEVEL)
+                },
+            ],
+        )
+        .expect("shape should build");
+
+        assert_eq!(
+            shape.validate(&[literal(NAME, "Alice"), literal(LEVEL, "gold")]),
+            vec![]
+        );
+
+        let violations = shape.validate(&[
+            literal(NAME, "Alice"),
+            literal(NAME, "Bob"),
+            DecodedClaim {
+                predicate: LEVEL.to_string(),
+                value: DecodedClaimValue::Iri("https://example.com/levels/gold".to_string()),
+            },
+        ]);
+        assert_eq!(
+            violations,
+            vec![
+                ShapeViolation {
+                    credential_type: MEMBER.to_string(),
+                    path: NAME.to_string(),
+                    constraint: ShapeConstraint::MaxCount(1),
+                },
+                ShapeViolation {
+                    credential_type: MEMBER.to_string(),
+                    path: LEVEL.to_string(),
+                    constraint: ShapeConstraint::NodeKind(NodeKind::Literal),
+                },
+                ShapeViolation {
+                    credential_type: MEMBER.to_string(),
+                    path: LEVEL.to_string(),
+                    constraint: ShapeConstraint::In,
+                },
+            ]
+        );
+        assert_eq!(
+            ShapeReport(violations).to_string(),
+            format!("{MEMBER} {NAME}: max_count 1; {MEMBER} {LEVEL}: node_kind literal; {MEMBER} {LEVEL}: allowed_values")
+        );
+
+        assert_eq!(
+            shape.validate(&[DecodedClaim {
+                predicate: NAME.to_string(),
+                value: DecodedClaimValue::BlankNode,
+            }]),
+            vec![
+                ShapeViolation {
+                    credential_type: MEMBER.to_string(),
+                    path: NAME.to_string(),
+                    constraint: ShapeConstraint::Datatype(XSD_STRING.to_string()),
+                },
+                ShapeViolation {
+                    credential_type: MEMBER.to_string(),
+                    path: LEVEL.to_string(),
+                    constraint: ShapeConstraint::MinCount(1),
+                },
+            ]
+        );
+    }
+}
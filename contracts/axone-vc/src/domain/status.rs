@@ -0,0 +1,245 @@
+use crate::{domain::Authority, translation::CredentialStatusEntry};
+use cosmwasm_schema::cw_serde;
+use oxrdf::NamedNode;
+use thiserror::Error;
+
+/// The minimum length of a published status list, in entries, as required by the Bitstring
+/// Status List specification.
+///
+/// The specification asks for this length to provide group privacy, which the contract does
+/// not provide: indexes are allocated sequentially, and every credential is issued in a public
+/// transaction disclosing its status entries.
+pub const MIN_STATUS_LIST_LENGTH: u64 = 131_072;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum StatusListError {
+    #[error("status list URL must be an absolute HTTP(S) URL without query nor fragment: {0}")]
+    InvalidUrl(String),
+}
+
+/// Where the status lists of the authority are published, the list of each purpose being
+/// identified as `<location>/<purpose>`.
+#[cw_serde]
+pub struct StatusListLocation(String);
+
+impl StatusListLocation {
+    /// The location of the status lists under the authority DID,
+    /// `<authority DID>/credentials/status`.
+    ///
+    /// The `did:pkh` method resolves no such path, the lists then being only readable through
+    /// the `StatusList` query of the contract.
+    pub fn of_authority(authority: &Authority) -> Self {
+        Self(format!("{}/credentials/status", authority.did()))
+    }
+
+    /// The location of the status lists at an HTTP(S) URL, where the results of the
+    /// `StatusList` query are expected to be served. A trailing slash is ignored.
+    pub fn url(url: &str) -> Result<Self, StatusListError> {
+        let location = url.strip_suffix('/').unwrap_or(url);
+        let authority = location
+            .strip_prefix("https://")
+            .or_else(|| location.strip_prefix("http://"));
+        if authority.is_none_or(|authority| authority.is_empty() || authority.starts_with('/'))
+            || location.contains(['?', '#'])
+            || NamedNode::new(location).is_err()
+        {
+            return Err(StatusListError::InvalidUrl(url.to_string()));
+        }
+
+        Ok(Self(location.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Purposes of the Bitstring Status Lists published by the contract.
+#[cw_serde]
+#[derive(Copy)]
+pub enum StatusPurpose {
+    /// A set bit marks a credential as revoked, which is terminal.
+    #[serde(rename = "revocation")]
+    Revocation,
+    /// A set bit marks a credential as suspended.
+    #[serde(rename = "suspension")]
+    Suspension,
+}
+
+impl StatusPurpose {
+    /// Every purpose an issued credential gets a status entry for.
+    pub const ALL: [StatusPurpose; 2] = [StatusPurpose::Revocation, StatusPurpose::Suspension];
+
+    /// The `statusPurpose` value of the purpose.
+    pub fn name(self) -> &'static str {
+        match self {
+            StatusPurpose::Revocation => "revocation",
+            StatusPurpose::Suspension => "suspension",
+        }
+    }
+
+    /// The identifier of the status list credential published at `location` for this purpose.
+    ///
+    /// Form: `<location>/<purpose>`
+    pub fn status_list_credential(self, location: &StatusListLocation) -> String {
+        format!("{}/{}", location.as_str(), self.name())
+    }
+
+    /// The status entry of the credential at `index` in the list of this purpose.
+    pub fn status_entry(self, location: &StatusListLocation, index: u64) -> CredentialStatusEntry {
+        let status_list_credential = self.status_list_credential(location);
+        CredentialStatusEntry::new(
+            format!("{status_list_credential}#{index}"),
+            self.name().to_string(),
+            index,
+            status_list_credential,
+        )
+    }
+}
+
+/// Status of a credential of the active credential set.
+#[cw_serde]
+#[derive(Copy, Default)]
+pub enum CredentialStatus {
+    /// The credential is in force.
+    #[serde(rename = "active")]
+    #[default]
+    Active,
+    /// The credential is temporarily suspended, until reinstated.
+    #[serde(rename = "suspended")]
+    Suspended,
+}
+
+impl CredentialStatus {
+    /// The status name, as emitted in events.
+    pub fn name(self) -> &'static str {
+        match self {
+            CredentialStatus::Active => "active",
+            CredentialStatus::Suspended => "suspended",
+        }
+    }
+}
+
+/// Reason codes a revocation may be recorded with.
+#[cw_serde]
+#[derive(Copy)]
+pub enum RevocationReasonCode {
+    /// No specific reason is given.
+    #[serde(rename = "unspecified")]
+    Unspecified,
+    /// A key the credential relies on has been compromised.
+    #[serde(rename = "key_compromise")]
+    KeyCompromise,
+    /// The credential has been replaced by another one.
+    #[serde(rename = "superseded")]
+    Superseded,
+    /// The credential subject no longer holds the attested claims.
+    #[serde(rename = "privilege_withdrawn")]
+    PrivilegeWithdrawn,
+    /// The activity the credential attested of has ceased.
+    #[serde(rename = "cessation_of_operation")]
+    CessationOfOperation,
+    /// The credential should never have been issued.
+    #[serde(rename = "issued_in_error")]
+    IssuedInError,
+}
+
+impl RevocationReasonCode {
+    /// The reason code name, as emitted in events.
+    pub fn name(self) -> &'static str {
+        match self {
+            RevocationReasonCode::Unspecified => "unspecified",
+            RevocationReasonCode::KeyCompromise => "key_compromise",
+            RevocationReasonCode::Superseded => "superseded",
+            RevocationReasonCode::PrivilegeWithdrawn => "privilege_withdrawn",
+            RevocationReasonCode::CessationOfOperation => "cessation_of_operation",
+            RevocationReasonCode::IssuedInError => "issued_in_error",
+        }
+    }
+}
+
+/// The number of entries of the status lists once `allocated` indexes are assigned.
+pub fn status_list_length(allocated: u64) -> u64 {
+    allocated
+        .div_ceil(MIN_STATUS_LIST_LENGTH)
+        .max(1)
+        .saturating_mul(MIN_STATUS_LIST_LENGTH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        status_list_length, StatusListError, StatusListLocation, StatusPurpose,
+        MIN_STATUS_LIST_LENGTH,
+    };
+    use crate::domain::Authority;
+    use bech32::{Bech32, Hrp};
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn status_entries_reference_the_list_of_their_purpose() {
+        let account_addr = bech32::encode::<Bech32>(Hrp::parse("axone").unwrap(), &[0x42; 20])
+            .expect("valid address");
+        let authority = Authority::new("axone-localnet-1", &Addr::unchecked(account_addr))
+            .expect("authority should build");
+        let location = StatusListLocation::of_authority(&authority);
+        let list = format!("{}/credentials/status/suspension", authority.did());
+
+        assert_eq!(
+            StatusPurpose::Suspension.status_list_credential(&location),
+            list
+        );
+        assert_eq!(
+            StatusPurpose::Suspension.status_entry(&location, 42),
+            crate::translation::CredentialStatusEntry::new(
+                format!("{list}#42"),
+                "suspension".to_string(),
+                42,
+                list,
+            )
+        );
+    }
+
+    #[test]
+    fn status_list_locations_must_be_http_urls() {
+        for (url, location) in [
+            ("https://example.org/status", "https://example.org/status"),
+            ("https://example.org/status/", "https://example.org/status"),
+            ("http://localhost:8080", "http://localhost:8080"),
+        ] {
+            assert_eq!(
+                StatusListLocation::url(url).map(|location| location.as_str().to_string()),
+                Ok(location.to_string()),
+                "{url}"
+            );
+        }
+
+        for url in [
+            "",
+            "https://",
+            "https:///status",
+            "ftp://example.org/status",
+            "did:example:issuer",
+            "https://example.org/status?purpose=",
+            "https://example.org/status#list",
+            "https://example.org/sta tus",
+        ] {
+            assert_eq!(
+                StatusListLocation::url(url),
+                Err(StatusListError::InvalidUrl(url.to_string())),
+                "{url}"
+            );
+        }
+    }
+
+    #[test]
+    fn status_list_length_grows_by_minimum_length_steps() {
+        for (allocated, length) in [
+            (0, MIN_STATUS_LIST_LENGTH),
+            (MIN_STATUS_LIST_LENGTH, MIN_STATUS_LIST_LENGTH),
+            (MIN_STATUS_LIST_LENGTH + 1, 2 * MIN_STATUS_LIST_LENGTH),
+        ] {
+            assert_eq!(status_list_length(allocated), length, "{allocated}");
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use crate::{
-    domain::CredentialError,
-    services::{IssueCredentialError, RevokeCredentialError},
-    translation::CredentialDecodingError,
+    domain::{CredentialError, DelegationError, ShapeError, StatusListError},
+    services::{
+        AnchorCredentialError, CredentialStatusError, IssueCredentialError, RevokeCredentialError,
+    },
+    translation::{CredentialDecodingError, CredentialEncodingError},
 };
 use abstract_app::sdk::AbstractSdkError;
 use abstract_app::std::AbstractError;
@@ -30,12 +32,30 @@ pub enum AxoneVcError {
     #[error(transparent)]
     IssueCredential(#[from] IssueCredentialError),
 
+    #[error(transparent)]
+    AnchorCredential(#[from] AnchorCredentialError),
+
     #[error(transparent)]
     RevokeCredential(#[from] RevokeCredentialError),
 
+    #[error(transparent)]
+    CredentialStatus(#[from] CredentialStatusError),
+
+    #[error(transparent)]
+    Delegation(#[from] DelegationError),
+
+    #[error(transparent)]
+    Shape(#[from] ShapeError),
+
+    #[error(transparent)]
+    StatusList(#[from] StatusListError),
+
     #[error(transparent)]
     Credential(#[from] CredentialError),
 
     #[error(transparent)]
     CredentialDecode(#[from] CredentialDecodingError),
+
+    #[error(transparent)]
+    CredentialEncode(#[from] CredentialEncodingError),
 }
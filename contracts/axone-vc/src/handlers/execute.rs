@@ -1,13 +1,19 @@
 use crate::{
     contract::{AxoneVc, AxoneVcResult},
-    msg::AxoneVcExecuteMsg,
+    domain::IssuerDelegation,
+    msg::{AxoneVcExecuteMsg, PropertyShape, RevocationReasonCode},
     services::issue_credential,
-    RESPONSE_KEY_IDENTIFIER, RESPONSE_KEY_ISSUER, RESPONSE_KEY_SUBJECT, RESPONSE_KEY_TYPES,
+    RESPONSE_KEY_DELEGATE, RESPONSE_KEY_IDENTIFIER, RESPONSE_KEY_ISSUER, RESPONSE_KEY_STATUS,
+    RESPONSE_KEY_SUBJECT, RESPONSE_KEY_TYPES,
 };
 
-use crate::services::revoke_credential;
+use crate::services::{
+    anchor_credential, delegated_issuer, grant_issuer, register_shape, reinstate_credential,
+    revoke_credential, revoke_issuer, suspend_credential, unregister_shape,
+};
 use abstract_app::traits::AbstractResponse;
-use cosmwasm_std::{DepsMut, Env, MessageInfo};
+use cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, Timestamp};
+use cw_controllers::AdminError;
 
 pub fn execute_handler(
     deps: DepsMut<'_>,
@@ -25,9 +31,58 @@ pub fn execute_handler(
             credential.as_slice(),
             format.unwrap_or_default(),
         ),
-        AxoneVcExecuteMsg::RevokeCredential { identifier } => {
-            execute_revoke_credential(deps, env, info, module, identifier)
+        AxoneVcExecuteMsg::AnchorCredential { credential, format } => execute_anchor_credential(
+            deps,
+            env,
+            info,
+            module,
+            credential.as_slice(),
+            format.unwrap_or_default(),
+        ),
+        AxoneVcExecuteMsg::RevokeCredential {
+            identifier,
+            reason_code,
+            reason,
+        } => execute_revoke_credential(deps, env, info, module, identifier, reason_code, reason),
+        AxoneVcExecuteMsg::SuspendCredential { identifier } => {
+            execute_suspend_credential(deps, env, info, module, identifier)
+        }
+        AxoneVcExecuteMsg::ReinstateCredential { identifier } => {
+            execute_reinstate_credential(deps, env, info, module, identifier)
         }
+        AxoneVcExecuteMsg::GrantIssuer {
+            address,
+            credential_types,
+            expires,
+        } => execute_grant_issuer(deps, env, info, module, address, credential_types, expires),
+        AxoneVcExecuteMsg::RevokeIssuer { address } => {
+            execute_revoke_issuer(deps, env, info, module, address)
+        }
+        AxoneVcExecuteMsg::RegisterShape {
+            credential_type,
+            properties,
+        } => execute_register_shape(deps, env, info, module, credential_type, properties),
+        AxoneVcExecuteMsg::UnregisterShape { credential_type } => {
+            execute_unregister_shape(deps, env, info, module, credential_type)
+        }
+    }
+}
+
+/// Authorize the sender to issue or revoke credentials, either as the app authority or as a
+/// delegated issuer whose delegation is returned.
+fn authorize_issuer(
+    deps: Deps<'_>,
+    env: &Env,
+    info: &MessageInfo,
+    module: &AxoneVc,
+) -> AxoneVcResult<Option<IssuerDelegation>> {
+    if module.admin.is_admin(deps, env, &info.sender)? {
+        return Ok(None);
+    }
+
+    match delegated_issuer(deps.storage, &info.sender, env.block.time)? {
+        Some(delegation) => Ok(Some(delegation)),
+        None => Err(AdminError::NotAdmin {}.into()),
     }
 }
 
@@ -39,11 +94,9 @@ fn execute_issue_credential(
     credential: &[u8],
     format: crate::msg::CredentialInputFormat,
 ) -> AxoneVcResult {
-    module
-        .admin
-        .assert_admin(deps.as_ref(), &env, &info.sender)?;
+    let delegate = authorize_issuer(deps.as_ref(), &env, &info, &module)?;
 
-    let result = issue_credential(deps.storage, credential, format)?;
+    let result = issue_credential(deps.storage, credential, format, delegate.as_ref())?;
 
     let mut attributes = vec![
         (RESPONSE_KEY_IDENTIFIER.to_string(), result.credential_id),
@@ -57,28 +110,239 @@ fn execute_issue_credential(
     if let Some(valid_until) = result.valid_until {
         attributes.push(("valid_until".to_string(), valid_until.to_string()));
     }
+    if let Some(delegate) = result.delegate {
+        attributes.push((RESPONSE_KEY_DELEGATE.to_string(), delegate.to_string()));
+    }
 
     Ok(module.custom_response("issue_credential", attributes))
 }
 
+fn execute_anchor_credential(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    module: AxoneVc,
+    credential: &[u8],
+    format: crate::msg::CredentialInputFormat,
+) -> AxoneVcResult {
+    module
+        .admin
+        .assert_admin(deps.as_ref(), &env, &info.sender)?;
+
+    let result = anchor_credential(deps.storage, deps.api, credential, format)?;
+
+    Ok(module.custom_response(
+        "anchor_credential",
+        vec![
+            (RESPONSE_KEY_IDENTIFIER.to_string(), result.credential_id),
+            (RESPONSE_KEY_ISSUER.to_string(), result.issuer),
+            (RESPONSE_KEY_SUBJECT.to_string(), result.subject),
+            (RESPONSE_KEY_TYPES.to_string(), result.types.join(",")),
+            (
+                "cryptosuite".to_string(),
+                result.cryptosuite.name().to_string(),
+            ),
+            (
+                "verification_method".to_string(),
+                result.verification_method,
+            ),
+            (
+                "status_list_index".to_string(),
+                result.status_list_index.to_string(),
+            ),
+        ],
+    ))
+}
+
 fn execute_revoke_credential(
     deps: DepsMut<'_>,
     env: Env,
     info: MessageInfo,
     module: AxoneVc,
     identifier: String,
+    reason_code: Option<RevocationReasonCode>,
+    reason: Option<String>,
+) -> AxoneVcResult {
+    let delegate = authorize_issuer(deps.as_ref(), &env, &info, &module)?;
+
+    let result = revoke_credential(
+        deps.storage,
+        &env.block,
+        &info.sender,
+        delegate.as_ref(),
+        &identifier,
+        reason_code,
+        reason,
+    )?;
+
+    let mut attributes = vec![
+        (RESPONSE_KEY_IDENTIFIER.to_string(), result.identifier),
+        (RESPONSE_KEY_ISSUER.to_string(), result.issuer),
+    ];
+    if let Some(reason_code) = result.reason_code {
+        attributes.push(("reason_code".to_string(), reason_code.name().to_string()));
+    }
+
+    Ok(module.custom_response("revoke_credential", attributes))
+}
+
+fn execute_suspend_credential(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    module: AxoneVc,
+    identifier: String,
 ) -> AxoneVcResult {
     module
         .admin
         .assert_admin(deps.as_ref(), &env, &info.sender)?;
 
-    let result = revoke_credential(deps.storage, &identifier)?;
+    let result = suspend_credential(deps.storage, &identifier)?;
 
     Ok(module.custom_response(
-        "revoke_credential",
+        "suspend_credential",
         vec![
             (RESPONSE_KEY_IDENTIFIER.to_string(), result.identifier),
             (RESPONSE_KEY_ISSUER.to_string(), result.issuer),
+            (
+                RESPONSE_KEY_STATUS.to_string(),
+                result.status.name().to_string(),
+            ),
         ],
     ))
 }
+
+fn execute_reinstate_credential(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    module: AxoneVc,
+    identifier: String,
+) -> AxoneVcResult {
+    module
+        .admin
+        .assert_admin(deps.as_ref(), &env, &info.sender)?;
+
+    let result = reinstate_credential(deps.storage, &identifier)?;
+
+    Ok(module.custom_response(
+        "reinstate_credential",
+        vec![
+            (RESPONSE_KEY_IDENTIFIER.to_string(), result.identifier),
+            (RESPONSE_KEY_ISSUER.to_string(), result.issuer),
+            (
+                RESPONSE_KEY_STATUS.to_string(),
+                result.status.name().to_string(),
+            ),
+        ],
+    ))
+}
+
+fn execute_grant_issuer(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    module: AxoneVc,
+    address: String,
+    credential_types: Vec<String>,
+    expires: Option<Timestamp>,
+) -> AxoneVcResult {
+    module
+        .admin
+        .assert_admin(deps.as_ref(), &env, &info.sender)?;
+
+    let address = deps.api.addr_validate(&address)?;
+    let delegation = grant_issuer(
+        deps.storage,
+        address,
+        credential_types,
+        expires,
+        env.block.time,
+    )?;
+
+    let mut attributes = vec![
+        (
+            RESPONSE_KEY_DELEGATE.to_string(),
+            delegation.address.to_string(),
+        ),
+        (
+            "credential_types".to_string(),
+            delegation.credential_types.join(","),
+        ),
+    ];
+    if let Some(expires) = delegation.expires {
+        attributes.push(("expires".to_string(), expires.to_string()));
+    }
+
+    Ok(module.custom_response("grant_issuer", attributes))
+}
+
+fn execute_revoke_issuer(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    module: AxoneVc,
+    address: String,
+) -> AxoneVcResult {
+    module
+        .admin
+        .assert_admin(deps.as_ref(), &env, &info.sender)?;
+
+    let address = deps.api.addr_validate(&address)?;
+    revoke_issuer(deps.storage, &address)?;
+
+    Ok(module.custom_response(
+        "revoke_issuer",
+        vec![(RESPONSE_KEY_DELEGATE.to_string(), address.to_string())],
+    ))
+}
+
+fn execute_register_shape(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    module: AxoneVc,
+    credential_type: String,
+    properties: Vec<PropertyShape>,
+) -> AxoneVcResult {
+    module
+        .admin
+        .assert_admin(deps.as_ref(), &env, &info.sender)?;
+
+    let shape = register_shape(deps.storage, credential_type, properties)?;
+
+    Ok(module.custom_response(
+        "register_shape",
+        vec![
+            ("credential_type".to_string(), shape.credential_type),
+            (
+                "properties".to_string(),
+                shape
+                    .properties
+                    .into_iter()
+                    .map(|property| property.path)
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+        ],
+    ))
+}
+
+fn execute_unregister_shape(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    module: AxoneVc,
+    credential_type: String,
+) -> AxoneVcResult {
+    module
+        .admin
+        .assert_admin(deps.as_ref(), &env, &info.sender)?;
+
+    unregister_shape(deps.storage, &credential_type)?;
+
+    Ok(module.custom_response(
+        "unregister_shape",
+        vec![("credential_type".to_string(), credential_type)],
+    ))
+}
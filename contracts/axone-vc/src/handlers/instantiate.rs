@@ -1,7 +1,7 @@
 use crate::{
     contract::{AxoneVc, AxoneVcResult},
     msg::AxoneVcInstantiateMsg,
-    services::initialize_authority,
+    services::{configure_status_list_url, initialize_authority},
     RESPONSE_KEY_AUTHORITY,
 };
 
@@ -14,11 +14,14 @@ pub fn instantiate_handler(
     env: Env,
     _info: MessageInfo,
     module: AxoneVc,
-    _msg: AxoneVcInstantiateMsg,
+    msg: AxoneVcInstantiateMsg,
 ) -> AxoneVcResult {
     let account = module.load_state(deps.storage)?;
     let authority =
         initialize_authority(deps.storage, &env.block.chain_id, account.account.addr())?;
+    if let Some(url) = msg.status_list_url {
+        configure_status_list_url(deps.storage, &url)?;
+    }
 
     Ok(module.custom_response(
         "instantiate",
@@ -1,10 +1,16 @@
 use crate::{
     contract::{AxoneVc, AxoneVcResult},
     msg::{
-        pagination_limit, AuthorityResponse, AxoneVcQueryMsg, CredentialRawResponse,
-        CredentialResponse, CredentialsResponse, Quad as QuadResponse, VerifyCredentialResponse,
+        pagination_limit, AuthorityResponse, AxoneVcQueryMsg, CredentialOutputFormat,
+        CredentialRawResponse, CredentialResponse, CredentialsResponse, IssuerResponse,
+        IssuersResponse, Quad as QuadResponse, RevokedCredentialResponse,
+        RevokedCredentialsResponse, ShapeResponse, ShapesResponse, StatusListResponse,
+        StatusListSubject, StatusPurpose, VerifyCredentialResponse,
+    },
+    services::{
+        authority, credential, credential_raw, credentials, issuers, revoked_credential,
+        revoked_credentials, shapes, status_list, verify_credential, RevokedCredentialResult,
     },
-    services::{authority, credential, credential_raw, credentials, verify_credential},
     translation::DecodedQuad,
 };
 
@@ -23,9 +29,14 @@ pub fn query_handler(
             identifier,
             valid_at,
         } => to_json_binary(&query_verify_credential(deps, identifier, valid_at)?),
-        AxoneVcQueryMsg::CredentialRaw { identifier } => {
-            to_json_binary(&query_credential_raw(deps, identifier)?)
-        }
+        AxoneVcQueryMsg::CredentialRaw {
+            identifier,
+            output_format,
+        } => to_json_binary(&query_credential_raw(
+            deps,
+            identifier,
+            output_format.unwrap_or_default(),
+        )?),
         AxoneVcQueryMsg::Credential { identifier } => {
             to_json_binary(&query_credential(deps, identifier)?)
         }
@@ -34,6 +45,17 @@ pub fn query_handler(
             limit,
             start_after,
         } => to_json_binary(&query_credentials(deps, filter, limit, start_after)?),
+        AxoneVcQueryMsg::RevokedCredential { identifier } => {
+            to_json_binary(&query_revoked_credential(deps, identifier)?)
+        }
+        AxoneVcQueryMsg::RevokedCredentials { limit, start_after } => {
+            to_json_binary(&query_revoked_credentials(deps, limit, start_after)?)
+        }
+        AxoneVcQueryMsg::Issuers {} => to_json_binary(&query_issuers(deps)?),
+        AxoneVcQueryMsg::Shapes {} => to_json_binary(&query_shapes(deps)?),
+        AxoneVcQueryMsg::StatusList { purpose } => {
+            to_json_binary(&query_status_list(deps, purpose)?)
+        }
     }
     .map_err(Into::into)
 }
@@ -47,15 +69,17 @@ fn query_verify_credential(
     Ok(VerifyCredentialResponse {
         exists: result.exists,
         valid: result.valid,
+        status: result.status,
     })
 }
 
 fn query_credential_raw(
     deps: Deps<'_>,
     identifier: String,
+    output_format: CredentialOutputFormat,
 ) -> AxoneVcResult<CredentialRawResponse> {
     Ok(CredentialRawResponse {
-        credential: credential_raw(deps.storage, &identifier)?,
+        credential: credential_raw(deps.storage, &identifier, output_format)?,
     })
 }
 
@@ -68,6 +92,10 @@ fn query_credential(deps: Deps<'_>, identifier: String) -> AxoneVcResult<Credent
         subject: result.parsed.subject_id().clone(),
         valid_from: result.parsed.valid_from(),
         valid_until: result.parsed.valid_until(),
+        data_model: result.data_model,
+        proof: result.proof,
+        status: result.status,
+        delegate: result.delegate,
         quads: result.quads.into_iter().map(QuadResponse::from).collect(),
     })
 }
@@ -83,6 +111,8 @@ fn query_credentials(
         filter.subject.as_deref(),
         filter.credential_type.as_deref(),
         filter.valid_at,
+        filter.data_model,
+        filter.status,
         pagination_limit(limit),
         start_after,
     )?;
@@ -91,6 +121,72 @@ fn query_credentials(
     })
 }
 
+fn query_revoked_credential(
+    deps: Deps<'_>,
+    identifier: String,
+) -> AxoneVcResult<RevokedCredentialResponse> {
+    Ok(revoked_credential(deps.storage, &identifier)?.into())
+}
+
+fn query_revoked_credentials(
+    deps: Deps<'_>,
+    limit: Option<u32>,
+    start_after: Option<String>,
+) -> AxoneVcResult<RevokedCredentialsResponse> {
+    let result = revoked_credentials(deps.storage, pagination_limit(limit), start_after)?;
+    Ok(RevokedCredentialsResponse {
+        revocations: result.into_iter().map(Into::into).collect(),
+    })
+}
+
+fn query_issuers(deps: Deps<'_>) -> AxoneVcResult<IssuersResponse> {
+    let result = issuers(deps.storage)?;
+    Ok(IssuersResponse {
+        issuers: result
+            .into_iter()
+            .map(|delegation| IssuerResponse {
+                address: delegation.address,
+                credential_types: delegation.credential_types,
+                expires: delegation.expires,
+            })
+            .collect(),
+    })
+}
+
+fn query_shapes(deps: Deps<'_>) -> AxoneVcResult<ShapesResponse> {
+    let result = shapes(deps.storage)?;
+    Ok(ShapesResponse {
+        shapes: result
+            .into_iter()
+            .map(|shape| ShapeResponse {
+                credential_type: shape.credential_type,
+                properties: shape.properties,
+            })
+            .collect(),
+    })
+}
+
+const VC_V2_CONTEXT: &str = "https://www.w3.org/ns/credentials/v2";
+
+fn query_status_list(deps: Deps<'_>, purpose: StatusPurpose) -> AxoneVcResult<StatusListResponse> {
+    let result = status_list(deps.storage, purpose)?;
+    Ok(StatusListResponse {
+        context: vec![VC_V2_CONTEXT.to_string()],
+        id: result.id.clone(),
+        types: vec![
+            "VerifiableCredential".to_string(),
+            "BitstringStatusListCredential".to_string(),
+        ],
+        issuer: result.issuer,
+        credential_subject: StatusListSubject {
+            id: format!("{}#list", result.id),
+            subject_type: "BitstringStatusList".to_string(),
+            status_purpose: result.purpose,
+            encoded_list: result.encoded_list,
+        },
+    })
+}
+
 fn query_authority(deps: Deps<'_>) -> AxoneVcResult<AuthorityResponse> {
     let authority = authority(deps.storage)?;
     Ok(AuthorityResponse {
@@ -111,3 +207,18 @@ impl From<DecodedQuad> for QuadResponse {
         }
     }
 }
+
+impl From<RevokedCredentialResult> for RevokedCredentialResponse {
+    fn from(result: RevokedCredentialResult) -> Self {
+        let tombstone = result.tombstone;
+        Self {
+            identifier: result.identifier,
+            block_height: tombstone.block_height,
+            revoked_at: tombstone.revoked_at,
+            revoker: tombstone.revoker,
+            reason_code: tombstone.reason_code,
+            reason: tombstone.reason,
+            canonical_hash: tombstone.canonical_hash,
+        }
+    }
+}
@@ -18,8 +18,10 @@ pub const AXONE_NAMESPACE: &str = "axone";
 pub const AXONE_VC_NAME: &str = "axone-vc";
 pub const AXONE_VC_ID: &str = const_format::concatcp!(AXONE_NAMESPACE, ":", AXONE_VC_NAME);
 pub const RESPONSE_KEY_AUTHORITY: &str = "authority";
+pub const RESPONSE_KEY_DELEGATE: &str = "delegate";
 pub const RESPONSE_KEY_IDENTIFIER: &str = "identifier";
 pub const RESPONSE_KEY_ISSUER: &str = "issuer";
+pub const RESPONSE_KEY_STATUS: &str = "status";
 pub const RESPONSE_KEY_SUBJECT: &str = "subject";
 pub const RESPONSE_KEY_TYPES: &str = "types";
 
@@ -1,7 +1,13 @@
 use crate::{contract::AxoneVc, domain::Uri};
 
+pub use crate::domain::{
+    CredentialStatus, Cryptosuite, DataIntegrityProof, DataModelVersion, NodeKind, PropertyShape,
+    RevocationReasonCode, StatusPurpose,
+};
+
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::{Binary, Timestamp};
+use cosmwasm_std::{Addr, Binary, Checksum, Timestamp};
+use std::collections::BTreeMap;
 
 abstract_app::app_msg_types!(AxoneVc, AxoneVcExecuteMsg, AxoneVcQueryMsg);
 
@@ -18,11 +24,18 @@ pub(crate) fn pagination_limit(limit: Option<u32>) -> usize {
 ///
 /// Instantiating this app attaches a verifiable credential authority to the resource
 /// represented by the host Abstract Account.
-///
-/// This contract requires no caller-provided configuration.
 #[cosmwasm_schema::cw_serde]
 #[derive(Default)]
-pub struct AxoneVcInstantiateMsg {}
+pub struct AxoneVcInstantiateMsg {
+    /// Optional HTTP(S) URL the status lists of the authority are published at.
+    ///
+    /// The list of each purpose is then identified as `<status_list_url>/<purpose>`, where
+    /// the result of the `StatusList` query of that purpose is expected to be served. When
+    /// omitted, the lists are identified under the authority DID, and only readable through
+    /// the `StatusList` query.
+    #[serde(default)]
+    pub status_list_url: Option<String>,
+}
 
 /// Execute messages.
 #[cosmwasm_schema::cw_serde]
@@ -30,7 +43,10 @@ pub struct AxoneVcInstantiateMsg {}
 pub enum AxoneVcExecuteMsg {
     /// Issue a verifiable credential from this authority.
     ///
-    /// Only the app authority is allowed to call this message.
+    /// Only the app authority, or an issuer it delegated to with `GrantIssuer`, is
+    /// allowed to call this message. A delegated issuer may only issue credentials having
+    /// a type besides `VerifiableCredential`, must be granted every such type, and is
+    /// recorded with the credential.
     ///
     /// The submitted payload must match the declared `format` and must describe
     /// exactly one credential that satisfies the contract invariants.
@@ -40,6 +56,7 @@ pub enum AxoneVcExecuteMsg {
     /// - either no issuer or an issuer equal to the authority DID exposed by this contract
     /// - a subject identifier
     /// - at least one type, including `VerifiableCredential`
+    /// - credential terms from the `https://www.w3.org/2018/credentials#` vocabulary
     /// - optional `validFrom` and `validUntil` claims, when present, encoded as
     ///   `xsd:dateTimeStamp` instants with `validFrom < validUntil`
     ///
@@ -48,6 +65,10 @@ pub enum AxoneVcExecuteMsg {
     ///
     /// The registered credential therefore always contains the effective issuer.
     ///
+    /// The claims about the credential subject must conform to the shapes registered with
+    /// `RegisterShape` for the credential types, a non-conforming credential being rejected
+    /// with the list of the constraints it fails.
+    ///
     /// Issuance fails if the payload format is not supported, if the credential
     /// representation cannot be interpreted according to that format, or if a
     /// credential with the same identifier has already been issued by this authority.
@@ -64,16 +85,143 @@ pub enum AxoneVcExecuteMsg {
         format: Option<CredentialInputFormat>,
     },
 
-    /// Revoke a verifiable credential from this authority.
+    /// Anchor a verifiable credential of another issuer secured by a Data Integrity proof.
     ///
     /// Only the app authority is allowed to call this message.
     ///
+    /// The credential must satisfy the same invariants as an issued credential, except
+    /// that its issuer is kept as signed, and must embed exactly one
+    /// `DataIntegrityProof` with:
+    /// - an `eddsa-rdfc-2022` or `ecdsa-rdfc-2019` cryptosuite
+    /// - the `assertionMethod` proof purpose
+    /// - a verification method of the issuer DID, which must use the `did:key` method
+    ///   with an Ed25519 or P-256 key, given as `did:key:<key>#<key>`
+    /// - a `proofValue` holding the base58btc multibase signature
+    ///
+    /// The signature is verified over the RDFC-1.0 canonical forms of the credential and
+    /// of the proof options. The credential is then registered with its proof, which is
+    /// kept in the stored dataset and returned by the `Credential` query, and allocated an
+    /// index in the status lists of the authority, returned as the `status_list_index`
+    /// attribute, at which its revocation and suspension are reflected.
+    ///
+    /// Anchoring fails for the same reasons as issuance, or if the proof is missing,
+    /// unsupported or does not verify.
+    AnchorCredential {
+        /// Serialized secured credential payload.
+        credential: Binary,
+        /// Encoding used by the submitted credential payload.
+        ///
+        /// Defaults to `n_quads` when omitted.
+        #[serde(default)]
+        format: Option<CredentialInputFormat>,
+    },
+
+    /// Revoke a verifiable credential from this authority.
+    ///
+    /// Only the app authority, or a delegated issuer granted every type of the
+    /// credential besides `VerifiableCredential`, is allowed to call this message. A
+    /// delegated issuer cannot revoke credentials having no other type, nor anchored
+    /// credentials.
+    ///
     /// The revocation is terminal, the same identifier cannot be issued again.
     ///
-    /// Revocation fails if the identifier is unknown or already revoked.
+    /// The contract records the block and the sender of the revocation, the given reason
+    /// and the hash of the revoked canonical dataset, as returned by the
+    /// `RevokedCredential` query.
+    ///
+    /// Revocation fails if the identifier is unknown or already revoked, or if the reason
+    /// exceeds 512 bytes.
     RevokeCredential {
         /// The credential identifier to revoke.
         identifier: Uri,
+        /// Optional code classifying the reason of the revocation.
+        #[serde(default)]
+        reason_code: Option<RevocationReasonCode>,
+        /// Optional free-text reason of the revocation.
+        #[serde(default)]
+        reason: Option<String>,
+    },
+
+    /// Suspend an active credential of this authority.
+    ///
+    /// Only the app authority is allowed to call this message.
+    ///
+    /// The suspension is reversible: the credential stays in the active credential set
+    /// with a `suspended` status, is no longer reported as valid, and gets its bit set
+    /// in the `suspension` status list until reinstated.
+    ///
+    /// Suspension fails if the identifier is unknown, revoked or already suspended.
+    SuspendCredential {
+        /// The credential identifier to suspend.
+        identifier: Uri,
+    },
+
+    /// Delegate the issuance and revocation of credentials to an address.
+    ///
+    /// Only the app authority is allowed to call this message.
+    ///
+    /// The delegation replaces any other held by the address. It fails if no credential
+    /// type is given or if the expiration is not in the future.
+    GrantIssuer {
+        /// The address to delegate to.
+        address: String,
+        /// The credential type URIs the delegate is allowed to issue and revoke.
+        credential_types: Vec<Uri>,
+        /// Optional instant from which the delegation no longer applies.
+        #[serde(default)]
+        expires: Option<Timestamp>,
+    },
+
+    /// Withdraw the delegation of an address.
+    ///
+    /// Only the app authority is allowed to call this message.
+    ///
+    /// Credentials issued by the delegate are kept. This fails if the address holds no
+    /// delegation.
+    RevokeIssuer {
+        /// The delegated address.
+        address: String,
+    },
+
+    /// Register the shape the subject of issued credentials of a type must conform to.
+    ///
+    /// Only the app authority is allowed to call this message.
+    ///
+    /// The shape is a subset of SHACL Core: each property shape constrains the values of a
+    /// predicate of which the credential subject is the RDF subject. A credential of several
+    /// types must conform to the shapes of all of them. The shape replaces any registered for
+    /// the type, and does not apply to credentials already issued or anchored.
+    ///
+    /// Registration fails if no property is given, if a property is given twice, if a
+    /// minimum count exceeds its maximum count or if a list of allowed values is empty.
+    RegisterShape {
+        /// The credential type URI the shape applies to.
+        credential_type: Uri,
+        /// The constraints on the properties of the credential subject.
+        properties: Vec<PropertyShape>,
+    },
+
+    /// Remove the shape of a credential type.
+    ///
+    /// Only the app authority is allowed to call this message.
+    ///
+    /// This fails if no shape is registered for the type.
+    UnregisterShape {
+        /// The credential type URI of the shape.
+        credential_type: Uri,
+    },
+
+    /// Reinstate a suspended credential of this authority.
+    ///
+    /// Only the app authority is allowed to call this message.
+    ///
+    /// The credential gets back its `active` status and its bit is cleared in the
+    /// `suspension` status list.
+    ///
+    /// Reinstatement fails if the identifier is unknown, revoked or not suspended.
+    ReinstateCredential {
+        /// The credential identifier to reinstate.
+        identifier: Uri,
     },
 }
 
@@ -89,6 +237,89 @@ pub enum CredentialInputFormat {
     #[serde(rename = "n_quads")]
     #[default]
     NQuads,
+    /// UTF-8 RDF graph serialized as Turtle.
+    ///
+    /// Prefixes and base IRIs declared by the document are resolved at issuance, and its
+    /// triples are placed in the default graph. Relative IRIs without a declared base are
+    /// rejected.
+    /// See the [Turtle specification](https://www.w3.org/TR/turtle/).
+    #[serde(rename = "turtle")]
+    Turtle,
+    /// UTF-8 RDF dataset serialized as TriG.
+    ///
+    /// TriG extends Turtle with named graph blocks. Prefixes and base IRIs are resolved as
+    /// for `turtle`.
+    /// See the [TriG specification](https://www.w3.org/TR/trig/).
+    #[serde(rename = "trig")]
+    TriG,
+    /// UTF-8 JSON-LD document.
+    ///
+    /// The document is expanded to an RDF dataset following the
+    /// [JSON-LD 1.1 specification](https://www.w3.org/TR/json-ld11/), and is then
+    /// processed as an N-Quads payload would be.
+    ///
+    /// Remote contexts are never loaded: only the following contexts, bundled with the
+    /// contract, may be referenced, and any other is rejected:
+    /// - `https://www.w3.org/2018/credentials/v1`
+    /// - `https://www.w3.org/ns/credentials/v2`
+    /// - `https://w3id.org/security/data-integrity/v2`
+    ///
+    /// Inline contexts are supported, except for `@import`, `@propagate`, `@reverse`,
+    /// `@nest`, `@direction` and relative IRIs. Unlike plain JSON-LD processing, properties
+    /// and types that do not expand to an absolute IRI are rejected instead of being dropped.
+    ///
+    /// Validity claims keep their `xsd:dateTimeStamp` requirement, which the bundled
+    /// contexts' `xsd:dateTime` coercion does not satisfy: they must be given as value
+    /// objects with an explicit `@type`.
+    #[serde(rename = "json_ld")]
+    JsonLd,
+}
+
+/// Supported credential output serializations.
+#[cosmwasm_schema::cw_serde]
+#[derive(Default)]
+pub enum CredentialOutputFormat {
+    /// Canonical RDF dataset serialized as N-Quads, exactly as stored by the contract.
+    ///
+    /// See the [N-Quads specification](https://www.w3.org/TR/n-quads/).
+    #[serde(rename = "n_quads")]
+    #[default]
+    NQuads,
+    /// RDF graph serialized as N-Triples.
+    ///
+    /// N-Triples has no named graphs, credentials holding some, such as embedded proofs,
+    /// cannot be returned in this format.
+    /// See the [N-Triples specification](https://www.w3.org/TR/n-triples/).
+    #[serde(rename = "n_triples")]
+    NTriples,
+    /// RDF graph serialized as Turtle.
+    ///
+    /// As for `n_triples`, credentials holding named graphs cannot be returned in this
+    /// format.
+    /// See the [Turtle specification](https://www.w3.org/TR/turtle/).
+    #[serde(rename = "turtle")]
+    Turtle {
+        /// Prefixes declared by the document and used to abbreviate IRIs, keyed by prefix
+        /// name.
+        #[serde(default)]
+        prefixes: BTreeMap<String, String>,
+    },
+    /// Compacted JSON-LD document.
+    ///
+    /// The document is flattened: every RDF subject is a node object of its top-level
+    /// `@graph`. Terms of the given contexts are used only where expanding the document
+    /// yields the stored dataset again; other properties and values keep their absolute
+    /// IRIs and explicit value objects.
+    /// See the [JSON-LD 1.1 specification](https://www.w3.org/TR/json-ld11/).
+    #[serde(rename = "json_ld")]
+    JsonLd {
+        /// Contexts to compact the document against, among the contexts bundled with the
+        /// contract and accepted by the `json_ld` input format.
+        ///
+        /// Defaults to `https://www.w3.org/ns/credentials/v2` when omitted.
+        #[serde(default)]
+        contexts: Option<Vec<String>>,
+    },
 }
 
 /// Migrate message.
@@ -119,7 +350,8 @@ pub enum AxoneVcQueryMsg {
     /// Check whether a credential is active and, optionally, valid at a given instant.
     ///
     /// Revoked credentials are not part of the active credential set and therefore
-    /// return the same result as unknown identifiers.
+    /// return the same result as unknown identifiers. Suspended credentials exist but are
+    /// never valid.
     #[returns(VerifyCredentialResponse)]
     VerifyCredential {
         /// Identifier of the credential to check.
@@ -130,17 +362,23 @@ pub enum AxoneVcQueryMsg {
         valid_at: Option<Timestamp>,
     },
 
-    /// Return the canonical serialized representation stored for an active credential.
+    /// Return the serialized representation of an active credential.
     ///
-    /// The returned representation is the contract's canonical storage format. It is
-    /// not guaranteed to preserve the encoding or presentation of the issued payload.
+    /// The representation is derived from the canonical N-Quads stored by the contract,
+    /// which remain the source of truth. It is not guaranteed to preserve the encoding or
+    /// presentation of the issued payload.
     ///
-    /// This query fails when the identifier is unknown or the credential has been
-    /// revoked.
+    /// This query fails when the identifier is unknown, the credential has been revoked,
+    /// or the credential cannot be represented in the requested format.
     #[returns(CredentialRawResponse)]
     CredentialRaw {
         /// Identifier of the credential to retrieve.
         identifier: Uri,
+        /// Serialization of the returned representation.
+        ///
+        /// Defaults to `n_quads` when omitted.
+        #[serde(default)]
+        output_format: Option<CredentialOutputFormat>,
     },
 
     /// Return an active issued credential with its RDF dataset.
@@ -177,6 +415,71 @@ pub enum AxoneVcQueryMsg {
         /// a previous page.
         start_after: Option<Uri>,
     },
+
+    /// Return the revocation record of a revoked credential.
+    ///
+    /// This query fails when the identifier has not been revoked.
+    #[returns(RevokedCredentialResponse)]
+    RevokedCredential {
+        /// Identifier of the revoked credential.
+        identifier: Uri,
+    },
+
+    /// Return the revocation records of revoked credentials, ordered by credential
+    /// identifier and paginated with `start_after`.
+    #[returns(RevokedCredentialsResponse)]
+    RevokedCredentials {
+        /// Maximum number of records to return.
+        ///
+        /// When omitted, the contract default is used. Values above the contract
+        /// maximum are capped.
+        limit: Option<u32>,
+        /// Exclusive pagination cursor using a credential identifier returned by
+        /// a previous page.
+        start_after: Option<Uri>,
+    },
+
+    /// Return the issuers the authority delegated to, ordered by address.
+    ///
+    /// Expired delegations are returned until withdrawn.
+    #[returns(IssuersResponse)]
+    Issuers {},
+
+    /// Return the shapes registered for credential types, ordered by credential type.
+    #[returns(ShapesResponse)]
+    Shapes {},
+
+    /// Return the W3C Bitstring Status List credential of the given purpose.
+    ///
+    /// Every credential issued by this authority is assigned a status list index and
+    /// embeds, for each purpose, a `BitstringStatusListEntry` as `credentialStatus`:
+    ///
+    /// - `id`: `<status list credential>#<index>`
+    /// - `statusPurpose`: the purpose, `revocation` or `suspension`
+    /// - `statusListIndex`: the index, as a decimal string
+    /// - `statusListCredential`: `<status_list_url>/<purpose>` when the `status_list_url`
+    ///   is given at instantiation, `<authority DID>/credentials/status/<purpose>` otherwise
+    ///
+    /// The bit of that index is set in the list once the credential is revoked, or while
+    /// it is suspended. Anchored credentials are assigned an index too, but do not embed
+    /// the entries, their proof covering the credential as signed by their issuer.
+    ///
+    /// The list holds at least 131,072 entries, and grows by that length as indexes get
+    /// allocated. Indexes are allocated sequentially, and the entries of a credential are
+    /// disclosed by the transaction issuing it, so the list provides no group privacy.
+    ///
+    /// The returned credential is not secured by a proof, the contract holding no key to
+    /// sign it: its authenticity rests on the contract state it is read from. A verifier
+    /// dereferencing `statusListCredential` at the configured URL should therefore check the
+    /// served list against the result of this query, read from a node it trusts, and the
+    /// `statusListCredential` DID URL of the default location is only readable through this
+    /// query.
+    /// See the [Bitstring Status List specification](https://www.w3.org/TR/vc-bitstring-status-list/).
+    #[returns(StatusListResponse)]
+    StatusList {
+        /// Purpose of the status list to return.
+        purpose: StatusPurpose,
+    },
 }
 
 /// Response returned by `AxoneVcQueryMsg::Authority`.
@@ -201,14 +504,17 @@ pub struct VerifyCredentialResponse {
     pub exists: bool,
     /// Whether the active credential is valid for the requested instant.
     ///
-    /// This is equal to `exists` when no instant was requested.
+    /// This is `false` for suspended credentials, and is otherwise equal to `exists` when
+    /// no instant was requested.
     pub valid: bool,
+    /// Status of the active credential, `None` when it does not exist.
+    pub status: Option<CredentialStatus>,
 }
 
 /// Response returned by `AxoneVcQueryMsg::CredentialRaw`.
 #[cosmwasm_schema::cw_serde]
 pub struct CredentialRawResponse {
-    /// Canonical serialized credential representation persisted by the contract.
+    /// Serialized credential representation in the requested output format.
     ///
     /// This binary value is base64-encoded in JSON responses and is independent
     /// from the format and presentation of the credential submitted at issuance.
@@ -222,7 +528,8 @@ pub struct CredentialResponse {
     pub identifier: Uri,
     /// Credential type URIs extracted from the VC `type` values.
     pub types: Vec<Uri>,
-    /// Authority DID recorded as the credential issuer.
+    /// DID recorded as the credential issuer, the authority DID unless the credential
+    /// was anchored.
     pub issuer: Uri,
     /// Credential subject identifier.
     pub subject: Uri,
@@ -230,6 +537,17 @@ pub struct CredentialResponse {
     pub valid_from: Option<Timestamp>,
     /// Optional exclusive upper bound of the credential validity interval.
     pub valid_until: Option<Timestamp>,
+    /// VC data model version the credential follows, given by the base context of a JSON-LD
+    /// payload or else by its validity terms.
+    pub data_model: DataModelVersion,
+    /// Verified Data Integrity proof of an anchored credential, `None` for credentials
+    /// issued by this authority.
+    pub proof: Option<DataIntegrityProof>,
+    /// Whether the credential is active or suspended.
+    pub status: CredentialStatus,
+    /// Address of the delegated issuer that issued the credential, `None` when issued by
+    /// the authority itself.
+    pub delegate: Option<Addr>,
     /// Canonical credential RDF dataset represented as structured quads.
     pub quads: Vec<Quad>,
 }
@@ -247,6 +565,14 @@ pub struct CredentialFilter {
     /// `validFrom` is inclusive when present, `validUntil` is exclusive when
     /// present, and missing bounds are treated as unbounded.
     pub valid_at: Option<Timestamp>,
+    /// VC data model version the credential must follow.
+    pub data_model: Option<DataModelVersion>,
+    /// Credential status to match, `active` to exclude suspended credentials or
+    /// `suspended` to only return them.
+    ///
+    /// When omitted, both active and suspended credentials are returned.
+    #[serde(default)]
+    pub status: Option<CredentialStatus>,
 }
 
 /// Response returned by `AxoneVcQueryMsg::Credentials`.
@@ -256,6 +582,107 @@ pub struct CredentialsResponse {
     pub identifiers: Vec<Uri>,
 }
 
+/// Response returned by `AxoneVcQueryMsg::RevokedCredential`.
+#[cosmwasm_schema::cw_serde]
+pub struct RevokedCredentialResponse {
+    /// Identifier of the revoked credential.
+    pub identifier: Uri,
+    /// Height of the block the credential was revoked at.
+    pub block_height: u64,
+    /// Time of the block the credential was revoked at.
+    pub revoked_at: Timestamp,
+    /// Address of the sender of the revocation.
+    ///
+    /// This and `canonical_hash` are `None` for credentials revoked before revocation
+    /// records were kept, whose block height and time are zero.
+    pub revoker: Option<Addr>,
+    /// Code classifying the reason of the revocation, when given.
+    pub reason_code: Option<RevocationReasonCode>,
+    /// Free-text reason of the revocation, when given.
+    pub reason: Option<String>,
+    /// SHA-256 hash of the canonical N-Quads of the revoked credential.
+    pub canonical_hash: Option<Checksum>,
+}
+
+/// Response returned by `AxoneVcQueryMsg::RevokedCredentials`.
+#[cosmwasm_schema::cw_serde]
+pub struct RevokedCredentialsResponse {
+    /// Revocation records, ordered by credential identifier.
+    pub revocations: Vec<RevokedCredentialResponse>,
+}
+
+/// Response returned by `AxoneVcQueryMsg::Issuers`.
+#[cosmwasm_schema::cw_serde]
+pub struct IssuersResponse {
+    /// Delegated issuers, ordered by address.
+    pub issuers: Vec<IssuerResponse>,
+}
+
+/// Delegated issuer returned by `AxoneVcQueryMsg::Issuers`.
+#[cosmwasm_schema::cw_serde]
+pub struct IssuerResponse {
+    /// The delegated address.
+    pub address: Addr,
+    /// The credential type URIs the delegate is allowed to issue and revoke.
+    pub credential_types: Vec<Uri>,
+    /// Optional instant from which the delegation no longer applies.
+    pub expires: Option<Timestamp>,
+}
+
+/// Response returned by `AxoneVcQueryMsg::Shapes`.
+#[cosmwasm_schema::cw_serde]
+pub struct ShapesResponse {
+    /// Registered shapes, ordered by credential type.
+    pub shapes: Vec<ShapeResponse>,
+}
+
+/// Shape returned by `AxoneVcQueryMsg::Shapes`.
+#[cosmwasm_schema::cw_serde]
+pub struct ShapeResponse {
+    /// The credential type URI the shape applies to.
+    pub credential_type: Uri,
+    /// The constraints on the properties of the credential subject.
+    pub properties: Vec<PropertyShape>,
+}
+
+/// Response returned by `AxoneVcQueryMsg::StatusList`.
+///
+/// This is a `BitstringStatusListCredential` in the JSON-LD compacted form of the VC 2.0
+/// data model.
+#[cosmwasm_schema::cw_serde]
+pub struct StatusListResponse {
+    /// JSON-LD context of the credential, the VC 2.0 context.
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    /// Identifier of the status list credential, referenced by `statusListCredential`.
+    pub id: Uri,
+    /// Types of the credential.
+    #[serde(rename = "type")]
+    pub types: Vec<String>,
+    /// Authority DID issuing the status list.
+    pub issuer: Uri,
+    /// The status list.
+    #[serde(rename = "credentialSubject")]
+    pub credential_subject: StatusListSubject,
+}
+
+/// Subject of a `StatusListResponse`.
+#[cosmwasm_schema::cw_serde]
+pub struct StatusListSubject {
+    /// Identifier of the status list.
+    pub id: Uri,
+    /// Type of the subject, `BitstringStatusList`.
+    #[serde(rename = "type")]
+    pub subject_type: String,
+    /// Purpose of the status list.
+    #[serde(rename = "statusPurpose")]
+    pub status_purpose: StatusPurpose,
+    /// GZIP compressed bitstring, base64url encoded without padding and prefixed with the
+    /// `u` multibase header.
+    #[serde(rename = "encodedList")]
+    pub encoded_list: String,
+}
+
 /// RDF quad returned by `AxoneVcQueryMsg::Credential`.
 #[cosmwasm_schema::cw_serde]
 pub struct Quad {
@@ -1,4 +1,8 @@
-use crate::{contract::AxoneVcResult, domain::Authority, state};
+use crate::{
+    contract::AxoneVcResult,
+    domain::{Authority, StatusListLocation},
+    state,
+};
 
 use cosmwasm_std::{Addr, Storage};
 
@@ -17,6 +21,19 @@ pub fn authority(storage: &dyn Storage) -> AxoneVcResult<Authority> {
     state::authority(storage)
 }
 
+/// Publish the status lists of the authority at an HTTP(S) URL, instead of under its DID.
+pub fn configure_status_list_url(storage: &mut dyn Storage, url: &str) -> AxoneVcResult<()> {
+    state::save_status_list_location(storage, &StatusListLocation::url(url)?)
+}
+
+/// Where the status lists of the authority are published.
+pub fn status_list_location(storage: &dyn Storage) -> AxoneVcResult<StatusListLocation> {
+    match state::status_list_location(storage)? {
+        Some(location) => Ok(location),
+        None => Ok(StatusListLocation::of_authority(&authority(storage)?)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{authority, initialize_authority};
@@ -1,21 +1,32 @@
-use crate::domain::Uri;
+use crate::domain::{
+    status_list_length, CredentialStatus, Cryptosuite, DataIntegrityProof, DataModelVersion,
+    DelegationError, IssuerDelegation, ProofError, RevocationReasonCode, StatusListLocation,
+    StatusPurpose, Uri,
+};
 use crate::{
     contract::AxoneVcResult,
     domain::{Credential, CredentialError},
-    msg::CredentialInputFormat,
-    services::authority,
+    msg::{CredentialInputFormat, CredentialOutputFormat},
+    services::{authority, proof::verify_proof, shape::conform_to_shapes, status_list_location},
     state,
     state::{
-        credential as stored_credential, credentials as stored_credentials, credentials_by_subject,
-        credentials_by_type, has_credential, is_revoked, record_credential, CredentialRecord,
-        CredentialTombstone,
+        allocate_status_list_index, clear_status_bit, credential as stored_credential,
+        credentials as stored_credentials, credentials_by_subject, credentials_by_type,
+        has_credential, is_revoked, next_status_list_index, record_credential,
+        revoked_credential as stored_revoked_credential,
+        revoked_credentials as stored_revoked_credentials, set_status_bit, status_bits,
+        CredentialRecord, CredentialTombstone,
     },
     translation::{
-        decode_canonical_nquads_credential, decode_nquads_credential_for_issuer,
-        CredentialDecodingError, DecodedQuad,
+        decode_canonical_nquads_credential, decode_credential_for_issuer,
+        decode_secured_credential, encode_json_ld_credential, encode_ntriples_credential,
+        encode_status_list, encode_turtle_credential, CredentialDecodingError, DecodedQuad,
+        RdfSyntax,
     },
 };
-use cosmwasm_std::{Binary, StdError, StdResult, Storage, Timestamp};
+use cosmwasm_std::{
+    Addr, Api, Binary, BlockInfo, Checksum, StdError, StdResult, Storage, Timestamp,
+};
 use thiserror::Error;
 
 #[derive(Debug, PartialEq)]
@@ -26,6 +37,7 @@ pub struct IssueCredentialResult {
     pub types: Vec<String>,
     pub valid_from: Option<Timestamp>,
     pub valid_until: Option<Timestamp>,
+    pub delegate: Option<Addr>,
 }
 
 #[derive(Debug, Error, PartialEq)]
@@ -43,14 +55,33 @@ pub enum IssueCredentialError {
     Domain(#[from] CredentialError),
 }
 
+/// Issue a credential from the authority, on behalf of which the `delegate`, if any, must be
+/// allowed to issue credentials of its types. The credential subject must conform to the
+/// shapes registered for these types.
 pub fn issue_credential(
     storage: &mut dyn Storage,
     input: &[u8],
     format: CredentialInputFormat,
+    delegate: Option<&IssuerDelegation>,
 ) -> AxoneVcResult<IssueCredentialResult> {
     let authority = authority(storage)?;
-    let (credential, record) = issue_credential_with_authority(storage, authority, input, format)?;
+    let location = status_list_location(storage)?;
+    let status_list_index = next_status_list_index(storage)?;
+    let (credential, record) = issue_credential_with_authority(
+        storage,
+        authority,
+        &location,
+        input,
+        format,
+        status_list_index,
+    )?;
+    if let Some(delegate) = delegate {
+        delegate.authorize(credential.types())?;
+    }
+    conform_to_shapes(storage, &credential)?;
+    let record = record.with_delegate(delegate.map(|delegate| delegate.address.clone()));
 
+    allocate_status_list_index(storage)?;
     record_credential(storage, credential.id(), &record)?;
 
     Ok(IssueCredentialResult {
@@ -60,20 +91,22 @@ pub fn issue_credential(
         types: credential.types().clone(),
         valid_from: credential.valid_from(),
         valid_until: credential.valid_until(),
+        delegate: record.delegate,
     })
 }
 
 fn issue_credential_with_authority(
     storage: &dyn Storage,
     authority: crate::domain::Authority,
+    location: &StatusListLocation,
     input: &[u8],
     format: CredentialInputFormat,
+    status_list_index: u64,
 ) -> Result<(Credential, CredentialRecord), IssueCredentialError> {
-    let decoded = match format {
-        CredentialInputFormat::NQuads => {
-            decode_nquads_credential_for_issuer(input, authority.did())?
-        }
-    };
+    let status_entries =
+        StatusPurpose::ALL.map(|purpose| purpose.status_entry(location, status_list_index));
+    let decoded =
+        decode_credential_for_issuer(input, format.into(), authority.did(), &status_entries)?;
     let canonical_nquads = decoded.canonical_nquads().clone();
     let valid_from = *decoded.valid_from();
     let valid_until = *decoded.valid_until();
@@ -93,7 +126,123 @@ fn issue_credential_with_authority(
         credential.types().clone(),
         valid_from,
         valid_until,
-    );
+        credential.data_model(),
+    )
+    .with_status_list_index(status_list_index);
+
+    Ok((credential, record))
+}
+
+impl From<CredentialInputFormat> for RdfSyntax {
+    fn from(format: CredentialInputFormat) -> Self {
+        match format {
+            CredentialInputFormat::NQuads => RdfSyntax::NQuads,
+            CredentialInputFormat::Turtle => RdfSyntax::Turtle,
+            CredentialInputFormat::TriG => RdfSyntax::TriG,
+            CredentialInputFormat::JsonLd => RdfSyntax::JsonLd,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AnchorCredentialResult {
+    pub credential_id: String,
+    pub issuer: String,
+    pub subject: String,
+    pub types: Vec<String>,
+    pub cryptosuite: Cryptosuite,
+    pub verification_method: String,
+    pub status_list_index: u64,
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum AnchorCredentialError {
+    #[error("credential already exists")]
+    CredentialAlreadyExists,
+
+    #[error("credential revoked")]
+    CredentialRevoked,
+
+    #[error(transparent)]
+    Decode(#[from] CredentialDecodingError),
+
+    #[error(transparent)]
+    Domain(#[from] CredentialError),
+
+    #[error(transparent)]
+    Proof(#[from] ProofError),
+}
+
+/// Record a credential of another issuer once its Data Integrity proof is verified.
+///
+/// The credential is stored as secured, its proof graph included, and keeps the issuer it
+/// was signed by. It is allocated an index in the status lists of the authority, which reflect
+/// its revocation and suspension as for the credentials the authority issues.
+pub fn anchor_credential(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    input: &[u8],
+    format: CredentialInputFormat,
+) -> AxoneVcResult<AnchorCredentialResult> {
+    let (credential, record) = anchor_credential_record(storage, api, input, format)?;
+
+    let status_list_index = allocate_status_list_index(storage)?;
+    let record = record.with_status_list_index(status_list_index);
+    record_credential(storage, credential.id(), &record)?;
+
+    let proof = record
+        .proof
+        .ok_or_else(|| StdError::generic_err("anchored credential proof missing"))?;
+    Ok(AnchorCredentialResult {
+        credential_id: credential.id().clone(),
+        issuer: credential.issuer().clone(),
+        subject: credential.subject_id().clone(),
+        types: credential.types().clone(),
+        cryptosuite: proof.cryptosuite,
+        verification_method: proof.verification_method,
+        status_list_index,
+    })
+}
+
+fn anchor_credential_record(
+    storage: &dyn Storage,
+    api: &dyn Api,
+    input: &[u8],
+    format: CredentialInputFormat,
+) -> Result<(Credential, CredentialRecord), AnchorCredentialError> {
+    let (decoded, decoded_proof, canonical_document, canonical_proof_config) =
+        decode_secured_credential(input, format.into())?.into_parts();
+    let canonical_nquads = decoded.canonical_nquads().clone();
+    let valid_from = *decoded.valid_from();
+    let valid_until = *decoded.valid_until();
+    let credential = Credential::try_from(decoded)?;
+
+    if has_credential(storage, credential.id()) {
+        return Err(AnchorCredentialError::CredentialAlreadyExists);
+    }
+
+    if is_revoked(storage, credential.id()) {
+        return Err(AnchorCredentialError::CredentialRevoked);
+    }
+
+    let proof = DataIntegrityProof::try_from(&decoded_proof)?;
+    verify_proof(
+        api,
+        &proof,
+        credential.issuer(),
+        &canonical_document,
+        &canonical_proof_config,
+    )?;
+
+    let record = CredentialRecord::new(
+        canonical_nquads,
+        credential.subject_id().clone(),
+        credential.types().clone(),
+        valid_from,
+        valid_until,
+        credential.data_model(),
+    )
+    .with_proof(proof);
 
     Ok((credential, record))
 }
@@ -102,6 +251,7 @@ fn issue_credential_with_authority(
 pub struct VerifyCredentialResult {
     pub exists: bool,
     pub valid: bool,
+    pub status: Option<CredentialStatus>,
 }
 
 pub fn verify_credential(
@@ -113,31 +263,61 @@ pub fn verify_credential(
         return Ok(VerifyCredentialResult {
             exists: false,
             valid: false,
+            status: None,
         });
     };
 
-    let valid = valid_at.is_none_or(|at| {
-        record.valid_from.is_none_or(|from| from <= at)
-            && record.valid_until.is_none_or(|until| at < until)
-    });
+    let valid = record.status == CredentialStatus::Active
+        && valid_at.is_none_or(|at| {
+            record.valid_from.is_none_or(|from| from <= at)
+                && record.valid_until.is_none_or(|until| at < until)
+        });
 
     Ok(VerifyCredentialResult {
         exists: true,
         valid,
+        status: Some(record.status),
     })
 }
 
-pub fn credential_raw(storage: &dyn Storage, credential_id: &str) -> AxoneVcResult<Binary> {
+/// The context compacted JSON-LD credentials are returned against when none is requested.
+const DEFAULT_JSON_LD_CONTEXT: &str = "https://www.w3.org/ns/credentials/v2";
+
+pub fn credential_raw(
+    storage: &dyn Storage,
+    credential_id: &str,
+    output_format: CredentialOutputFormat,
+) -> AxoneVcResult<Binary> {
     let record = stored_credential(storage, credential_id)?
         .ok_or_else(|| StdError::not_found("credential"))?;
+    let canonical = record.canonical_nquads;
+
+    let encoded = match output_format {
+        CredentialOutputFormat::NQuads => canonical.into_bytes(),
+        CredentialOutputFormat::NTriples => encode_ntriples_credential(&canonical)?,
+        CredentialOutputFormat::Turtle { prefixes } => encode_turtle_credential(
+            &canonical,
+            prefixes
+                .iter()
+                .map(|(name, iri)| (name.as_str(), iri.as_str())),
+        )?,
+        CredentialOutputFormat::JsonLd { contexts } => encode_json_ld_credential(
+            &canonical,
+            &contexts.unwrap_or_else(|| vec![DEFAULT_JSON_LD_CONTEXT.to_string()]),
+        )?,
+    };
 
-    Ok(Binary::from(record.canonical_nquads.into_bytes()))
+    Ok(Binary::from(encoded))
 }
 
 #[derive(Debug, PartialEq)]
 pub struct CredentialResult {
     pub parsed: Credential,
+    pub data_model: DataModelVersion,
     pub quads: Vec<DecodedQuad>,
+    pub proof: Option<DataIntegrityProof>,
+    pub status: CredentialStatus,
+    pub delegate: Option<Addr>,
 }
 
 pub fn credential(storage: &dyn Storage, credential_id: &str) -> AxoneVcResult<CredentialResult> {
@@ -148,14 +328,24 @@ pub fn credential(storage: &dyn Storage, credential_id: &str) -> AxoneVcResult<C
     let quads = decoded.quads().clone();
     let parsed = Credential::try_from(decoded)?;
 
-    Ok(CredentialResult { parsed, quads })
+    Ok(CredentialResult {
+        parsed,
+        data_model: record.data_model,
+        quads,
+        proof: record.proof,
+        status: record.status,
+        delegate: record.delegate,
+    })
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn credentials(
     storage: &dyn Storage,
     subject: Option<&str>,
     credential_type: Option<&str>,
     valid_at: Option<Timestamp>,
+    data_model: Option<DataModelVersion>,
+    status: Option<CredentialStatus>,
     limit: usize,
     start_after: Option<String>,
 ) -> AxoneVcResult<Vec<Uri>> {
@@ -192,17 +382,39 @@ pub fn credentials(
         None => Box::new(|_| true),
     };
 
+    let data_model_filter: FilterPredicate = match data_model {
+        Some(version) => Box::new(move |r| match r {
+            Ok((_, c)) => c.data_model == version,
+            Err(_) => true,
+        }),
+        None => Box::new(|_| true),
+    };
+
+    let status_filter: FilterPredicate = match status {
+        Some(status) => Box::new(move |r| match r {
+            Ok((_, c)) => c.status == status,
+            Err(_) => true,
+        }),
+        None => Box::new(|_| true),
+    };
+
     it.filter(type_filter)
         .filter(valid_filter)
+        .filter(data_model_filter)
+        .filter(status_filter)
         .take(limit)
         .map(|item| item.map(|(id, _record)| id).map_err(Into::into))
         .collect::<AxoneVcResult<Vec<_>>>()
 }
 
+/// The maximum length, in bytes, of the free-text reason of a revocation.
+pub const MAX_REVOCATION_REASON_LENGTH: usize = 512;
+
 #[derive(Debug, PartialEq)]
 pub struct RevokeCredentialResult {
     pub identifier: String,
     pub issuer: String,
+    pub reason_code: Option<RevocationReasonCode>,
 }
 
 #[derive(Debug, Error, PartialEq)]
@@ -212,26 +424,207 @@ pub enum RevokeCredentialError {
 
     #[error("credential unknown")]
     UnknownCredential,
+
+    #[error("revocation reason exceeds {MAX_REVOCATION_REASON_LENGTH} bytes")]
+    ReasonTooLong,
 }
 
+/// Revoke a credential, recording who revoked it, when, why, and the hash of the
+/// canonical dataset it had.
+///
+/// The `delegate`, if any, is the revoker, and must be allowed to revoke credentials of the
+/// types of the revoked one. Anchored credentials, issued outside of this authority, are only
+/// revoked by the authority itself.
+#[allow(clippy::too_many_arguments)]
 pub fn revoke_credential(
     storage: &mut dyn Storage,
+    block: &BlockInfo,
+    revoker: &Addr,
+    delegate: Option<&IssuerDelegation>,
     credential_id: &str,
+    reason_code: Option<RevocationReasonCode>,
+    reason: Option<String>,
 ) -> AxoneVcResult<RevokeCredentialResult> {
-    let authority = authority(storage)?;
     if is_revoked(storage, credential_id) {
         return Err(RevokeCredentialError::CredentialAlreadyRevoked.into());
     }
-    if !has_credential(storage, credential_id) {
-        return Err(RevokeCredentialError::UnknownCredential.into());
+    let record = stored_credential(storage, credential_id)?
+        .ok_or(RevokeCredentialError::UnknownCredential)?;
+    if let Some(delegate) = delegate {
+        if record.proof.is_some() {
+            return Err(DelegationError::AnchoredCredential.into());
+        }
+        delegate.authorize(&record.types)?;
+    }
+    if reason
+        .as_ref()
+        .is_some_and(|reason| reason.len() > MAX_REVOCATION_REASON_LENGTH)
+    {
+        return Err(RevokeCredentialError::ReasonTooLong.into());
     }
 
-    let tombstone = CredentialTombstone::new();
+    let tombstone = CredentialTombstone::new(
+        block.height,
+        block.time,
+        revoker.clone(),
+        Checksum::generate(record.canonical_nquads.as_bytes()),
+    )
+    .with_reason(reason_code, reason);
     state::revoke_credential(storage, credential_id, &tombstone)?;
+    if let Some(index) = record.status_list_index {
+        set_status_bit(storage, StatusPurpose::Revocation, index)?;
+    }
 
     Ok(RevokeCredentialResult {
         identifier: credential_id.to_string(),
+        issuer: record_issuer(&record)?,
+        reason_code,
+    })
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RevokedCredentialResult {
+    pub identifier: Uri,
+    pub tombstone: CredentialTombstone,
+}
+
+pub fn revoked_credential(
+    storage: &dyn Storage,
+    credential_id: &str,
+) -> AxoneVcResult<RevokedCredentialResult> {
+    let tombstone = stored_revoked_credential(storage, credential_id)?
+        .ok_or_else(|| StdError::not_found("revoked credential"))?;
+
+    Ok(RevokedCredentialResult {
+        identifier: credential_id.to_string(),
+        tombstone,
+    })
+}
+
+pub fn revoked_credentials(
+    storage: &dyn Storage,
+    limit: usize,
+    start_after: Option<String>,
+) -> AxoneVcResult<Vec<RevokedCredentialResult>> {
+    stored_revoked_credentials(storage, start_after.as_deref())
+        .take(limit)
+        .map(|item| {
+            item.map(|(identifier, tombstone)| RevokedCredentialResult {
+                identifier,
+                tombstone,
+            })
+            .map_err(Into::into)
+        })
+        .collect()
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CredentialStatusResult {
+    pub identifier: String,
+    pub issuer: String,
+    pub status: CredentialStatus,
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum CredentialStatusError {
+    #[error("credential already suspended")]
+    CredentialAlreadySuspended,
+
+    #[error("credential not suspended")]
+    CredentialNotSuspended,
+
+    #[error("credential revoked")]
+    CredentialRevoked,
+
+    #[error("credential unknown")]
+    UnknownCredential,
+}
+
+/// Suspend an active credential, setting its bit in the suspension status list.
+pub fn suspend_credential(
+    storage: &mut dyn Storage,
+    credential_id: &str,
+) -> AxoneVcResult<CredentialStatusResult> {
+    update_credential_status(storage, credential_id, CredentialStatus::Suspended)
+}
+
+/// Reinstate a suspended credential, clearing its bit in the suspension status list.
+pub fn reinstate_credential(
+    storage: &mut dyn Storage,
+    credential_id: &str,
+) -> AxoneVcResult<CredentialStatusResult> {
+    update_credential_status(storage, credential_id, CredentialStatus::Active)
+}
+
+fn update_credential_status(
+    storage: &mut dyn Storage,
+    credential_id: &str,
+    status: CredentialStatus,
+) -> AxoneVcResult<CredentialStatusResult> {
+    if is_revoked(storage, credential_id) {
+        return Err(CredentialStatusError::CredentialRevoked.into());
+    }
+    let mut record = stored_credential(storage, credential_id)?
+        .ok_or(CredentialStatusError::UnknownCredential)?;
+    if record.status == status {
+        return Err(match status {
+            CredentialStatus::Active => CredentialStatusError::CredentialNotSuspended,
+            CredentialStatus::Suspended => CredentialStatusError::CredentialAlreadySuspended,
+        }
+        .into());
+    }
+
+    record.status = status;
+    record_credential(storage, credential_id, &record)?;
+    if let Some(index) = record.status_list_index {
+        match status {
+            CredentialStatus::Active => clear_status_bit(storage, StatusPurpose::Suspension, index),
+            CredentialStatus::Suspended => {
+                set_status_bit(storage, StatusPurpose::Suspension, index)?
+            }
+        }
+    }
+
+    Ok(CredentialStatusResult {
+        identifier: credential_id.to_string(),
+        issuer: record_issuer(&record)?,
+        status,
+    })
+}
+
+/// The issuer of a recorded credential, which is not the authority for anchored credentials.
+fn record_issuer(record: &CredentialRecord) -> AxoneVcResult<String> {
+    let decoded = decode_canonical_nquads_credential(&record.canonical_nquads)?;
+    Ok(Credential::try_from(decoded)?.issuer().clone())
+}
+
+#[derive(Debug, PartialEq)]
+pub struct StatusListResult {
+    pub id: Uri,
+    pub issuer: Uri,
+    pub purpose: StatusPurpose,
+    pub encoded_list: String,
+}
+
+/// Build the Bitstring Status List of the given purpose over every allocated index.
+pub fn status_list(
+    storage: &dyn Storage,
+    purpose: StatusPurpose,
+) -> AxoneVcResult<StatusListResult> {
+    let authority = authority(storage)?;
+    let location = status_list_location(storage)?;
+    let length = status_list_length(next_status_list_index(storage)?);
+    let set_indexes = status_bits(storage, purpose).collect::<StdResult<Vec<_>>>()?;
+    let encoded_list = encode_status_list(
+        usize::try_from(length).map_err(|_| StdError::generic_err("status list too large"))?,
+        set_indexes,
+    )?;
+
+    Ok(StatusListResult {
+        id: purpose.status_list_credential(&location),
         issuer: authority.did().to_string(),
+        purpose,
+        encoded_list,
     })
 }
 
@@ -239,12 +632,18 @@ pub fn revoke_credential(
 mod tests {
     use super::*;
     use crate::{
-        domain::Authority, error::AxoneVcError, msg::CredentialInputFormat,
-        services::initialize_authority, state::load_credential,
+        domain::{Authority, StatusListError},
+        error::AxoneVcError,
+        msg::CredentialInputFormat,
+        services::{configure_status_list_url, initialize_authority},
+        state::load_credential,
         translation::CredentialDecodingError,
     };
     use bech32::{Bech32, Hrp};
-    use cosmwasm_std::{testing::mock_dependencies, Addr};
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env},
+        Addr, Checksum,
+    };
 
     fn credential_payload(authority_did: &str, id: &str) -> Vec<u8> {
         format!(
@@ -336,6 +735,18 @@ mod tests {
         .expect("authority should initialize")
     }
 
+    fn revoke(storage: &mut dyn Storage, id: &str) -> AxoneVcResult<RevokeCredentialResult> {
+        revoke_credential(
+            storage,
+            &mock_env().block,
+            &Addr::unchecked("revoker"),
+            None,
+            id,
+            None,
+            None,
+        )
+    }
+
     #[test]
     fn issue_credential_persists_canonical_record() {
         let mut deps = mock_dependencies();
@@ -346,6 +757,7 @@ mod tests {
             deps.as_mut().storage,
             &credential_payload(authority.did(), credential_id),
             CredentialInputFormat::NQuads,
+            None,
         )
         .expect("submit should succeed");
 
@@ -377,6 +789,7 @@ mod tests {
             deps.as_mut().storage,
             &payload,
             CredentialInputFormat::NQuads,
+            None,
         )
         .expect("first submit should succeed");
 
@@ -384,6 +797,7 @@ mod tests {
             deps.as_mut().storage,
             &payload,
             CredentialInputFormat::NQuads,
+            None,
         )
         .expect_err("second submit should fail");
 
@@ -403,6 +817,7 @@ mod tests {
             deps.as_mut().storage,
             &credential_payload_without_issuer(credential_id),
             CredentialInputFormat::NQuads,
+            None,
         )
         .expect("missing issuer should be inferred from authority");
 
@@ -417,6 +832,73 @@ mod tests {
         )));
     }
 
+    #[test]
+    fn issue_credential_accepts_json_ld_payloads_as_canonical_n_quads() {
+        let mut deps = mock_dependencies();
+        let credential_id = "urn:uuid:credential-json-ld";
+        let authority = initialized_authority(&mut deps);
+        let payload = format!(
+            r#"{{
+                "@context": "https://www.w3.org/2018/credentials/v1",
+                "id": "{credential_id}",
+                "type": "VerifiableCredential",
+                "issuanceDate": "2025-01-01T00:00:00Z",
+                "credentialSubject": "did:example:subject"
+            }}"#
+        );
+
+        issue_credential(
+            deps.as_mut().storage,
+            payload.as_bytes(),
+            CredentialInputFormat::JsonLd,
+            None,
+        )
+        .expect("JSON-LD credential should issue");
+
+        let record = load_credential(deps.as_ref().storage, credential_id)
+            .expect("credential should be persisted");
+        let mut expected = Vec::from_iter(
+            [
+                format!("<{credential_id}> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential> ."),
+                format!("<{credential_id}> <https://www.w3.org/2018/credentials#credentialSubject> <did:example:subject> ."),
+                format!("<{credential_id}> <https://www.w3.org/2018/credentials#issuanceDate> \"2025-01-01T00:00:00Z\"^^<http://www.w3.org/2001/XMLSchema#dateTime> ."),
+                format!("<{credential_id}> <https://www.w3.org/2018/credentials#issuer> <{}> .", authority.did()),
+            ],
+        );
+        for purpose in StatusPurpose::ALL {
+            let list =
+                purpose.status_list_credential(&StatusListLocation::of_authority(&authority));
+            expected.extend([
+                format!("<{credential_id}> <https://www.w3.org/2018/credentials#credentialStatus> <{list}#0> ."),
+                format!("<{list}#0> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/ns/credentials/status#BitstringStatusListEntry> ."),
+                format!("<{list}#0> <https://www.w3.org/ns/credentials/status#statusListCredential> <{list}> ."),
+                format!("<{list}#0> <https://www.w3.org/ns/credentials/status#statusListIndex> \"0\" ."),
+                format!("<{list}#0> <https://www.w3.org/ns/credentials/status#statusPurpose> \"{}\" .", purpose.name()),
+            ]);
+        }
+        expected.sort();
+        assert_eq!(
+            record.canonical_nquads,
+            format!("{}\n", expected.join("\n"))
+        );
+
+        let err = issue_credential(
+            deps.as_mut().storage,
+            br#"{"@context": "https://example.com/credentials/v1", "id": "urn:uuid:other"}"#,
+            CredentialInputFormat::JsonLd,
+            None,
+        )
+        .expect_err("unknown contexts should be rejected");
+        assert_eq!(
+            err,
+            AxoneVcError::IssueCredential(IssueCredentialError::Decode(
+                CredentialDecodingError::UnsupportedJsonLdContext(
+                    "https://example.com/credentials/v1".to_string()
+                )
+            ))
+        );
+    }
+
     #[test]
     fn issue_credential_with_authority_rejects_duplicates() {
         let mut deps = mock_dependencies();
@@ -427,14 +909,18 @@ mod tests {
             deps.as_mut().storage,
             &payload,
             CredentialInputFormat::NQuads,
+            None,
         )
         .expect("first submit should succeed");
 
+        let location = StatusListLocation::of_authority(&authority);
         let err = issue_credential_with_authority(
             deps.as_ref().storage,
             authority,
+            &location,
             &payload,
             CredentialInputFormat::NQuads,
+            1,
         )
         .expect_err("second submit should fail");
 
@@ -451,17 +937,20 @@ mod tests {
             deps.as_mut().storage,
             &payload,
             CredentialInputFormat::NQuads,
+            None,
         )
         .expect("first submit should succeed");
 
-        revoke_credential(deps.as_mut().storage, "urn:uuid:credential-1")
-            .expect("revocation should succeed");
+        revoke(deps.as_mut().storage, "urn:uuid:credential-1").expect("revocation should succeed");
 
+        let location = StatusListLocation::of_authority(&authority);
         let err = issue_credential_with_authority(
             deps.as_ref().storage,
             authority,
+            &location,
             &payload,
             CredentialInputFormat::NQuads,
+            1,
         )
         .expect_err("second submit should fail");
 
@@ -485,6 +974,7 @@ mod tests {
                 "1970-01-01T00:00:20Z",
             ),
             CredentialInputFormat::NQuads,
+            None,
         )
         .expect("credential should issue");
 
@@ -507,6 +997,7 @@ mod tests {
                 VerifyCredentialResult {
                     exists: true,
                     valid: expected_valid,
+                    status: Some(CredentialStatus::Active),
                 }
             );
         }
@@ -529,6 +1020,7 @@ mod tests {
                 "1970-01-01T00:00:20Z",
             ),
             CredentialInputFormat::NQuads,
+            None,
         )
         .expect("credential should issue");
 
@@ -570,9 +1062,10 @@ mod tests {
             deps.as_mut().storage,
             &credential_payload(authority.did(), credential_id),
             CredentialInputFormat::NQuads,
+            None,
         )
         .expect("credential should issue");
-        revoke_credential(deps.as_mut().storage, credential_id).expect("credential should revoke");
+        revoke(deps.as_mut().storage, credential_id).expect("credential should revoke");
 
         let err = credential(deps.as_ref().storage, credential_id)
             .expect_err("revoked credential should fail");
@@ -585,8 +1078,17 @@ mod tests {
         let authority = initialized_authority(&mut deps);
 
         assert_eq!(
-            credentials(deps.as_ref().storage, None, None, None, 10, None)
-                .expect("credentials query should succeed"),
+            credentials(
+                deps.as_ref().storage,
+                None,
+                None,
+                None,
+                None,
+                None,
+                10,
+                None
+            )
+            .expect("credentials query should succeed"),
             Vec::<String>::new()
         );
 
@@ -594,11 +1096,12 @@ mod tests {
             deps.as_mut().storage,
             &credential_payload(authority.did(), "urn:uuid:credential-1"),
             CredentialInputFormat::NQuads,
+            None,
         )
         .expect("credential should issue");
 
         assert_eq!(
-            credentials(deps.as_ref().storage, None, None, None, 0, None)
+            credentials(deps.as_ref().storage, None, None, None, None, None, 0, None)
                 .expect("credentials query should succeed"),
             Vec::<String>::new()
         );
@@ -618,12 +1121,13 @@ mod tests {
                 deps.as_mut().storage,
                 &credential_payload(authority.did(), id),
                 CredentialInputFormat::NQuads,
+                None,
             )
             .expect("credential should issue");
         }
 
         assert_eq!(
-            credentials(deps.as_ref().storage, None, None, None, 2, None)
+            credentials(deps.as_ref().storage, None, None, None, None, None, 2, None)
                 .expect("first page should load"),
             vec!["urn:uuid:credential-a", "urn:uuid:credential-b"]
         );
@@ -633,6 +1137,8 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
+                None,
                 2,
                 Some("urn:uuid:credential-b".to_string())
             )
@@ -680,6 +1186,7 @@ mod tests {
                 deps.as_mut().storage,
                 &payload,
                 CredentialInputFormat::NQuads,
+                None,
             )
             .expect("credential should issue");
         }
@@ -690,6 +1197,8 @@ mod tests {
                 Some("did:example:alice"),
                 None,
                 None,
+                None,
+                None,
                 10,
                 None
             )
@@ -706,6 +1215,8 @@ mod tests {
                 None,
                 Some("https://example.com/types/Member"),
                 None,
+                None,
+                None,
                 10,
                 None
             )
@@ -718,6 +1229,8 @@ mod tests {
                 Some("did:example:alice"),
                 Some("https://example.com/types/Member"),
                 Some(Timestamp::from_seconds(25)),
+                None,
+                None,
                 10,
                 None
             )
@@ -730,6 +1243,8 @@ mod tests {
                 None,
                 None,
                 Some(Timestamp::from_seconds(20)),
+                None,
+                None,
                 10,
                 None
             )
@@ -757,10 +1272,10 @@ mod tests {
                 None,
             ),
             CredentialInputFormat::NQuads,
+            None,
         )
         .expect("credential should issue");
-        revoke_credential(deps.as_mut().storage, "urn:uuid:credential-a")
-            .expect("credential should revoke");
+        revoke(deps.as_mut().storage, "urn:uuid:credential-a").expect("credential should revoke");
 
         assert_eq!(
             credentials(
@@ -768,6 +1283,8 @@ mod tests {
                 Some("did:example:alice"),
                 Some("https://example.com/types/Member"),
                 None,
+                None,
+                None,
                 10,
                 None
             )
@@ -793,6 +1310,7 @@ mod tests {
             deps.as_mut().storage,
             wrong_type.as_bytes(),
             CredentialInputFormat::NQuads,
+            None,
         )
         .expect_err("non-dateTimeStamp validity claims should fail");
         assert_eq!(
@@ -811,6 +1329,7 @@ mod tests {
                 "1970-01-01T00:00:10Z",
             ),
             CredentialInputFormat::NQuads,
+            None,
         )
         .expect_err("inverted validity interval should fail");
         assert_eq!(
@@ -833,6 +1352,7 @@ mod tests {
             VerifyCredentialResult {
                 exists: false,
                 valid: false,
+                status: None,
             }
         );
 
@@ -840,9 +1360,10 @@ mod tests {
             deps.as_mut().storage,
             &credential_payload(authority.did(), credential_id),
             CredentialInputFormat::NQuads,
+            None,
         )
         .expect("credential should issue");
-        revoke_credential(deps.as_mut().storage, credential_id).expect("credential should revoke");
+        revoke(deps.as_mut().storage, credential_id).expect("credential should revoke");
 
         assert_eq!(
             verify_credential(deps.as_ref().storage, credential_id, None)
@@ -850,6 +1371,7 @@ mod tests {
             VerifyCredentialResult {
                 exists: false,
                 valid: false,
+                status: None,
             }
         );
     }
@@ -864,56 +1386,837 @@ mod tests {
             deps.as_mut().storage,
             &payload,
             CredentialInputFormat::NQuads,
+            None,
         )
         .expect("submit should succeed");
+        let canonical_nquads = load_credential(deps.as_ref().storage, "urn:uuid:credential-1")
+            .expect("credential should be persisted")
+            .canonical_nquads;
 
-        let res = revoke_credential(deps.as_mut().storage, "urn:uuid:credential-1");
+        let block = mock_env().block;
+        let res = revoke_credential(
+            deps.as_mut().storage,
+            &block,
+            &Addr::unchecked("revoker"),
+            None,
+            "urn:uuid:credential-1",
+            Some(RevocationReasonCode::KeyCompromise),
+            Some("signing key leaked".to_string()),
+        );
         assert!(res.is_ok(), "revoke should succeed");
         assert_eq!(
             res.unwrap(),
             RevokeCredentialResult {
                 identifier: "urn:uuid:credential-1".to_string(),
                 issuer: authority.did().to_string(),
+                reason_code: Some(RevocationReasonCode::KeyCompromise),
             }
         );
-    }
-
-    #[test]
-    fn revoke_credential_rejects_unknown_credential() {
-        let mut deps = mock_dependencies();
-        initialized_authority(&mut deps);
-
-        let err = revoke_credential(deps.as_mut().storage, "urn:uuid:credential-1")
-            .expect_err("revocation should fail");
-
         assert_eq!(
-            err,
-            AxoneVcError::RevokeCredential(RevokeCredentialError::UnknownCredential)
+            revoked_credential(deps.as_ref().storage, "urn:uuid:credential-1"),
+            Ok(RevokedCredentialResult {
+                identifier: "urn:uuid:credential-1".to_string(),
+                tombstone: CredentialTombstone {
+                    block_height: block.height,
+                    revoked_at: block.time,
+                    revoker: Some(Addr::unchecked("revoker")),
+                    reason_code: Some(RevocationReasonCode::KeyCompromise),
+                    reason: Some("signing key leaked".to_string()),
+                    canonical_hash: Some(Checksum::generate(canonical_nquads.as_bytes())),
+                },
+            })
         );
     }
 
     #[test]
-    fn revoke_credential_rejects_revoked_credential() {
+    fn revoke_credential_rejects_too_long_reasons() {
         let mut deps = mock_dependencies();
         let authority = initialized_authority(&mut deps);
-        let payload = credential_payload(authority.did(), "urn:uuid:credential-1");
-
         issue_credential(
             deps.as_mut().storage,
-            &payload,
+            &credential_payload(authority.did(), "urn:uuid:credential-1"),
             CredentialInputFormat::NQuads,
+            None,
         )
         .expect("submit should succeed");
 
-        revoke_credential(deps.as_mut().storage, "urn:uuid:credential-1")
-            .expect("first revoke should succeed");
-
-        let err = revoke_credential(deps.as_mut().storage, "urn:uuid:credential-1")
-            .expect_err("second revocation should fail");
-
         assert_eq!(
-            err,
-            AxoneVcError::RevokeCredential(RevokeCredentialError::CredentialAlreadyRevoked)
+            revoke_credential(
+                deps.as_mut().storage,
+                &mock_env().block,
+                &Addr::unchecked("revoker"),
+                None,
+                "urn:uuid:credential-1",
+                None,
+                Some("x".repeat(MAX_REVOCATION_REASON_LENGTH + 1)),
+            ),
+            Err(AxoneVcError::RevokeCredential(
+                RevokeCredentialError::ReasonTooLong
+            ))
+        );
+        assert!(!is_revoked(deps.as_ref().storage, "urn:uuid:credential-1"));
+    }
+
+    #[test]
+    fn revoked_credentials_paginates_revocation_records() {
+        let mut deps = mock_dependencies();
+        let authority = initialized_authority(&mut deps);
+        for id in [
+            "urn:uuid:credential-c",
+            "urn:uuid:credential-a",
+            "urn:uuid:credential-b",
+        ] {
+            issue_credential(
+                deps.as_mut().storage,
+                &credential_payload(authority.did(), id),
+                CredentialInputFormat::NQuads,
+                None,
+            )
+            .expect("submit should succeed");
+            revoke(deps.as_mut().storage, id).expect("revoke should succeed");
+        }
+
+        let identifiers = |limit, start_after: Option<&str>| {
+            revoked_credentials(
+                deps.as_ref().storage,
+                limit,
+                start_after.map(str::to_string),
+            )
+            .expect("revoked credentials should load")
+            .into_iter()
+            .map(|result| result.identifier)
+            .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            identifiers(2, None),
+            vec!["urn:uuid:credential-a", "urn:uuid:credential-b"]
+        );
+        assert_eq!(
+            identifiers(2, Some("urn:uuid:credential-b")),
+            vec!["urn:uuid:credential-c"]
+        );
+        assert_eq!(
+            revoked_credential(deps.as_ref().storage, "urn:uuid:unknown"),
+            Err(StdError::not_found("revoked credential").into())
+        );
+    }
+
+    #[test]
+    fn delegated_issuers_issue_and_revoke_granted_types_only() {
+        let mut deps = mock_dependencies();
+        let authority = initialized_authority(&mut deps);
+        let member = "https://example.com/types/Member";
+        let employee = "https://example.com/types/Employee";
+        let delegate = IssuerDelegation::new(
+            Addr::unchecked("delegate"),
+            vec![member.to_string()],
+            None,
+            mock_env().block.time,
+        )
+        .expect("delegation should build");
+
+        let result = issue_credential(
+            deps.as_mut().storage,
+            &credential_payload_with_metadata(
+                authority.did(),
+                "urn:uuid:credential-member",
+                "did:example:alice",
+                &[member],
+                None,
+            ),
+            CredentialInputFormat::NQuads,
+            Some(&delegate),
+        )
+        .expect("granted type should issue");
+        assert_eq!(result.delegate, Some(Addr::unchecked("delegate")));
+        assert_eq!(
+            credential(deps.as_ref().storage, "urn:uuid:credential-member")
+                .expect("credential should load")
+                .delegate,
+            Some(Addr::unchecked("delegate"))
+        );
+
+        assert_eq!(
+            issue_credential(
+                deps.as_mut().storage,
+                &credential_payload_with_metadata(
+                    authority.did(),
+                    "urn:uuid:credential-employee",
+                    "did:example:alice",
+                    &[member, employee],
+                    None,
+                ),
+                CredentialInputFormat::NQuads,
+                Some(&delegate),
+            ),
+            Err(AxoneVcError::Delegation(
+                DelegationError::CredentialTypeNotGranted(employee.to_string())
+            ))
+        );
+        assert!(!has_credential(
+            deps.as_ref().storage,
+            "urn:uuid:credential-employee"
+        ));
+
+        issue_credential(
+            deps.as_mut().storage,
+            &credential_payload_with_metadata(
+                authority.did(),
+                "urn:uuid:credential-employee",
+                "did:example:alice",
+                &[employee],
+                None,
+            ),
+            CredentialInputFormat::NQuads,
+            None,
+        )
+        .expect("authority should issue any type");
+        assert_eq!(
+            revoke_credential(
+                deps.as_mut().storage,
+                &mock_env().block,
+                &delegate.address,
+                Some(&delegate),
+                "urn:uuid:credential-employee",
+                None,
+                None,
+            ),
+            Err(AxoneVcError::Delegation(
+                DelegationError::CredentialTypeNotGranted(employee.to_string())
+            ))
+        );
+        revoke_credential(
+            deps.as_mut().storage,
+            &mock_env().block,
+            &delegate.address,
+            Some(&delegate),
+            "urn:uuid:credential-member",
+            None,
+            None,
+        )
+        .expect("granted type should revoke");
+
+        let base = credential_payload(authority.did(), "urn:uuid:credential-base");
+        assert_eq!(
+            issue_credential(
+                deps.as_mut().storage,
+                &base,
+                CredentialInputFormat::NQuads,
+                Some(&delegate),
+            ),
+            Err(AxoneVcError::Delegation(DelegationError::BaseTypeOnly))
+        );
+        issue_credential(
+            deps.as_mut().storage,
+            &base,
+            CredentialInputFormat::NQuads,
+            None,
+        )
+        .expect("authority should issue credentials of the base type only");
+        assert_eq!(
+            revoke_credential(
+                deps.as_mut().storage,
+                &mock_env().block,
+                &delegate.address,
+                Some(&delegate),
+                "urn:uuid:credential-base",
+                None,
+                None,
+            ),
+            Err(AxoneVcError::Delegation(DelegationError::BaseTypeOnly))
+        );
+    }
+
+    #[test]
+    fn revoke_credential_rejects_unknown_credential() {
+        let mut deps = mock_dependencies();
+        initialized_authority(&mut deps);
+
+        let err = revoke(deps.as_mut().storage, "urn:uuid:credential-1")
+            .expect_err("revocation should fail");
+
+        assert_eq!(
+            err,
+            AxoneVcError::RevokeCredential(RevokeCredentialError::UnknownCredential)
+        );
+    }
+
+    #[test]
+    fn revoke_credential_rejects_revoked_credential() {
+        let mut deps = mock_dependencies();
+        let authority = initialized_authority(&mut deps);
+        let payload = credential_payload(authority.did(), "urn:uuid:credential-1");
+
+        issue_credential(
+            deps.as_mut().storage,
+            &payload,
+            CredentialInputFormat::NQuads,
+            None,
+        )
+        .expect("submit should succeed");
+
+        revoke(deps.as_mut().storage, "urn:uuid:credential-1")
+            .expect("first revoke should succeed");
+
+        let err = revoke(deps.as_mut().storage, "urn:uuid:credential-1")
+            .expect_err("second revocation should fail");
+
+        assert_eq!(
+            err,
+            AxoneVcError::RevokeCredential(RevokeCredentialError::CredentialAlreadyRevoked)
+        );
+    }
+
+    #[test]
+    fn status_list_sets_the_bits_of_revoked_credentials() {
+        use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+        use std::io::Read;
+
+        let mut deps = mock_dependencies();
+        let authority = initialized_authority(&mut deps);
+        for id in ["urn:uuid:credential-1", "urn:uuid:credential-2"] {
+            issue_credential(
+                deps.as_mut().storage,
+                &credential_payload(authority.did(), id),
+                CredentialInputFormat::NQuads,
+                None,
+            )
+            .expect("submit should succeed");
+        }
+        let record = load_credential(deps.as_ref().storage, "urn:uuid:credential-2")
+            .expect("credential should be persisted");
+        assert_eq!(record.status_list_index, Some(1));
+
+        revoke(deps.as_mut().storage, "urn:uuid:credential-2").expect("revoke should succeed");
+
+        let bitstring = |purpose| {
+            let result =
+                status_list(deps.as_ref().storage, purpose).expect("status list should build");
+            assert_eq!(
+                result.id,
+                purpose.status_list_credential(&StatusListLocation::of_authority(&authority))
+            );
+            assert_eq!(result.issuer, authority.did());
+            let compressed = URL_SAFE_NO_PAD
+                .decode(result.encoded_list.trim_start_matches('u'))
+                .expect("encoded list should be base64url");
+            let mut bitstring = Vec::new();
+            flate2::read::GzDecoder::new(compressed.as_slice())
+                .read_to_end(&mut bitstring)
+                .expect("encoded list should be gzip compressed");
+            bitstring
+        };
+
+        let revocation = bitstring(StatusPurpose::Revocation);
+        assert_eq!(revocation.len(), 16_384);
+        assert_eq!(revocation.first(), Some(&0x40));
+        assert!(revocation.iter().skip(1).all(|byte| *byte == 0));
+        assert!(bitstring(StatusPurpose::Suspension)
+            .iter()
+            .all(|byte| *byte == 0));
+    }
+
+    #[test]
+    fn status_lists_are_published_at_the_configured_url() {
+        let mut deps = mock_dependencies();
+        let authority = initialized_authority(&mut deps);
+        configure_status_list_url(deps.as_mut().storage, "https://status.example.org/lists/")
+            .expect("status list URL should be configured");
+        issue_credential(
+            deps.as_mut().storage,
+            &credential_payload(authority.did(), "urn:uuid:credential-1"),
+            CredentialInputFormat::NQuads,
+            None,
+        )
+        .expect("submit should succeed");
+
+        let record = load_credential(deps.as_ref().storage, "urn:uuid:credential-1")
+            .expect("credential should be persisted");
+        assert!(record.canonical_nquads.contains(
+            "<https://status.example.org/lists/revocation#0> <https://www.w3.org/ns/credentials/status#statusListCredential> <https://status.example.org/lists/revocation> ."
+        ));
+        assert_eq!(
+            status_list(deps.as_ref().storage, StatusPurpose::Suspension)
+                .expect("status list should build")
+                .id,
+            "https://status.example.org/lists/suspension"
+        );
+        assert_eq!(
+            configure_status_list_url(deps.as_mut().storage, "ipfs://lists"),
+            Err(StatusListError::InvalidUrl("ipfs://lists".to_string()).into())
+        );
+    }
+
+    #[test]
+    fn suspend_and_reinstate_credential_toggle_status_and_suspension_bit() {
+        let mut deps = mock_dependencies();
+        let authority = initialized_authority(&mut deps);
+        for id in ["urn:uuid:credential-1", "urn:uuid:credential-2"] {
+            issue_credential(
+                deps.as_mut().storage,
+                &credential_payload(authority.did(), id),
+                CredentialInputFormat::NQuads,
+                None,
+            )
+            .expect("submit should succeed");
+        }
+        let suspension_bits = |storage: &dyn Storage| {
+            status_bits(storage, StatusPurpose::Suspension)
+                .collect::<StdResult<Vec<_>>>()
+                .expect("status bits should load")
+        };
+
+        assert_eq!(
+            suspend_credential(deps.as_mut().storage, "urn:uuid:credential-2"),
+            Ok(CredentialStatusResult {
+                identifier: "urn:uuid:credential-2".to_string(),
+                issuer: authority.did().to_string(),
+                status: CredentialStatus::Suspended,
+            })
+        );
+        assert_eq!(suspension_bits(deps.as_ref().storage), vec![1]);
+        assert_eq!(
+            verify_credential(deps.as_ref().storage, "urn:uuid:credential-2", None),
+            Ok(VerifyCredentialResult {
+                exists: true,
+                valid: false,
+                status: Some(CredentialStatus::Suspended),
+            })
+        );
+        assert_eq!(
+            credential(deps.as_ref().storage, "urn:uuid:credential-2")
+                .expect("credential should load")
+                .status,
+            CredentialStatus::Suspended
+        );
+        for (status, expected) in [
+            (None, vec!["urn:uuid:credential-1", "urn:uuid:credential-2"]),
+            (
+                Some(CredentialStatus::Active),
+                vec!["urn:uuid:credential-1"],
+            ),
+            (
+                Some(CredentialStatus::Suspended),
+                vec!["urn:uuid:credential-2"],
+            ),
+        ] {
+            assert_eq!(
+                credentials(
+                    deps.as_ref().storage,
+                    None,
+                    None,
+                    None,
+                    None,
+                    status,
+                    10,
+                    None
+                )
+                .expect("status filter should load"),
+                expected
+            );
+        }
+        assert_eq!(
+            suspend_credential(deps.as_mut().storage, "urn:uuid:credential-2"),
+            Err(AxoneVcError::CredentialStatus(
+                CredentialStatusError::CredentialAlreadySuspended
+            ))
+        );
+
+        assert_eq!(
+            reinstate_credential(deps.as_mut().storage, "urn:uuid:credential-2")
+                .map(|result| result.status),
+            Ok(CredentialStatus::Active)
+        );
+        assert!(suspension_bits(deps.as_ref().storage).is_empty());
+        assert_eq!(
+            verify_credential(deps.as_ref().storage, "urn:uuid:credential-2", None)
+                .map(|result| result.valid),
+            Ok(true)
+        );
+        assert_eq!(
+            reinstate_credential(deps.as_mut().storage, "urn:uuid:credential-2"),
+            Err(AxoneVcError::CredentialStatus(
+                CredentialStatusError::CredentialNotSuspended
+            ))
+        );
+    }
+
+    #[test]
+    fn suspend_credential_rejects_unknown_and_revoked_credentials() {
+        let mut deps = mock_dependencies();
+        let authority = initialized_authority(&mut deps);
+        issue_credential(
+            deps.as_mut().storage,
+            &credential_payload(authority.did(), "urn:uuid:credential-1"),
+            CredentialInputFormat::NQuads,
+            None,
+        )
+        .expect("submit should succeed");
+        revoke(deps.as_mut().storage, "urn:uuid:credential-1").expect("revoke should succeed");
+
+        assert_eq!(
+            suspend_credential(deps.as_mut().storage, "urn:uuid:credential-1"),
+            Err(AxoneVcError::CredentialStatus(
+                CredentialStatusError::CredentialRevoked
+            ))
+        );
+        assert_eq!(
+            reinstate_credential(deps.as_mut().storage, "urn:uuid:unknown"),
+            Err(AxoneVcError::CredentialStatus(
+                CredentialStatusError::UnknownCredential
+            ))
+        );
+    }
+
+    enum TestKey {
+        Ed25519,
+        Secp256k1,
+        P256,
+    }
+
+    impl TestKey {
+        fn cryptosuite(&self) -> Cryptosuite {
+            match self {
+                TestKey::Ed25519 => Cryptosuite::EddsaRdfc2022,
+                TestKey::Secp256k1 | TestKey::P256 => Cryptosuite::EcdsaRdfc2019,
+            }
+        }
+
+        fn did(&self) -> String {
+            let key = match self {
+                TestKey::Ed25519 => {
+                    let signing_key = ed25519_zebra::SigningKey::from([1u8; 32]);
+                    let mut key = vec![0xed, 0x01];
+                    key.extend_from_slice(
+                        ed25519_zebra::VerificationKey::from(&signing_key).as_ref(),
+                    );
+                    key
+                }
+                TestKey::Secp256k1 => {
+                    let signing_key = k256::ecdsa::SigningKey::from_bytes(&[2u8; 32].into())
+                        .expect("valid secp256k1 key");
+                    let mut key = vec![0xe7, 0x01];
+                    key.extend_from_slice(
+                        signing_key
+                            .verifying_key()
+                            .to_encoded_point(true)
+                            .as_bytes(),
+                    );
+                    key
+                }
+                TestKey::P256 => {
+                    let signing_key = p256::ecdsa::SigningKey::from_bytes(&[3u8; 32].into())
+                        .expect("valid p-256 key");
+                    let mut key = vec![0x80, 0x24];
+                    key.extend_from_slice(
+                        signing_key
+                            .verifying_key()
+                            .to_encoded_point(true)
+                            .as_bytes(),
+                    );
+                    key
+                }
+            };
+            format!("did:key:z{}", bs58::encode(key).into_string())
+        }
+
+        fn sign(&self, hash_data: &[u8]) -> Vec<u8> {
+            use k256::ecdsa::signature::Signer;
+
+            match self {
+                TestKey::Ed25519 => ed25519_zebra::SigningKey::from([1u8; 32])
+                    .sign(hash_data)
+                    .to_bytes()
+                    .to_vec(),
+                TestKey::Secp256k1 => {
+                    let signature: k256::ecdsa::Signature =
+                        k256::ecdsa::SigningKey::from_bytes(&[2u8; 32].into())
+                            .expect("valid secp256k1 key")
+                            .sign(hash_data);
+                    signature.to_bytes().to_vec()
+                }
+                TestKey::P256 => {
+                    let signature: p256::ecdsa::Signature =
+                        p256::ecdsa::SigningKey::from_bytes(&[3u8; 32].into())
+                            .expect("valid p-256 key")
+                            .sign(hash_data);
+                    signature.to_bytes().to_vec()
+                }
+            }
+        }
+    }
+
+    fn unsigned_credential_payload(key: &TestKey, id: &str, name: &str) -> String {
+        let issuer = key.did();
+        let multibase = issuer.trim_start_matches("did:key:");
+        format!(
+            r#"<{id}> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential> .
+<{id}> <https://www.w3.org/2018/credentials#issuer> <{issuer}> .
+<{id}> <https://www.w3.org/2018/credentials#credentialSubject> <did:example:subject> .
+<did:example:subject> <https://schema.org/name> "{name}" .
+<{id}> <https://w3id.org/security#proof> _:proof .
+_:node <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://w3id.org/security#DataIntegrityProof> _:proof .
+_:node <https://w3id.org/security#cryptosuite> "{}"^^<https://w3id.org/security#cryptosuiteString> _:proof .
+_:node <https://w3id.org/security#proofPurpose> <https://w3id.org/security#assertionMethod> _:proof .
+_:node <https://w3id.org/security#verificationMethod> <{issuer}#{multibase}> _:proof .
+_:node <http://purl.org/dc/terms/created> "2025-01-01T00:00:00Z"^^<http://www.w3.org/2001/XMLSchema#dateTime> _:proof .
+"#,
+            key.cryptosuite().name()
+        )
+    }
+
+    fn signed_credential_payload(key: &TestKey, id: &str) -> String {
+        let unsigned = unsigned_credential_payload(key, id, "Alice");
+        let (_, _, canonical_document, canonical_proof_config) =
+            decode_secured_credential(unsigned.as_bytes(), RdfSyntax::NQuads)
+                .expect("unsigned credential should decode")
+                .into_parts();
+
+        let mut hash_data = Checksum::generate(canonical_proof_config.as_bytes())
+            .as_slice()
+            .to_vec();
+        hash_data.extend_from_slice(Checksum::generate(canonical_document.as_bytes()).as_slice());
+        let proof_value = format!("z{}", bs58::encode(key.sign(&hash_data)).into_string());
+
+        format!(
+            "{unsigned}_:node <https://w3id.org/security#proofValue> \"{proof_value}\"^^<https://w3id.org/security#multibase> _:proof .\n"
+        )
+    }
+
+    #[test]
+    fn anchor_credential_verifies_proofs_and_persists_them() {
+        for key in [TestKey::Ed25519, TestKey::P256] {
+            let mut deps = mock_dependencies();
+            initialized_authority(&mut deps);
+            let credential_id = "urn:uuid:credential-1";
+            let payload = signed_credential_payload(&key, credential_id);
+
+            let deps_mut = deps.as_mut();
+            let result = anchor_credential(
+                deps_mut.storage,
+                deps_mut.api,
+                payload.as_bytes(),
+                CredentialInputFormat::NQuads,
+            )
+            .expect("anchor should succeed");
+
+            assert_eq!(result.credential_id, credential_id);
+            assert_eq!(result.issuer, key.did());
+            assert_eq!(result.subject, "did:example:subject");
+            assert_eq!(result.cryptosuite, key.cryptosuite());
+            assert_eq!(result.status_list_index, 0);
+
+            let credential = credential(deps.as_ref().storage, credential_id)
+                .expect("anchored credential should be queryable");
+            assert_eq!(credential.parsed.issuer(), &key.did());
+            let proof = credential
+                .proof
+                .expect("anchored credential should keep its proof");
+            assert_eq!(proof.cryptosuite, key.cryptosuite());
+            assert_eq!(proof.created.as_deref(), Some("2025-01-01T00:00:00Z"));
+            assert!(payload.contains(&proof.proof_value));
+            assert!(credential
+                .quads
+                .iter()
+                .any(|quad| quad.predicate.as_str() == "https://w3id.org/security#proofValue"));
+        }
+    }
+
+    #[test]
+    fn anchored_credentials_are_reflected_in_status_lists_under_their_issuer() {
+        let mut deps = mock_dependencies();
+        let authority = initialized_authority(&mut deps);
+        issue_credential(
+            deps.as_mut().storage,
+            &credential_payload(authority.did(), "urn:uuid:credential-1"),
+            CredentialInputFormat::NQuads,
+            None,
+        )
+        .expect("submit should succeed");
+        let key = TestKey::P256;
+        let payload = signed_credential_payload(&key, "urn:uuid:credential-2");
+        let deps_mut = deps.as_mut();
+        let result = anchor_credential(
+            deps_mut.storage,
+            deps_mut.api,
+            payload.as_bytes(),
+            CredentialInputFormat::NQuads,
+        )
+        .expect("anchor should succeed");
+        assert_eq!(result.status_list_index, 1);
+        let bits = |storage: &dyn Storage, purpose| {
+            status_bits(storage, purpose)
+                .collect::<StdResult<Vec<_>>>()
+                .expect("status bits should load")
+        };
+
+        assert_eq!(
+            suspend_credential(deps.as_mut().storage, "urn:uuid:credential-2"),
+            Ok(CredentialStatusResult {
+                identifier: "urn:uuid:credential-2".to_string(),
+                issuer: key.did(),
+                status: CredentialStatus::Suspended,
+            })
+        );
+        assert_eq!(
+            bits(deps.as_ref().storage, StatusPurpose::Suspension),
+            vec![1]
+        );
+
+        let env = mock_env();
+        assert_eq!(
+            revoke_credential(
+                deps.as_mut().storage,
+                &env.block,
+                &Addr::unchecked("revoker"),
+                None,
+                "urn:uuid:credential-2",
+                None,
+                None,
+            ),
+            Ok(RevokeCredentialResult {
+                identifier: "urn:uuid:credential-2".to_string(),
+                issuer: key.did(),
+                reason_code: None,
+            })
+        );
+        assert_eq!(
+            bits(deps.as_ref().storage, StatusPurpose::Revocation),
+            vec![1]
+        );
+    }
+
+    #[test]
+    fn anchored_credentials_are_only_revoked_by_the_authority() {
+        let mut deps = mock_dependencies();
+        initialized_authority(&mut deps);
+        let credential_id = "urn:uuid:credential-1";
+        let payload = signed_credential_payload(&TestKey::Ed25519, credential_id);
+        let deps_mut = deps.as_mut();
+        anchor_credential(
+            deps_mut.storage,
+            deps_mut.api,
+            payload.as_bytes(),
+            CredentialInputFormat::NQuads,
+        )
+        .expect("anchor should succeed");
+        let delegate = IssuerDelegation::new(
+            Addr::unchecked("delegate"),
+            vec!["https://example.com/types/Member".to_string()],
+            None,
+            mock_env().block.time,
+        )
+        .expect("delegation should build");
+
+        let block = mock_env().block;
+        assert_eq!(
+            revoke_credential(
+                deps.as_mut().storage,
+                &block,
+                &Addr::unchecked("delegate"),
+                Some(&delegate),
+                credential_id,
+                None,
+                None,
+            ),
+            Err(DelegationError::AnchoredCredential.into())
+        );
+        assert!(!is_revoked(deps.as_ref().storage, credential_id));
+
+        revoke_credential(
+            deps.as_mut().storage,
+            &block,
+            &Addr::unchecked("authority"),
+            None,
+            credential_id,
+            None,
+            None,
+        )
+        .expect("authority should revoke anchored credentials");
+        assert!(is_revoked(deps.as_ref().storage, credential_id));
+    }
+
+    #[test]
+    fn anchor_credential_rejects_secp256k1_keys() {
+        let mut deps = mock_dependencies();
+        initialized_authority(&mut deps);
+        let payload = signed_credential_payload(&TestKey::Secp256k1, "urn:uuid:credential-1");
+
+        let deps_mut = deps.as_mut();
+        assert_eq!(
+            anchor_credential(
+                deps_mut.storage,
+                deps_mut.api,
+                payload.as_bytes(),
+                CredentialInputFormat::NQuads,
+            ),
+            Err(AnchorCredentialError::Proof(ProofError::KeyCryptosuiteMismatch).into())
+        );
+    }
+
+    #[test]
+    fn anchor_credential_rejects_tampered_and_duplicate_credentials() {
+        let mut deps = mock_dependencies();
+        initialized_authority(&mut deps);
+        let key = TestKey::Ed25519;
+        let payload = signed_credential_payload(&key, "urn:uuid:credential-1");
+        let tampered = payload.replace("\"Alice\"", "\"Mallory\"");
+
+        let deps_mut = deps.as_mut();
+        let err = anchor_credential(
+            deps_mut.storage,
+            deps_mut.api,
+            tampered.as_bytes(),
+            CredentialInputFormat::NQuads,
+        )
+        .expect_err("tampered credential should be rejected");
+        assert!(matches!(
+            err,
+            AxoneVcError::AnchorCredential(AnchorCredentialError::Proof(
+                ProofError::InvalidSignature(_)
+            ))
+        ));
+
+        let unsigned = unsigned_credential_payload(&key, "urn:uuid:credential-1", "Alice");
+        let deps_mut = deps.as_mut();
+        assert_eq!(
+            anchor_credential(
+                deps_mut.storage,
+                deps_mut.api,
+                unsigned.as_bytes(),
+                CredentialInputFormat::NQuads,
+            ),
+            Err(AnchorCredentialError::Proof(ProofError::MissingProofValue).into())
+        );
+
+        let deps_mut = deps.as_mut();
+        assert_eq!(
+            anchor_credential(
+                deps_mut.storage,
+                deps_mut.api,
+                &credential_payload("did:example:issuer", "urn:uuid:credential-1"),
+                CredentialInputFormat::NQuads,
+            ),
+            Err(AnchorCredentialError::Decode(CredentialDecodingError::MissingProof).into())
+        );
+
+        let deps_mut = deps.as_mut();
+        anchor_credential(
+            deps_mut.storage,
+            deps_mut.api,
+            payload.as_bytes(),
+            CredentialInputFormat::NQuads,
+        )
+        .expect("anchor should succeed");
+        let deps_mut = deps.as_mut();
+        assert_eq!(
+            anchor_credential(
+                deps_mut.storage,
+                deps_mut.api,
+                payload.as_bytes(),
+                CredentialInputFormat::NQuads,
+            ),
+            Err(AnchorCredentialError::CredentialAlreadyExists.into())
         );
     }
 }
@@ -0,0 +1,112 @@
+use crate::{
+    contract::AxoneVcResult,
+    domain::{DelegationError, IssuerDelegation, Uri},
+    state,
+};
+
+use cosmwasm_std::{Addr, StdResult, Storage, Timestamp};
+
+/// Delegate the issuance and revocation of credentials of the given types to an address,
+/// replacing any delegation it already holds.
+pub fn grant_issuer(
+    storage: &mut dyn Storage,
+    address: Addr,
+    credential_types: Vec<Uri>,
+    expires: Option<Timestamp>,
+    now: Timestamp,
+) -> AxoneVcResult<IssuerDelegation> {
+    let delegation = IssuerDelegation::new(address, credential_types, expires, now)?;
+    state::save_issuer(storage, &delegation)?;
+
+    Ok(delegation)
+}
+
+/// Withdraw the delegation of an address, credentials it issued being kept.
+pub fn revoke_issuer(storage: &mut dyn Storage, address: &Addr) -> AxoneVcResult<()> {
+    if state::issuer(storage, address)?.is_none() {
+        return Err(DelegationError::UnknownIssuer.into());
+    }
+    state::remove_issuer(storage, address);
+
+    Ok(())
+}
+
+/// Return the delegation of an address, if any, failing if it has expired.
+pub fn delegated_issuer(
+    storage: &dyn Storage,
+    address: &Addr,
+    now: Timestamp,
+) -> AxoneVcResult<Option<IssuerDelegation>> {
+    match state::issuer(storage, address)? {
+        Some(delegation) if delegation.is_expired(now) => Err(DelegationError::Expired.into()),
+        delegation => Ok(delegation),
+    }
+}
+
+pub fn issuers(storage: &dyn Storage) -> AxoneVcResult<Vec<IssuerDelegation>> {
+    Ok(state::issuers(storage).collect::<StdResult<Vec<_>>>()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{grant_issuer, issuers, revoke_issuer};
+    use crate::{
+        domain::{DelegationError, IssuerDelegation},
+        error::AxoneVcError,
+    };
+    use cosmwasm_std::{testing::mock_dependencies, Addr, Timestamp};
+
+    #[test]
+    fn grant_issuer_replaces_delegations_and_revoke_issuer_removes_them() {
+        let mut deps = mock_dependencies();
+        let now = Timestamp::from_seconds(10);
+        let member = "https://example.com/types/Member".to_string();
+        let employee = "https://example.com/types/Employee".to_string();
+
+        for address in ["delegate-b", "delegate-a"] {
+            grant_issuer(
+                deps.as_mut().storage,
+                Addr::unchecked(address),
+                vec![member.clone()],
+                None,
+                now,
+            )
+            .expect("grant should succeed");
+        }
+        grant_issuer(
+            deps.as_mut().storage,
+            Addr::unchecked("delegate-b"),
+            vec![employee.clone()],
+            Some(Timestamp::from_seconds(20)),
+            now,
+        )
+        .expect("grant should replace the previous one");
+
+        assert_eq!(
+            issuers(deps.as_ref().storage),
+            Ok(vec![
+                IssuerDelegation {
+                    address: Addr::unchecked("delegate-a"),
+                    credential_types: vec![member],
+                    expires: None,
+                },
+                IssuerDelegation {
+                    address: Addr::unchecked("delegate-b"),
+                    credential_types: vec![employee],
+                    expires: Some(Timestamp::from_seconds(20)),
+                },
+            ])
+        );
+
+        revoke_issuer(deps.as_mut().storage, &Addr::unchecked("delegate-a"))
+            .expect("revoke should succeed");
+        assert_eq!(
+            revoke_issuer(deps.as_mut().storage, &Addr::unchecked("delegate-a")),
+            Err(AxoneVcError::Delegation(DelegationError::UnknownIssuer))
+        );
+        assert_eq!(
+            issuers(deps.as_ref().storage).map(|issuers| issuers.len()),
+            Ok(1)
+        );
+    }
+}
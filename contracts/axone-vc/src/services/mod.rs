@@ -1,8 +1,17 @@
 mod authority;
 mod credential;
+mod issuer;
+mod proof;
+mod shape;
 
-pub use authority::{authority, initialize_authority};
+pub use authority::{
+    authority, configure_status_list_url, initialize_authority, status_list_location,
+};
 pub use credential::{
-    credential, credential_raw, credentials, issue_credential, revoke_credential,
-    verify_credential, IssueCredentialError, RevokeCredentialError,
+    anchor_credential, credential, credential_raw, credentials, issue_credential,
+    reinstate_credential, revoke_credential, revoked_credential, revoked_credentials, status_list,
+    suspend_credential, verify_credential, AnchorCredentialError, CredentialStatusError,
+    IssueCredentialError, RevokeCredentialError, RevokedCredentialResult,
 };
+pub use issuer::{delegated_issuer, grant_issuer, issuers, revoke_issuer};
+pub use shape::{register_shape, shapes, unregister_shape};
@@ -0,0 +1,40 @@
+use crate::domain::{DataIntegrityProof, ProofError, SignatureScheme};
+use cosmwasm_std::{Api, Checksum};
+
+/// Verify a Data Integrity proof of an `eddsa-rdfc-2022` or `ecdsa-rdfc-2019` cryptosuite.
+///
+/// Both suites sign the hash data `sha256(proof config) || sha256(document)` computed over the
+/// RDFC-1.0 canonical forms, Ed25519 signing it as is and ECDSA signing its SHA-256 digest.
+pub(crate) fn verify_proof(
+    api: &dyn Api,
+    proof: &DataIntegrityProof,
+    issuer: &str,
+    canonical_document: &str,
+    canonical_proof_config: &str,
+) -> Result<(), ProofError> {
+    let key = proof.verification_key(issuer)?;
+    let signature = proof.signature()?;
+
+    let mut hash_data = Checksum::generate(canonical_proof_config.as_bytes())
+        .as_slice()
+        .to_vec();
+    hash_data.extend_from_slice(Checksum::generate(canonical_document.as_bytes()).as_slice());
+
+    let verified = match key.scheme {
+        SignatureScheme::Ed25519 => api.ed25519_verify(&hash_data, &signature, &key.public_key),
+        SignatureScheme::P256Sha256 => api.secp256r1_verify(
+            Checksum::generate(&hash_data).as_slice(),
+            &signature,
+            &key.public_key,
+        ),
+    }
+    .map_err(|err| ProofError::InvalidSignature(err.to_string()))?;
+
+    if !verified {
+        return Err(ProofError::InvalidSignature(
+            "signature does not match the secured credential".to_string(),
+        ));
+    }
+
+    Ok(())
+}
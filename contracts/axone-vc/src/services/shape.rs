@@ -0,0 +1,105 @@
+use crate::{
+    contract::AxoneVcResult,
+    domain::{Credential, CredentialShape, PropertyShape, ShapeError, ShapeReport, Uri},
+    state,
+};
+
+use cosmwasm_std::{StdResult, Storage};
+
+/// Register the shape credentials of a type must conform to, replacing any registered for
+/// that type.
+pub fn register_shape(
+    storage: &mut dyn Storage,
+    credential_type: Uri,
+    properties: Vec<PropertyShape>,
+) -> AxoneVcResult<CredentialShape> {
+    let shape = CredentialShape::new(credential_type, properties)?;
+    state::save_shape(storage, &shape)?;
+
+    Ok(shape)
+}
+
+/// Remove the shape of a credential type, credentials already issued being kept.
+pub fn unregister_shape(storage: &mut dyn Storage, credential_type: &str) -> AxoneVcResult<()> {
+    if state::shape(storage, credential_type)?.is_none() {
+        return Err(ShapeError::UnknownShape.into());
+    }
+    state::remove_shape(storage, credential_type);
+
+    Ok(())
+}
+
+pub fn shapes(storage: &dyn Storage) -> AxoneVcResult<Vec<CredentialShape>> {
+    Ok(state::shapes(storage).collect::<StdResult<Vec<_>>>()?)
+}
+
+/// Check the subject of a credential conforms to the shapes of all its types, reporting
+/// every failed constraint otherwise.
+pub fn conform_to_shapes(storage: &dyn Storage, credential: &Credential) -> AxoneVcResult<()> {
+    let mut violations = Vec::new();
+    for credential_type in credential.types() {
+        if let Some(shape) = state::shape(storage, credential_type)? {
+            violations.extend(shape.validate(credential.claims()));
+        }
+    }
+
+    if !violations.is_empty() {
+        return Err(ShapeError::NonConforming(ShapeReport(violations)).into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{register_shape, shapes, unregister_shape};
+    use crate::{
+        domain::{PropertyShape, ShapeError},
+        error::AxoneVcError,
+    };
+    use cosmwasm_std::testing::mock_dependencies;
+
+    #[test]
+    fn register_shape_replaces_shapes_and_unregister_shape_removes_them() {
+        let mut deps = mock_dependencies();
+        let member = "https://example.com/types/Member".to_string();
+        let property = |path: &str| PropertyShape {
+            path: path.to_string(),
+            min_count: Some(1),
+            max_count: None,
+            datatype: None,
+            node_kind: None,
+            allowed_values: None,
+        };
+
+        register_shape(
+            deps.as_mut().storage,
+            member.clone(),
+            vec![property("https://schema.org/name")],
+        )
+        .expect("register should succeed");
+        register_shape(
+            deps.as_mut().storage,
+            member.clone(),
+            vec![property("https://schema.org/email")],
+        )
+        .expect("register should replace the previous shape");
+
+        let registered = shapes(deps.as_ref().storage).expect("shapes should load");
+        assert_eq!(registered.len(), 1);
+        assert_eq!(
+            registered.first().map(|shape| shape.properties.clone()),
+            Some(vec![property("https://schema.org/email")])
+        );
+
+        unregister_shape(deps.as_mut().storage, &member).expect("unregister should succeed");
+        assert_eq!(
+            unregister_shape(deps.as_mut().storage, &member),
+            Err(AxoneVcError::Shape(ShapeError::UnknownShape))
+        );
+        assert_eq!(
+            register_shape(deps.as_mut().storage, member, vec![]),
+            Err(AxoneVcError::Shape(ShapeError::NoProperties))
+        );
+    }
+}
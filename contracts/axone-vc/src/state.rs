@@ -1,13 +1,21 @@
-use crate::domain::Authority;
+use crate::domain::{
+    Authority, CredentialShape, CredentialStatus, DataIntegrityProof, DataModelVersion,
+    IssuerDelegation, RevocationReasonCode, StatusListLocation, StatusPurpose,
+};
 use crate::error::AxoneVcError;
 use crate::index::OneToManyIndex;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Order, StdError, StdResult, Storage, Timestamp};
+use cosmwasm_std::{Addr, Checksum, Empty, Order, StdError, StdResult, Storage, Timestamp};
 use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 
 const AUTHORITY: Item<Authority> = Item::new("authority");
 const REVOKED_CREDENTIALS: Map<&str, CredentialTombstone> = Map::new("revoked_credentials");
+const STATUS_LIST_LOCATION: Item<StatusListLocation> = Item::new("status_list_location");
+const NEXT_STATUS_LIST_INDEX: Item<u64> = Item::new("next_status_list_index");
+const STATUS_LIST_BITS: Map<(&str, u64), Empty> = Map::new("status_list_bits");
+const ISSUERS: Map<&Addr, IssuerDelegation> = Map::new("issuers");
+const SHAPES: Map<&str, CredentialShape> = Map::new("shapes");
 
 const CREDENTIALS: IndexedMap<&str, CredentialRecord, CredentialIndexes<'static>> = IndexedMap::new(
     "credentials",
@@ -48,6 +56,16 @@ pub struct CredentialRecord {
     pub valid_from: Option<Timestamp>,
     #[serde(default)]
     pub valid_until: Option<Timestamp>,
+    #[serde(default)]
+    pub data_model: DataModelVersion,
+    #[serde(default)]
+    pub proof: Option<DataIntegrityProof>,
+    #[serde(default)]
+    pub status_list_index: Option<u64>,
+    #[serde(default)]
+    pub status: CredentialStatus,
+    #[serde(default)]
+    pub delegate: Option<Addr>,
 }
 
 impl CredentialRecord {
@@ -57,6 +75,7 @@ impl CredentialRecord {
         types: Vec<String>,
         valid_from: Option<Timestamp>,
         valid_until: Option<Timestamp>,
+        data_model: DataModelVersion,
     ) -> Self {
         Self {
             canonical_nquads,
@@ -64,17 +83,71 @@ impl CredentialRecord {
             types,
             valid_from,
             valid_until,
+            data_model,
+            proof: None,
+            status_list_index: None,
+            status: CredentialStatus::Active,
+            delegate: None,
         }
     }
+
+    pub fn with_delegate(mut self, delegate: Option<Addr>) -> Self {
+        self.delegate = delegate;
+        self
+    }
+
+    pub fn with_status_list_index(mut self, index: u64) -> Self {
+        self.status_list_index = Some(index);
+        self
+    }
+
+    pub fn with_proof(mut self, proof: DataIntegrityProof) -> Self {
+        self.proof = Some(proof);
+        self
+    }
 }
 
 #[cw_serde]
-#[derive(Default)]
-pub struct CredentialTombstone {}
+pub struct CredentialTombstone {
+    #[serde(default)]
+    pub block_height: u64,
+    #[serde(default)]
+    pub revoked_at: Timestamp,
+    #[serde(default)]
+    pub revoker: Option<Addr>,
+    #[serde(default)]
+    pub reason_code: Option<RevocationReasonCode>,
+    #[serde(default)]
+    pub reason: Option<String>,
+    #[serde(default)]
+    pub canonical_hash: Option<Checksum>,
+}
 
 impl CredentialTombstone {
-    pub fn new() -> Self {
-        Self::default()
+    pub fn new(
+        block_height: u64,
+        revoked_at: Timestamp,
+        revoker: Addr,
+        canonical_hash: Checksum,
+    ) -> Self {
+        Self {
+            block_height,
+            revoked_at,
+            revoker: Some(revoker),
+            reason_code: None,
+            reason: None,
+            canonical_hash: Some(canonical_hash),
+        }
+    }
+
+    pub fn with_reason(
+        mut self,
+        reason_code: Option<RevocationReasonCode>,
+        reason: Option<String>,
+    ) -> Self {
+        self.reason_code = reason_code;
+        self.reason = reason;
+        self
     }
 }
 
@@ -157,6 +230,25 @@ pub fn is_revoked(storage: &dyn Storage, credential_id: &str) -> bool {
     REVOKED_CREDENTIALS.has(storage, credential_id)
 }
 
+pub fn revoked_credential(
+    storage: &dyn Storage,
+    credential_id: &str,
+) -> Result<Option<CredentialTombstone>, AxoneVcError> {
+    Ok(REVOKED_CREDENTIALS.may_load(storage, credential_id)?)
+}
+
+pub fn revoked_credentials<'a>(
+    storage: &'a dyn Storage,
+    start_after: Option<&'a str>,
+) -> Box<dyn Iterator<Item = StdResult<(String, CredentialTombstone)>> + 'a> {
+    REVOKED_CREDENTIALS.range(
+        storage,
+        start_after.map(Bound::exclusive),
+        None,
+        Order::Ascending,
+    )
+}
+
 pub fn revoke_credential(
     storage: &mut dyn Storage,
     credential_id: &str,
@@ -167,6 +259,111 @@ pub fn revoke_credential(
     Ok(())
 }
 
+pub fn status_list_location(
+    storage: &dyn Storage,
+) -> Result<Option<StatusListLocation>, AxoneVcError> {
+    Ok(STATUS_LIST_LOCATION.may_load(storage)?)
+}
+
+pub fn save_status_list_location(
+    storage: &mut dyn Storage,
+    location: &StatusListLocation,
+) -> Result<(), AxoneVcError> {
+    STATUS_LIST_LOCATION.save(storage, location)?;
+    Ok(())
+}
+
+pub fn next_status_list_index(storage: &dyn Storage) -> Result<u64, AxoneVcError> {
+    Ok(NEXT_STATUS_LIST_INDEX
+        .may_load(storage)?
+        .unwrap_or_default())
+}
+
+/// Allocate the next status list index, indexes being assigned sequentially.
+pub fn allocate_status_list_index(storage: &mut dyn Storage) -> Result<u64, AxoneVcError> {
+    let index = next_status_list_index(storage)?;
+    let next = index
+        .checked_add(1)
+        .ok_or_else(|| StdError::generic_err("status list index overflow"))?;
+    NEXT_STATUS_LIST_INDEX.save(storage, &next)?;
+    Ok(index)
+}
+
+pub fn set_status_bit(
+    storage: &mut dyn Storage,
+    purpose: StatusPurpose,
+    index: u64,
+) -> Result<(), AxoneVcError> {
+    STATUS_LIST_BITS.save(storage, (purpose.name(), index), &Empty {})?;
+    Ok(())
+}
+
+pub fn clear_status_bit(storage: &mut dyn Storage, purpose: StatusPurpose, index: u64) {
+    STATUS_LIST_BITS.remove(storage, (purpose.name(), index));
+}
+
+pub fn status_bits<'a>(
+    storage: &'a dyn Storage,
+    purpose: StatusPurpose,
+) -> Box<dyn Iterator<Item = StdResult<u64>> + 'a> {
+    STATUS_LIST_BITS
+        .prefix(purpose.name())
+        .keys(storage, None, None, Order::Ascending)
+}
+
+pub fn issuer(
+    storage: &dyn Storage,
+    address: &Addr,
+) -> Result<Option<IssuerDelegation>, AxoneVcError> {
+    Ok(ISSUERS.may_load(storage, address)?)
+}
+
+pub fn save_issuer(
+    storage: &mut dyn Storage,
+    delegation: &IssuerDelegation,
+) -> Result<(), AxoneVcError> {
+    ISSUERS.save(storage, &delegation.address, delegation)?;
+    Ok(())
+}
+
+pub fn remove_issuer(storage: &mut dyn Storage, address: &Addr) {
+    ISSUERS.remove(storage, address);
+}
+
+pub fn issuers(
+    storage: &dyn Storage,
+) -> Box<dyn Iterator<Item = StdResult<IssuerDelegation>> + '_> {
+    Box::new(
+        ISSUERS
+            .range(storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, delegation)| delegation)),
+    )
+}
+
+pub fn shape(
+    storage: &dyn Storage,
+    credential_type: &str,
+) -> Result<Option<CredentialShape>, AxoneVcError> {
+    Ok(SHAPES.may_load(storage, credential_type)?)
+}
+
+pub fn save_shape(storage: &mut dyn Storage, shape: &CredentialShape) -> Result<(), AxoneVcError> {
+    SHAPES.save(storage, &shape.credential_type, shape)?;
+    Ok(())
+}
+
+pub fn remove_shape(storage: &mut dyn Storage, credential_type: &str) {
+    SHAPES.remove(storage, credential_type);
+}
+
+pub fn shapes(storage: &dyn Storage) -> Box<dyn Iterator<Item = StdResult<CredentialShape>> + '_> {
+    Box::new(
+        SHAPES
+            .range(storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, shape)| shape)),
+    )
+}
+
 #[cfg(test)]
 pub fn load_credential(
     storage: &dyn Storage,
@@ -178,8 +375,22 @@ pub fn load_credential(
 
 #[cfg(test)]
 mod tests {
-    use super::CredentialRecord;
-    use cosmwasm_std::from_json;
+    use super::{CredentialRecord, CredentialTombstone};
+    use crate::domain::{CredentialStatus, DataModelVersion};
+    use cosmwasm_std::{from_json, Timestamp};
+
+    #[test]
+    fn credential_tombstone_without_revocation_metadata_deserializes() {
+        let tombstone: CredentialTombstone =
+            from_json(b"{}").expect("legacy credential tombstone should deserialize");
+
+        assert_eq!(tombstone.block_height, 0);
+        assert_eq!(tombstone.revoked_at, Timestamp::default());
+        assert_eq!(tombstone.revoker, None);
+        assert_eq!(tombstone.reason_code, None);
+        assert_eq!(tombstone.reason, None);
+        assert_eq!(tombstone.canonical_hash, None);
+    }
 
     #[test]
     fn credential_record_without_validity_bounds_deserializes_as_unbounded() {
@@ -190,5 +401,10 @@ mod tests {
         assert!(record.types.is_empty());
         assert_eq!(record.valid_from, None);
         assert_eq!(record.valid_until, None);
+        assert_eq!(record.data_model, DataModelVersion::V1);
+        assert_eq!(record.proof, None);
+        assert_eq!(record.status_list_index, None);
+        assert_eq!(record.status, CredentialStatus::Active);
+        assert_eq!(record.delegate, None);
     }
 }
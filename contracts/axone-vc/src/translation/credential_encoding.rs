@@ -0,0 +1,265 @@
+use super::json_ld::quads_to_json_ld;
+use oxrdf::{GraphName, Quad};
+use oxttl::{NQuadsParser, NTriplesSerializer, TurtleSerializer};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum CredentialEncodingError {
+    #[error("stored credential is not valid canonical n-quads")]
+    InvalidCanonicalNQuads,
+
+    #[error("credential holds named graphs, which {0} cannot represent")]
+    NamedGraphsUnsupported(String),
+
+    #[error("credential turtle prefix is invalid: {0}")]
+    InvalidPrefix(String),
+
+    #[error("credential json-ld context is not supported: {0}")]
+    UnsupportedJsonLdContext(String),
+
+    #[error("credential json-ld compaction failed")]
+    JsonLdCompactionFailed,
+
+    #[error("credential serialization failed")]
+    SerializationFailed,
+}
+
+/// Serialize a stored credential as N-Triples.
+///
+/// N-Triples only represents the default graph, credentials holding named graphs, such as
+/// embedded proofs, are rejected.
+pub fn encode_ntriples_credential(
+    canonical_nquads: &str,
+) -> Result<Vec<u8>, CredentialEncodingError> {
+    let quads = default_graph_quads(canonical_nquads, "n-triples")?;
+    let mut serializer = NTriplesSerializer::new().for_writer(Vec::new());
+    for quad in &quads {
+        serializer
+            .serialize_triple(quad.as_ref())
+            .map_err(|_| CredentialEncodingError::SerializationFailed)?;
+    }
+
+    Ok(serializer.finish())
+}
+
+/// Serialize a stored credential as Turtle, abbreviating IRIs with the given prefixes.
+///
+/// As for N-Triples, credentials holding named graphs are rejected.
+pub fn encode_turtle_credential<'a>(
+    canonical_nquads: &str,
+    prefixes: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> Result<Vec<u8>, CredentialEncodingError> {
+    let quads = default_graph_quads(canonical_nquads, "turtle")?;
+    let mut serializer = TurtleSerializer::new();
+    for (name, iri) in prefixes {
+        if !is_prefix_name(name) {
+            return Err(CredentialEncodingError::InvalidPrefix(name.to_string()));
+        }
+        serializer = serializer
+            .with_prefix(name, iri)
+            .map_err(|_| CredentialEncodingError::InvalidPrefix(name.to_string()))?;
+    }
+
+    let mut serializer = serializer.for_writer(Vec::new());
+    for quad in &quads {
+        serializer
+            .serialize_triple(quad.as_ref())
+            .map_err(|_| CredentialEncodingError::SerializationFailed)?;
+    }
+
+    serializer
+        .finish()
+        .map_err(|_| CredentialEncodingError::SerializationFailed)
+}
+
+/// Serialize a stored credential as a compacted JSON-LD document against the given bundled
+/// contexts.
+pub fn encode_json_ld_credential(
+    canonical_nquads: &str,
+    contexts: &[String],
+) -> Result<Vec<u8>, CredentialEncodingError> {
+    let quads = parse_canonical_quads(canonical_nquads)?;
+    quads_to_json_ld(&quads, contexts).map(String::into_bytes)
+}
+
+fn parse_canonical_quads(canonical_nquads: &str) -> Result<Vec<Quad>, CredentialEncodingError> {
+    NQuadsParser::new()
+        .for_slice(canonical_nquads.as_bytes())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| CredentialEncodingError::InvalidCanonicalNQuads)
+}
+
+fn default_graph_quads(
+    canonical_nquads: &str,
+    format: &str,
+) -> Result<Vec<Quad>, CredentialEncodingError> {
+    let quads = parse_canonical_quads(canonical_nquads)?;
+    if quads
+        .iter()
+        .any(|quad| quad.graph_name != GraphName::DefaultGraph)
+    {
+        return Err(CredentialEncodingError::NamedGraphsUnsupported(
+            format.to_string(),
+        ));
+    }
+
+    Ok(quads)
+}
+
+/// Whether a name is a Turtle prefix name, restricted to ASCII characters.
+fn is_prefix_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        None => true,
+        Some(first) => {
+            first.is_ascii_alphabetic()
+                && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+                && !name.ends_with('.')
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        encode_json_ld_credential, encode_ntriples_credential, encode_turtle_credential,
+        CredentialEncodingError,
+    };
+    use crate::translation::{
+        decode_json_ld_credential_for_issuer, decode_nquads_credential_for_issuer,
+    };
+
+    const AUTHORITY_DID: &str = "did:pkh:cosmos:axone-localnet-1:cosmos1authority";
+    const VC_V1: &str = "https://www.w3.org/2018/credentials/v1";
+    const VC_V2: &str = "https://www.w3.org/ns/credentials/v2";
+    const DATA_INTEGRITY_V2: &str = "https://w3id.org/security/data-integrity/v2";
+
+    fn canonical(input: &str) -> String {
+        decode_nquads_credential_for_issuer(input.as_bytes(), AUTHORITY_DID)
+            .expect("credential should decode")
+            .canonical_nquads()
+            .clone()
+    }
+
+    fn credential() -> String {
+        canonical(&format!(
+            r#"<urn:uuid:credential-1> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential> .
+<urn:uuid:credential-1> <https://www.w3.org/2018/credentials#issuer> <{AUTHORITY_DID}> .
+<urn:uuid:credential-1> <https://www.w3.org/2018/credentials#validFrom> "2025-01-01T00:00:00Z"^^<http://www.w3.org/2001/XMLSchema#dateTimeStamp> .
+<urn:uuid:credential-1> <https://www.w3.org/2018/credentials#credentialSubject> <did:example:subject> .
+<did:example:subject> <https://example.com/vocab#name> "Alice" .
+<did:example:subject> <https://example.com/vocab#label> "Bonjour"@fr .
+"#
+        ))
+    }
+
+    fn credential_with_proof() -> String {
+        canonical(&format!(
+            r#"<urn:uuid:credential-1> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential> .
+<urn:uuid:credential-1> <https://www.w3.org/2018/credentials#issuer> <{AUTHORITY_DID}> .
+<urn:uuid:credential-1> <https://www.w3.org/2018/credentials#credentialSubject> <did:example:subject> .
+<urn:uuid:credential-1> <https://w3id.org/security#proof> _:proof .
+_:node <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://w3id.org/security#DataIntegrityProof> _:proof .
+_:node <https://w3id.org/security#cryptosuite> "eddsa-rdfc-2022"^^<https://w3id.org/security#cryptosuiteString> _:proof .
+_:node <https://w3id.org/security#proofPurpose> <https://w3id.org/security#assertionMethod> _:proof .
+_:node <https://w3id.org/security#verificationMethod> <{AUTHORITY_DID}#key-1> _:proof .
+"#
+        ))
+    }
+
+    #[test]
+    fn encode_ntriples_credential_serializes_the_default_graph() {
+        let ntriples = encode_ntriples_credential(&credential()).expect("credential should encode");
+
+        assert_eq!(
+            String::from_utf8(ntriples).expect("output should be utf-8"),
+            credential()
+        );
+        assert_eq!(
+            encode_ntriples_credential(&credential_with_proof()),
+            Err(CredentialEncodingError::NamedGraphsUnsupported(
+                "n-triples".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn encode_turtle_credential_abbreviates_with_prefixes() {
+        let turtle = encode_turtle_credential(
+            &credential(),
+            [
+                ("cred", "https://www.w3.org/2018/credentials#"),
+                ("ex", "https://example.com/vocab#"),
+            ],
+        )
+        .expect("credential should encode");
+        let turtle = String::from_utf8(turtle).expect("output should be utf-8");
+
+        assert!(turtle.starts_with(
+            "@prefix ex: <https://example.com/vocab#> .\n@prefix cred: <https://www.w3.org/2018/credentials#> .\n"
+        ));
+        assert!(turtle.contains("<did:example:subject> ex:label \"Bonjour\"@fr ;"));
+        assert!(turtle.contains("a cred:VerifiableCredential"));
+
+        for (name, iri) in [("1ex", "https://example.com/"), ("ex", "not an iri")] {
+            assert_eq!(
+                encode_turtle_credential(&credential(), [(name, iri)]),
+                Err(CredentialEncodingError::InvalidPrefix(name.to_string()))
+            );
+        }
+        assert_eq!(
+            encode_turtle_credential(&credential_with_proof(), []),
+            Err(CredentialEncodingError::NamedGraphsUnsupported(
+                "turtle".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn encode_json_ld_credential_compacts_against_bundled_contexts() {
+        let json_ld = encode_json_ld_credential(&credential(), &[VC_V2.to_string()])
+            .expect("credential should encode");
+
+        assert_eq!(
+            String::from_utf8(json_ld).expect("output should be utf-8"),
+            format!(
+                concat!(
+                    r#"{{"@context":"{}","@graph":["#,
+                    r#"{{"https://example.com/vocab#label":{{"@language":"fr","@value":"Bonjour"}},"#,
+                    r#""https://example.com/vocab#name":"Alice","id":"did:example:subject"}},"#,
+                    r#"{{"credentialSubject":"did:example:subject","id":"urn:uuid:credential-1","#,
+                    r#""issuer":"{}","type":"VerifiableCredential","#,
+                    r#""validFrom":{{"@type":"http://www.w3.org/2001/XMLSchema#dateTimeStamp","#,
+                    r#""@value":"2025-01-01T00:00:00Z"}}}}]}}"#
+                ),
+                VC_V2, AUTHORITY_DID
+            )
+        );
+    }
+
+    #[test]
+    fn encode_json_ld_credential_round_trips_through_expansion() {
+        for (credential, contexts) in [
+            (credential(), vec![]),
+            (credential(), vec![VC_V1.to_string()]),
+            (
+                credential_with_proof(),
+                vec![VC_V2.to_string(), DATA_INTEGRITY_V2.to_string()],
+            ),
+        ] {
+            let json_ld = encode_json_ld_credential(&credential, &contexts)
+                .expect("credential should encode");
+            let decoded = decode_json_ld_credential_for_issuer(&json_ld, AUTHORITY_DID)
+                .expect("compacted credential should expand");
+
+            assert_eq!(decoded.canonical_nquads(), &credential, "{contexts:?}");
+        }
+
+        assert_eq!(
+            encode_json_ld_credential(&credential(), &["https://example.com/context".to_string()]),
+            Err(CredentialEncodingError::UnsupportedJsonLdContext(
+                "https://example.com/context".to_string()
+            ))
+        );
+    }
+}
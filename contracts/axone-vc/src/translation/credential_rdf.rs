@@ -1,10 +1,15 @@
+use super::{
+    json_ld::{json_ld_data_model, json_ld_to_quads},
+    status_list::{insert_status_entries, CredentialStatusEntry},
+};
+use crate::domain::DataModelVersion;
 use cosmwasm_std::Timestamp;
 use getset::Getters;
 use oxrdf::{
     vocab::{rdf, xsd},
     Dataset, GraphName, Literal, NamedNode, NamedNodeRef, Quad, Subject, Term,
 };
-use oxttl::NQuadsParser;
+use oxttl::{NQuadsParser, TriGParser, TurtleParser};
 use rdf_canon::canonicalize;
 use std::{collections::HashSet, str};
 use thiserror::Error;
@@ -20,6 +25,10 @@ const VC_VALID_UNTIL: NamedNodeRef<'static> =
     NamedNodeRef::new_unchecked("https://www.w3.org/2018/credentials#validUntil");
 const VC_CREDENTIAL_SUBJECT: NamedNodeRef<'static> =
     NamedNodeRef::new_unchecked("https://www.w3.org/2018/credentials#credentialSubject");
+const VC_ISSUANCE_DATE: NamedNodeRef<'static> =
+    NamedNodeRef::new_unchecked("https://www.w3.org/2018/credentials#issuanceDate");
+const VC_EXPIRATION_DATE: NamedNodeRef<'static> =
+    NamedNodeRef::new_unchecked("https://www.w3.org/2018/credentials#expirationDate");
 
 #[derive(Debug, Error, PartialEq)]
 pub enum CredentialDecodingError {
@@ -32,9 +41,33 @@ pub enum CredentialDecodingError {
     #[error("credential input is not valid n-quads")]
     InvalidNQuads,
 
+    #[error("credential input is not valid turtle")]
+    InvalidTurtle,
+
+    #[error("credential input is not valid trig")]
+    InvalidTriG,
+
+    #[error("credential input is not valid json-ld")]
+    InvalidJsonLd,
+
+    #[error("credential json-ld context is not supported: {0}")]
+    UnsupportedJsonLdContext(String),
+
+    #[error("credential json-ld term is not defined: {0}")]
+    UndefinedJsonLdTerm(String),
+
     #[error("credential RDF dataset is invalid")]
     InvalidDataset,
 
+    #[error("credential status is assigned by the issuing contract")]
+    CredentialStatusProvided,
+
+    #[error("credential has no data integrity proof")]
+    MissingProof,
+
+    #[error("credential data integrity proof is invalid")]
+    InvalidProof,
+
     #[error("credential canonicalization failed")]
     CanonicalizationFailed,
 }
@@ -46,6 +79,21 @@ pub enum DecodedUri {
     Invalid,
 }
 
+/// The value of a claim about the credential subject.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DecodedClaimValue {
+    Iri(String),
+    BlankNode,
+    Literal { value: String, datatype: String },
+}
+
+/// A statement about the credential subject, of which it is the RDF subject.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedClaim {
+    pub predicate: String,
+    pub value: DecodedClaimValue,
+}
+
 #[derive(Clone, Debug, Getters, PartialEq)]
 pub struct DecodedCredential {
     #[getset(get = "pub(crate)")]
@@ -64,6 +112,8 @@ pub struct DecodedCredential {
     canonical_nquads: String,
     #[getset(get = "pub(crate)")]
     quads: Vec<DecodedQuad>,
+    #[getset(get = "pub(crate)")]
+    data_model: DataModelVersion,
 }
 
 impl DecodedCredential {
@@ -83,9 +133,15 @@ impl DecodedCredential {
             types,
             canonical_nquads,
             quads: Vec::new(),
+            data_model: DataModelVersion::default(),
         }
     }
 
+    pub(crate) fn with_data_model(mut self, data_model: DataModelVersion) -> Self {
+        self.data_model = data_model;
+        self
+    }
+
     pub(crate) fn with_quads(mut self, quads: Vec<DecodedQuad>) -> Self {
         self.quads = quads;
         self
@@ -100,6 +156,31 @@ impl DecodedCredential {
         self.valid_until = valid_until;
         self
     }
+
+    /// The claims about the credential subject, none when it has no identifier.
+    pub(crate) fn subject_claims(&self) -> Vec<DecodedClaim> {
+        let DecodedUri::Uri(subject_id) = &self.subject_id else {
+            return Vec::new();
+        };
+
+        self.quads
+            .iter()
+            .filter(|quad| {
+                matches!(&quad.subject, Subject::NamedNode(node) if node.as_str() == subject_id)
+            })
+            .map(|quad| DecodedClaim {
+                predicate: quad.predicate.as_str().to_string(),
+                value: match &quad.object {
+                    Term::NamedNode(node) => DecodedClaimValue::Iri(node.as_str().to_string()),
+                    Term::BlankNode(_) => DecodedClaimValue::BlankNode,
+                    Term::Literal(literal) => DecodedClaimValue::Literal {
+                        value: literal.value().to_string(),
+                        datatype: literal.datatype().as_str().to_string(),
+                    },
+                },
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -112,19 +193,102 @@ fn decode_nquads_credential(input: &[u8]) -> Result<DecodedCredential, Credentia
     decode_dataset_credential(&quads, &dataset, canonical_nquads)
 }
 
+/// RDF syntaxes credential payloads may be submitted in.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RdfSyntax {
+    NQuads,
+    Turtle,
+    TriG,
+    JsonLd,
+}
+
+/// Parse a credential payload into the RDF quads it holds.
+pub(super) fn parse_quads(
+    input: &[u8],
+    syntax: RdfSyntax,
+) -> Result<Vec<Quad>, CredentialDecodingError> {
+    let utf8 = str::from_utf8(input).map_err(|_| CredentialDecodingError::InvalidUtf8)?;
+
+    match syntax {
+        RdfSyntax::NQuads => parse_nquads_quads(utf8.as_bytes()),
+        RdfSyntax::Turtle => parse_turtle_quads(utf8.as_bytes()),
+        RdfSyntax::TriG => parse_trig_quads(utf8.as_bytes()),
+        RdfSyntax::JsonLd => json_ld_to_quads(utf8),
+    }
+}
+
+/// The data model a credential payload declares, which only JSON-LD payloads do, through the
+/// base context they start with.
+pub(super) fn declared_data_model(input: &[u8], syntax: RdfSyntax) -> Option<DataModelVersion> {
+    match syntax {
+        RdfSyntax::JsonLd => str::from_utf8(input).ok().and_then(json_ld_data_model),
+        RdfSyntax::NQuads | RdfSyntax::Turtle | RdfSyntax::TriG => None,
+    }
+}
+
+/// Decode a credential issued by `issuer_did`, embedding the given status entries.
+///
+/// The issuer is added when the payload omits it. The status entries are assigned by the
+/// contract, a payload carrying its own `credentialStatus` is rejected when some are given.
+pub fn decode_credential_for_issuer(
+    input: &[u8],
+    syntax: RdfSyntax,
+    issuer_did: &str,
+    status_entries: &[CredentialStatusEntry],
+) -> Result<DecodedCredential, CredentialDecodingError> {
+    let quads = parse_quads(input, syntax)?;
+    let decoded = decode_quads_credential_for_issuer(quads, issuer_did, status_entries)?;
+
+    Ok(match declared_data_model(input, syntax) {
+        Some(data_model) => decoded.with_data_model(data_model),
+        None => decoded,
+    })
+}
+
+#[cfg(test)]
 pub fn decode_nquads_credential_for_issuer(
     input: &[u8],
     issuer_did: &str,
 ) -> Result<DecodedCredential, CredentialDecodingError> {
-    let utf8 = str::from_utf8(input).map_err(|_| CredentialDecodingError::InvalidUtf8)?;
-    let quads = parse_nquads_quads(utf8.as_bytes())?;
-    let mut dataset = Dataset::from_iter(quads.iter().cloned());
+    decode_credential_for_issuer(input, RdfSyntax::NQuads, issuer_did, &[])
+}
+
+#[cfg(test)]
+pub fn decode_turtle_credential_for_issuer(
+    input: &[u8],
+    issuer_did: &str,
+) -> Result<DecodedCredential, CredentialDecodingError> {
+    decode_credential_for_issuer(input, RdfSyntax::Turtle, issuer_did, &[])
+}
+
+#[cfg(test)]
+pub fn decode_trig_credential_for_issuer(
+    input: &[u8],
+    issuer_did: &str,
+) -> Result<DecodedCredential, CredentialDecodingError> {
+    decode_credential_for_issuer(input, RdfSyntax::TriG, issuer_did, &[])
+}
+
+#[cfg(test)]
+pub fn decode_json_ld_credential_for_issuer(
+    input: &[u8],
+    issuer_did: &str,
+) -> Result<DecodedCredential, CredentialDecodingError> {
+    decode_credential_for_issuer(input, RdfSyntax::JsonLd, issuer_did, &[])
+}
+
+fn decode_quads_credential_for_issuer(
+    quads: Vec<Quad>,
+    issuer_did: &str,
+    status_entries: &[CredentialStatusEntry],
+) -> Result<DecodedCredential, CredentialDecodingError> {
+    let mut dataset = Dataset::from_iter(quads);
     let credential_subject = find_credential_subject(&dataset)?;
     let issuer = extract_issuer(&dataset, &credential_subject)?;
 
     match issuer {
         DecodedUri::Missing => {
-            insert_issuer(&mut dataset, credential_subject, issuer_did)?;
+            insert_issuer(&mut dataset, credential_subject.clone(), issuer_did)?;
         }
         DecodedUri::Uri(issuer) if issuer == issuer_did => {}
         DecodedUri::Uri(_) | DecodedUri::Invalid => {
@@ -132,6 +296,10 @@ pub fn decode_nquads_credential_for_issuer(
         }
     }
 
+    if !status_entries.is_empty() {
+        insert_status_entries(&mut dataset, &credential_subject, status_entries)?;
+    }
+
     let canonical_nquads = canonicalize_dataset(&dataset)?;
     let quads = parse_nquads_quads(canonical_nquads.as_bytes())?;
 
@@ -147,7 +315,7 @@ pub(crate) fn decode_canonical_nquads_credential(
     decode_dataset_credential(&quads, &dataset, input.to_string())
 }
 
-fn decode_dataset_credential(
+pub(super) fn decode_dataset_credential(
     quads: &[Quad],
     dataset: &Dataset,
     canonical_nquads: String,
@@ -159,27 +327,70 @@ fn decode_dataset_credential(
     let valid_until = extract_validity_bound(quads, &credential_subject, VC_VALID_UNTIL)?;
     let subject_id = extract_subject_id(dataset, &credential_subject)?;
     let types = extract_types(dataset, &credential_subject);
+    let data_model = infer_data_model(dataset, &credential_subject);
 
     Ok(
         DecodedCredential::new(id, issuer, subject_id, types, canonical_nquads)
             .with_quads(quads.to_vec())
-            .with_validity(valid_from, valid_until),
+            .with_validity(valid_from, valid_until)
+            .with_data_model(data_model),
     )
 }
 
+/// Infer the data model of a credential from its validity terms, the 2018 vocabulary being
+/// shared by both data models.
+///
+/// `issuanceDate` and `expirationDate` are only defined by the VC Data Model 1.1, and
+/// `validFrom` and `validUntil` only by the VC Data Model 2.0. A credential holding none of
+/// them is taken as a 1.1 one.
+fn infer_data_model(dataset: &Dataset, credential_subject: &Subject) -> DataModelVersion {
+    let holds_any = |predicates: [NamedNodeRef<'_>; 2]| {
+        predicates.into_iter().any(|predicate| {
+            dataset
+                .quads_for_subject(credential_subject)
+                .any(|quad| quad.predicate == predicate)
+        })
+    };
+
+    if !holds_any([VC_ISSUANCE_DATE, VC_EXPIRATION_DATE])
+        && holds_any([VC_VALID_FROM, VC_VALID_UNTIL])
+    {
+        DataModelVersion::V2
+    } else {
+        DataModelVersion::V1
+    }
+}
+
 #[cfg(test)]
 fn parse_nquads(input: &[u8]) -> Result<Dataset, CredentialDecodingError> {
     Ok(Dataset::from_iter(parse_nquads_quads(input)?))
 }
 
-fn parse_nquads_quads(input: &[u8]) -> Result<Vec<Quad>, CredentialDecodingError> {
+pub(super) fn parse_nquads_quads(input: &[u8]) -> Result<Vec<Quad>, CredentialDecodingError> {
     NQuadsParser::new()
         .for_slice(input)
         .collect::<Result<Vec<_>, _>>()
         .map_err(|_| CredentialDecodingError::InvalidNQuads)
 }
 
-pub fn find_credential_subject(dataset: &Dataset) -> Result<Subject, CredentialDecodingError> {
+fn parse_turtle_quads(input: &[u8]) -> Result<Vec<Quad>, CredentialDecodingError> {
+    TurtleParser::new()
+        .for_slice(input)
+        .map(|triple| triple.map(|triple| triple.in_graph(GraphName::DefaultGraph)))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| CredentialDecodingError::InvalidTurtle)
+}
+
+fn parse_trig_quads(input: &[u8]) -> Result<Vec<Quad>, CredentialDecodingError> {
+    TriGParser::new()
+        .for_slice(input)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| CredentialDecodingError::InvalidTriG)
+}
+
+pub(super) fn find_credential_subject(
+    dataset: &Dataset,
+) -> Result<Subject, CredentialDecodingError> {
     let candidate_subjects: HashSet<Subject> = [
         VC_ISSUER,
         VC_VALID_FROM,
@@ -312,7 +523,7 @@ fn insert_issuer(
     Ok(())
 }
 
-fn canonicalize_dataset(dataset: &Dataset) -> Result<String, CredentialDecodingError> {
+pub(super) fn canonicalize_dataset(dataset: &Dataset) -> Result<String, CredentialDecodingError> {
     canonicalize(dataset).map_err(map_canonicalization_error)
 }
 
@@ -323,11 +534,13 @@ fn map_canonicalization_error(_: rdf_canon::CanonicalizationError) -> Credential
 #[cfg(test)]
 mod tests {
     use super::{
-        decode_nquads_credential, decode_nquads_credential_for_issuer, extract_issuer,
+        decode_nquads_credential, decode_nquads_credential_for_issuer,
+        decode_trig_credential_for_issuer, decode_turtle_credential_for_issuer, extract_issuer,
         extract_subject_id, extract_validity_bound, find_credential_subject,
         map_canonicalization_error, parse_nquads, parse_nquads_quads, parse_validity_bound,
         subject_to_identifier, CredentialDecodingError, DecodedUri, VC_ISSUER, VC_VALID_FROM,
     };
+    use crate::domain::DataModelVersion;
     use cosmwasm_std::Timestamp;
     use oxrdf::{BlankNode, Literal, NamedNodeRef, Subject};
 
@@ -386,6 +599,76 @@ mod tests {
         assert_eq!(err, CredentialDecodingError::InvalidNQuads);
     }
 
+    #[test]
+    fn decode_turtle_credential_resolves_prefixes_and_base() {
+        let decoded = decode_turtle_credential_for_issuer(
+            format!(
+                r#"@base <https://example.com/credentials/> .
+@prefix cred: <{VC_NAMESPACE}> .
+
+<credential-1> a cred:VerifiableCredential ;
+    cred:issuanceDate "2025-01-01T00:00:00Z"^^<http://www.w3.org/2001/XMLSchema#dateTime> ;
+    cred:credentialSubject <did:example:subject> .
+"#
+            )
+            .as_bytes(),
+            AUTHORITY_DID,
+        )
+        .expect("turtle credential should decode");
+
+        let expected = decode_nquads_credential_for_issuer(
+            format!(
+                r#"<https://example.com/credentials/credential-1> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <{VC_NAMESPACE}VerifiableCredential> .
+<https://example.com/credentials/credential-1> <{VC_NAMESPACE}issuanceDate> "2025-01-01T00:00:00Z"^^<http://www.w3.org/2001/XMLSchema#dateTime> .
+<https://example.com/credentials/credential-1> <{VC_NAMESPACE}credentialSubject> <did:example:subject> .
+"#
+            )
+            .as_bytes(),
+            AUTHORITY_DID,
+        )
+        .expect("n-quads credential should decode");
+
+        assert_eq!(decoded, expected);
+    }
+
+    #[test]
+    fn decode_trig_credential_keeps_named_graphs() {
+        let decoded = decode_trig_credential_for_issuer(
+            format!(
+                r#"@prefix cred: <{VC_NAMESPACE}> .
+
+<{CREDENTIAL_ID}> a cred:VerifiableCredential ;
+    cred:issuer <{AUTHORITY_DID}> ;
+    cred:credentialSubject <did:example:subject> .
+
+<urn:graph:claims> {{
+    <did:example:subject> <https://schema.org/name> "Alice" .
+}}
+"#
+            )
+            .as_bytes(),
+            AUTHORITY_DID,
+        )
+        .expect("trig credential should decode");
+
+        assert_eq!(decoded.id().as_deref(), Some(CREDENTIAL_ID));
+        assert!(decoded.canonical_nquads().contains(
+            r#"<did:example:subject> <https://schema.org/name> "Alice" <urn:graph:claims> ."#
+        ));
+    }
+
+    #[test]
+    fn decode_turtle_and_trig_credentials_reject_invalid_syntax() {
+        assert_eq!(
+            decode_turtle_credential_for_issuer(b"<relative> a <x> .", AUTHORITY_DID),
+            Err(CredentialDecodingError::InvalidTurtle)
+        );
+        assert_eq!(
+            decode_trig_credential_for_issuer(b"{ not trig", AUTHORITY_DID),
+            Err(CredentialDecodingError::InvalidTriG)
+        );
+    }
+
     #[test]
     fn decode_credential_allows_missing_types_for_domain_validation() {
         let decoded = decode_nquads_credential(
@@ -534,6 +817,55 @@ mod tests {
         assert_eq!(err, CredentialDecodingError::InvalidDataset);
     }
 
+    #[test]
+    fn decode_credential_infers_the_data_model_from_its_validity_terms() {
+        let credential = |validity: &str| {
+            decode_nquads_credential_for_issuer(
+                format!(
+                    r#"<{CREDENTIAL_ID}> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <{VC_NAMESPACE}VerifiableCredential> .
+<{CREDENTIAL_ID}> <{VC_NAMESPACE}credentialSubject> <did:example:subject> .
+{validity}"#
+                )
+                .as_bytes(),
+                AUTHORITY_DID,
+            )
+            .expect("credential should decode")
+        };
+        let date = r#""2026-01-01T00:00:00Z"^^<http://www.w3.org/2001/XMLSchema#dateTimeStamp>"#;
+
+        for (validity, data_model) in [
+            (String::new(), DataModelVersion::V1),
+            (
+                format!("<{CREDENTIAL_ID}> <{VC_NAMESPACE}issuanceDate> {date} .\n"),
+                DataModelVersion::V1,
+            ),
+            (
+                format!("<{CREDENTIAL_ID}> <{VC_NAMESPACE}expirationDate> {date} .\n"),
+                DataModelVersion::V1,
+            ),
+            (
+                format!("<{CREDENTIAL_ID}> <{VC_NAMESPACE}validFrom> {date} .\n"),
+                DataModelVersion::V2,
+            ),
+            (
+                format!("<{CREDENTIAL_ID}> <{VC_NAMESPACE}validUntil> {date} .\n"),
+                DataModelVersion::V2,
+            ),
+            (
+                format!(
+                    "<{CREDENTIAL_ID}> <{VC_NAMESPACE}issuanceDate> {date} .\n<{CREDENTIAL_ID}> <{VC_NAMESPACE}validUntil> {date} .\n"
+                ),
+                DataModelVersion::V1,
+            ),
+        ] {
+            assert_eq!(
+                credential(&validity).data_model(),
+                &data_model,
+                "{validity}"
+            );
+        }
+    }
+
     #[test]
     fn find_credential_subject_ignores_typed_nodes_unrelated_to_credential_identity() {
         let dataset = parsed_dataset(
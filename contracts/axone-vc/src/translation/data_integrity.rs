@@ -0,0 +1,288 @@
+use super::credential_rdf::{
+    canonicalize_dataset, declared_data_model, decode_dataset_credential, find_credential_subject,
+    parse_quads, CredentialDecodingError, DecodedCredential, RdfSyntax,
+};
+use getset::Getters;
+use oxrdf::{vocab::rdf, Dataset, GraphName, NamedNodeRef, Quad, Subject, Term};
+
+const SEC_PROOF: NamedNodeRef<'static> =
+    NamedNodeRef::new_unchecked("https://w3id.org/security#proof");
+const SEC_DATA_INTEGRITY_PROOF: NamedNodeRef<'static> =
+    NamedNodeRef::new_unchecked("https://w3id.org/security#DataIntegrityProof");
+const SEC_CRYPTOSUITE: NamedNodeRef<'static> =
+    NamedNodeRef::new_unchecked("https://w3id.org/security#cryptosuite");
+const SEC_VERIFICATION_METHOD: NamedNodeRef<'static> =
+    NamedNodeRef::new_unchecked("https://w3id.org/security#verificationMethod");
+const SEC_PROOF_PURPOSE: NamedNodeRef<'static> =
+    NamedNodeRef::new_unchecked("https://w3id.org/security#proofPurpose");
+const SEC_PROOF_VALUE: NamedNodeRef<'static> =
+    NamedNodeRef::new_unchecked("https://w3id.org/security#proofValue");
+const DC_CREATED: NamedNodeRef<'static> =
+    NamedNodeRef::new_unchecked("http://purl.org/dc/terms/created");
+
+/// The Data Integrity proof options carried by a secured credential.
+#[derive(Clone, Debug, Getters, PartialEq)]
+pub struct DecodedProof {
+    #[getset(get = "pub(crate)")]
+    cryptosuite: String,
+    #[getset(get = "pub(crate)")]
+    verification_method: String,
+    #[getset(get = "pub(crate)")]
+    proof_purpose: String,
+    #[getset(get = "pub(crate)")]
+    created: Option<String>,
+    #[getset(get = "pub(crate)")]
+    proof_value: Option<String>,
+}
+
+/// A credential secured by a Data Integrity proof, along with the canonical forms the proof
+/// signs.
+#[derive(Clone, Debug, Getters, PartialEq)]
+pub struct DecodedSecuredCredential {
+    /// The credential decoded from the whole dataset, proof graph included.
+    #[getset(get = "pub(crate)")]
+    credential: DecodedCredential,
+    #[getset(get = "pub(crate)")]
+    proof: DecodedProof,
+    /// The canonical N-Quads of the credential without its proof.
+    #[getset(get = "pub(crate)")]
+    canonical_document: String,
+    /// The canonical N-Quads of the proof options, i.e. the proof graph without its value.
+    #[getset(get = "pub(crate)")]
+    canonical_proof_config: String,
+}
+
+impl DecodedSecuredCredential {
+    pub(crate) fn into_parts(self) -> (DecodedCredential, DecodedProof, String, String) {
+        (
+            self.credential,
+            self.proof,
+            self.canonical_document,
+            self.canonical_proof_config,
+        )
+    }
+}
+
+/// Decode a credential secured by a single embedded Data Integrity proof.
+///
+/// The proof is expected as the only `sec:proof` of the credential node, pointing to the
+/// blank node named graph that holds the proof node, as JSON-LD expands embedded proofs. The
+/// credential is not otherwise altered: its issuer is kept as signed.
+pub fn decode_secured_credential(
+    input: &[u8],
+    syntax: RdfSyntax,
+) -> Result<DecodedSecuredCredential, CredentialDecodingError> {
+    let quads = parse_quads(input, syntax)?;
+    let dataset = Dataset::from_iter(quads.iter().cloned());
+    let credential_subject = find_credential_subject(&dataset)?;
+    let credential = decode_dataset_credential(&quads, &dataset, canonicalize_dataset(&dataset)?)?;
+    let credential = match declared_data_model(input, syntax) {
+        Some(data_model) => credential.with_data_model(data_model),
+        None => credential,
+    };
+
+    let proof_graph = proof_graph(&quads, &credential_subject)?;
+    let mut document = Vec::new();
+    let mut proof = Vec::new();
+    for quad in quads {
+        if quad.graph_name == proof_graph {
+            proof.push(Quad::new(
+                quad.subject,
+                quad.predicate,
+                quad.object,
+                GraphName::DefaultGraph,
+            ));
+        } else if quad.predicate != SEC_PROOF || quad.subject != credential_subject {
+            document.push(quad);
+        }
+    }
+
+    let decoded_proof = decode_proof(&proof)?;
+    let proof_config = proof
+        .into_iter()
+        .filter(|quad| quad.predicate != SEC_PROOF_VALUE)
+        .collect::<Dataset>();
+    let canonical_document = canonicalize_dataset(&Dataset::from_iter(document))?;
+    let canonical_proof_config = canonicalize_dataset(&proof_config)?;
+
+    Ok(DecodedSecuredCredential {
+        credential,
+        proof: decoded_proof,
+        canonical_document,
+        canonical_proof_config,
+    })
+}
+
+fn proof_graph(
+    quads: &[Quad],
+    credential_subject: &Subject,
+) -> Result<GraphName, CredentialDecodingError> {
+    let mut graphs = quads
+        .iter()
+        .filter(|quad| quad.subject == *credential_subject && quad.predicate == SEC_PROOF)
+        .map(|quad| match (&quad.object, &quad.graph_name) {
+            (Term::BlankNode(node), GraphName::DefaultGraph) => {
+                Ok(GraphName::BlankNode(node.clone()))
+            }
+            _ => Err(CredentialDecodingError::InvalidProof),
+        });
+
+    let graph = graphs
+        .next()
+        .ok_or(CredentialDecodingError::MissingProof)??;
+    if graphs.next().is_some() {
+        return Err(CredentialDecodingError::InvalidProof);
+    }
+
+    Ok(graph)
+}
+
+fn decode_proof(quads: &[Quad]) -> Result<DecodedProof, CredentialDecodingError> {
+    let mut nodes = quads
+        .iter()
+        .filter(|quad| {
+            quad.predicate == rdf::TYPE && quad.object == SEC_DATA_INTEGRITY_PROOF.into()
+        })
+        .map(|quad| &quad.subject);
+    let node = nodes.next().ok_or(CredentialDecodingError::InvalidProof)?;
+    if nodes.next().is_some() || quads.iter().any(|quad| quad.subject != *node) {
+        return Err(CredentialDecodingError::InvalidProof);
+    }
+
+    let value = |predicate: NamedNodeRef<'_>| -> Result<Option<&Term>, CredentialDecodingError> {
+        let mut objects = quads
+            .iter()
+            .filter(|quad| quad.predicate == predicate)
+            .map(|quad| &quad.object);
+        let object = objects.next();
+        if objects.next().is_some() {
+            return Err(CredentialDecodingError::InvalidProof);
+        }
+        Ok(object)
+    };
+    let literal = |predicate| match value(predicate)? {
+        None => Ok(None),
+        Some(Term::Literal(literal)) => Ok(Some(literal.value().to_string())),
+        Some(_) => Err(CredentialDecodingError::InvalidProof),
+    };
+    let iri = |predicate| match value(predicate)? {
+        Some(Term::NamedNode(node)) => Ok(node.as_str().to_string()),
+        _ => Err(CredentialDecodingError::InvalidProof),
+    };
+
+    Ok(DecodedProof {
+        cryptosuite: literal(SEC_CRYPTOSUITE)?.ok_or(CredentialDecodingError::InvalidProof)?,
+        verification_method: iri(SEC_VERIFICATION_METHOD)?,
+        proof_purpose: iri(SEC_PROOF_PURPOSE)?,
+        created: literal(DC_CREATED)?,
+        proof_value: literal(SEC_PROOF_VALUE)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_secured_credential, CredentialDecodingError, RdfSyntax};
+
+    const ISSUER: &str = "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK";
+    const CREDENTIAL_ID: &str = "urn:uuid:credential-1";
+
+    fn credential(proof: &str) -> Vec<u8> {
+        format!(
+            r#"<{CREDENTIAL_ID}> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential> .
+<{CREDENTIAL_ID}> <https://www.w3.org/2018/credentials#issuer> <{ISSUER}> .
+<{CREDENTIAL_ID}> <https://www.w3.org/2018/credentials#credentialSubject> <did:example:subject> .
+{proof}"#
+        )
+        .into_bytes()
+    }
+
+    fn proof(value: &str) -> String {
+        format!(
+            r#"<{CREDENTIAL_ID}> <https://w3id.org/security#proof> _:proof .
+_:node <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://w3id.org/security#DataIntegrityProof> _:proof .
+_:node <https://w3id.org/security#cryptosuite> "eddsa-rdfc-2022"^^<https://w3id.org/security#cryptosuiteString> _:proof .
+_:node <https://w3id.org/security#proofPurpose> <https://w3id.org/security#assertionMethod> _:proof .
+_:node <https://w3id.org/security#verificationMethod> <{ISSUER}#key-1> _:proof .
+_:node <http://purl.org/dc/terms/created> "2025-01-01T00:00:00Z"^^<http://www.w3.org/2001/XMLSchema#dateTime> _:proof .
+{value}"#
+        )
+    }
+
+    #[test]
+    fn decode_secured_credential_splits_the_document_from_its_proof() {
+        let secured = decode_secured_credential(
+            &credential(&proof(
+                r#"_:node <https://w3id.org/security#proofValue> "zSignature"^^<https://w3id.org/security#multibase> _:proof .
+"#,
+            )),
+            RdfSyntax::NQuads,
+        )
+        .expect("secured credential should decode");
+
+        assert_eq!(secured.proof().cryptosuite(), "eddsa-rdfc-2022");
+        assert_eq!(
+            secured.proof().verification_method(),
+            &format!("{ISSUER}#key-1")
+        );
+        assert_eq!(
+            secured.proof().proof_purpose(),
+            "https://w3id.org/security#assertionMethod"
+        );
+        assert_eq!(
+            secured.proof().created().as_deref(),
+            Some("2025-01-01T00:00:00Z")
+        );
+        assert_eq!(secured.proof().proof_value().as_deref(), Some("zSignature"));
+
+        assert_eq!(
+            secured.canonical_document(),
+            &format!(
+                r#"<{CREDENTIAL_ID}> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential> .
+<{CREDENTIAL_ID}> <https://www.w3.org/2018/credentials#credentialSubject> <did:example:subject> .
+<{CREDENTIAL_ID}> <https://www.w3.org/2018/credentials#issuer> <{ISSUER}> .
+"#
+            )
+        );
+        assert_eq!(
+            secured.canonical_proof_config(),
+            &format!(
+                r#"_:c14n0 <http://purl.org/dc/terms/created> "2025-01-01T00:00:00Z"^^<http://www.w3.org/2001/XMLSchema#dateTime> .
+_:c14n0 <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://w3id.org/security#DataIntegrityProof> .
+_:c14n0 <https://w3id.org/security#cryptosuite> "eddsa-rdfc-2022"^^<https://w3id.org/security#cryptosuiteString> .
+_:c14n0 <https://w3id.org/security#proofPurpose> <https://w3id.org/security#assertionMethod> .
+_:c14n0 <https://w3id.org/security#verificationMethod> <{ISSUER}#key-1> .
+"#
+            )
+        );
+        assert!(secured
+            .credential()
+            .canonical_nquads()
+            .contains("<https://w3id.org/security#proofValue>"));
+    }
+
+    #[test]
+    fn decode_secured_credential_rejects_missing_and_malformed_proofs() {
+        assert_eq!(
+            decode_secured_credential(&credential(""), RdfSyntax::NQuads),
+            Err(CredentialDecodingError::MissingProof)
+        );
+
+        for proof in [
+            format!("<{CREDENTIAL_ID}> <https://w3id.org/security#proof> <urn:proof> .\n"),
+            proof(&format!(
+                "<{CREDENTIAL_ID}> <https://w3id.org/security#proof> _:other .\n"
+            )),
+            proof(
+                r#"_:node <https://w3id.org/security#cryptosuite> "ecdsa-rdfc-2019" _:proof .
+"#,
+            ),
+            proof("_:other <https://schema.org/name> \"Other\" _:proof .\n"),
+        ] {
+            assert_eq!(
+                decode_secured_credential(&credential(&proof), RdfSyntax::NQuads),
+                Err(CredentialDecodingError::InvalidProof),
+                "{proof}"
+            );
+        }
+    }
+}
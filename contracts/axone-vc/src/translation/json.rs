@@ -0,0 +1,379 @@
+use std::collections::BTreeMap;
+
+/// The maximum nesting depth of arrays and objects accepted by the reader.
+const MAX_DEPTH: usize = 64;
+
+/// A JSON value.
+///
+/// Numbers keep their lexical form, so documents are read and written back without any
+/// floating point arithmetic, which contracts cannot rely on.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Json {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(BTreeMap<String, Json>),
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct InvalidJson;
+
+impl Json {
+    /// Read a JSON document, rejecting duplicated object keys.
+    pub(crate) fn parse(input: &str) -> Result<Self, InvalidJson> {
+        let mut reader = Reader {
+            bytes: input.as_bytes(),
+            pos: 0,
+        };
+        let value = reader.value(0)?;
+        reader.whitespace();
+        if reader.pos != reader.bytes.len() {
+            return Err(InvalidJson);
+        }
+
+        Ok(value)
+    }
+
+    /// Return the value as a slice of values, a non-array value being a single element.
+    pub(crate) fn as_slice(&self) -> &[Json] {
+        match self {
+            Json::Array(items) => items,
+            value => std::slice::from_ref(value),
+        }
+    }
+
+    /// Serialize the value canonically, with sorted object keys and no insignificant
+    /// whitespace.
+    ///
+    /// Only integer numbers have a canonical form that can be computed without floating point
+    /// arithmetic, `None` is returned for documents holding other numbers.
+    pub(crate) fn to_canonical_string(&self) -> Option<String> {
+        let mut out = String::new();
+        self.write_canonical(&mut out)?;
+        Some(out)
+    }
+
+    fn write_canonical(&self, out: &mut String) -> Option<()> {
+        match self {
+            Json::Null => out.push_str("null"),
+            Json::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+            Json::Number(lexical) => {
+                if !is_integer(lexical) {
+                    return None;
+                }
+                out.push_str(if lexical == "-0" { "0" } else { lexical });
+            }
+            Json::String(value) => write_string(value, out),
+            Json::Array(items) => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    item.write_canonical(out)?;
+                }
+                out.push(']');
+            }
+            Json::Object(entries) => {
+                out.push('{');
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    write_string(key, out);
+                    out.push(':');
+                    value.write_canonical(out)?;
+                }
+                out.push('}');
+            }
+        }
+        Some(())
+    }
+}
+
+/// Whether a JSON number lexical form denotes an integer, i.e. has no fraction nor exponent.
+pub(crate) fn is_integer(lexical: &str) -> bool {
+    !lexical.contains(['.', 'e', 'E'])
+}
+
+fn write_string(value: &str, out: &mut String) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Result<u8, InvalidJson> {
+        let byte = self.peek().ok_or(InvalidJson)?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn expect(&mut self, literal: &[u8]) -> Result<(), InvalidJson> {
+        if !self.bytes[self.pos..].starts_with(literal) {
+            return Err(InvalidJson);
+        }
+        self.pos += literal.len();
+        Ok(())
+    }
+
+    fn whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn value(&mut self, depth: usize) -> Result<Json, InvalidJson> {
+        if depth > MAX_DEPTH {
+            return Err(InvalidJson);
+        }
+
+        self.whitespace();
+        match self.peek().ok_or(InvalidJson)? {
+            b'n' => self.expect(b"null").map(|_| Json::Null),
+            b't' => self.expect(b"true").map(|_| Json::Bool(true)),
+            b'f' => self.expect(b"false").map(|_| Json::Bool(false)),
+            b'"' => self.string().map(Json::String),
+            b'[' => self.array(depth),
+            b'{' => self.object(depth),
+            b'-' | b'0'..=b'9' => self.number(),
+            _ => Err(InvalidJson),
+        }
+    }
+
+    fn array(&mut self, depth: usize) -> Result<Json, InvalidJson> {
+        self.pos += 1;
+        let mut items = Vec::new();
+        self.whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(Json::Array(items));
+        }
+
+        loop {
+            items.push(self.value(depth + 1)?);
+            self.whitespace();
+            match self.next()? {
+                b',' => {}
+                b']' => return Ok(Json::Array(items)),
+                _ => return Err(InvalidJson),
+            }
+        }
+    }
+
+    fn object(&mut self, depth: usize) -> Result<Json, InvalidJson> {
+        self.pos += 1;
+        let mut entries = BTreeMap::new();
+        self.whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(Json::Object(entries));
+        }
+
+        loop {
+            self.whitespace();
+            if self.peek() != Some(b'"') {
+                return Err(InvalidJson);
+            }
+            let key = self.string()?;
+            self.whitespace();
+            if self.next()? != b':' {
+                return Err(InvalidJson);
+            }
+            let value = self.value(depth + 1)?;
+            if entries.insert(key, value).is_some() {
+                return Err(InvalidJson);
+            }
+            self.whitespace();
+            match self.next()? {
+                b',' => {}
+                b'}' => return Ok(Json::Object(entries)),
+                _ => return Err(InvalidJson),
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<Json, InvalidJson> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        match self.next()? {
+            b'0' => {}
+            b'1'..=b'9' => self.digits(),
+            _ => return Err(InvalidJson),
+        }
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            self.required_digits()?;
+        }
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            self.pos += 1;
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.pos += 1;
+            }
+            self.required_digits()?;
+        }
+
+        let lexical = std::str::from_utf8(&self.bytes[start..self.pos]).map_err(|_| InvalidJson)?;
+        Ok(Json::Number(lexical.to_string()))
+    }
+
+    fn digits(&mut self) {
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+    }
+
+    fn required_digits(&mut self) -> Result<(), InvalidJson> {
+        let start = self.pos;
+        self.digits();
+        if self.pos == start {
+            return Err(InvalidJson);
+        }
+        Ok(())
+    }
+
+    fn string(&mut self) -> Result<String, InvalidJson> {
+        self.pos += 1;
+        let mut out = Vec::new();
+        loop {
+            match self.next()? {
+                b'"' => return String::from_utf8(out).map_err(|_| InvalidJson),
+                b'\\' => {
+                    let c = match self.next()? {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => self.escaped_char()?,
+                        _ => return Err(InvalidJson),
+                    };
+                    let mut buf = [0; 4];
+                    out.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                }
+                byte if byte < 0x20 => return Err(InvalidJson),
+                byte => out.push(byte),
+            }
+        }
+    }
+
+    fn escaped_char(&mut self) -> Result<char, InvalidJson> {
+        let high = self.hex4()?;
+        let code = if (0xD800..0xDC00).contains(&high) {
+            self.expect(b"\\u")?;
+            let low = self.hex4()?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(InvalidJson);
+            }
+            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            high
+        };
+
+        char::from_u32(code).ok_or(InvalidJson)
+    }
+
+    fn hex4(&mut self) -> Result<u32, InvalidJson> {
+        let mut code = 0;
+        for _ in 0..4 {
+            let digit = char::from(self.next()?).to_digit(16).ok_or(InvalidJson)?;
+            code = code * 16 + digit;
+        }
+        Ok(code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidJson, Json};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn parse_reads_values_and_keeps_number_lexical_forms() {
+        let json = Json::parse(r#" {"a": [1, -2.5e3, true, false, null], "b": "x\"é😀\n"} "#)
+            .expect("document should parse");
+
+        assert_eq!(
+            json,
+            Json::Object(BTreeMap::from([
+                (
+                    "a".to_string(),
+                    Json::Array(vec![
+                        Json::Number("1".to_string()),
+                        Json::Number("-2.5e3".to_string()),
+                        Json::Bool(true),
+                        Json::Bool(false),
+                        Json::Null,
+                    ])
+                ),
+                ("b".to_string(), Json::String("x\"é😀\n".to_string())),
+            ]))
+        );
+    }
+
+    #[test]
+    fn parse_rejects_malformed_documents() {
+        for input in [
+            "",
+            "{",
+            "[1,]",
+            r#"{"a": 1,}"#,
+            r#"{"a": 1, "a": 2}"#,
+            "01",
+            "1.",
+            "-",
+            r#""\x""#,
+            r#""\ud800""#,
+            "\"a\nb\"",
+            "true false",
+            &"[".repeat(100),
+        ] {
+            assert_eq!(Json::parse(input), Err(InvalidJson), "{input}");
+        }
+    }
+
+    #[test]
+    fn to_canonical_string_sorts_keys_and_rejects_non_integers() {
+        let json = Json::parse(r#"{"b": [1, "\u0001"], "a": {"d": null, "c": -0}}"#)
+            .expect("document should parse");
+
+        assert_eq!(
+            json.to_canonical_string().as_deref(),
+            Some(r#"{"a":{"c":0,"d":null},"b":[1,"\u0001"]}"#)
+        );
+        assert_eq!(
+            Json::parse("[1.5]")
+                .expect("document should parse")
+                .to_canonical_string(),
+            None
+        );
+    }
+}
@@ -0,0 +1,1431 @@
+use super::{
+    json::{self, Json},
+    CredentialDecodingError, CredentialEncodingError,
+};
+use crate::domain::DataModelVersion;
+use oxrdf::{
+    vocab::{rdf, xsd},
+    BlankNode, GraphName, Literal, NamedNode, NamedNodeRef, Quad, Subject, Term,
+};
+use std::collections::BTreeMap;
+
+const VC_V1_CONTEXT: &str = "https://www.w3.org/2018/credentials/v1";
+const VC_V2_CONTEXT: &str = "https://www.w3.org/ns/credentials/v2";
+
+/// The contexts a JSON-LD credential may reference, bundled with the contract as no remote
+/// document is ever loaded.
+const BUNDLED_CONTEXTS: [(&str, &str); 3] = [
+    (
+        VC_V1_CONTEXT,
+        include_str!("contexts/credentials-v1.jsonld"),
+    ),
+    (
+        VC_V2_CONTEXT,
+        include_str!("contexts/credentials-v2.jsonld"),
+    ),
+    (
+        "https://w3id.org/security/data-integrity/v2",
+        include_str!("contexts/data-integrity-v2.jsonld"),
+    ),
+];
+
+/// The maximum nesting of node objects and contexts processed while expanding a document.
+const MAX_DEPTH: usize = 32;
+
+const RDF_JSON: NamedNodeRef<'static> =
+    NamedNodeRef::new_unchecked("http://www.w3.org/1999/02/22-rdf-syntax-ns#JSON");
+
+const KEYWORDS: [&str; 24] = [
+    "@base",
+    "@container",
+    "@context",
+    "@direction",
+    "@graph",
+    "@id",
+    "@import",
+    "@included",
+    "@index",
+    "@json",
+    "@language",
+    "@list",
+    "@nest",
+    "@none",
+    "@prefix",
+    "@propagate",
+    "@protected",
+    "@reverse",
+    "@set",
+    "@type",
+    "@value",
+    "@version",
+    "@vocab",
+    "@default",
+];
+
+/// Convert a JSON-LD document into the RDF quads it represents.
+///
+/// The conversion follows the JSON-LD 1.1 expansion and RDF serialization algorithms over the
+/// subset of features used by verifiable credentials. Remote contexts are resolved against the
+/// bundled contexts only, and any term that does not expand to an absolute IRI is rejected
+/// instead of being dropped.
+pub(crate) fn json_ld_to_quads(input: &str) -> Result<Vec<Quad>, CredentialDecodingError> {
+    let document = Json::parse(input).map_err(|_| CredentialDecodingError::InvalidJsonLd)?;
+    let mut expander = Expander::default();
+    let context = Context::default();
+
+    for item in document.as_slice() {
+        let Json::Object(node) = item else {
+            return Err(CredentialDecodingError::InvalidJsonLd);
+        };
+        expander.top_level_node(&context, node)?;
+    }
+
+    Ok(expander.quads)
+}
+
+/// The data model of a JSON-LD credential, given by the base context its `@context` starts
+/// with, if any.
+///
+/// Both base contexts map the credential terms to the same 2018 vocabulary, the context being
+/// the only place a JSON-LD credential tells its data model.
+pub(crate) fn json_ld_data_model(input: &str) -> Option<DataModelVersion> {
+    let document = Json::parse(input).ok()?;
+    let [Json::Object(node)] = document.as_slice() else {
+        return None;
+    };
+
+    match node.get("@context")?.as_slice().first()? {
+        Json::String(url) if url == VC_V1_CONTEXT => Some(DataModelVersion::V1),
+        Json::String(url) if url == VC_V2_CONTEXT => Some(DataModelVersion::V2),
+        _ => None,
+    }
+}
+
+/// Convert RDF quads into a compacted JSON-LD document against the given bundled contexts.
+///
+/// The document is flattened: every subject becomes a node object of the top-level `@graph`,
+/// and named graphs are nested in the node object of their name. Properties, types and
+/// datatypes are compacted to terms of the active context only when expanding them yields the
+/// same RDF terms back, and are otherwise kept as absolute IRIs and explicit value objects.
+pub(crate) fn quads_to_json_ld(
+    quads: &[Quad],
+    contexts: &[String],
+) -> Result<String, CredentialEncodingError> {
+    let mut expander = Expander::default();
+    let local = Json::Array(contexts.iter().cloned().map(Json::String).collect());
+    let context = expander
+        .process_context(&Context::default(), &local, false, true)
+        .map_err(compaction_error)?;
+
+    let mut graphs = BTreeMap::<Option<String>, BTreeMap<String, NodeQuads<'_>>>::new();
+    for quad in quads {
+        let graph = match &quad.graph_name {
+            GraphName::DefaultGraph => None,
+            name => Some(name.to_string()),
+        };
+        graphs
+            .entry(graph)
+            .or_default()
+            .entry(quad.subject.to_string())
+            .or_insert_with(|| NodeQuads {
+                subject: &quad.subject,
+                properties: Vec::new(),
+            })
+            .properties
+            .push((&quad.predicate, &quad.object));
+    }
+
+    let mut nodes = BTreeMap::new();
+    for (id, node) in graphs.remove(&None).unwrap_or_default() {
+        nodes.insert(id, expander.compact_node(&context, &node)?);
+    }
+
+    let mut holders = Vec::new();
+    for (name, members) in graphs
+        .into_iter()
+        .filter_map(|(name, nodes)| Some((name?, nodes)))
+    {
+        let (holder, active) = match nodes.remove(&name) {
+            Some((holder, active)) => (holder, active),
+            None => {
+                let mut holder = BTreeMap::new();
+                holder.insert(
+                    "@id".to_string(),
+                    Json::String(compact_id(&context, &name)?),
+                );
+                (holder, context.clone())
+            }
+        };
+        let mut graph = Vec::new();
+        for node in members.values() {
+            graph.push(Json::Object(expander.compact_node(&active, node)?.0));
+        }
+        holders.push((name, holder, graph, active));
+    }
+    for (name, mut holder, graph, active) in holders {
+        holder.insert("@graph".to_string(), Json::Array(graph));
+        nodes.insert(name, (holder, active));
+    }
+
+    let mut document = BTreeMap::new();
+    if !contexts.is_empty() {
+        document.insert(
+            "@context".to_string(),
+            one_or_many(local.as_slice().to_vec()),
+        );
+    }
+    document.insert(
+        "@graph".to_string(),
+        Json::Array(
+            nodes
+                .into_values()
+                .map(|(node, _)| Json::Object(node))
+                .collect(),
+        ),
+    );
+
+    Json::Object(document)
+        .to_canonical_string()
+        .ok_or(CredentialEncodingError::JsonLdCompactionFailed)
+}
+
+fn invalid<T>() -> Result<T, CredentialDecodingError> {
+    Err(CredentialDecodingError::InvalidJsonLd)
+}
+
+fn is_keyword(value: &str) -> bool {
+    KEYWORDS.contains(&value)
+}
+
+/// The active context against which terms, compact IRIs and values are expanded.
+#[derive(Clone, Debug, Default)]
+struct Context {
+    terms: BTreeMap<String, TermDefinition>,
+    vocab: Option<String>,
+    language: Option<String>,
+    /// The context to revert to when entering a nested node object, set by type-scoped
+    /// contexts which do not propagate.
+    previous: Option<Box<Context>>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct TermDefinition {
+    /// The expanded IRI or keyword, `None` for terms explicitly mapped to `null`.
+    iri: Option<String>,
+    type_mapping: Option<String>,
+    container: Container,
+    /// `Some(None)` when the term resets the default language.
+    language: Option<Option<String>>,
+    context: Option<Json>,
+    protected: bool,
+}
+
+impl TermDefinition {
+    fn same_as(&self, other: &Self) -> bool {
+        Self {
+            protected: other.protected,
+            ..self.clone()
+        } == *other
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Container {
+    graph: bool,
+    index: bool,
+    language: bool,
+    list: bool,
+}
+
+/// The context definition being processed, used to define terms on demand when other terms
+/// depend on them.
+struct LocalContext<'a> {
+    entries: &'a BTreeMap<String, Json>,
+    defined: BTreeMap<String, bool>,
+    protected: bool,
+    override_protected: bool,
+}
+
+/// The quads sharing a subject within a graph, compacted into a single node object.
+struct NodeQuads<'a> {
+    subject: &'a Subject,
+    properties: Vec<(&'a NamedNode, &'a Term)>,
+}
+
+#[derive(Default)]
+struct Expander {
+    quads: Vec<Quad>,
+    blank_nodes: BTreeMap<String, BlankNode>,
+    next_blank_node: usize,
+    contexts: BTreeMap<&'static str, Json>,
+    depth: usize,
+}
+
+impl Expander {
+    fn enter(&mut self) -> Result<(), CredentialDecodingError> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return invalid();
+        }
+        Ok(())
+    }
+
+    fn leave(&mut self) {
+        self.depth -= 1;
+    }
+
+    fn blank_node(&mut self) -> BlankNode {
+        let node = BlankNode::new_unchecked(format!("b{}", self.next_blank_node));
+        self.next_blank_node += 1;
+        node
+    }
+
+    fn labelled_blank_node(&mut self, label: &str) -> BlankNode {
+        if let Some(node) = self.blank_nodes.get(label) {
+            return node.clone();
+        }
+        let node = self.blank_node();
+        self.blank_nodes.insert(label.to_string(), node.clone());
+        node
+    }
+
+    fn push(&mut self, subject: Subject, predicate: NamedNode, object: Term, graph: &GraphName) {
+        self.quads
+            .push(Quad::new(subject, predicate, object, graph.clone()));
+    }
+
+    fn bundled_context(&mut self, url: &str) -> Result<Json, CredentialDecodingError> {
+        let (url, document) = BUNDLED_CONTEXTS
+            .iter()
+            .find(|(candidate, _)| *candidate == url)
+            .ok_or_else(|| CredentialDecodingError::UnsupportedJsonLdContext(url.to_string()))?;
+
+        if !self.contexts.contains_key(url) {
+            let Json::Object(mut document) =
+                Json::parse(document).map_err(|_| CredentialDecodingError::InvalidJsonLd)?
+            else {
+                return invalid();
+            };
+            let context = document
+                .remove("@context")
+                .ok_or(CredentialDecodingError::InvalidJsonLd)?;
+            self.contexts.insert(url, context);
+        }
+
+        self.contexts
+            .get(url)
+            .cloned()
+            .ok_or(CredentialDecodingError::InvalidJsonLd)
+    }
+
+    fn process_context(
+        &mut self,
+        active: &Context,
+        local: &Json,
+        override_protected: bool,
+        propagate: bool,
+    ) -> Result<Context, CredentialDecodingError> {
+        self.enter()?;
+        let mut result = active.clone();
+        if !propagate && result.previous.is_none() {
+            result.previous = Some(Box::new(active.clone()));
+        }
+
+        for item in local.as_slice() {
+            match item {
+                Json::Null => {
+                    if !override_protected && result.terms.values().any(|term| term.protected) {
+                        return invalid();
+                    }
+                    result = Context {
+                        previous: result.previous.take(),
+                        ..Context::default()
+                    };
+                }
+                Json::String(url) => {
+                    let context = self.bundled_context(url)?;
+                    result = self.process_context(&result, &context, override_protected, true)?;
+                }
+                Json::Object(entries) => {
+                    self.process_context_definition(&mut result, entries, override_protected)?;
+                }
+                _ => return invalid(),
+            }
+        }
+
+        self.leave();
+        Ok(result)
+    }
+
+    fn process_context_definition(
+        &mut self,
+        result: &mut Context,
+        entries: &BTreeMap<String, Json>,
+        override_protected: bool,
+    ) -> Result<(), CredentialDecodingError> {
+        let mut protected = false;
+        for (key, value) in entries {
+            match (key.as_str(), value) {
+                ("@version", Json::Number(version)) if version == "1.1" => {}
+                ("@protected", Json::Bool(value)) => protected = *value,
+                ("@base", Json::Null) => {}
+                ("@vocab", Json::Null) => result.vocab = None,
+                ("@vocab", Json::String(vocab)) => {
+                    let vocab = expand_iri(result, vocab, true)
+                        .filter(|iri| NamedNode::new(iri.as_str()).is_ok())
+                        .ok_or(CredentialDecodingError::InvalidJsonLd)?;
+                    result.vocab = Some(vocab);
+                }
+                ("@language", Json::Null) => result.language = None,
+                ("@language", Json::String(language)) => {
+                    result.language = Some(language.to_ascii_lowercase());
+                }
+                (key, _) if key.starts_with('@') => return invalid(),
+                _ => {}
+            }
+        }
+
+        let mut local = LocalContext {
+            entries,
+            defined: BTreeMap::new(),
+            protected,
+            override_protected,
+        };
+        for term in entries.keys().filter(|key| !key.starts_with('@')) {
+            define_term(result, &mut local, term)?;
+        }
+
+        Ok(())
+    }
+
+    fn top_level_node(
+        &mut self,
+        context: &Context,
+        node: &BTreeMap<String, Json>,
+    ) -> Result<(), CredentialDecodingError> {
+        let local = match node.get("@context") {
+            Some(local) => self.process_context(context, local, false, true)?,
+            None => context.clone(),
+        };
+
+        let mut keys = node.keys().filter(|key| *key != "@context");
+        let graph = match (keys.next(), keys.next()) {
+            (Some(key), None) if expand_iri(&local, key, true).as_deref() == Some("@graph") => {
+                &node[key]
+            }
+            _ => {
+                self.node(context, None, node, &GraphName::DefaultGraph)?;
+                return Ok(());
+            }
+        };
+
+        for item in graph.as_slice() {
+            let Json::Object(node) = item else {
+                return invalid();
+            };
+            self.node(&local, None, node, &GraphName::DefaultGraph)?;
+        }
+
+        Ok(())
+    }
+
+    fn node(
+        &mut self,
+        active: &Context,
+        property_context: Option<&Json>,
+        node: &BTreeMap<String, Json>,
+        graph: &GraphName,
+    ) -> Result<Subject, CredentialDecodingError> {
+        self.enter()?;
+        let mut context = match &active.previous {
+            Some(previous) => (**previous).clone(),
+            None => active.clone(),
+        };
+        if let Some(local) = property_context {
+            context = self.process_context(&context, local, true, true)?;
+        }
+        if let Some(local) = node.get("@context") {
+            context = self.process_context(&context, local, false, true)?;
+        }
+
+        let type_context = context.clone();
+        let mut types = Vec::new();
+        for (key, value) in node.iter().filter(|(key, _)| *key != "@context") {
+            if expand_iri(&type_context, key, true).as_deref() == Some("@type") {
+                for value in value.as_slice() {
+                    let Json::String(value) = value else {
+                        return invalid();
+                    };
+                    types.push(value.as_str());
+                }
+            }
+        }
+        types.sort_unstable();
+        for value in &types {
+            if let Some(local) = type_context
+                .terms
+                .get(*value)
+                .and_then(|term| term.context.clone())
+            {
+                context = self.process_context(&context, &local, false, false)?;
+            }
+        }
+
+        let mut subject = None;
+        for (key, value) in node.iter().filter(|(key, _)| *key != "@context") {
+            if expand_key(&context, key)? == "@id" {
+                let (Json::String(id), None) = (value, &subject) else {
+                    return invalid();
+                };
+                subject = Some(self.reference(&context, id, false)?);
+            }
+        }
+        let subject = match subject {
+            Some(subject) => subject,
+            None => self.blank_node().into(),
+        };
+
+        for value in types {
+            let object = self.reference(&type_context, value, true)?;
+            self.push(
+                subject.clone(),
+                rdf::TYPE.into_owned(),
+                object.into(),
+                graph,
+            );
+        }
+
+        for (key, value) in node.iter().filter(|(key, _)| *key != "@context") {
+            match expand_key(&context, key)?.as_str() {
+                "@id" | "@type" | "@index" => {}
+                "@graph" => {
+                    let named_graph = match &subject {
+                        Subject::NamedNode(node) => GraphName::NamedNode(node.clone()),
+                        Subject::BlankNode(node) => GraphName::BlankNode(node.clone()),
+                        #[allow(unreachable_patterns)]
+                        _ => return invalid(),
+                    };
+                    for item in value.as_slice() {
+                        let Json::Object(item) = item else {
+                            return invalid();
+                        };
+                        self.node(&context, None, item, &named_graph)?;
+                    }
+                }
+                iri if iri.starts_with('@') => return invalid(),
+                iri => {
+                    let predicate = NamedNode::new(iri)
+                        .map_err(|_| CredentialDecodingError::UndefinedJsonLdTerm(key.clone()))?;
+                    self.property(&context, &subject, key, predicate, value, graph)?;
+                }
+            }
+        }
+
+        self.leave();
+        Ok(subject)
+    }
+
+    fn property(
+        &mut self,
+        context: &Context,
+        subject: &Subject,
+        key: &str,
+        predicate: NamedNode,
+        value: &Json,
+        graph: &GraphName,
+    ) -> Result<(), CredentialDecodingError> {
+        let term = context.terms.get(key).cloned().unwrap_or_default();
+        let mut objects = Vec::new();
+
+        if term.type_mapping.as_deref() == Some("@json") {
+            objects.push(json_literal(value)?.into());
+        } else if term.container.language {
+            let Json::Object(languages) = value else {
+                return invalid();
+            };
+            for (language, values) in languages {
+                for value in values.as_slice() {
+                    match value {
+                        Json::Null => {}
+                        Json::String(value) if language == "@none" => {
+                            objects.push(Literal::new_simple_literal(value).into());
+                        }
+                        Json::String(value) => {
+                            objects.push(language_literal(value, language)?.into());
+                        }
+                        _ => return invalid(),
+                    }
+                }
+            }
+        } else if term.container.list {
+            let items = match value {
+                Json::Object(entries) if entries.contains_key("@list") => &entries["@list"],
+                value => value,
+            };
+            objects.push(self.list(context, &term, items.as_slice(), graph)?);
+        } else if term.container.index {
+            let Json::Object(indexes) = value else {
+                return invalid();
+            };
+            for values in indexes.values() {
+                for item in values.as_slice() {
+                    self.item(context, &term, item, graph, &mut objects)?;
+                }
+            }
+        } else {
+            for item in value.as_slice() {
+                self.item(context, &term, item, graph, &mut objects)?;
+            }
+        }
+
+        for object in objects {
+            self.push(subject.clone(), predicate.clone(), object, graph);
+        }
+
+        Ok(())
+    }
+
+    fn item(
+        &mut self,
+        context: &Context,
+        term: &TermDefinition,
+        value: &Json,
+        graph: &GraphName,
+        objects: &mut Vec<Term>,
+    ) -> Result<(), CredentialDecodingError> {
+        match value {
+            Json::Null => {}
+            Json::Array(_) => return invalid(),
+            Json::Object(entries) => {
+                let keywords = entries
+                    .keys()
+                    .filter_map(|key| {
+                        expand_iri(context, key, true)
+                            .filter(|iri| is_keyword(iri))
+                            .map(|iri| (iri, key))
+                    })
+                    .collect::<BTreeMap<_, _>>();
+                if keywords.contains_key("@value") {
+                    let context = self.scoped_context(context, term)?;
+                    objects.extend(self.value_object(&context, entries)?);
+                } else if let Some(key) = keywords.get("@list") {
+                    objects.push(self.list(context, term, entries[*key].as_slice(), graph)?);
+                } else if let Some(key) = keywords.get("@set") {
+                    for item in entries[*key].as_slice() {
+                        self.item(context, term, item, graph, objects)?;
+                    }
+                } else if term.container.graph {
+                    let name = self.blank_node();
+                    self.node(
+                        context,
+                        term.context.as_ref(),
+                        entries,
+                        &GraphName::BlankNode(name.clone()),
+                    )?;
+                    objects.push(name.into());
+                } else {
+                    let subject = self.node(context, term.context.as_ref(), entries, graph)?;
+                    objects.push(subject.into());
+                }
+            }
+            scalar => {
+                let context = self.scoped_context(context, term)?;
+                objects.push(self.scalar(&context, term, scalar)?);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn scoped_context(
+        &mut self,
+        context: &Context,
+        term: &TermDefinition,
+    ) -> Result<Context, CredentialDecodingError> {
+        match &term.context {
+            Some(local) => self.process_context(context, local, true, true),
+            None => Ok(context.clone()),
+        }
+    }
+
+    fn list(
+        &mut self,
+        context: &Context,
+        term: &TermDefinition,
+        items: &[Json],
+        graph: &GraphName,
+    ) -> Result<Term, CredentialDecodingError> {
+        let mut objects = Vec::new();
+        for item in items {
+            self.item(context, term, item, graph, &mut objects)?;
+        }
+
+        let mut head: Term = rdf::NIL.into_owned().into();
+        for object in objects.into_iter().rev() {
+            let node = self.blank_node();
+            self.push(node.clone().into(), rdf::FIRST.into_owned(), object, graph);
+            self.push(node.clone().into(), rdf::REST.into_owned(), head, graph);
+            head = node.into();
+        }
+
+        Ok(head)
+    }
+
+    fn scalar(
+        &mut self,
+        context: &Context,
+        term: &TermDefinition,
+        value: &Json,
+    ) -> Result<Term, CredentialDecodingError> {
+        let datatype = term
+            .type_mapping
+            .as_deref()
+            .filter(|mapping| !mapping.starts_with('@'));
+
+        match (value, term.type_mapping.as_deref()) {
+            (Json::String(value), Some("@id")) => Ok(self.reference(context, value, false)?.into()),
+            (Json::String(value), Some("@vocab")) => {
+                Ok(self.reference(context, value, true)?.into())
+            }
+            (Json::String(value), _) => match (datatype, &term.language, &context.language) {
+                (Some(datatype), _, _) => typed_literal(value, datatype).map(Into::into),
+                (None, Some(Some(language)), _) | (None, None, Some(language)) => {
+                    language_literal(value, language).map(Into::into)
+                }
+                _ => Ok(Literal::new_simple_literal(value).into()),
+            },
+            (Json::Bool(value), _) => typed_literal(
+                if *value { "true" } else { "false" },
+                datatype.unwrap_or(xsd::BOOLEAN.as_str()),
+            )
+            .map(Into::into),
+            (Json::Number(value), _) => number_literal(value, datatype).map(Into::into),
+            _ => invalid(),
+        }
+    }
+
+    fn value_object(
+        &mut self,
+        context: &Context,
+        entries: &BTreeMap<String, Json>,
+    ) -> Result<Option<Term>, CredentialDecodingError> {
+        let mut value = &Json::Null;
+        let mut datatype = None;
+        let mut language = None;
+        for (key, entry) in entries {
+            match (expand_key(context, key)?.as_str(), entry) {
+                ("@value", entry) => value = entry,
+                ("@type", Json::String(entry)) => {
+                    datatype = Some(
+                        expand_iri(context, entry, true)
+                            .ok_or(CredentialDecodingError::InvalidJsonLd)?,
+                    );
+                }
+                ("@language", Json::String(entry)) => language = Some(entry.as_str()),
+                ("@index", _) => {}
+                _ => return invalid(),
+            }
+        }
+
+        let literal = match (value, datatype.as_deref(), language) {
+            (Json::Null, _, _) => return Ok(None),
+            (value, Some("@json"), None) => json_literal(value)?,
+            (_, Some(_), Some(_)) => return invalid(),
+            (Json::String(value), Some(datatype), None) => typed_literal(value, datatype)?,
+            (Json::String(value), None, Some(language)) => language_literal(value, language)?,
+            (Json::String(value), None, None) => Literal::new_simple_literal(value),
+            (Json::Bool(value), datatype, None) => typed_literal(
+                if *value { "true" } else { "false" },
+                datatype.unwrap_or(xsd::BOOLEAN.as_str()),
+            )?,
+            (Json::Number(value), datatype, None) => number_literal(value, datatype)?,
+            _ => return invalid(),
+        };
+
+        Ok(Some(literal.into()))
+    }
+
+    fn reference(
+        &mut self,
+        context: &Context,
+        value: &str,
+        vocab: bool,
+    ) -> Result<Subject, CredentialDecodingError> {
+        if let Some(label) = value.strip_prefix("_:") {
+            return Ok(self.labelled_blank_node(label).into());
+        }
+
+        let iri = expand_iri(context, value, vocab)
+            .ok_or_else(|| CredentialDecodingError::UndefinedJsonLdTerm(value.to_string()))?;
+        if let Some(label) = iri.strip_prefix("_:") {
+            return Ok(self.labelled_blank_node(label).into());
+        }
+
+        NamedNode::new(iri)
+            .map(Into::into)
+            .map_err(|_| CredentialDecodingError::UndefinedJsonLdTerm(value.to_string()))
+    }
+}
+
+impl Expander {
+    /// Compact the quads of a node, returning its node object and the context its nested
+    /// node objects are compacted against.
+    ///
+    /// The node contexts are derived as expansion derives them, type-scoped contexts
+    /// included, so that every compacted key and value is checked against the context it
+    /// will be expanded with.
+    fn compact_node(
+        &mut self,
+        active: &Context,
+        node: &NodeQuads<'_>,
+    ) -> Result<(BTreeMap<String, Json>, Context), CredentialEncodingError> {
+        let type_context = match &active.previous {
+            Some(previous) => (**previous).clone(),
+            None => active.clone(),
+        };
+
+        let mut types = Vec::new();
+        let mut properties = BTreeMap::<&str, (&NamedNode, Vec<&Term>)>::new();
+        for (predicate, object) in &node.properties {
+            match object {
+                Term::NamedNode(object) if predicate.as_ref() == rdf::TYPE => {
+                    types.push(compact_vocab(&type_context, object.as_str(), |_| true)?);
+                }
+                object => properties
+                    .entry(predicate.as_str())
+                    .or_insert_with(|| (predicate, Vec::new()))
+                    .1
+                    .push(object),
+            }
+        }
+        types.sort_unstable();
+
+        let mut context = type_context.clone();
+        for value in &types {
+            if let Some(local) = type_context
+                .terms
+                .get(value)
+                .and_then(|term| term.context.clone())
+            {
+                context = self
+                    .process_context(&context, &local, false, false)
+                    .map_err(compaction_error)?;
+            }
+        }
+
+        let mut result = BTreeMap::new();
+        result.insert(
+            keyword_alias(&[&context], "@id"),
+            Json::String(compact_id(&context, &node.subject.to_string())?),
+        );
+        if !types.is_empty() {
+            result.insert(
+                keyword_alias(&[&type_context, &context], "@type"),
+                one_or_many(types.into_iter().map(Json::String).collect()),
+            );
+        }
+
+        for (predicate, objects) in properties.into_values() {
+            let key = compact_vocab(&context, predicate.as_str(), |term| {
+                term.container == Container::default()
+                    && term.type_mapping.as_deref() != Some("@json")
+            })?;
+            let term = context.terms.get(&key).cloned().unwrap_or_default();
+            let value_context = self
+                .scoped_context(&context, &term)
+                .map_err(compaction_error)?;
+            let values = objects
+                .into_iter()
+                .map(|object| compact_value(&value_context, &term, object))
+                .collect::<Result<Vec<_>, _>>()?;
+            result.insert(key, one_or_many(values));
+        }
+
+        Ok((result, context))
+    }
+}
+
+fn compaction_error(error: CredentialDecodingError) -> CredentialEncodingError {
+    match error {
+        CredentialDecodingError::UnsupportedJsonLdContext(url) => {
+            CredentialEncodingError::UnsupportedJsonLdContext(url)
+        }
+        _ => CredentialEncodingError::JsonLdCompactionFailed,
+    }
+}
+
+fn one_or_many(mut values: Vec<Json>) -> Json {
+    match values.len() {
+        1 => values.remove(0),
+        _ => Json::Array(values),
+    }
+}
+
+/// The shortest term aliasing a keyword in all the given contexts, or the keyword itself.
+fn keyword_alias(contexts: &[&Context], keyword: &str) -> String {
+    let mut aliases = contexts
+        .iter()
+        .flat_map(|context| context.terms.keys())
+        .filter(|alias| {
+            contexts
+                .iter()
+                .all(|context| expand_iri(context, alias, true).as_deref() == Some(keyword))
+        })
+        .collect::<Vec<_>>();
+    aliases.sort_unstable_by(|a, b| a.len().cmp(&b.len()).then(a.cmp(b)));
+
+    aliases
+        .first()
+        .map_or_else(|| keyword.to_string(), |alias| alias.to_string())
+}
+
+/// Compact an IRI expanded against the vocabulary, i.e. a key, a type or a datatype, to the
+/// shortest term, vocabulary relative IRI or compact IRI that expands back to it.
+fn compact_vocab(
+    context: &Context,
+    iri: &str,
+    eligible: impl Fn(&TermDefinition) -> bool,
+) -> Result<String, CredentialEncodingError> {
+    let mut candidates = context
+        .terms
+        .iter()
+        .filter(|(_, term)| term.iri.as_deref() == Some(iri))
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    if let Some(suffix) = context
+        .vocab
+        .as_deref()
+        .and_then(|vocab| iri.strip_prefix(vocab))
+    {
+        candidates.push(suffix.to_string());
+    }
+    for (name, term) in &context.terms {
+        let suffix = term
+            .iri
+            .as_deref()
+            .filter(|prefix| !is_keyword(prefix) && !name.contains(':'))
+            .and_then(|prefix| iri.strip_prefix(prefix))
+            .filter(|suffix| !suffix.is_empty() && !suffix.starts_with("//"));
+        if let Some(suffix) = suffix {
+            candidates.push(format!("{name}:{suffix}"));
+        }
+    }
+    candidates.push(iri.to_string());
+
+    candidates
+        .into_iter()
+        .filter(|candidate| !candidate.is_empty() && !candidate.starts_with('@'))
+        .filter(|candidate| context.terms.get(candidate).is_none_or(&eligible))
+        .filter(|candidate| expand_iri(context, candidate, true).as_deref() == Some(iri))
+        .min_by(|a, b| a.len().cmp(&b.len()).then(a.cmp(b)))
+        .ok_or(CredentialEncodingError::JsonLdCompactionFailed)
+}
+
+/// Compact a node identifier, given in N-Quads term syntax, checking it expands back to
+/// itself.
+fn compact_id(context: &Context, id: &str) -> Result<String, CredentialEncodingError> {
+    if id.starts_with("_:") {
+        return Ok(id.to_string());
+    }
+
+    let iri = id
+        .strip_prefix('<')
+        .and_then(|id| id.strip_suffix('>'))
+        .filter(|iri| expand_iri(context, iri, false).as_deref() == Some(*iri))
+        .ok_or(CredentialEncodingError::JsonLdCompactionFailed)?;
+    Ok(iri.to_string())
+}
+
+fn compact_value(
+    context: &Context,
+    term: &TermDefinition,
+    object: &Term,
+) -> Result<Json, CredentialEncodingError> {
+    let literal = match object {
+        Term::Literal(literal) => literal,
+        object => {
+            let id = compact_id(context, &object.to_string())?;
+            return Ok(match term.type_mapping.as_deref() {
+                Some("@id") => Json::String(id),
+                _ => Json::Object(BTreeMap::from([("@id".to_string(), Json::String(id))])),
+            });
+        }
+    };
+
+    let value = Json::String(literal.value().to_string());
+    let mut entries = BTreeMap::from([("@value".to_string(), value.clone())]);
+    if let Some(language) = literal.language() {
+        entries.insert("@language".to_string(), Json::String(language.to_string()));
+    } else if literal.datatype() == xsd::STRING {
+        let language = match &term.language {
+            Some(language) => language,
+            None => &context.language,
+        };
+        if term.type_mapping.is_none() && language.is_none() {
+            return Ok(value);
+        }
+    } else if term.type_mapping.as_deref() == Some(literal.datatype().as_str()) {
+        return Ok(value);
+    } else {
+        let datatype = compact_vocab(context, literal.datatype().as_str(), |_| true)?;
+        entries.insert("@type".to_string(), Json::String(datatype));
+    }
+
+    Ok(Json::Object(entries))
+}
+
+fn define_term(
+    context: &mut Context,
+    local: &mut LocalContext<'_>,
+    term: &str,
+) -> Result<(), CredentialDecodingError> {
+    match local.defined.get(term) {
+        Some(true) => return Ok(()),
+        Some(false) => return invalid(),
+        None => {}
+    }
+    local.defined.insert(term.to_string(), false);
+
+    let mut definition = TermDefinition {
+        protected: local.protected,
+        ..TermDefinition::default()
+    };
+    let mut id = None;
+    match &local.entries[term] {
+        value @ (Json::Null | Json::String(_)) => id = Some(value),
+        Json::Object(entries) => {
+            for (key, value) in entries {
+                match (key.as_str(), value) {
+                    ("@id", value) => id = Some(value),
+                    ("@protected", Json::Bool(protected)) => definition.protected = *protected,
+                    ("@type", Json::String(mapping)) => {
+                        definition.type_mapping = Some(match mapping.as_str() {
+                            "@id" | "@vocab" | "@json" => mapping.clone(),
+                            mapping => expand_definition_iri(context, local, mapping)?
+                                .filter(|iri| NamedNode::new(iri.as_str()).is_ok())
+                                .ok_or(CredentialDecodingError::InvalidJsonLd)?,
+                        });
+                    }
+                    ("@container", value) => definition.container = container(value)?,
+                    ("@context", value) => definition.context = Some(value.clone()),
+                    ("@language", Json::Null) => definition.language = Some(None),
+                    ("@language", Json::String(language)) => {
+                        definition.language = Some(Some(language.to_ascii_lowercase()));
+                    }
+                    ("@prefix", Json::Bool(_)) => {}
+                    _ => return invalid(),
+                }
+            }
+        }
+        _ => return invalid(),
+    }
+
+    definition.iri = match id {
+        Some(Json::Null) => None,
+        Some(Json::String(id)) => Some(
+            expand_definition_iri(context, local, id)?
+                .ok_or(CredentialDecodingError::InvalidJsonLd)?,
+        ),
+        Some(_) => return invalid(),
+        None if term.contains(':') => expand_definition_iri(context, local, term)?,
+        None => context.vocab.as_ref().map(|vocab| format!("{vocab}{term}")),
+    };
+    if definition.iri.is_none() && id.is_none() {
+        return invalid();
+    }
+
+    if let Some(previous) = context.terms.get(term) {
+        if previous.protected && !local.override_protected {
+            if !previous.same_as(&definition) {
+                return invalid();
+            }
+            definition = previous.clone();
+        }
+    }
+
+    context.terms.insert(term.to_string(), definition);
+    local.defined.insert(term.to_string(), true);
+
+    Ok(())
+}
+
+/// Expand an IRI found in a context definition, defining the local terms it depends on first.
+fn expand_definition_iri(
+    context: &mut Context,
+    local: &mut LocalContext<'_>,
+    value: &str,
+) -> Result<Option<String>, CredentialDecodingError> {
+    if is_keyword(value) {
+        return Ok(Some(value.to_string()));
+    }
+    if local.entries.contains_key(value) && !value.starts_with('@') {
+        define_term(context, local, value)?;
+    }
+    if let Some((prefix, _)) = value.split_once(':') {
+        if local.entries.contains_key(prefix) && prefix != value {
+            define_term(context, local, prefix)?;
+        }
+    }
+
+    Ok(expand_iri(context, value, true))
+}
+
+/// Expand a term, compact IRI or IRI against the active context.
+///
+/// Terms are only considered when expanding against the vocabulary, i.e. for keys, types and
+/// `@vocab` coerced values, as for identifiers only compact IRIs apply.
+fn expand_iri(context: &Context, value: &str, vocab: bool) -> Option<String> {
+    if is_keyword(value) {
+        return Some(value.to_string());
+    }
+    if vocab {
+        if let Some(term) = context.terms.get(value) {
+            return term.iri.clone();
+        }
+    }
+    if let Some((prefix, suffix)) = value.split_once(':') {
+        if prefix == "_" || suffix.starts_with("//") {
+            return Some(value.to_string());
+        }
+        if let Some(iri) = context
+            .terms
+            .get(prefix)
+            .and_then(|term| term.iri.as_deref())
+            .filter(|iri| !is_keyword(iri))
+        {
+            return Some(format!("{iri}{suffix}"));
+        }
+        return Some(value.to_string());
+    }
+    if vocab {
+        return context
+            .vocab
+            .as_ref()
+            .map(|vocab| format!("{vocab}{value}"));
+    }
+
+    None
+}
+
+/// Expand a node object key to a keyword or an absolute IRI.
+fn expand_key(context: &Context, key: &str) -> Result<String, CredentialDecodingError> {
+    match expand_iri(context, key, true) {
+        Some(iri) if is_keyword(&iri) => Ok(iri),
+        Some(iri) if !iri.starts_with("_:") && NamedNode::new(iri.as_str()).is_ok() => Ok(iri),
+        _ if key.starts_with('@') => invalid(),
+        _ => Err(CredentialDecodingError::UndefinedJsonLdTerm(
+            key.to_string(),
+        )),
+    }
+}
+
+fn container(value: &Json) -> Result<Container, CredentialDecodingError> {
+    let mut container = Container::default();
+    for item in value.as_slice() {
+        match item {
+            Json::String(item) if item == "@set" => {}
+            Json::String(item) if item == "@graph" => container.graph = true,
+            Json::String(item) if item == "@index" => container.index = true,
+            Json::String(item) if item == "@language" => container.language = true,
+            Json::String(item) if item == "@list" => container.list = true,
+            _ => return invalid(),
+        }
+    }
+
+    Ok(container)
+}
+
+fn typed_literal(value: &str, datatype: &str) -> Result<Literal, CredentialDecodingError> {
+    let datatype = NamedNode::new(datatype).map_err(|_| CredentialDecodingError::InvalidJsonLd)?;
+    Ok(Literal::new_typed_literal(value, datatype))
+}
+
+fn language_literal(value: &str, language: &str) -> Result<Literal, CredentialDecodingError> {
+    Literal::new_language_tagged_literal(value, language.to_ascii_lowercase())
+        .map_err(|_| CredentialDecodingError::InvalidJsonLd)
+}
+
+fn json_literal(value: &Json) -> Result<Literal, CredentialDecodingError> {
+    let value = value
+        .to_canonical_string()
+        .ok_or(CredentialDecodingError::InvalidJsonLd)?;
+    Ok(Literal::new_typed_literal(value, RDF_JSON))
+}
+
+/// Convert a JSON number to a literal, integers becoming `xsd:integer` and other numbers
+/// `xsd:double` in their canonical form, unless coerced to another datatype.
+fn number_literal(
+    lexical: &str,
+    datatype: Option<&str>,
+) -> Result<Literal, CredentialDecodingError> {
+    let integer = json::is_integer(lexical) && lexical.trim_start_matches('-').len() <= 21;
+
+    match datatype {
+        None if integer => typed_literal(lexical, xsd::INTEGER.as_str()),
+        Some(datatype) if integer && datatype != xsd::DOUBLE.as_str() => {
+            typed_literal(lexical, datatype)
+        }
+        datatype => typed_literal(
+            &canonical_double(lexical)?,
+            datatype.unwrap_or(xsd::DOUBLE.as_str()),
+        ),
+    }
+}
+
+/// The canonical `xsd:double` form of a JSON number, computed on its decimal digits.
+///
+/// Numbers with more significant digits than a double holds are rejected, as they cannot be
+/// rounded without floating point arithmetic.
+fn canonical_double(lexical: &str) -> Result<String, CredentialDecodingError> {
+    let (negative, unsigned) = match lexical.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, lexical),
+    };
+    let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (
+            mantissa,
+            exponent
+                .trim_start_matches('+')
+                .parse::<i64>()
+                .map_err(|_| CredentialDecodingError::InvalidJsonLd)?,
+        ),
+        None => (unsigned, 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    let digits = format!("{integer}{fraction}");
+    let significant = digits.trim_start_matches('0');
+    let leading_zeros = i64::try_from(digits.len() - significant.len())
+        .map_err(|_| CredentialDecodingError::InvalidJsonLd)?;
+    let significant = significant.trim_end_matches('0');
+    if significant.is_empty() {
+        return Ok("0.0E0".to_string());
+    }
+    if significant.len() > 15 {
+        return Err(CredentialDecodingError::InvalidJsonLd);
+    }
+
+    let integer_len =
+        i64::try_from(integer.len()).map_err(|_| CredentialDecodingError::InvalidJsonLd)?;
+    let exponent = exponent + integer_len - leading_zeros - 1;
+    if !(-307..=308).contains(&exponent) {
+        return Err(CredentialDecodingError::InvalidJsonLd);
+    }
+
+    let (first, rest) = significant.split_at(1);
+    let rest = if rest.is_empty() { "0" } else { rest };
+    let sign = if negative { "-" } else { "" };
+
+    Ok(format!("{sign}{first}.{rest}E{exponent}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{canonical_double, json_ld_data_model, json_ld_to_quads, CredentialDecodingError};
+    use crate::domain::DataModelVersion;
+
+    const VC_V1: &str = "https://www.w3.org/2018/credentials/v1";
+    const VC_V2: &str = "https://www.w3.org/ns/credentials/v2";
+
+    fn nquads(input: &str) -> Vec<String> {
+        let mut quads = json_ld_to_quads(input)
+            .expect("document should expand")
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        quads.sort();
+        quads
+    }
+
+    #[test]
+    fn expands_credentials_v1_documents() {
+        let quads = nquads(&format!(
+            r#"{{
+                "@context": ["{VC_V1}", {{"ex": "https://example.com/vocab#"}}],
+                "id": "urn:uuid:credential-1",
+                "type": ["VerifiableCredential", "ex:AlumniCredential"],
+                "issuer": "did:example:issuer",
+                "issuanceDate": "2025-01-01T00:00:00Z",
+                "credentialSubject": {{
+                    "id": "did:example:subject",
+                    "ex:alumniOf": "Example University",
+                    "ex:graduation": 2020
+                }}
+            }}"#
+        ));
+
+        assert_eq!(
+            quads,
+            vec![
+                r#"<did:example:subject> <https://example.com/vocab#alumniOf> "Example University""#,
+                r#"<did:example:subject> <https://example.com/vocab#graduation> "2020"^^<http://www.w3.org/2001/XMLSchema#integer>"#,
+                "<urn:uuid:credential-1> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://example.com/vocab#AlumniCredential>",
+                "<urn:uuid:credential-1> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential>",
+                "<urn:uuid:credential-1> <https://www.w3.org/2018/credentials#credentialSubject> <did:example:subject>",
+                r#"<urn:uuid:credential-1> <https://www.w3.org/2018/credentials#issuanceDate> "2025-01-01T00:00:00Z"^^<http://www.w3.org/2001/XMLSchema#dateTime>"#,
+                "<urn:uuid:credential-1> <https://www.w3.org/2018/credentials#issuer> <did:example:issuer>",
+            ]
+        );
+    }
+
+    #[test]
+    fn expands_credentials_v2_documents_with_proof_graphs() {
+        let quads = nquads(&format!(
+            r#"{{
+                "@context": ["{VC_V2}", "https://w3id.org/security/data-integrity/v2"],
+                "id": "urn:uuid:credential-1",
+                "type": ["VerifiableCredential", "ExampleCredential"],
+                "issuer": {{"id": "did:example:issuer", "name": "Issuer"}},
+                "credentialSubject": {{"id": "did:example:subject", "tags": ["a", "b"]}},
+                "proof": {{
+                    "type": "DataIntegrityProof",
+                    "cryptosuite": "eddsa-rdfc-2022",
+                    "proofPurpose": "assertionMethod",
+                    "verificationMethod": "did:example:issuer#key-1"
+                }}
+            }}"#
+        ));
+
+        assert_eq!(
+            quads,
+            vec![
+                "<did:example:issuer> <https://schema.org/name> \"Issuer\"",
+                "<did:example:subject> <https://www.w3.org/ns/credentials/issuer-dependent#tags> \"a\"",
+                "<did:example:subject> <https://www.w3.org/ns/credentials/issuer-dependent#tags> \"b\"",
+                "<urn:uuid:credential-1> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential>",
+                "<urn:uuid:credential-1> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/ns/credentials/issuer-dependent#ExampleCredential>",
+                "<urn:uuid:credential-1> <https://w3id.org/security#proof> _:b0",
+                "<urn:uuid:credential-1> <https://www.w3.org/2018/credentials#credentialSubject> <did:example:subject>",
+                "<urn:uuid:credential-1> <https://www.w3.org/2018/credentials#issuer> <did:example:issuer>",
+                "_:b1 <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://w3id.org/security#DataIntegrityProof> _:b0",
+                "_:b1 <https://w3id.org/security#cryptosuite> \"eddsa-rdfc-2022\"^^<https://w3id.org/security#cryptosuiteString> _:b0",
+                "_:b1 <https://w3id.org/security#proofPurpose> <https://w3id.org/security#assertionMethod> _:b0",
+                "_:b1 <https://w3id.org/security#verificationMethod> <did:example:issuer#key-1> _:b0",
+            ]
+        );
+    }
+
+    #[test]
+    fn expands_value_objects_lists_and_language_maps() {
+        let quads = nquads(
+            r#"{
+                "@context": {
+                    "@vocab": "https://example.com/vocab#",
+                    "xsd": "http://www.w3.org/2001/XMLSchema#",
+                    "label": {"@container": "@language"},
+                    "steps": {"@container": "@list"},
+                    "config": {"@type": "@json"}
+                },
+                "@id": "https://example.com/node",
+                "at": {"@value": "2025-01-01T00:00:00Z", "@type": "xsd:dateTimeStamp"},
+                "label": {"en": "Hello", "fr": "Bonjour"},
+                "steps": ["one", true],
+                "ratio": 2.50,
+                "config": {"b": [1], "a": null}
+            }"#,
+        );
+
+        assert_eq!(
+            quads,
+            vec![
+                r#"<https://example.com/node> <https://example.com/vocab#at> "2025-01-01T00:00:00Z"^^<http://www.w3.org/2001/XMLSchema#dateTimeStamp>"#,
+                r#"<https://example.com/node> <https://example.com/vocab#config> "{\"a\":null,\"b\":[1]}"^^<http://www.w3.org/1999/02/22-rdf-syntax-ns#JSON>"#,
+                r#"<https://example.com/node> <https://example.com/vocab#label> "Bonjour"@fr"#,
+                r#"<https://example.com/node> <https://example.com/vocab#label> "Hello"@en"#,
+                r#"<https://example.com/node> <https://example.com/vocab#ratio> "2.5E0"^^<http://www.w3.org/2001/XMLSchema#double>"#,
+                "<https://example.com/node> <https://example.com/vocab#steps> _:b1",
+                r#"_:b0 <http://www.w3.org/1999/02/22-rdf-syntax-ns#first> "true"^^<http://www.w3.org/2001/XMLSchema#boolean>"#,
+                "_:b0 <http://www.w3.org/1999/02/22-rdf-syntax-ns#rest> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil>",
+                r#"_:b1 <http://www.w3.org/1999/02/22-rdf-syntax-ns#first> "one""#,
+                "_:b1 <http://www.w3.org/1999/02/22-rdf-syntax-ns#rest> _:b0",
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_contexts_and_undefined_terms() {
+        let cases = [
+            (
+                r#"{"@context": "https://example.com/context.jsonld", "@id": "urn:a"}"#.to_string(),
+                CredentialDecodingError::UnsupportedJsonLdContext(
+                    "https://example.com/context.jsonld".to_string(),
+                ),
+            ),
+            (
+                format!(r#"{{"@context": "{VC_V1}", "id": "urn:a", "name": "Alice"}}"#),
+                CredentialDecodingError::UndefinedJsonLdTerm("name".to_string()),
+            ),
+            (
+                format!(
+                    r#"{{"@context": "{VC_V1}", "id": "relative", "type": "VerifiableCredential"}}"#
+                ),
+                CredentialDecodingError::UndefinedJsonLdTerm("relative".to_string()),
+            ),
+            (
+                format!(
+                    r#"{{"@context": ["{VC_V2}", {{"name": "https://example.com/name"}}], "id": "urn:a"}}"#
+                ),
+                CredentialDecodingError::InvalidJsonLd,
+            ),
+            (
+                format!(r#"{{"@context": ["{VC_V2}", null], "id": "urn:a"}}"#),
+                CredentialDecodingError::InvalidJsonLd,
+            ),
+            (
+                r#"{"@context": {"@import": "https://example.com/context.jsonld"}}"#.to_string(),
+                CredentialDecodingError::InvalidJsonLd,
+            ),
+            (
+                r#"{"@context": {"@vocab": "https://example.com/"}, "ratio": 0.1234567890123456}"#
+                    .to_string(),
+                CredentialDecodingError::InvalidJsonLd,
+            ),
+            ("[1]".to_string(), CredentialDecodingError::InvalidJsonLd),
+            ("{".to_string(), CredentialDecodingError::InvalidJsonLd),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(json_ld_to_quads(&input), Err(expected), "{input}");
+        }
+    }
+
+    #[test]
+    fn data_model_follows_the_leading_base_context() {
+        assert_eq!(
+            json_ld_data_model(&format!(r#"{{"@context": "{VC_V1}"}}"#)),
+            Some(DataModelVersion::V1)
+        );
+        assert_eq!(
+            json_ld_data_model(&format!(
+                r#"{{"@context": ["{VC_V2}", {{"ex": "https://example.com/vocab#"}}]}}"#
+            )),
+            Some(DataModelVersion::V2)
+        );
+        assert_eq!(
+            json_ld_data_model(&format!(r#"{{"@context": [{{}}, "{VC_V2}"]}}"#)),
+            None
+        );
+        assert_eq!(json_ld_data_model(r#"{"id": "urn:a"}"#), None);
+        assert_eq!(json_ld_data_model("not json"), None);
+    }
+
+    #[test]
+    fn canonical_double_normalizes_decimal_digits() {
+        for (lexical, expected) in [
+            ("0", "0.0E0"),
+            ("-0.0", "0.0E0"),
+            ("1", "1.0E0"),
+            ("2.50", "2.5E0"),
+            ("-123.456", "-1.23456E2"),
+            ("0.00120", "1.2E-3"),
+            ("1.5e10", "1.5E10"),
+            ("12E-2", "1.2E-1"),
+        ] {
+            assert_eq!(
+                canonical_double(lexical).as_deref(),
+                Ok(expected),
+                "{lexical}"
+            );
+        }
+    }
+}
@@ -1,6 +1,21 @@
+mod credential_encoding;
 mod credential_rdf;
+mod data_integrity;
+mod json;
+mod json_ld;
+mod status_list;
 
+pub(crate) use credential_encoding::{
+    encode_json_ld_credential, encode_ntriples_credential, encode_turtle_credential,
+    CredentialEncodingError,
+};
+pub(crate) use credential_rdf::{
+    decode_canonical_nquads_credential, decode_credential_for_issuer, CredentialDecodingError,
+    DecodedClaim, DecodedClaimValue, DecodedCredential, DecodedQuad, DecodedUri, RdfSyntax,
+};
+#[cfg(test)]
 pub(crate) use credential_rdf::{
-    decode_canonical_nquads_credential, decode_nquads_credential_for_issuer,
-    CredentialDecodingError, DecodedCredential, DecodedQuad, DecodedUri,
+    decode_json_ld_credential_for_issuer, decode_nquads_credential_for_issuer,
 };
+pub(crate) use data_integrity::{decode_secured_credential, DecodedProof};
+pub(crate) use status_list::{encode_status_list, CredentialStatusEntry};
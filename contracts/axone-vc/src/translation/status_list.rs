@@ -0,0 +1,215 @@
+use super::{
+    credential_encoding::CredentialEncodingError, credential_rdf::CredentialDecodingError,
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use flate2::{write::GzEncoder, Compression};
+use oxrdf::{vocab::rdf, Dataset, GraphName, Literal, NamedNode, NamedNodeRef, Quad, Subject};
+use std::io::Write;
+
+const VC_CREDENTIAL_STATUS: NamedNodeRef<'static> =
+    NamedNodeRef::new_unchecked("https://www.w3.org/2018/credentials#credentialStatus");
+const STATUS_BITSTRING_STATUS_LIST_ENTRY: NamedNodeRef<'static> = NamedNodeRef::new_unchecked(
+    "https://www.w3.org/ns/credentials/status#BitstringStatusListEntry",
+);
+const STATUS_PURPOSE: NamedNodeRef<'static> =
+    NamedNodeRef::new_unchecked("https://www.w3.org/ns/credentials/status#statusPurpose");
+const STATUS_LIST_INDEX: NamedNodeRef<'static> =
+    NamedNodeRef::new_unchecked("https://www.w3.org/ns/credentials/status#statusListIndex");
+const STATUS_LIST_CREDENTIAL: NamedNodeRef<'static> =
+    NamedNodeRef::new_unchecked("https://www.w3.org/ns/credentials/status#statusListCredential");
+
+const MULTIBASE_BASE64URL: char = 'u';
+
+/// A `BitstringStatusListEntry` to embed as a `credentialStatus` of an issued credential.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CredentialStatusEntry {
+    id: String,
+    purpose: String,
+    index: u64,
+    status_list_credential: String,
+}
+
+impl CredentialStatusEntry {
+    pub fn new(id: String, purpose: String, index: u64, status_list_credential: String) -> Self {
+        Self {
+            id,
+            purpose,
+            index,
+            status_list_credential,
+        }
+    }
+}
+
+/// Link the status entries to the credential, with the terms the VC 2.0 context expands
+/// `credentialStatus` and `BitstringStatusListEntry` to.
+pub(super) fn insert_status_entries(
+    dataset: &mut Dataset,
+    credential_subject: &Subject,
+    entries: &[CredentialStatusEntry],
+) -> Result<(), CredentialDecodingError> {
+    if dataset
+        .quads_for_subject(credential_subject)
+        .any(|quad| quad.predicate == VC_CREDENTIAL_STATUS)
+    {
+        return Err(CredentialDecodingError::CredentialStatusProvided);
+    }
+
+    for entry in entries {
+        let id = NamedNode::new(&entry.id).map_err(|_| CredentialDecodingError::InvalidDataset)?;
+        let list = NamedNode::new(&entry.status_list_credential)
+            .map_err(|_| CredentialDecodingError::InvalidDataset)?;
+
+        for quad in [
+            Quad::new(
+                credential_subject.clone(),
+                VC_CREDENTIAL_STATUS,
+                id.clone(),
+                GraphName::DefaultGraph,
+            ),
+            Quad::new(
+                id.clone(),
+                rdf::TYPE,
+                STATUS_BITSTRING_STATUS_LIST_ENTRY,
+                GraphName::DefaultGraph,
+            ),
+            Quad::new(
+                id.clone(),
+                STATUS_PURPOSE,
+                Literal::new_simple_literal(&entry.purpose),
+                GraphName::DefaultGraph,
+            ),
+            Quad::new(
+                id.clone(),
+                STATUS_LIST_INDEX,
+                Literal::new_simple_literal(entry.index.to_string()),
+                GraphName::DefaultGraph,
+            ),
+            Quad::new(id, STATUS_LIST_CREDENTIAL, list, GraphName::DefaultGraph),
+        ] {
+            dataset.insert(quad.as_ref());
+        }
+    }
+
+    Ok(())
+}
+
+/// Encode a status bitstring of `length` bits as the `encodedList` of a Bitstring Status
+/// List: the GZIP compressed bitstring, base64url multibase encoded.
+///
+/// The bit of index 0 is the most significant bit of the first byte, indexes beyond the
+/// bitstring length are ignored.
+pub fn encode_status_list(
+    length: usize,
+    set_indexes: impl IntoIterator<Item = u64>,
+) -> Result<String, CredentialEncodingError> {
+    let mut bitstring = vec![0u8; length.div_ceil(8)];
+    for index in set_indexes {
+        let Ok(index) = usize::try_from(index) else {
+            continue;
+        };
+        if index >= length {
+            continue;
+        }
+        if let Some(byte) = bitstring.get_mut(index / 8) {
+            *byte |= 0x80 >> (index % 8);
+        }
+    }
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+    encoder
+        .write_all(&bitstring)
+        .map_err(|_| CredentialEncodingError::SerializationFailed)?;
+    let compressed = encoder
+        .finish()
+        .map_err(|_| CredentialEncodingError::SerializationFailed)?;
+
+    let mut encoded = String::from(MULTIBASE_BASE64URL);
+    encoded.push_str(&URL_SAFE_NO_PAD.encode(compressed));
+    Ok(encoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_status_list, CredentialStatusEntry, MULTIBASE_BASE64URL};
+    use crate::translation::{
+        credential_rdf::{decode_credential_for_issuer, RdfSyntax},
+        CredentialDecodingError,
+    };
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+    use flate2::read::GzDecoder;
+    use std::io::Read;
+
+    const AUTHORITY_DID: &str = "did:pkh:cosmos:axone-localnet-1:cosmos1authority";
+    const STATUS_LIST: &str =
+        "did:pkh:cosmos:axone-localnet-1:cosmos1authority/credentials/status/revocation";
+
+    fn decode(encoded: &str) -> Vec<u8> {
+        let compressed = URL_SAFE_NO_PAD
+            .decode(
+                encoded
+                    .strip_prefix(MULTIBASE_BASE64URL)
+                    .expect("multibase prefix"),
+            )
+            .expect("base64url");
+        let mut bitstring = Vec::new();
+        GzDecoder::new(compressed.as_slice())
+            .read_to_end(&mut bitstring)
+            .expect("gzip");
+        bitstring
+    }
+
+    #[test]
+    fn encode_status_list_sets_bits_from_the_most_significant() {
+        let bitstring = decode(
+            &encode_status_list(131_072, [0, 9, 131_071, 131_072]).expect("list should encode"),
+        );
+
+        assert_eq!(bitstring.len(), 16_384);
+        assert_eq!(bitstring.first(), Some(&0x80));
+        assert_eq!(bitstring.get(1), Some(&0x40));
+        assert_eq!(bitstring.last(), Some(&0x01));
+        assert_eq!(bitstring.iter().filter(|byte| **byte != 0).count(), 3);
+    }
+
+    #[test]
+    fn decode_credential_for_issuer_embeds_status_entries() {
+        let payload = br#"<urn:uuid:credential-1> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential> .
+<urn:uuid:credential-1> <https://www.w3.org/2018/credentials#credentialSubject> <did:example:subject> .
+"#;
+        let entry = CredentialStatusEntry::new(
+            format!("{STATUS_LIST}#7"),
+            "revocation".to_string(),
+            7,
+            STATUS_LIST.to_string(),
+        );
+
+        let decoded = decode_credential_for_issuer(
+            payload,
+            RdfSyntax::NQuads,
+            AUTHORITY_DID,
+            std::slice::from_ref(&entry),
+        )
+        .expect("credential should decode");
+
+        assert_eq!(
+            decoded.canonical_nquads(),
+            &format!(
+                r#"<{STATUS_LIST}#7> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/ns/credentials/status#BitstringStatusListEntry> .
+<{STATUS_LIST}#7> <https://www.w3.org/ns/credentials/status#statusListCredential> <{STATUS_LIST}> .
+<{STATUS_LIST}#7> <https://www.w3.org/ns/credentials/status#statusListIndex> "7" .
+<{STATUS_LIST}#7> <https://www.w3.org/ns/credentials/status#statusPurpose> "revocation" .
+<urn:uuid:credential-1> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential> .
+<urn:uuid:credential-1> <https://www.w3.org/2018/credentials#credentialStatus> <{STATUS_LIST}#7> .
+<urn:uuid:credential-1> <https://www.w3.org/2018/credentials#credentialSubject> <did:example:subject> .
+<urn:uuid:credential-1> <https://www.w3.org/2018/credentials#issuer> <{AUTHORITY_DID}> .
+"#
+            )
+        );
+
+        let with_status = decoded.canonical_nquads().as_bytes().to_vec();
+        assert_eq!(
+            decode_credential_for_issuer(&with_status, RdfSyntax::NQuads, AUTHORITY_DID, &[entry]),
+            Err(CredentialDecodingError::CredentialStatusProvided)
+        );
+    }
+}
@@ -3,14 +3,20 @@ use abstract_client::{AbstractClient, Application};
 use axone_vc::{
     msg::{
         AxoneVcExecuteMsgFns, AxoneVcInstantiateMsg, AxoneVcQueryMsgFns, CredentialFilter,
-        CredentialInputFormat, Quad,
+        CredentialInputFormat, CredentialOutputFormat, CredentialStatus, Cryptosuite,
+        DataModelVersion, IssuerResponse, IssuersResponse, NodeKind, PropertyShape, Quad,
+        RevocationReasonCode, RevokedCredentialResponse, ShapeResponse, ShapesResponse,
+        StatusPurpose,
     },
     AxoneVcInterface, AXONE_NAMESPACE,
 };
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
 use bech32::{Bech32, Hrp};
-use cosmwasm_std::{Binary, Timestamp};
+use cosmwasm_std::{Binary, Checksum, Timestamp};
 use cw_orch::contract::interface_traits::CallAs;
 use cw_orch::{anyhow, prelude::*};
+use flate2::read::GzDecoder;
+use std::io::Read;
 
 const COLLAB_AI_ZONE_PROFILE: &str = include_str!("fixtures/collab-ai-zone-profile.nq");
 const RESOURCE_LICENSE_ASSERTION: &str = include_str!("fixtures/resource-license-assertion.nq");
@@ -27,6 +33,10 @@ struct TestEnv<Env: CwEnv> {
 
 impl TestEnv<MockBech32> {
     fn setup() -> anyhow::Result<Self> {
+        Self::setup_with(&AxoneVcInstantiateMsg::default())
+    }
+
+    fn setup_with(msg: &AxoneVcInstantiateMsg) -> anyhow::Result<Self> {
         let chain = MockBech32::new_with_chain_id("axone", "axone-localnet-1");
         let client = AbstractClient::builder(chain.clone()).build()?;
         let publisher = client
@@ -38,7 +48,7 @@ impl TestEnv<MockBech32> {
 
         let app = publisher
             .account()
-            .install_app::<AxoneVcInterface<MockBech32>>(&AxoneVcInstantiateMsg {}, &[])?;
+            .install_app::<AxoneVcInterface<MockBech32>>(msg, &[])?;
 
         Ok(Self { app })
     }
@@ -143,6 +153,247 @@ fn issue_credential_accepts_collab_ai_zone_profile_example_without_issuer() -> a
     Ok(())
 }
 
+#[test]
+fn issue_credential_accepts_json_ld_payloads() -> anyhow::Result<()> {
+    let env = TestEnv::setup()?;
+    let authority = AxoneVcQueryMsgFns::authority(&env.app)?;
+    let credential_id = "urn:uuid:json-ld-credential";
+    let credential = format!(
+        r#"{{
+            "@context": [
+                "https://www.w3.org/ns/credentials/v2",
+                {{"ex": "https://example.com/vocab#"}}
+            ],
+            "id": "{credential_id}",
+            "type": ["VerifiableCredential", "ex:MembershipCredential"],
+            "issuer": "{}",
+            "validFrom": {{
+                "@value": "1970-01-01T00:00:10Z",
+                "@type": "http://www.w3.org/2001/XMLSchema#dateTimeStamp"
+            }},
+            "credentialSubject": {{"id": "did:example:alice", "ex:memberOf": "AXONE"}}
+        }}"#,
+        authority.did
+    );
+
+    env.app.issue_credential(
+        Binary::from(credential.into_bytes()),
+        Some(CredentialInputFormat::JsonLd),
+    )?;
+
+    let credential = env.app.credential(credential_id.to_string())?;
+    assert_eq!(credential.issuer, authority.did);
+    assert_eq!(credential.subject, "did:example:alice");
+    assert_eq!(
+        credential.types,
+        vec![
+            "https://example.com/vocab#MembershipCredential",
+            "https://www.w3.org/2018/credentials#VerifiableCredential"
+        ]
+    );
+    assert_eq!(credential.valid_from, Some(Timestamp::from_seconds(10)));
+    assert_eq!(credential.data_model, DataModelVersion::V2);
+    assert!(credential.quads.contains(&Quad {
+        subject: "<did:example:alice>".to_string(),
+        predicate: "<https://example.com/vocab#memberOf>".to_string(),
+        object: "\"AXONE\"".to_string(),
+        graph_name: None,
+    }));
+
+    let err = env
+        .app
+        .issue_credential(
+            Binary::from(br#"{"@context": "https://example.com/v1", "id": "urn:a"}"#.to_vec()),
+            Some(CredentialInputFormat::JsonLd),
+        )
+        .expect_err("unknown contexts should be rejected");
+    assert!(
+        format!("{err:?}").contains("credential json-ld context is not supported"),
+        "{err:?}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn issue_credential_accepts_turtle_and_trig_payloads() -> anyhow::Result<()> {
+    let env = TestEnv::setup()?;
+    let prefixes = r#"@base <https://credentials.axone.xyz/> .
+@prefix cred: <https://www.w3.org/2018/credentials#> .
+"#;
+
+    env.app.issue_credential(
+        Binary::from(format!(
+            "{prefixes}<turtle> a cred:VerifiableCredential ; cred:credentialSubject <did:example:alice> ."
+        ).into_bytes()),
+        Some(CredentialInputFormat::Turtle),
+    )?;
+    env.app.issue_credential(
+        Binary::from(format!(
+            "{prefixes}<trig> a cred:VerifiableCredential ; cred:credentialSubject <did:example:bob> .
+<claims> {{ <did:example:bob> <https://schema.org/name> \"Bob\" . }}"
+        ).into_bytes()),
+        Some(CredentialInputFormat::TriG),
+    )?;
+
+    let turtle = env
+        .app
+        .credential("https://credentials.axone.xyz/turtle".to_string())?;
+    assert_eq!(turtle.subject, "did:example:alice");
+
+    let trig = env
+        .app
+        .credential("https://credentials.axone.xyz/trig".to_string())?;
+    assert_eq!(trig.subject, "did:example:bob");
+    assert!(trig.quads.contains(&Quad {
+        subject: "<did:example:bob>".to_string(),
+        predicate: "<https://schema.org/name>".to_string(),
+        object: "\"Bob\"".to_string(),
+        graph_name: Some("<https://credentials.axone.xyz/claims>".to_string()),
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn credential_raw_serializes_the_requested_output_format() -> anyhow::Result<()> {
+    let env = TestEnv::setup()?;
+    let authority = AxoneVcQueryMsgFns::authority(&env.app)?;
+    let credential_id = "urn:uuid:credential-formats";
+    env.app.issue_credential(
+        Binary::from(
+            format!(
+                r#"<{credential_id}> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential> .
+<{credential_id}> <https://www.w3.org/2018/credentials#credentialSubject> <did:example:subject> .
+<did:example:subject> <https://schema.org/name> "Alice" .
+"#
+            )
+            .into_bytes(),
+        ),
+        None,
+    )?;
+
+    let canonical = AxoneVcQueryMsgFns::credential_raw(&env.app, credential_id.to_string(), None)?;
+    let ntriples = AxoneVcQueryMsgFns::credential_raw(
+        &env.app,
+        credential_id.to_string(),
+        Some(CredentialOutputFormat::NTriples),
+    )?;
+    assert_eq!(ntriples.credential, canonical.credential);
+
+    let turtle = AxoneVcQueryMsgFns::credential_raw(
+        &env.app,
+        credential_id.to_string(),
+        Some(CredentialOutputFormat::Turtle {
+            prefixes: [(
+                "cred".to_string(),
+                "https://www.w3.org/2018/credentials#".to_string(),
+            )]
+            .into(),
+        }),
+    )?;
+    let turtle = String::from_utf8(turtle.credential.to_vec())?;
+    assert!(turtle.starts_with("@prefix cred: <https://www.w3.org/2018/credentials#> .\n"));
+    assert!(turtle.contains("cred:credentialSubject <did:example:subject>"));
+
+    let json_ld = AxoneVcQueryMsgFns::credential_raw(
+        &env.app,
+        credential_id.to_string(),
+        Some(CredentialOutputFormat::JsonLd { contexts: None }),
+    )?;
+    let json_ld = String::from_utf8(json_ld.credential.to_vec())?;
+    assert!(json_ld.starts_with(r#"{"@context":"https://www.w3.org/ns/credentials/v2","#));
+    assert!(json_ld.contains(&format!(
+        r#""credentialSubject":"did:example:subject","id":"{credential_id}","issuer":"{}","type":"VerifiableCredential""#,
+        authority.did
+    )));
+
+    let err = AxoneVcQueryMsgFns::credential_raw(
+        &env.app,
+        credential_id.to_string(),
+        Some(CredentialOutputFormat::JsonLd {
+            contexts: Some(vec!["https://example.com/context".to_string()]),
+        }),
+    )
+    .expect_err("unknown contexts should be rejected");
+    assert!(
+        format!("{err:?}").contains("credential json-ld context is not supported"),
+        "{err:?}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn credentials_are_recognized_under_both_vc_data_models() -> anyhow::Result<()> {
+    let env = TestEnv::setup()?;
+    for (credential_id, validity_term) in [
+        ("urn:uuid:credential-v1", "expirationDate"),
+        ("urn:uuid:credential-v2", "validUntil"),
+    ] {
+        env.app.issue_credential(
+            Binary::from(
+                format!(
+                    r#"<{credential_id}> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential> .
+<{credential_id}> <https://www.w3.org/2018/credentials#credentialSubject> <did:example:alice> .
+<{credential_id}> <https://www.w3.org/2018/credentials#{validity_term}> "2000-01-01T00:00:00Z"^^<http://www.w3.org/2001/XMLSchema#dateTimeStamp> .
+"#
+                )
+                .into_bytes(),
+            ),
+            None,
+        )?;
+    }
+
+    let authority = AxoneVcQueryMsgFns::authority(&env.app)?;
+    let credential = env.app.credential("urn:uuid:credential-v2".to_string())?;
+    assert_eq!(credential.data_model, DataModelVersion::V2);
+    assert_eq!(credential.issuer, authority.did);
+    assert_eq!(credential.subject, "did:example:alice");
+    assert_eq!(
+        credential.valid_until,
+        Some(Timestamp::from_seconds(946_684_800))
+    );
+    assert_eq!(
+        env.app
+            .credential("urn:uuid:credential-v1".to_string())?
+            .data_model,
+        DataModelVersion::V1
+    );
+
+    let response = env.app.credentials(
+        CredentialFilter {
+            subject: Some("did:example:alice".to_string()),
+            ..Default::default()
+        },
+        None,
+        None,
+    )?;
+    assert_eq!(
+        response.identifiers,
+        vec!["urn:uuid:credential-v1", "urn:uuid:credential-v2"]
+    );
+
+    let response = env.app.credentials(
+        CredentialFilter {
+            data_model: Some(DataModelVersion::V2),
+            ..Default::default()
+        },
+        None,
+        None,
+    )?;
+    assert_eq!(response.identifiers, vec!["urn:uuid:credential-v2"]);
+
+    let verification = env.app.verify_credential(
+        "urn:uuid:credential-v2".to_string(),
+        Some(Timestamp::from_seconds(946_684_800)),
+    )?;
+    assert!(verification.exists);
+    assert!(!verification.valid);
+
+    Ok(())
+}
+
 #[test]
 fn issue_credential_rejects_duplicates() -> anyhow::Result<()> {
     let env = TestEnv::setup()?;
@@ -193,6 +444,7 @@ fn verify_credential_reports_activity_and_validity_interval() -> anyhow::Result<
         axone_vc::msg::VerifyCredentialResponse {
             exists: false,
             valid: false,
+            status: None,
         }
     );
 
@@ -229,12 +481,14 @@ fn verify_credential_reports_activity_and_validity_interval() -> anyhow::Result<
         assert_eq!(response.valid, expected_valid);
     }
 
-    env.app.revoke_credential(credential_id.to_string())?;
+    env.app
+        .revoke_credential(credential_id.to_string(), None, None)?;
     assert_eq!(
         AxoneVcQueryMsgFns::verify_credential(&env.app, credential_id.to_string(), None)?,
         axone_vc::msg::VerifyCredentialResponse {
             exists: false,
             valid: false,
+            status: None,
         }
     );
 
@@ -255,11 +509,13 @@ fn credential_raw_returns_the_expected_canonical_representation() -> anyhow::Res
         authority.did
     );
     let expected = format!(
-        r#"<{credential_id}> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential> .
-<{credential_id}> <https://www.w3.org/2018/credentials#credentialSubject> <did:example:subject> .
+        r#"{}<{credential_id}> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential> .
+{}<{credential_id}> <https://www.w3.org/2018/credentials#credentialSubject> <did:example:subject> .
 <{credential_id}> <https://www.w3.org/2018/credentials#issuanceDate> "2025-01-01T00:00:00Z"^^<http://www.w3.org/2001/XMLSchema#dateTime> .
 <{credential_id}> <https://www.w3.org/2018/credentials#issuer> <{}> .
 "#,
+        to_nquads(&status_entry_quads(&authority.did, 0)),
+        to_nquads(&credential_status_quads(credential_id, &authority.did, 0)),
         authority.did
     );
 
@@ -269,7 +525,7 @@ fn credential_raw_returns_the_expected_canonical_representation() -> anyhow::Res
         Some(CredentialInputFormat::NQuads),
     )?;
 
-    let response = AxoneVcQueryMsgFns::credential_raw(&env.app, credential_id.to_string())?;
+    let response = AxoneVcQueryMsgFns::credential_raw(&env.app, credential_id.to_string(), None)?;
     assert_eq!(response.credential, Binary::from(expected.into_bytes()));
 
     Ok(())
@@ -287,11 +543,13 @@ fn credential_raw_includes_inferred_issuer() -> anyhow::Result<()> {
 "#
     );
     let expected = format!(
-        r#"<{credential_id}> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential> .
-<{credential_id}> <https://www.w3.org/2018/credentials#credentialSubject> <did:example:subject> .
+        r#"{}<{credential_id}> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential> .
+{}<{credential_id}> <https://www.w3.org/2018/credentials#credentialSubject> <did:example:subject> .
 <{credential_id}> <https://www.w3.org/2018/credentials#issuanceDate> "2025-01-01T00:00:00Z"^^<http://www.w3.org/2001/XMLSchema#dateTime> .
 <{credential_id}> <https://www.w3.org/2018/credentials#issuer> <{}> .
 "#,
+        to_nquads(&status_entry_quads(&authority.did, 0)),
+        to_nquads(&credential_status_quads(credential_id, &authority.did, 0)),
         authority.did
     );
 
@@ -300,7 +558,7 @@ fn credential_raw_includes_inferred_issuer() -> anyhow::Result<()> {
         Some(CredentialInputFormat::NQuads),
     )?;
 
-    let response = AxoneVcQueryMsgFns::credential_raw(&env.app, credential_id.to_string())?;
+    let response = AxoneVcQueryMsgFns::credential_raw(&env.app, credential_id.to_string(), None)?;
     assert_eq!(response.credential, Binary::from(expected.into_bytes()));
 
     Ok(())
@@ -317,13 +575,15 @@ fn credential_query_returns_metadata_and_canonical_rdf() -> anyhow::Result<()> {
         "1970-01-01T00:00:10Z",
         "1970-01-01T00:00:20Z",
     );
-    let expected_quads = vec![
-        Quad {
-            subject: format!("<{credential_id}>"),
-            predicate: "<http://www.w3.org/1999/02/22-rdf-syntax-ns#type>".to_string(),
-            object: "<https://www.w3.org/2018/credentials#VerifiableCredential>".to_string(),
-            graph_name: None,
-        },
+    let mut expected_quads = status_entry_quads(&authority.did, 0);
+    expected_quads.push(Quad {
+        subject: format!("<{credential_id}>"),
+        predicate: "<http://www.w3.org/1999/02/22-rdf-syntax-ns#type>".to_string(),
+        object: "<https://www.w3.org/2018/credentials#VerifiableCredential>".to_string(),
+        graph_name: None,
+    });
+    expected_quads.extend(credential_status_quads(credential_id, &authority.did, 0));
+    expected_quads.extend([
         Quad {
             subject: format!("<{credential_id}>"),
             predicate: "<https://www.w3.org/2018/credentials#credentialSubject>".to_string(),
@@ -350,7 +610,7 @@ fn credential_query_returns_metadata_and_canonical_rdf() -> anyhow::Result<()> {
                 .to_string(),
             graph_name: None,
         },
-    ];
+    ]);
 
     env.app
         .issue_credential(Binary::from(input), Some(CredentialInputFormat::NQuads))?;
@@ -393,7 +653,8 @@ fn credential_query_rejects_unknown_and_revoked_credentials() -> anyhow::Result<
         )),
         Some(CredentialInputFormat::NQuads),
     )?;
-    env.app.revoke_credential(credential_id.to_string())?;
+    env.app
+        .revoke_credential(credential_id.to_string(), None, None)?;
 
     let err = AxoneVcQueryMsgFns::credential(&env.app, credential_id.to_string())
         .expect_err("revoked credential should be rejected");
@@ -532,6 +793,7 @@ fn credentials_query_filters_and_paginates_identifiers() -> anyhow::Result<()> {
             subject: Some("did:example:alice".to_string()),
             credential_type: Some("https://example.com/types/Member".to_string()),
             valid_at: Some(Timestamp::from_seconds(25)),
+            ..Default::default()
         },
         None,
         None,
@@ -539,7 +801,7 @@ fn credentials_query_filters_and_paginates_identifiers() -> anyhow::Result<()> {
     assert_eq!(response.identifiers, vec!["urn:uuid:credential-c"]);
 
     env.app
-        .revoke_credential("urn:uuid:credential-c".to_string())?;
+        .revoke_credential("urn:uuid:credential-c".to_string(), None, None)?;
     let response = AxoneVcQueryMsgFns::credentials(
         &env.app,
         CredentialFilter {
@@ -560,7 +822,7 @@ fn credential_raw_rejects_unknown_and_revoked_credentials() -> anyhow::Result<()
     let env = TestEnv::setup()?;
     let credential_id = "urn:uuid:credential-raw";
 
-    let err = AxoneVcQueryMsgFns::credential_raw(&env.app, credential_id.to_string())
+    let err = AxoneVcQueryMsgFns::credential_raw(&env.app, credential_id.to_string(), None)
         .expect_err("unknown credential should be rejected");
     assert!(
         format!("{err:?}").contains("credential not found"),
@@ -577,9 +839,10 @@ fn credential_raw_rejects_unknown_and_revoked_credentials() -> anyhow::Result<()
         )),
         Some(CredentialInputFormat::NQuads),
     )?;
-    env.app.revoke_credential(credential_id.to_string())?;
+    env.app
+        .revoke_credential(credential_id.to_string(), None, None)?;
 
-    let err = AxoneVcQueryMsgFns::credential_raw(&env.app, credential_id.to_string())
+    let err = AxoneVcQueryMsgFns::credential_raw(&env.app, credential_id.to_string(), None)
         .expect_err("revoked credential should be rejected");
     assert!(
         format!("{err:?}").contains("credential not found"),
@@ -598,7 +861,7 @@ fn revoke_credential_accepts_issued_credential() -> anyhow::Result<()> {
     env.app
         .issue_credential(credential, Some(CredentialInputFormat::NQuads))?;
     env.app
-        .revoke_credential(RESOURCE_LICENSE_ASSERTION_ID.to_string())?;
+        .revoke_credential(RESOURCE_LICENSE_ASSERTION_ID.to_string(), None, None)?;
 
     Ok(())
 }
@@ -611,9 +874,9 @@ fn revoke_credential_emits_event() -> anyhow::Result<()> {
 
     env.app
         .issue_credential(credential, Some(CredentialInputFormat::NQuads))?;
-    let response = env
-        .app
-        .revoke_credential(RESOURCE_LICENSE_ASSERTION_ID.to_string())?;
+    let response =
+        env.app
+            .revoke_credential(RESOURCE_LICENSE_ASSERTION_ID.to_string(), None, None)?;
 
     assert_eq!(
         response
@@ -640,13 +903,452 @@ fn revoke_credential_emits_event() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn revoked_credential_queries_return_the_revocation_trail() -> anyhow::Result<()> {
+    let env = TestEnv::setup()?;
+    let authority = AxoneVcQueryMsgFns::authority(&env.app)?;
+    let sender = env.app.environment().sender_addr();
+    for credential_id in ["urn:uuid:credential-b", "urn:uuid:credential-a"] {
+        env.app.issue_credential(
+            Binary::from(credential_payload(
+                &authority.did,
+                credential_id,
+                "did:example:subject",
+                &[],
+                None,
+            )),
+            Some(CredentialInputFormat::NQuads),
+        )?;
+    }
+    let canonical =
+        AxoneVcQueryMsgFns::credential_raw(&env.app, "urn:uuid:credential-b".to_string(), None)?
+            .credential;
+
+    let response = env.app.revoke_credential(
+        "urn:uuid:credential-b".to_string(),
+        Some("superseded by credential-c".to_string()),
+        Some(RevocationReasonCode::Superseded),
+    )?;
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "reason_code")
+            .expect("Missing reason_code attribute"),
+        "superseded"
+    );
+    env.app
+        .revoke_credential("urn:uuid:credential-a".to_string(), None, None)?;
+
+    let block = env.app.environment().block_info()?;
+    let revocation =
+        AxoneVcQueryMsgFns::revoked_credential(&env.app, "urn:uuid:credential-b".to_string())?;
+    assert_eq!(
+        revocation,
+        RevokedCredentialResponse {
+            identifier: "urn:uuid:credential-b".to_string(),
+            block_height: block.height,
+            revoked_at: block.time,
+            revoker: Some(sender),
+            reason_code: Some(RevocationReasonCode::Superseded),
+            reason: Some("superseded by credential-c".to_string()),
+            canonical_hash: Some(Checksum::generate(canonical.as_slice())),
+        }
+    );
+
+    let page = AxoneVcQueryMsgFns::revoked_credentials(&env.app, Some(1), None)?;
+    assert_eq!(
+        page.revocations
+            .iter()
+            .map(|revocation| revocation.identifier.as_str())
+            .collect::<Vec<_>>(),
+        vec!["urn:uuid:credential-a"]
+    );
+    assert_eq!(page.revocations[0].reason_code, None);
+    let page = AxoneVcQueryMsgFns::revoked_credentials(
+        &env.app,
+        None,
+        Some("urn:uuid:credential-a".to_string()),
+    )?;
+    assert_eq!(page.revocations, vec![revocation]);
+
+    let err = AxoneVcQueryMsgFns::revoked_credential(&env.app, "urn:uuid:unknown".to_string())
+        .expect_err("unrevoked credential should be rejected");
+    assert!(
+        format!("{err:?}").contains("revoked credential not found"),
+        "{err:?}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn status_list_query_reflects_revoked_credentials() -> anyhow::Result<()> {
+    let env = TestEnv::setup()?;
+    let authority = AxoneVcQueryMsgFns::authority(&env.app)?;
+    for credential_id in ["urn:uuid:credential-a", "urn:uuid:credential-b"] {
+        env.app.issue_credential(
+            Binary::from(credential_payload(
+                &authority.did,
+                credential_id,
+                "did:example:subject",
+                &[],
+                None,
+            )),
+            Some(CredentialInputFormat::NQuads),
+        )?;
+    }
+    env.app
+        .revoke_credential("urn:uuid:credential-b".to_string(), None, None)?;
+
+    let revocation = AxoneVcQueryMsgFns::status_list(&env.app, StatusPurpose::Revocation)?;
+    let list = format!("{}/credentials/status/revocation", authority.did);
+    assert_eq!(
+        revocation.context,
+        vec!["https://www.w3.org/ns/credentials/v2"]
+    );
+    assert_eq!(revocation.id, list);
+    assert_eq!(
+        revocation.types,
+        vec!["VerifiableCredential", "BitstringStatusListCredential"]
+    );
+    assert_eq!(revocation.issuer, authority.did);
+    assert_eq!(revocation.credential_subject.id, format!("{list}#list"));
+    assert_eq!(
+        revocation.credential_subject.subject_type,
+        "BitstringStatusList"
+    );
+    assert_eq!(
+        revocation.credential_subject.status_purpose,
+        StatusPurpose::Revocation
+    );
+
+    let bitstring = decode_status_list(&revocation.credential_subject.encoded_list)?;
+    assert_eq!(bitstring.len(), 16_384);
+    assert_eq!(bitstring.first(), Some(&0x40));
+    assert!(bitstring.iter().skip(1).all(|byte| *byte == 0));
+
+    let suspension = AxoneVcQueryMsgFns::status_list(&env.app, StatusPurpose::Suspension)?;
+    assert_eq!(
+        suspension.id,
+        format!("{}/credentials/status/suspension", authority.did)
+    );
+    let bitstring = decode_status_list(&suspension.credential_subject.encoded_list)?;
+    assert!(bitstring.iter().all(|byte| *byte == 0));
+
+    Ok(())
+}
+
+#[test]
+fn status_lists_are_published_at_the_configured_url() -> anyhow::Result<()> {
+    let env = TestEnv::setup_with(&AxoneVcInstantiateMsg {
+        status_list_url: Some("https://status.example.org/lists".to_string()),
+    })?;
+    let authority = AxoneVcQueryMsgFns::authority(&env.app)?;
+    env.app.issue_credential(
+        Binary::from(credential_payload(
+            &authority.did,
+            "urn:uuid:credential-a",
+            "did:example:subject",
+            &[],
+            None,
+        )),
+        Some(CredentialInputFormat::NQuads),
+    )?;
+
+    let revocation = AxoneVcQueryMsgFns::status_list(&env.app, StatusPurpose::Revocation)?;
+    assert_eq!(revocation.id, "https://status.example.org/lists/revocation");
+    assert_eq!(revocation.issuer, authority.did);
+    let credential = AxoneVcQueryMsgFns::credential(&env.app, "urn:uuid:credential-a".to_string())?;
+    assert!(credential.quads.iter().any(|quad| {
+        quad.predicate == "<https://www.w3.org/ns/credentials/status#statusListCredential>"
+            && quad.object == "<https://status.example.org/lists/revocation>"
+    }));
+
+    assert!(TestEnv::setup_with(&AxoneVcInstantiateMsg {
+        status_list_url: Some("did:example:lists".to_string()),
+    })
+    .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn suspend_and_reinstate_credential_update_status() -> anyhow::Result<()> {
+    let env = TestEnv::setup()?;
+    let authority = AxoneVcQueryMsgFns::authority(&env.app)?;
+    let credential_id = "urn:uuid:credential-suspended";
+    env.app.issue_credential(
+        Binary::from(credential_payload(
+            &authority.did,
+            credential_id,
+            "did:example:subject",
+            &[],
+            None,
+        )),
+        Some(CredentialInputFormat::NQuads),
+    )?;
+
+    let response = env.app.suspend_credential(credential_id.to_string())?;
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "action")
+            .expect("Missing action attribute"),
+        "suspend_credential"
+    );
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "identifier")
+            .expect("Missing identifier attribute"),
+        credential_id
+    );
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "status")
+            .expect("Missing status attribute"),
+        "suspended"
+    );
+    assert_eq!(
+        AxoneVcQueryMsgFns::verify_credential(&env.app, credential_id.to_string(), None)?,
+        axone_vc::msg::VerifyCredentialResponse {
+            exists: true,
+            valid: false,
+            status: Some(CredentialStatus::Suspended),
+        }
+    );
+    assert_eq!(
+        AxoneVcQueryMsgFns::credential(&env.app, credential_id.to_string())?.status,
+        CredentialStatus::Suspended
+    );
+    for (status, expected) in [
+        (Some(CredentialStatus::Active), Vec::<String>::new()),
+        (
+            Some(CredentialStatus::Suspended),
+            vec![credential_id.to_string()],
+        ),
+    ] {
+        let response = AxoneVcQueryMsgFns::credentials(
+            &env.app,
+            CredentialFilter {
+                status,
+                ..Default::default()
+            },
+            None,
+            None,
+        )?;
+        assert_eq!(response.identifiers, expected);
+    }
+    let suspension = AxoneVcQueryMsgFns::status_list(&env.app, StatusPurpose::Suspension)?;
+    assert_eq!(
+        decode_status_list(&suspension.credential_subject.encoded_list)?.first(),
+        Some(&0x80)
+    );
+
+    let err = env
+        .app
+        .suspend_credential(credential_id.to_string())
+        .expect_err("suspended credential should not be suspended again");
+    assert!(
+        format!("{err:?}").contains("credential already suspended"),
+        "{err:?}"
+    );
+
+    let response = env.app.reinstate_credential(credential_id.to_string())?;
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "status")
+            .expect("Missing status attribute"),
+        "active"
+    );
+    assert_eq!(
+        AxoneVcQueryMsgFns::verify_credential(&env.app, credential_id.to_string(), None)?,
+        axone_vc::msg::VerifyCredentialResponse {
+            exists: true,
+            valid: true,
+            status: Some(CredentialStatus::Active),
+        }
+    );
+    let suspension = AxoneVcQueryMsgFns::status_list(&env.app, StatusPurpose::Suspension)?;
+    assert!(
+        decode_status_list(&suspension.credential_subject.encoded_list)?
+            .iter()
+            .all(|byte| *byte == 0)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn suspend_credential_rejects_non_host_account_sender() -> anyhow::Result<()> {
+    let env = TestEnv::setup()?;
+    let authority = AxoneVcQueryMsgFns::authority(&env.app)?;
+    let credential = Binary::from(resource_license_assertion_payload(&authority.did));
+    let unauthorized = env.app.environment().addr_make("unauthorized");
+
+    env.app
+        .issue_credential(credential, Some(CredentialInputFormat::NQuads))?;
+    let err = env
+        .app
+        .call_as(&unauthorized)
+        .suspend_credential(RESOURCE_LICENSE_ASSERTION_ID.to_string())
+        .expect_err("non-host sender should be rejected");
+    assert!(format!("{err:?}").contains("Caller is not admin"));
+
+    let err = env
+        .app
+        .call_as(&unauthorized)
+        .reinstate_credential(RESOURCE_LICENSE_ASSERTION_ID.to_string())
+        .expect_err("non-host sender should be rejected");
+    assert!(format!("{err:?}").contains("Caller is not admin"));
+
+    Ok(())
+}
+
+#[test]
+fn delegated_issuers_issue_and_revoke_granted_credential_types() -> anyhow::Result<()> {
+    let env = TestEnv::setup()?;
+    let authority = AxoneVcQueryMsgFns::authority(&env.app)?;
+    let delegate = env.app.environment().addr_make("delegate");
+    let member = "https://example.com/types/Member";
+    let employee = "https://example.com/types/Employee";
+
+    let response = env
+        .app
+        .grant_issuer(delegate.to_string(), vec![member.to_string()], None)?;
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "delegate")
+            .expect("Missing delegate attribute"),
+        delegate.to_string()
+    );
+    assert_eq!(
+        AxoneVcQueryMsgFns::issuers(&env.app)?,
+        IssuersResponse {
+            issuers: vec![IssuerResponse {
+                address: delegate.clone(),
+                credential_types: vec![member.to_string()],
+                expires: None,
+            }],
+        }
+    );
+
+    let response = env.app.call_as(&delegate).issue_credential(
+        Binary::from(credential_payload(
+            &authority.did,
+            "urn:uuid:credential-member",
+            "did:example:alice",
+            &[member],
+            None,
+        )),
+        Some(CredentialInputFormat::NQuads),
+    )?;
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "delegate")
+            .expect("Missing delegate attribute"),
+        delegate.to_string()
+    );
+    let credential =
+        AxoneVcQueryMsgFns::credential(&env.app, "urn:uuid:credential-member".to_string())?;
+    assert_eq!(credential.issuer, authority.did);
+    assert_eq!(credential.delegate, Some(delegate.clone()));
+
+    let err = env
+        .app
+        .call_as(&delegate)
+        .issue_credential(
+            Binary::from(credential_payload(
+                &authority.did,
+                "urn:uuid:credential-employee",
+                "did:example:alice",
+                &[employee],
+                None,
+            )),
+            Some(CredentialInputFormat::NQuads),
+        )
+        .expect_err("type not granted should be rejected");
+    assert!(
+        format!("{err:?}").contains("credential type not granted to the issuer"),
+        "{err:?}"
+    );
+    let err = env
+        .app
+        .call_as(&delegate)
+        .grant_issuer(delegate.to_string(), vec![employee.to_string()], None)
+        .expect_err("delegates should not grant issuers");
+    assert!(format!("{err:?}").contains("Caller is not admin"));
+
+    env.app.call_as(&delegate).revoke_credential(
+        "urn:uuid:credential-member".to_string(),
+        None,
+        None,
+    )?;
+
+    env.app.revoke_issuer(delegate.to_string())?;
+    assert_eq!(
+        AxoneVcQueryMsgFns::issuers(&env.app)?,
+        IssuersResponse { issuers: vec![] }
+    );
+    let err = env
+        .app
+        .call_as(&delegate)
+        .issue_credential(
+            Binary::from(credential_payload(
+                &authority.did,
+                "urn:uuid:credential-member-2",
+                "did:example:alice",
+                &[member],
+                None,
+            )),
+            Some(CredentialInputFormat::NQuads),
+        )
+        .expect_err("withdrawn delegate should be rejected");
+    assert!(format!("{err:?}").contains("Caller is not admin"));
+
+    Ok(())
+}
+
+#[test]
+fn delegated_issuers_are_rejected_once_expired() -> anyhow::Result<()> {
+    let env = TestEnv::setup()?;
+    let authority = AxoneVcQueryMsgFns::authority(&env.app)?;
+    let delegate = env.app.environment().addr_make("delegate");
+    let expires = env.app.environment().block_info()?.time.plus_seconds(10);
+
+    env.app.grant_issuer(
+        delegate.to_string(),
+        vec!["https://example.com/types/Member".to_string()],
+        Some(expires),
+    )?;
+    env.app.environment().wait_seconds(10)?;
+
+    let err = env
+        .app
+        .call_as(&delegate)
+        .issue_credential(
+            Binary::from(credential_payload(
+                &authority.did,
+                "urn:uuid:credential-member",
+                "did:example:alice",
+                &["https://example.com/types/Member"],
+                None,
+            )),
+            Some(CredentialInputFormat::NQuads),
+        )
+        .expect_err("expired delegate should be rejected");
+    assert!(
+        format!("{err:?}").contains("issuer delegation expired"),
+        "{err:?}"
+    );
+
+    Ok(())
+}
+
 #[test]
 fn revoke_credential_rejects_unknown_credential() -> anyhow::Result<()> {
     let env = TestEnv::setup()?;
 
     let err = env
         .app
-        .revoke_credential(RESOURCE_LICENSE_ASSERTION_ID.to_string())
+        .revoke_credential(RESOURCE_LICENSE_ASSERTION_ID.to_string(), None, None)
         .expect_err("unknown credential should be rejected");
 
     assert!(format!("{err:?}").contains("credential unknown"), "{err:?}");
@@ -654,6 +1356,102 @@ fn revoke_credential_rejects_unknown_credential() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn anchor_credential_verifies_and_records_data_integrity_proofs() -> anyhow::Result<()> {
+    let env = TestEnv::setup()?;
+    let signing_key = ed25519_zebra::SigningKey::from([5u8; 32]);
+    let mut public_key = vec![0xed, 0x01];
+    public_key.extend_from_slice(ed25519_zebra::VerificationKey::from(&signing_key).as_ref());
+    let key = format!("z{}", bs58::encode(public_key).into_string());
+    let issuer = format!("did:key:{key}");
+    let credential_id = "urn:uuid:anchored-credential";
+
+    let document = [
+        format!("<{credential_id}> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://www.w3.org/2018/credentials#VerifiableCredential> .\n"),
+        format!("<{credential_id}> <https://www.w3.org/2018/credentials#credentialSubject> <did:example:subject> .\n"),
+        format!("<{credential_id}> <https://www.w3.org/2018/credentials#issuer> <{issuer}> .\n"),
+        format!("<{credential_id}> <https://www.w3.org/2018/credentials#validFrom> \"1970-01-01T00:00:10Z\"^^<http://www.w3.org/2001/XMLSchema#dateTimeStamp> .\n"),
+    ];
+    let proof_config = [
+        "_:c14n0 <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <https://w3id.org/security#DataIntegrityProof> .\n".to_string(),
+        "_:c14n0 <https://w3id.org/security#cryptosuite> \"eddsa-rdfc-2022\"^^<https://w3id.org/security#cryptosuiteString> .\n".to_string(),
+        "_:c14n0 <https://w3id.org/security#proofPurpose> <https://w3id.org/security#assertionMethod> .\n".to_string(),
+        format!("_:c14n0 <https://w3id.org/security#verificationMethod> <{issuer}#{key}> .\n"),
+    ];
+    let mut hash_data = Checksum::generate(proof_config.concat().as_bytes())
+        .as_slice()
+        .to_vec();
+    hash_data.extend_from_slice(Checksum::generate(document.concat().as_bytes()).as_slice());
+    let proof_value = format!(
+        "z{}",
+        bs58::encode(signing_key.sign(&hash_data).to_bytes()).into_string()
+    );
+
+    let mut credential = document.concat();
+    credential.push_str(&format!(
+        "<{credential_id}> <https://w3id.org/security#proof> _:proof .\n"
+    ));
+    for quad in proof_config {
+        credential.push_str(
+            &quad
+                .replace("_:c14n0", "_:node")
+                .replace(" .\n", " _:proof .\n"),
+        );
+    }
+    let secured = format!(
+        "{credential}_:node <https://w3id.org/security#proofValue> \"{proof_value}\"^^<https://w3id.org/security#multibase> _:proof .\n"
+    );
+
+    let err = env
+        .app
+        .anchor_credential(
+            Binary::from(
+                secured
+                    .replace("did:example:subject", "did:example:other")
+                    .into_bytes(),
+            ),
+            None,
+        )
+        .expect_err("tampered credential should be rejected");
+    assert!(
+        format!("{err:?}").contains("proof signature is invalid"),
+        "{err:?}"
+    );
+
+    let response = env
+        .app
+        .anchor_credential(Binary::from(secured.into_bytes()), None)?;
+    assert_eq!(
+        response.event_attr_value(ABSTRACT_EVENT_TYPE, "action")?,
+        "anchor_credential"
+    );
+    assert_eq!(
+        response.event_attr_value(ABSTRACT_EVENT_TYPE, "issuer")?,
+        issuer
+    );
+    assert_eq!(
+        response.event_attr_value(ABSTRACT_EVENT_TYPE, "cryptosuite")?,
+        "eddsa-rdfc-2022"
+    );
+
+    let credential = env.app.credential(credential_id.to_string())?;
+    assert_eq!(credential.issuer, issuer);
+    assert_eq!(credential.data_model, DataModelVersion::V2);
+    let proof = credential
+        .proof
+        .expect("anchored credential should keep its proof");
+    assert_eq!(proof.cryptosuite, Cryptosuite::EddsaRdfc2022);
+    assert_eq!(proof.verification_method, format!("{issuer}#{key}"));
+    assert_eq!(proof.proof_value, proof_value);
+    assert!(
+        env.app
+            .verify_credential(credential_id.to_string(), None)?
+            .exists
+    );
+
+    Ok(())
+}
+
 #[test]
 fn revoke_credential_rejects_duplicates() -> anyhow::Result<()> {
     let env = TestEnv::setup()?;
@@ -663,10 +1461,10 @@ fn revoke_credential_rejects_duplicates() -> anyhow::Result<()> {
     env.app
         .issue_credential(credential, Some(CredentialInputFormat::NQuads))?;
     env.app
-        .revoke_credential(RESOURCE_LICENSE_ASSERTION_ID.to_string())?;
+        .revoke_credential(RESOURCE_LICENSE_ASSERTION_ID.to_string(), None, None)?;
     let err = env
         .app
-        .revoke_credential(RESOURCE_LICENSE_ASSERTION_ID.to_string())
+        .revoke_credential(RESOURCE_LICENSE_ASSERTION_ID.to_string(), None, None)
         .expect_err("duplicate revocation should fail");
 
     assert!(
@@ -686,7 +1484,7 @@ fn revoke_credential_prevents_reissuing_same_identifier() -> anyhow::Result<()>
     env.app
         .issue_credential(credential.clone(), Some(CredentialInputFormat::NQuads))?;
     env.app
-        .revoke_credential(RESOURCE_LICENSE_ASSERTION_ID.to_string())?;
+        .revoke_credential(RESOURCE_LICENSE_ASSERTION_ID.to_string(), None, None)?;
     let err = env
         .app
         .issue_credential(credential, Some(CredentialInputFormat::NQuads))
@@ -709,7 +1507,7 @@ fn revoke_credential_rejects_non_host_account_sender() -> anyhow::Result<()> {
     let err = env
         .app
         .call_as(&unauthorized)
-        .revoke_credential(RESOURCE_LICENSE_ASSERTION_ID.to_string())
+        .revoke_credential(RESOURCE_LICENSE_ASSERTION_ID.to_string(), None, None)
         .expect_err("non-host sender should be rejected");
 
     assert!(format!("{err:?}").contains("Caller is not admin"));
@@ -732,6 +1530,126 @@ fn collab_ai_zone_profile_payload_without_issuer() -> Vec<u8> {
         .into_bytes()
 }
 
+#[test]
+fn registered_shapes_reject_non_conforming_credential_subjects() -> anyhow::Result<()> {
+    let env = TestEnv::setup()?;
+    let authority = AxoneVcQueryMsgFns::authority(&env.app)?;
+    let member = "https://example.com/types/Member";
+    let name = "https://schema.org/name";
+    let level = "https://example.com/vocab#level";
+    let properties = vec![
+        PropertyShape {
+            path: name.to_string(),
+            min_count: Some(1),
+            max_count: Some(1),
+            datatype: Some("http://www.w3.org/2001/XMLSchema#string".to_string()),
+            node_kind: None,
+            allowed_values: None,
+        },
+        PropertyShape {
+            path: level.to_string(),
+            min_count: None,
+            max_count: None,
+            datatype: None,
+            node_kind: Some(NodeKind::Literal),
+            allowed_values: Some(vec!["gold".to_string(), "silver".to_string()]),
+        },
+    ];
+
+    let response = env
+        .app
+        .register_shape(member.to_string(), properties.clone())?;
+    assert_eq!(
+        response
+            .event_attr_value(ABSTRACT_EVENT_TYPE, "credential_type")
+            .expect("Missing credential_type attribute"),
+        member
+    );
+    assert_eq!(
+        AxoneVcQueryMsgFns::shapes(&env.app)?,
+        ShapesResponse {
+            shapes: vec![ShapeResponse {
+                credential_type: member.to_string(),
+                properties,
+            }],
+        }
+    );
+
+    let mut payload = credential_payload(
+        &authority.did,
+        "urn:uuid:credential-member",
+        "did:example:alice",
+        &[member],
+        None,
+    );
+    payload.extend_from_slice(
+        format!("<did:example:alice> <{level}> <https://example.com/levels/gold> .\n").as_bytes(),
+    );
+    let err = env
+        .app
+        .issue_credential(Binary::from(payload), Some(CredentialInputFormat::NQuads))
+        .expect_err("non-conforming credential should be rejected");
+    let report = format!("{err:?}");
+    assert!(
+        report.contains("credential does not conform to its shapes"),
+        "{report}"
+    );
+    assert!(
+        report.contains(&format!("{member} {name}: min_count 1")),
+        "{report}"
+    );
+    assert!(
+        report.contains(&format!("{member} {level}: node_kind literal")),
+        "{report}"
+    );
+    assert!(
+        report.contains(&format!("{member} {level}: allowed_values")),
+        "{report}"
+    );
+
+    let mut payload = credential_payload(
+        &authority.did,
+        "urn:uuid:credential-member",
+        "did:example:alice",
+        &[member],
+        None,
+    );
+    payload.extend_from_slice(
+        format!(
+            "<did:example:alice> <{name}> \"Alice\" .\n<did:example:alice> <{level}> \"gold\" .\n"
+        )
+        .as_bytes(),
+    );
+    env.app
+        .issue_credential(Binary::from(payload), Some(CredentialInputFormat::NQuads))?;
+
+    let err = env
+        .app
+        .call_as(&env.app.environment().addr_make("outsider"))
+        .register_shape(member.to_string(), vec![])
+        .expect_err("non-admin should not register shapes");
+    assert!(format!("{err:?}").contains("Caller is not admin"));
+
+    env.app.unregister_shape(member.to_string())?;
+    env.app.issue_credential(
+        Binary::from(credential_payload(
+            &authority.did,
+            "urn:uuid:credential-member-2",
+            "did:example:bob",
+            &[member],
+            None,
+        )),
+        Some(CredentialInputFormat::NQuads),
+    )?;
+    let err = env
+        .app
+        .unregister_shape(member.to_string())
+        .expect_err("unknown shape should be rejected");
+    assert!(format!("{err:?}").contains("shape unknown"), "{err:?}");
+
+    Ok(())
+}
+
 fn credential_payload_with_validity(
     authority_did: &str,
     credential_id: &str,
@@ -779,3 +1697,70 @@ fn credential_payload(
 
     payload.into_bytes()
 }
+
+fn status_entry_quads(authority_did: &str, index: u64) -> Vec<Quad> {
+    ["revocation", "suspension"]
+        .into_iter()
+        .flat_map(|purpose| {
+            let list = format!("{authority_did}/credentials/status/{purpose}");
+            let entry = format!("<{list}#{index}>");
+            [
+                (
+                    "<http://www.w3.org/1999/02/22-rdf-syntax-ns#type>",
+                    "<https://www.w3.org/ns/credentials/status#BitstringStatusListEntry>"
+                        .to_string(),
+                ),
+                (
+                    "<https://www.w3.org/ns/credentials/status#statusListCredential>",
+                    format!("<{list}>"),
+                ),
+                (
+                    "<https://www.w3.org/ns/credentials/status#statusListIndex>",
+                    format!("\"{index}\""),
+                ),
+                (
+                    "<https://www.w3.org/ns/credentials/status#statusPurpose>",
+                    format!("\"{purpose}\""),
+                ),
+            ]
+            .map(|(predicate, object)| Quad {
+                subject: entry.clone(),
+                predicate: predicate.to_string(),
+                object,
+                graph_name: None,
+            })
+        })
+        .collect()
+}
+
+fn credential_status_quads(credential_id: &str, authority_did: &str, index: u64) -> Vec<Quad> {
+    ["revocation", "suspension"]
+        .map(|purpose| Quad {
+            subject: format!("<{credential_id}>"),
+            predicate: "<https://www.w3.org/2018/credentials#credentialStatus>".to_string(),
+            object: format!("<{authority_did}/credentials/status/{purpose}#{index}>"),
+            graph_name: None,
+        })
+        .to_vec()
+}
+
+fn to_nquads(quads: &[Quad]) -> String {
+    quads.iter().fold(String::new(), |mut nquads, quad| {
+        nquads.push_str(&format!(
+            "{} {} {} .\n",
+            quad.subject, quad.predicate, quad.object
+        ));
+        nquads
+    })
+}
+
+fn decode_status_list(encoded_list: &str) -> anyhow::Result<Vec<u8>> {
+    let compressed = URL_SAFE_NO_PAD.decode(
+        encoded_list
+            .strip_prefix('u')
+            .ok_or_else(|| anyhow::anyhow!("missing multibase prefix"))?,
+    )?;
+    let mut bitstring = Vec::new();
+    GzDecoder::new(compressed.as_slice()).read_to_end(&mut bitstring)?;
+    Ok(bitstring)
+}
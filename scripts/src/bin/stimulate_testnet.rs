@@ -153,6 +153,7 @@ fn stimulate(network: ChainInfo, args: &Arguments) -> anyhow::Result<()> {
             .install_app::<AxoneGovInterface<_>>(
                 &AxoneGovInstantiateMsg {
                     constitution: Binary::from(CONSTITUTION.as_bytes()),
+                    ..Default::default()
                 },
                 &[],
             )?;
@@ -166,6 +167,7 @@ fn stimulate(network: ChainInfo, args: &Arguments) -> anyhow::Result<()> {
             &gov_execute(AxoneGovExecuteMsg::RecordDecision {
                 case,
                 motivated: Some(false),
+                signature: None,
             }),
             &[],
         )?;
@@ -174,7 +176,7 @@ fn stimulate(network: ChainInfo, args: &Arguments) -> anyhow::Result<()> {
 
     if profile.needs_credential() {
         let app: Application<Daemon, AxoneVcInterface<Daemon>> =
-            account.install_app::<AxoneVcInterface<_>>(&AxoneVcInstantiateMsg {}, &[])?;
+            account.install_app::<AxoneVcInterface<_>>(&AxoneVcInstantiateMsg::default(), &[])?;
         let authority: AuthorityResponse = app.query(&vc_query(AxoneVcQueryMsg::Authority {}))?;
         app.execute(
             &vc_execute(AxoneVcExecuteMsg::IssueCredential {